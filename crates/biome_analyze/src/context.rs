@@ -109,6 +109,14 @@ where
             .expect("Source type is not registered")
     }
 
+    /// Returns the service of type `T` that was registered in the service bag, if any.
+    ///
+    /// It's used to read the optional data, like the manifests of the project, that isn't
+    /// provided by the query of the rule.
+    pub fn get_service<T: 'static>(&self) -> Option<&T> {
+        self.bag.get_service::<T>()
+    }
+
     /// The file path of the current file
     pub fn file_path(&self) -> &Path {
         self.file_path
@@ -37,7 +37,8 @@ pub use crate::registry::{
 };
 pub use crate::rule::{
     CategoryLanguage, FixKind, GroupCategory, GroupLanguage, Rule, RuleAction, RuleDiagnostic,
-    RuleGroup, RuleMeta, RuleMetadata, RuleSource, RuleSourceKind, SuppressAction,
+    RuleGroup, RuleMeta, RuleMetadata, RuleOptionMigration, RuleSource, RuleSourceKind,
+    SuppressAction,
 };
 pub use crate::services::{FromServices, MissingServicesDiagnostic, ServiceBag};
 pub use crate::signals::{
@@ -129,6 +130,7 @@ where
 
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
+        let mut range_suppressions = Vec::new();
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
             let runner = PhaseRunner {
@@ -140,6 +142,7 @@ where
                 parse_suppression_comment,
                 line_index: &mut line_index,
                 line_suppressions: &mut line_suppressions,
+                range_suppressions: &mut range_suppressions,
                 emit_signal: &mut emit_signal,
                 root: &ctx.root,
                 services: &ctx.services,
@@ -172,16 +175,105 @@ where
             }
         }
 
-        for suppression in line_suppressions {
-            if suppression.did_suppress_signal {
-                continue;
+        let unused_suppressions = line_suppressions
+            .iter()
+            .filter(|suppression| !suppression.did_suppress_signal)
+            .map(|suppression| {
+                (
+                    vec![suppression.comment_span],
+                    suppression.suppress_all,
+                    &suppression.suppressed_rules,
+                )
+            })
+            .chain(
+                range_suppressions
+                    .iter()
+                    .filter(|suppression| !suppression.did_suppress_signal)
+                    .map(|suppression| {
+                        // The `// biome-ignore-end` comment is removed with the
+                        // `// biome-ignore-start` comment, unless it also ends
+                        // a suppression that is used
+                        let end_comment_span =
+                            suppression.end_comment_span.filter(|end_comment_span| {
+                                !range_suppressions.iter().any(|other| {
+                                    other.did_suppress_signal
+                                        && other.end_comment_span == Some(*end_comment_span)
+                                })
+                            });
+                        (
+                            std::iter::once(suppression.comment_span)
+                                .chain(end_comment_span)
+                                .collect(),
+                            suppression.suppress_all,
+                            &suppression.suppressed_rules,
+                        )
+                    }),
+            );
+
+        for (comment_spans, suppress_all, suppressed_rules) in unused_suppressions {
+            let comment_span = comment_spans[0];
+            // The suppression can't be used if the configuration doesn't
+            // enable any of the rules it suppresses
+            let is_disabled = !suppress_all
+                && ctx
+                    .options
+                    .configuration
+                    .enabled_rules
+                    .as_deref()
+                    .is_some_and(|enabled_rules| {
+                        suppressed_rules
+                            .iter()
+                            .all(|filter| !filter.matches_any(enabled_rules))
+                    });
+            let disabled_rules = is_disabled.then_some(suppressed_rules);
+
+            let signal = DiagnosticSignal::new(|| match disabled_rules {
+                Some(disabled_rules) => SuppressionDiagnostic::new(
+                    category!("suppressions/unused"),
+                    comment_span,
+                    format_args!(
+                        "Suppression comment has no effect because the {} {} {} disabled",
+                        if disabled_rules.len() == 1 {
+                            "rule"
+                        } else {
+                            "rules"
+                        },
+                        disabled_rules
+                            .iter()
+                            .map(|filter| filter.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        if disabled_rules.len() == 1 {
+                            "is"
+                        } else {
+                            "are"
+                        }
+                    ),
+                ),
+                None => SuppressionDiagnostic::new(
+                    category!("suppressions/unused"),
+                    comment_span,
+                    "Suppression comment is not being used",
+                ),
+            })
+            .with_action(|| remove_suppression_comments(&ctx.root, &comment_spans));
+
+            if let ControlFlow::Break(br) = (emit_signal)(&signal) {
+                return Some(br);
             }
+        }
+
+        // A range suppression that isn't ended applies until the end of the file
+        let unended_suppressions = range_suppressions
+            .iter()
+            .filter(|suppression| !suppression.is_file && suppression.end_comment_span.is_none());
 
+        for suppression in unended_suppressions {
             let signal = DiagnosticSignal::new(|| {
                 SuppressionDiagnostic::new(
-                    category!("suppressions/unused"),
+                    category!("suppressions/unmatched"),
                     suppression.comment_span,
-                    "Suppression comment is not being used",
+                    "This suppression comment isn't ended by a // biome-ignore-end comment, so it applies until the end of the file",
                 )
             });
 
@@ -214,6 +306,9 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     line_index: &'phase mut usize,
     /// Track active suppression comments per-line, ordered by line index
     line_suppressions: &'phase mut Vec<LineSuppression>,
+    /// Track the suppression comments that apply to a range of the file, or
+    /// to the whole file, ordered by start position
+    range_suppressions: &'phase mut Vec<RangeSuppression>,
     /// Handles analyzer signals emitted by individual rules
     emit_signal: &'phase mut SignalHandler<'analyzer, L, Break>,
     /// Root node of the file being analyzed
@@ -246,6 +341,52 @@ struct LineSuppression {
     did_suppress_signal: bool,
 }
 
+/// Single entry for a `// biome-ignore-start` or `// biome-ignore-all`
+/// suppression comment in the `range_suppressions` buffer
+#[derive(Debug)]
+struct RangeSuppression {
+    /// Range of source text covered by the suppression comment
+    comment_span: TextRange,
+    /// Position of the source text this comment starts suppressing lint rules from
+    start: TextSize,
+    /// Range of source text covered by the matching `// biome-ignore-end`
+    /// comment, `None` until this comment is reached
+    end_comment_span: Option<TextRange>,
+    /// Set to true if this comment is a `// biome-ignore-all` comment, in
+    /// which case `// biome-ignore-end` comments don't end it
+    is_file: bool,
+    /// Set to true if this comment suppresses all lint rules
+    suppress_all: bool,
+    /// List of all the rules this comment is suppressing
+    suppressed_rules: Vec<RuleFilter<'static>>,
+    /// Set to `true` when a signal matching this suppression was emitted and
+    /// suppressed
+    did_suppress_signal: bool,
+}
+
+impl RangeSuppression {
+    /// Returns `true` if the source text at `position` is suppressed by this comment
+    fn contains(&self, position: TextSize) -> bool {
+        self.start <= position
+            && self
+                .end_comment_span
+                .map_or(true, |end_comment_span| position < end_comment_span.start())
+    }
+}
+
+/// What a suppression comment applies to
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum SuppressionScope {
+    /// The next line
+    Next,
+    /// The source text until the matching `// biome-ignore-end` comment
+    RangeStart,
+    /// Ends the suppressions of the matching `// biome-ignore-start` comments
+    RangeEnd,
+    /// The whole file, when the comment is placed at the top of the file
+    File,
+}
+
 impl<'a, 'phase, L, Matcher, Break, Diag> PhaseRunner<'a, 'phase, L, Matcher, Break, Diag>
 where
     L: Language,
@@ -411,10 +552,21 @@ where
                     .any(|filter| *filter == entry.rule)
             });
 
-            // If the signal is being suppressed mark the line suppression as
-            // hit, otherwise emit the signal
+            // If the signal is being suppressed mark the line or range
+            // suppression as hit, otherwise emit the signal
             if let Some(suppression) = suppression {
                 suppression.did_suppress_signal = true;
+            } else if let Some(suppression) =
+                self.range_suppressions.iter_mut().find(|suppression| {
+                    suppression.contains(start)
+                        && (suppression.suppress_all
+                            || suppression
+                                .suppressed_rules
+                                .iter()
+                                .any(|filter| *filter == entry.rule))
+                })
+            {
+                suppression.did_suppress_signal = true;
             } else if range_match(self.range, entry.text_range) {
                 (self.emit_signal)(&*entry.signal)?;
             }
@@ -440,6 +592,8 @@ where
         let mut suppress_all = false;
         let mut suppressions = Vec::new();
         let mut has_legacy = false;
+        let mut scope = SuppressionScope::Next;
+        let mut unknown_rules = Vec::new();
 
         for result in (self.parse_suppression_comment)(text) {
             let kind = match result {
@@ -477,6 +631,18 @@ where
                 SuppressionKind::Rule(rule) => Some(rule),
                 SuppressionKind::MaybeLegacy(rule) => Some(rule),
                 SuppressionKind::Deprecated => None,
+                SuppressionKind::RangeStart => {
+                    scope = SuppressionScope::RangeStart;
+                    continue;
+                }
+                SuppressionKind::RangeEnd => {
+                    scope = SuppressionScope::RangeEnd;
+                    continue;
+                }
+                SuppressionKind::File => {
+                    scope = SuppressionScope::File;
+                    continue;
+                }
             };
 
             if let Some(rule) = rule {
@@ -496,22 +662,7 @@ where
                     suppressions.push(key);
                     has_legacy |= matches!(kind, SuppressionKind::MaybeLegacy(_));
                 } else if range_match(self.range, range) {
-                    // Emit a warning for the unknown rule
-                    let signal = DiagnosticSignal::new(move || match group_rule {
-                        Some((group, rule)) => SuppressionDiagnostic::new(
-                            category!("suppressions/unknownRule"),
-                            range,
-                            format_args!("Unknown lint rule {group}/{rule} in suppression comment"),
-                        ),
-
-                        None => SuppressionDiagnostic::new(
-                            category!("suppressions/unknownGroup"),
-                            range,
-                            format_args!("Unknown lint rule group {rule} in suppression comment"),
-                        ),
-                    });
-
-                    (self.emit_signal)(&signal)?;
+                    unknown_rules.push((rule, group_rule));
                 }
             } else {
                 suppressions.clear();
@@ -522,6 +673,35 @@ where
             }
         }
 
+        // Emit a warning for the unknown rules. The comment can be removed if
+        // it doesn't suppress any known rule
+        let is_useless = !suppress_all && suppressions.is_empty();
+        let root = self.root;
+        for (rule, group_rule) in unknown_rules {
+            let signal = DiagnosticSignal::new(move || match group_rule {
+                Some((group, rule)) => SuppressionDiagnostic::new(
+                    category!("suppressions/unknownRule"),
+                    range,
+                    format_args!("Unknown lint rule {group}/{rule} in suppression comment"),
+                ),
+
+                None => SuppressionDiagnostic::new(
+                    category!("suppressions/unknownGroup"),
+                    range,
+                    format_args!("Unknown lint rule group {rule} in suppression comment"),
+                ),
+            })
+            .with_action(move || {
+                if is_useless && scope != SuppressionScope::RangeEnd {
+                    remove_suppression_comments(root, &[range])
+                } else {
+                    None
+                }
+            });
+
+            (self.emit_signal)(&signal)?;
+        }
+
         // Emit a warning for legacy suppression syntax
         if has_legacy && range_match(self.range, range) {
             let signal = DiagnosticSignal::new(move || {
@@ -539,6 +719,80 @@ where
             (self.emit_signal)(&signal)?;
         }
 
+        match scope {
+            SuppressionScope::Next => {}
+            SuppressionScope::File if !is_leading || token.prev_token().is_some() => {
+                // A `// biome-ignore-all` comment must be placed at the top
+                // of the file, before any code, otherwise it's ignored
+                if range_match(self.range, range) {
+                    let signal = DiagnosticSignal::new(move || {
+                        SuppressionDiagnostic::new(
+                            category!("suppressions/misplaced"),
+                            range,
+                            "This // biome-ignore-all comment must be placed at the top of the file, before any code",
+                        )
+                    });
+
+                    (self.emit_signal)(&signal)?;
+                }
+                return ControlFlow::Continue(());
+            }
+            SuppressionScope::RangeStart | SuppressionScope::File => {
+                if suppress_all || !suppressions.is_empty() {
+                    let is_file = scope == SuppressionScope::File;
+                    self.range_suppressions.push(RangeSuppression {
+                        comment_span: range,
+                        start: if is_file {
+                            TextSize::from(0)
+                        } else {
+                            range.end()
+                        },
+                        end_comment_span: None,
+                        is_file,
+                        suppress_all,
+                        suppressed_rules: suppressions,
+                        did_suppress_signal: false,
+                    });
+                }
+                return ControlFlow::Continue(());
+            }
+            SuppressionScope::RangeEnd => {
+                // A bare `// biome-ignore-end` or `// biome-ignore-end lint`
+                // comment ends all the started suppressions
+                let ends_all = suppress_all || suppressions.is_empty();
+                let mut did_end_suppression = false;
+                for suppression in self.range_suppressions.iter_mut() {
+                    if suppression.is_file || suppression.end_comment_span.is_some() {
+                        continue;
+                    }
+                    if ends_all
+                        || suppression
+                            .suppressed_rules
+                            .iter()
+                            .any(|filter| suppressions.contains(filter))
+                    {
+                        suppression.end_comment_span = Some(range);
+                        did_end_suppression = true;
+                    }
+                }
+
+                if !did_end_suppression && range_match(self.range, range) {
+                    let root = self.root;
+                    let signal = DiagnosticSignal::new(move || {
+                        SuppressionDiagnostic::new(
+                            category!("suppressions/unmatched"),
+                            range,
+                            "This // biome-ignore-end comment doesn't end any suppression started by a // biome-ignore-start comment",
+                        )
+                    })
+                    .with_action(move || remove_suppression_comments(root, &[range]));
+
+                    (self.emit_signal)(&signal)?;
+                }
+                return ControlFlow::Continue(());
+            }
+        }
+
         if !suppress_all && suppressions.is_empty() {
             return ControlFlow::Continue(());
         }
@@ -657,6 +911,101 @@ fn create_suppression_comment_action<L: Language>(
     })
 }
 
+/// Creates an action removing the suppression comments at `comment_spans`,
+/// e.g. a `// biome-ignore-start` comment with its `// biome-ignore-end`
+/// comment. When a comment is on its own line, the line is removed too
+fn remove_suppression_comments<L: Language>(
+    root: &L::Root,
+    comment_spans: &[TextRange],
+) -> Option<AnalyzerAction<L>> {
+    // The comments attached to the same token are removed together
+    let mut tokens: Vec<(SyntaxToken<L>, Vec<TextRange>)> = Vec::new();
+    for &comment_span in comment_spans {
+        let token = root
+            .syntax()
+            .token_at_offset(comment_span.start())
+            .find(|token| token.text_range().contains_range(comment_span))?;
+        match tokens.iter_mut().find(|(other, _)| *other == token) {
+            Some((_, token_comment_spans)) => token_comment_spans.push(comment_span),
+            None => tokens.push((token, vec![comment_span])),
+        }
+    }
+
+    let mut mutation = BatchMutation::new(root.syntax().clone());
+    for (token, token_comment_spans) in tokens {
+        let trimmed_start = token.text_trimmed_range().start();
+        let leading_pieces: Vec<_> = token.leading_trivia().pieces().collect();
+        let trailing_pieces: Vec<_> = token.trailing_trivia().pieces().collect();
+
+        let mut is_leading_removed = vec![false; leading_pieces.len()];
+        let mut is_trailing_removed = vec![false; trailing_pieces.len()];
+        for comment_span in token_comment_spans {
+            if comment_span.end() <= trimmed_start {
+                let index = leading_pieces
+                    .iter()
+                    .position(|piece| piece.text_range() == comment_span)?;
+                is_leading_removed[index] = true;
+                // Remove the line break and the indentation that follow the comment
+                let mut end = index + 1;
+                if leading_pieces
+                    .get(end)
+                    .is_some_and(|piece| piece.is_newline())
+                {
+                    is_leading_removed[end] = true;
+                    end += 1;
+                }
+                if leading_pieces
+                    .get(end)
+                    .is_some_and(|piece| piece.is_whitespace())
+                {
+                    is_leading_removed[end] = true;
+                }
+            } else {
+                let index = trailing_pieces
+                    .iter()
+                    .position(|piece| piece.text_range() == comment_span)?;
+                is_trailing_removed[index] = true;
+                // Remove the whitespace that separates the comment from the token
+                if index > 0 && trailing_pieces[index - 1].is_whitespace() {
+                    is_trailing_removed[index - 1] = true;
+                }
+            }
+        }
+
+        let new_leading_trivia = leading_pieces
+            .iter()
+            .zip(is_leading_removed)
+            .filter(|(_, is_removed)| !is_removed)
+            .map(|(piece, _)| (piece.kind(), piece.text()))
+            .collect::<Vec<_>>();
+        let new_trailing_trivia = trailing_pieces
+            .iter()
+            .zip(is_trailing_removed)
+            .filter(|(_, is_removed)| !is_removed)
+            .map(|(piece, _)| (piece.kind(), piece.text()))
+            .collect::<Vec<_>>();
+
+        let new_token = token
+            .clone()
+            .detach()
+            .with_leading_trivia(new_leading_trivia)
+            .with_trailing_trivia(new_trailing_trivia);
+        mutation.replace_token_discard_trivia(token, new_token);
+    }
+
+    Some(AnalyzerAction {
+        rule_name: None,
+        category: ActionCategory::QuickFix,
+        applicability: Applicability::Always,
+        message: if comment_spans.len() == 1 {
+            markup! { "Remove the suppression comment" }.to_owned()
+        } else {
+            markup! { "Remove the suppression comments" }.to_owned()
+        },
+        mutation,
+    })
+}
+
 fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
     filter.map_or(true, |filter| filter.intersect(range).is_some())
 }
@@ -675,6 +1024,9 @@ fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
 /// - `// rome-ignore lint/style/useWhile lint/nursery/noUnreachable` -> `vec![Rule("style/useWhile"), Rule("nursery/noUnreachable")]`
 /// - `// rome-ignore lint(style/useWhile)` -> `vec![MaybeLegacy("style/useWhile")]`
 /// - `// rome-ignore lint(style/useWhile) lint(nursery/noUnreachable)` -> `vec![MaybeLegacy("style/useWhile"), MaybeLegacy("nursery/noUnreachable")]`
+/// - `// biome-ignore-start lint/style/useWhile` -> `vec![RangeStart, Rule("style/useWhile")]`
+/// - `// biome-ignore-end` -> `vec![RangeEnd]`
+/// - `// biome-ignore-all lint` -> `vec![File, Everything]`
 type SuppressionParser<D> = fn(&str) -> Vec<Result<SuppressionKind, D>>;
 
 /// This enum is used to categorize what is disabled by a suppression comment and with what syntax
@@ -687,6 +1039,12 @@ pub enum SuppressionKind<'a> {
     MaybeLegacy(&'a str),
     /// `rome-ignore` is legacy
     Deprecated,
+    /// The suppressions that follow apply until the matching `// biome-ignore-end` comment
+    RangeStart,
+    /// The suppressions that follow end the matching `// biome-ignore-start` suppressions
+    RangeEnd,
+    /// The suppressions that follow apply to the whole file eg. `// biome-ignore-all lint`
+    File,
 }
 
 fn update_suppression<L: Language>(
@@ -752,6 +1110,8 @@ pub struct SuppressionCommentEmitterPayload<'a, L: Language> {
     pub mutation: &'a mut BatchMutation<L>,
     /// A string equals to "rome-ignore: lint(<RULE_GROUP>/<RULE_NAME>)"
     pub suppression_text: &'a str,
+    /// The reason of the suppression, written after the suppression text
+    pub suppression_reason: &'a str,
     /// The original range of the diagnostic where the rule was triggered
     pub diagnostic_text_range: &'a TextRange,
 }
@@ -768,7 +1128,25 @@ pub enum RuleFilter<'a> {
     Rule(&'a str, &'a str),
 }
 
-impl RuleFilter<'_> {
+impl<'a> RuleFilter<'a> {
+    /// Return `true` if one of the rules or groups of `filters` matches this filter
+    pub fn matches_any(self, filters: &[RuleFilter]) -> bool {
+        filters.iter().any(|filter| match (self, *filter) {
+            (RuleFilter::Group(group), filter) | (filter, RuleFilter::Group(group)) => {
+                filter.group() == group
+            }
+            (RuleFilter::Rule(..), RuleFilter::Rule(..)) => self == *filter,
+        })
+    }
+
+    /// Return the group of this filter
+    fn group(self) -> &'a str {
+        match self {
+            RuleFilter::Group(group) => group,
+            RuleFilter::Rule(group, _) => group,
+        }
+    }
+
     /// Return `true` if the group `G` matches this filter
     fn match_group<G: RuleGroup>(self) -> bool {
         match self {
@@ -1,6 +1,6 @@
 use rustc_hash::FxHashMap;
 
-use crate::{Rule, RuleKey};
+use crate::{Rule, RuleFilter, RuleKey};
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
 use std::path::PathBuf;
@@ -56,6 +56,10 @@ pub struct AnalyzerConfiguration {
 
     /// Allows to choose a different quote when applying fixes inside the lint rules
     pub preferred_quote: PreferredQuote,
+
+    /// The rules enabled by the configuration, used to report the suppression
+    /// comments of the disabled rules. [None] when the configuration isn't known
+    pub enabled_rules: Option<Vec<RuleFilter<'static>>>,
 }
 
 /// A set of information useful to the analyzer infrastructure
@@ -66,6 +70,10 @@ pub struct AnalyzerOptions {
 
     /// The file that is being analyzed
     pub file_path: PathBuf,
+
+    /// The reason written in the suppression comments created by the
+    /// suppression actions, `<explanation>` is used when it's [None]
+    pub suppression_reason: Option<String>,
 }
 impl AnalyzerOptions {
     pub fn globals(&self) -> Vec<&str> {
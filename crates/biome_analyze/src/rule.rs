@@ -36,6 +36,33 @@ pub struct RuleMetadata {
     pub source: Option<RuleSource>,
     /// The source kind of the rule
     pub source_kind: Option<RuleSourceKind>,
+    /// The names that the rule had in the previous versions, in the form `group/ruleName`.
+    /// `biome migrate` rewrites the configuration files that still use them.
+    pub previous_names: &'static [&'static str],
+    /// The changes of the options of the rule across the versions, applied by `biome migrate`
+    pub option_migrations: &'static [RuleOptionMigration],
+}
+
+/// A change of the options of a rule, which `biome migrate` applies to the configuration files
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RuleOptionMigration {
+    /// The option `from` was renamed `to`
+    Rename {
+        from: &'static str,
+        to: &'static str,
+    },
+    /// The option was removed, because the rule doesn't use it anymore
+    Remove(&'static str),
+}
+
+impl RuleOptionMigration {
+    /// The name of the option in the configuration files to migrate
+    pub const fn option_name(&self) -> &'static str {
+        match self {
+            Self::Rename { from, .. } => from,
+            Self::Remove(name) => name,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -181,6 +208,28 @@ impl RuleSource {
         (self.to_rule_url(), self.as_rule_name())
     }
 
+    /// The name used to configure the rule in an ESLint configuration file, prefixed
+    /// with the namespace of its plugin. It's [None] for the sources that aren't ESLint rules.
+    pub fn to_eslint_rule_name(&self) -> Option<String> {
+        let namespace = match self {
+            Self::Clippy(_) => return None,
+            Self::Eslint(_) => return Some(self.as_rule_name().to_string()),
+            Self::EslintImport(_) => "import",
+            Self::EslintImportAccess(_) => "import-access",
+            Self::EslintJest(_) => "jest",
+            Self::EslintJsxA11y(_) => "jsx-a11y",
+            Self::EslintReact(_) => "react",
+            Self::EslintReactHooks(_) => "react-hooks",
+            Self::EslintTypeScript(_) => "@typescript-eslint",
+            Self::EslintSonarJs(_) => "sonarjs",
+            Self::EslintStylistic(_) => "@stylistic",
+            Self::EslintUnicorn(_) => "unicorn",
+            Self::EslintMysticatea(_) => "@mysticatea",
+            Self::EslintBarrelFiles(_) => "barrel-files",
+        };
+        Some(format!("{namespace}/{}", self.as_rule_name()))
+    }
+
     /// Original ESLint rule
     pub const fn is_eslint(&self) -> bool {
         matches!(self, Self::Eslint(_))
@@ -238,6 +287,8 @@ impl RuleMetadata {
             fix_kind: None,
             source: None,
             source_kind: None,
+            previous_names: &[],
+            option_migrations: &[],
         }
     }
 
@@ -268,6 +319,19 @@ impl RuleMetadata {
         self.source_kind = Some(source_kind);
         self
     }
+
+    pub const fn previous_names(mut self, previous_names: &'static [&'static str]) -> Self {
+        self.previous_names = previous_names;
+        self
+    }
+
+    pub const fn option_migrations(
+        mut self,
+        option_migrations: &'static [RuleOptionMigration],
+    ) -> Self {
+        self.option_migrations = option_migrations;
+        self
+    }
 }
 
 pub trait RuleMeta {
@@ -553,10 +617,14 @@ pub trait Rule: RuleMeta + Sized {
 
     /// Create a code action that allows to suppress the rule. The function
     /// returns the node to which the suppression comment is applied.
+    ///
+    /// The `suppression_reason` is written after the rule name, it defaults to
+    /// `<explanation>`.
     fn suppress(
         ctx: &RuleContext<Self>,
         text_range: &TextRange,
         apply_suppression_comment: SuppressionCommentEmitter<RuleLanguage<Self>>,
+        suppression_reason: Option<&str>,
     ) -> Option<SuppressAction<RuleLanguage<Self>>>
     where
         Self: 'static,
@@ -574,6 +642,7 @@ pub trait Rule: RuleMeta + Sized {
             let mut mutation = root.begin();
             apply_suppression_comment(SuppressionCommentEmitterPayload {
                 suppression_text: suppression_text.as_str(),
+                suppression_reason: suppression_reason.unwrap_or("<explanation>"),
                 mutation: &mut mutation,
                 token_offset: token,
                 diagnostic_text_range: text_range,
@@ -1,28 +1,137 @@
 use crate::CliDiagnostic;
 use biome_fs::FileSystem;
+use biome_rowan::{TextRange, TextSize};
 use biome_service::{DynRef, PartialConfiguration};
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use std::collections::BTreeMap;
 use std::ffi::OsString;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 
 pub(crate) fn get_changed_files(
     fs: &DynRef<'_, dyn FileSystem>,
     configuration: &PartialConfiguration,
     since: Option<String>,
 ) -> Result<Vec<OsString>, CliDiagnostic> {
+    let base = resolve_base(configuration, since.as_deref())?;
+
+    let changed_files = fs.get_changed_files(base)?;
+
+    let filtered_changed_files = changed_files.iter().map(OsString::from).collect::<Vec<_>>();
+
+    Ok(filtered_changed_files)
+}
+
+/// Returns the lines that changed in each file, compared to the same base of [get_changed_files]
+pub(crate) fn get_changed_lines(
+    fs: &DynRef<'_, dyn FileSystem>,
+    configuration: &PartialConfiguration,
+    since: Option<String>,
+) -> Result<ChangedLines, CliDiagnostic> {
+    let base = resolve_base(configuration, since.as_deref())?;
+
+    let changed_files = fs.get_changed_lines(base)?;
+
+    Ok(ChangedLines(
+        changed_files
+            .into_iter()
+            .map(|changed_file| (PathBuf::from(changed_file.path), changed_file.lines))
+            .collect(),
+    ))
+}
+
+pub(crate) fn get_staged_files(
+    fs: &DynRef<'_, dyn FileSystem>,
+) -> Result<Vec<OsString>, CliDiagnostic> {
+    let staged_files = fs.get_staged_files()?;
+
+    Ok(staged_files.iter().map(OsString::from).collect())
+}
+
+fn resolve_base<'a>(
+    configuration: &'a PartialConfiguration,
+    since: Option<&'a str>,
+) -> Result<&'a str, CliDiagnostic> {
     let default_branch = configuration
         .vcs
         .as_ref()
-        .and_then(|v| v.default_branch.as_ref());
+        .and_then(|v| v.default_branch.as_deref());
 
-    let base = match (since.as_ref(), default_branch) {
-        (Some(since), Some(_)) => since,
-        (Some(since), None) => since,
-        (None, Some(branch)) => branch,
-        (None, None) => return Err(CliDiagnostic::incompatible_end_configuration("The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.")),
-    };
+    match (since, default_branch) {
+        (Some(since), Some(_)) => Ok(since),
+        (Some(since), None) => Ok(since),
+        (None, Some(branch)) => Ok(branch),
+        (None, None) => Err(CliDiagnostic::incompatible_end_configuration("The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.")),
+    }
+}
 
-    let changed_files = fs.get_changed_files(base)?;
+/// The lines that changed in each file, used to report only the diagnostics of the new code
+#[derive(Debug, Default)]
+pub(crate) struct ChangedLines(BTreeMap<PathBuf, Vec<Range<u32>>>);
 
-    let filtered_changed_files = changed_files.iter().map(OsString::from).collect::<Vec<_>>();
+impl ChangedLines {
+    /// The paths of the files that changed
+    pub(crate) fn paths(&self) -> Vec<OsString> {
+        self.0
+            .keys()
+            .map(|path| path.as_os_str().to_os_string())
+            .collect()
+    }
 
-    Ok(filtered_changed_files)
+    /// Whether the `range` of the `content` of the file at `path` spans over at least a line
+    /// that changed
+    pub(crate) fn intersects(&self, path: &Path, content: &str, range: TextRange) -> bool {
+        let Some(lines) = self.0.get(path) else {
+            return false;
+        };
+
+        let start_line = line_number(content, range.start());
+        let end_line = line_number(content, range.end());
+
+        lines
+            .iter()
+            .any(|lines| lines.start <= end_line && start_line < lines.end)
+    }
+
+    /// Whether rewriting the `old` content of the file at `path` into `new`, e.g. when it's
+    /// formatted, modifies at least a line that changed
+    pub(crate) fn intersects_edit(&self, path: &Path, old: &str, new: &str) -> bool {
+        let edit = TextEdit::from_unicode_words(old, new);
+        let mut position = TextSize::from(0);
+        for op in &edit {
+            match op {
+                CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                    position += range.len();
+                }
+                CompressedOp::DiffOp(DiffOp::Insert { .. }) => {
+                    if self.intersects(path, old, TextRange::empty(position)) {
+                        return true;
+                    }
+                }
+                CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                    if self.intersects(path, old, TextRange::at(position, range.len())) {
+                        return true;
+                    }
+                    position += range.len();
+                }
+                CompressedOp::EqualLines { line_count } => {
+                    let start = usize::from(position).min(old.len());
+                    let line_break_count = line_count.get() as usize + 1;
+                    for line in old[start..].split_inclusive('\n').take(line_break_count) {
+                        position += TextSize::of(line);
+                    }
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Returns the one-based number of the line that contains `offset`
+fn line_number(content: &str, offset: TextSize) -> u32 {
+    let offset = usize::from(offset).min(content.len());
+    let newlines = content
+        .get(..offset)
+        .map_or(0, |content| content.matches('\n').count());
+    newlines as u32 + 1
 }
@@ -1,8 +1,12 @@
-use crate::changed::get_changed_files;
+use crate::changed::{get_changed_files, get_changed_lines, get_staged_files};
 use crate::cli_options::CliOptions;
-use crate::commands::{get_stdin, resolve_manifest, validate_configuration_diagnostics};
+use crate::commands::{
+    get_stdin, nested_paths, resolve_manifest, update_nested_settings,
+    validate_configuration_diagnostics,
+};
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, hash_configuration, setup_cli_subscriber, CliDiagnostic, CliSession, Execution,
+    TraversalMode,
 };
 use biome_deserialize::Merge;
 use biome_service::configuration::organize_imports::PartialOrganizeImports;
@@ -26,11 +30,16 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) organize_imports_enabled: Option<bool>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) staged: bool,
+    pub(crate) watch: bool,
+    pub(crate) cache: bool,
+    pub(crate) cache_location: Option<String>,
 }
 
 /// Handler for the "check" command of the Biome CLI
 pub(crate) fn check(
-    session: CliSession,
+    mut session: CliSession,
     payload: CheckCommandPayload,
 ) -> Result<(), CliDiagnostic> {
     let CheckCommandPayload {
@@ -44,7 +53,12 @@ pub(crate) fn check(
         organize_imports_enabled,
         formatter_enabled,
         since,
+        changed_lines,
         changed,
+        staged,
+        watch,
+        cache,
+        cache_location,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
@@ -112,6 +126,7 @@ pub(crate) fn check(
     }
 
     // check if support of git ignore files is enabled
+    let root_directory = configuration_path.clone();
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
     let (vcs_base_path, gitignore_matches) =
         fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
@@ -122,9 +137,34 @@ pub(crate) fn check(
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
-    if changed {
+    if changed_lines && !changed {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "changed-lines",
+            "changed",
+        ));
+    }
+
+    if changed && staged {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed",
+            "--staged",
+        ));
+    }
+
+    let mut changed_lines_filter = None;
+    if changed && changed_lines {
+        let changed_lines = get_changed_lines(&session.app.fs, &fs_configuration, since)?;
+        paths = changed_lines.paths();
+        changed_lines_filter = Some(changed_lines);
+    } else if changed {
         paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
     }
+
+    if staged {
+        paths = get_staged_files(&session.app.fs)?;
+    }
+
+    let configuration_hash = cache.then(|| hash_configuration(&fs_configuration));
     session
         .app
         .workspace
@@ -133,13 +173,26 @@ pub(crate) fn check(
             configuration: fs_configuration,
             vcs_base_path,
             gitignore_matches,
+            nested_directory: None,
         })?;
+    let configuration_hash = update_nested_settings(
+        &mut session,
+        &cli_options,
+        &nested_paths(&paths, stdin.as_ref()),
+        root_directory.as_deref(),
+        configuration_hash,
+    )?;
 
     execute_mode(
         Execution::new(TraversalMode::Check {
             fix_file_mode,
             stdin,
-        }),
+        })
+        .with_cache(configuration_hash, cache_location)
+        .with_root_directory(root_directory)
+        .with_watch(watch)
+        .with_staged(staged)
+        .with_changed_lines(changed_lines_filter),
         session,
         &cli_options,
         paths,
@@ -1,7 +1,9 @@
-use crate::changed::get_changed_files;
+use crate::changed::{get_changed_files, get_changed_lines};
 use crate::cli_options::CliOptions;
-use crate::commands::validate_configuration_diagnostics;
-use crate::{execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution};
+use crate::commands::{update_nested_settings, validate_configuration_diagnostics};
+use crate::{
+    execute_mode, hash_configuration, setup_cli_subscriber, CliDiagnostic, CliSession, Execution,
+};
 use biome_deserialize::Merge;
 use biome_service::configuration::organize_imports::PartialOrganizeImports;
 use biome_service::configuration::{
@@ -21,10 +23,13 @@ pub(crate) struct CiCommandPayload {
     pub(crate) cli_options: CliOptions,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) cache: bool,
+    pub(crate) cache_location: Option<String>,
 }
 
 /// Handler for the "ci" command of the Biome CLI
-pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), CliDiagnostic> {
+pub(crate) fn ci(mut session: CliSession, payload: CiCommandPayload) -> Result<(), CliDiagnostic> {
     let CiCommandPayload {
         cli_options,
         formatter_enabled,
@@ -33,7 +38,10 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
         configuration,
         mut paths,
         since,
+        changed_lines,
         changed,
+        cache,
+        cache_location,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
@@ -95,6 +103,7 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
     }
 
     // check if support of git ignore files is enabled
+    let root_directory = configuration_path.clone();
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
     let (vcs_base_path, gitignore_matches) =
         fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
@@ -103,10 +112,24 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
-    if changed {
+    if changed_lines && !changed {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "changed-lines",
+            "changed",
+        ));
+    }
+
+    let mut changed_lines_filter = None;
+    if changed && changed_lines {
+        let changed_lines = get_changed_lines(&session.app.fs, &fs_configuration, since)?;
+        paths = changed_lines.paths();
+        changed_lines_filter = Some(changed_lines);
+    } else if changed {
         paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
     }
 
+    let configuration_hash = cache.then(|| hash_configuration(&fs_configuration));
+
     session
         .app
         .workspace
@@ -115,7 +138,23 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
             working_directory: session.app.fs.working_directory(),
             vcs_base_path,
             gitignore_matches,
+            nested_directory: None,
         })?;
+    let configuration_hash = update_nested_settings(
+        &mut session,
+        &cli_options,
+        &paths,
+        root_directory.as_deref(),
+        configuration_hash,
+    )?;
 
-    execute_mode(Execution::new_ci(), session, &cli_options, paths)
+    execute_mode(
+        Execution::new_ci()
+            .with_cache(configuration_hash, cache_location)
+            .with_root_directory(root_directory)
+            .with_changed_lines(changed_lines_filter),
+        session,
+        &cli_options,
+        paths,
+    )
 }
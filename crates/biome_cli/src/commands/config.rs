@@ -0,0 +1,326 @@
+use crate::cli_options::CliOptions;
+use crate::commands::validate_configuration_diagnostics;
+use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{markup, ConsoleExt};
+use biome_deserialize::Merge;
+use biome_service::configuration::{load_configuration, load_nested_configurations};
+use biome_service::settings::WorkspaceSettings;
+use biome_service::{Configuration, PartialConfiguration};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The keys of the configuration that don't configure the tools, and aren't printed
+const IGNORED_KEYS: &[&str] = &["$schema", "schema", "extends", "overrides"];
+
+/// Handler for the "config print" command of the Biome CLI
+pub(crate) fn print(
+    session: CliSession,
+    cli_options: CliOptions,
+    configuration: Option<PartialConfiguration>,
+    path: PathBuf,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+
+    let fs = &session.app.fs;
+    let working_directory = fs.working_directory();
+    let mut loaded_configuration =
+        load_configuration(fs, cli_options.as_configuration_base_path())?;
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+
+    let cli_configuration = configuration.map(|mut configuration| {
+        if let Some(linter) = configuration.linter.as_mut() {
+            // Same as the other commands: the rules of the CLI configuration only contain defaults
+            linter.rules = None;
+        }
+        configuration
+    });
+    if let Some(cli_configuration) = cli_configuration.clone() {
+        loaded_configuration
+            .configuration
+            .merge_with(cli_configuration);
+    }
+
+    let nested_configurations = load_nested_configurations(
+        fs,
+        &*session.app.workspace,
+        &[path.clone()],
+        loaded_configuration.directory_path.as_deref(),
+    )?;
+    let mut settings = WorkspaceSettings::default();
+    settings.merge_with_configuration(
+        loaded_configuration.configuration.clone(),
+        working_directory.clone(),
+        None,
+        &[],
+    )?;
+    for nested_configuration in &nested_configurations {
+        validate_configuration_diagnostics(
+            nested_configuration,
+            session.app.console,
+            cli_options.verbose,
+        )?;
+        if let Some(directory) = nested_configuration.directory_path.clone() {
+            settings.merge_with_nested_configuration(
+                directory,
+                nested_configuration.configuration.clone(),
+                None,
+                &[],
+            )?;
+        }
+    }
+
+    // The nested configuration files replace the configuration of the workspace, and they aren't
+    // affected by the arguments of the command line
+    let path_settings = settings.for_path(&path);
+    let (loaded_configuration, cli_configuration) = match path_settings.directory.as_ref() {
+        Some(directory) => (
+            nested_configurations
+                .iter()
+                .find(|configuration| configuration.directory_path.as_ref() == Some(directory))
+                .unwrap_or(&loaded_configuration),
+            None,
+        ),
+        None => (&loaded_configuration, cli_configuration),
+    };
+
+    let mut values = ConfigurationValues::default();
+    values.insert_layer(&Configuration::default(), "default".to_string(), true);
+    for source in &loaded_configuration.sources {
+        // The files loaded through `extends` are printed with the files that extend them
+        let mut chain = source
+            .chain
+            .iter()
+            .map(|path| {
+                relative_path(path, working_directory.as_deref())
+                    .display()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        let file_path = chain.pop().unwrap_or_default();
+        let source_name = if chain.is_empty() {
+            file_path
+        } else {
+            format!("{file_path} via {}", chain.join(" > "))
+        };
+        values.insert_layer(&source.configuration, source_name, false);
+    }
+    if let Some(cli_configuration) = cli_configuration {
+        values.insert_layer(&cli_configuration, "command line".to_string(), false);
+    }
+    // The values of the layers are merged, but some values, like the lists of globs, are
+    // the union of every layer
+    values.update_values(&loaded_configuration.configuration);
+
+    let overrides = loaded_configuration
+        .configuration
+        .overrides
+        .as_ref()
+        .map(|overrides| overrides.0.as_slice())
+        .unwrap_or_default();
+    for (index, (pattern, pattern_settings)) in overrides
+        .iter()
+        .zip(path_settings.override_settings.patterns.iter())
+        .enumerate()
+    {
+        let included =
+            !pattern_settings.include.is_empty() && pattern_settings.include.matches_path(&path);
+        let excluded =
+            !pattern_settings.exclude.is_empty() && pattern_settings.exclude.matches_path(&path);
+        if included && !excluded {
+            let mut pattern = pattern.clone();
+            pattern.include = None;
+            pattern.ignore = None;
+            values.insert_layer(&pattern, format!("overrides[{index}]"), false);
+        }
+    }
+
+    let enabled_rules = if path_settings.linter.enabled {
+        path_settings
+            .as_rules(&path)
+            .map(|rules| {
+                rules
+                    .as_enabled_rules()
+                    .into_iter()
+                    .map(|rule| rule.to_string())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    let path = path.display().to_string();
+    session.app.console.log(markup! {
+        "The configuration applied to "<Emphasis>{path}</Emphasis>":\n\n"
+        {values}
+    });
+    if enabled_rules.is_empty() {
+        session.app.console.log(markup! {
+            "No lint rule is enabled for "<Emphasis>{path}</Emphasis>"."
+        });
+    } else {
+        session.app.console.log(markup! {
+            "The lint rules enabled for "<Emphasis>{path}</Emphasis>":\n\n"
+            {EnabledRules(&enabled_rules)}
+        });
+    }
+
+    Ok(())
+}
+
+/// A value of the configuration, with the configuration layers that set it
+struct ConfigurationValue {
+    value: Value,
+    sources: Vec<String>,
+}
+
+/// The values of the configuration, indexed by their path in the configuration file, like
+/// `formatter.indentStyle`
+#[derive(Default)]
+struct ConfigurationValues(BTreeMap<String, ConfigurationValue>);
+
+impl ConfigurationValues {
+    /// Sets the values of `layer`, a configuration or a part of it, named `source`.
+    ///
+    /// `is_snake_case` tells whether the keys of the serialized `layer` must be converted to
+    /// camel case, which is the case for the configuration types that aren't partial.
+    fn insert_layer(&mut self, layer: &impl Serialize, source: String, is_snake_case: bool) {
+        let Ok(layer) = serde_json::to_value(layer) else {
+            return;
+        };
+        let mut values = Vec::new();
+        flatten(String::new(), layer, is_snake_case, &mut values);
+        for (key, value) in values {
+            self.insert(key, value, &source);
+        }
+    }
+
+    fn insert(&mut self, key: String, value: Value, source: &str) {
+        // A rule can be set as a severity, `rule`, or as an object, `rule.level`
+        self.0
+            .retain(|existing, _| !is_child_key(existing, &key) && !is_child_key(&key, existing));
+        match self.0.get_mut(&key) {
+            // The lists of the configuration files are merged
+            Some(existing)
+                if existing.value.is_array()
+                    && value.is_array()
+                    && existing.sources.iter().all(|source| source != "default") =>
+            {
+                existing.value = value;
+                existing.sources.push(source.to_string());
+            }
+            _ => {
+                self.0.insert(
+                    key,
+                    ConfigurationValue {
+                        value,
+                        sources: vec![source.to_string()],
+                    },
+                );
+            }
+        }
+    }
+
+    /// Replaces the values with the ones of the merged `configuration`
+    fn update_values(&mut self, configuration: &PartialConfiguration) {
+        let Ok(configuration) = serde_json::to_value(configuration) else {
+            return;
+        };
+        let mut values = Vec::new();
+        flatten(String::new(), configuration, false, &mut values);
+        for (key, value) in values {
+            if let Some(existing) = self.0.get_mut(&key) {
+                existing.value = value;
+            }
+        }
+    }
+}
+
+impl Display for ConfigurationValues {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        for (key, ConfigurationValue { value, sources }) in &self.0 {
+            fmt.write_markup(markup! {
+                "  "{key}": "{value.to_string()}" "<Dim>"("{sources.join(", ")}")"</Dim>"\n"
+            })?;
+        }
+        Ok(())
+    }
+}
+
+struct EnabledRules<'a>(&'a [String]);
+
+impl Display for EnabledRules<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        for rule in self.0 {
+            fmt.write_markup(markup! {
+                "  "{rule}"\n"
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether `key` is a child of `parent`, like `linter.enabled` is a child of `linter`
+fn is_child_key(key: &str, parent: &str) -> bool {
+    key.strip_prefix(parent)
+        .is_some_and(|rest| rest.starts_with('.'))
+}
+
+/// Pushes the values of `value` to `values`, with the dotted path of their keys.
+///
+/// The `null` values and the empty objects are skipped.
+fn flatten(prefix: String, value: Value, is_snake_case: bool, values: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Null => {}
+        Value::Object(object) => {
+            for (key, value) in object {
+                let key = if is_snake_case {
+                    to_camel_case(&key)
+                } else {
+                    key
+                };
+                if prefix.is_empty() && IGNORED_KEYS.contains(&key.as_str()) {
+                    continue;
+                }
+                let key = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(key, value, is_snake_case, values);
+            }
+        }
+        value => values.push((prefix, value)),
+    }
+}
+
+fn to_camel_case(key: &str) -> String {
+    let mut result = String::with_capacity(key.len());
+    let mut is_word_start = false;
+    for character in key.chars() {
+        if character == '_' {
+            is_word_start = true;
+        } else if is_word_start {
+            result.push(character.to_ascii_uppercase());
+            is_word_start = false;
+        } else {
+            result.push(character);
+        }
+    }
+    result
+}
+
+/// Returns `path` relative to the working directory, when it's inside it
+fn relative_path<'a>(path: &'a Path, working_directory: Option<&Path>) -> &'a Path {
+    working_directory
+        .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+        .unwrap_or(path)
+}
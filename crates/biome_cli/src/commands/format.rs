@@ -1,10 +1,14 @@
-use crate::changed::get_changed_files;
+use crate::changed::{get_changed_files, get_staged_files};
 use crate::cli_options::CliOptions;
-use crate::commands::{get_stdin, resolve_manifest, validate_configuration_diagnostics};
+use crate::commands::{
+    get_stdin, nested_paths, resolve_manifest, update_nested_settings,
+    validate_configuration_diagnostics,
+};
 use crate::diagnostics::DeprecatedArgument;
 use crate::execute::ReportMode;
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, hash_configuration, setup_cli_subscriber, CliDiagnostic, CliSession, Execution,
+    TraversalMode,
 };
 use biome_console::{markup, ConsoleExt};
 use biome_deserialize::Merge;
@@ -30,11 +34,15 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) paths: Vec<OsString>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) watch: bool,
+    pub(crate) cache: bool,
+    pub(crate) cache_location: Option<String>,
 }
 
 /// Handler for the "format" command of the Biome CLI
 pub(crate) fn format(
-    session: CliSession,
+    mut session: CliSession,
     payload: FormatCommandPayload,
 ) -> Result<(), CliDiagnostic> {
     let FormatCommandPayload {
@@ -50,6 +58,10 @@ pub(crate) fn format(
         mut css_formatter,
         since,
         changed,
+        staged,
+        watch,
+        cache,
+        cache_location,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
@@ -150,6 +162,7 @@ pub(crate) fn format(
     configuration.vcs.merge_with(vcs_configuration);
 
     // check if support of git ignore files is enabled
+    let root_directory = configuration_path.clone();
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
     let (vcs_base_path, gitignore_matches) =
         configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
@@ -158,10 +171,23 @@ pub(crate) fn format(
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    if changed && staged {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed",
+            "--staged",
+        ));
+    }
+
     if changed {
         paths = get_changed_files(&session.app.fs, &configuration, since)?;
     }
 
+    if staged {
+        paths = get_staged_files(&session.app.fs)?;
+    }
+
+    let configuration_hash = cache.then(|| hash_configuration(&configuration));
+
     session
         .app
         .workspace
@@ -170,10 +196,19 @@ pub(crate) fn format(
             configuration,
             vcs_base_path,
             gitignore_matches,
+            nested_directory: None,
         })?;
 
     let stdin = get_stdin(stdin_file_path, console, "format")?;
 
+    let configuration_hash = update_nested_settings(
+        &mut session,
+        &cli_options,
+        &nested_paths(&paths, stdin.as_ref()),
+        root_directory.as_deref(),
+        configuration_hash,
+    )?;
+
     let execution = if cli_options.json {
         Execution::with_report(
             TraversalMode::Format {
@@ -191,5 +226,14 @@ pub(crate) fn format(
         })
     };
 
-    execute_mode(execution, session, &cli_options, paths)
+    execute_mode(
+        execution
+            .with_cache(configuration_hash, cache_location)
+            .with_root_directory(root_directory)
+            .with_watch(watch)
+            .with_staged(staged),
+        session,
+        &cli_options,
+        paths,
+    )
 }
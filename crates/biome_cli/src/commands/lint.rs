@@ -1,8 +1,12 @@
-use crate::changed::get_changed_files;
+use crate::changed::{get_changed_files, get_changed_lines, get_staged_files};
 use crate::cli_options::CliOptions;
-use crate::commands::{get_stdin, resolve_manifest, validate_configuration_diagnostics};
+use crate::commands::{
+    get_stdin, nested_paths, resolve_manifest, update_nested_settings,
+    validate_configuration_diagnostics,
+};
 use crate::{
-    execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
+    execute_mode, hash_configuration, setup_cli_subscriber, CliDiagnostic, CliSession, Execution,
+    TraversalMode,
 };
 use biome_deserialize::Merge;
 use biome_service::configuration::vcs::PartialVcsConfiguration;
@@ -16,6 +20,9 @@ use std::ffi::OsString;
 pub(crate) struct LintCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) suppress: bool,
+    pub(crate) reason: Option<String>,
+    pub(crate) rule: Vec<String>,
     pub(crate) cli_options: CliOptions,
     pub(crate) linter_configuration: Option<PartialLinterConfiguration>,
     pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
@@ -24,13 +31,24 @@ pub(crate) struct LintCommandPayload {
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) staged: bool,
+    pub(crate) watch: bool,
+    pub(crate) cache: bool,
+    pub(crate) cache_location: Option<String>,
 }
 
 /// Handler for the "lint" command of the Biome CLI
-pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(), CliDiagnostic> {
+pub(crate) fn lint(
+    mut session: CliSession,
+    payload: LintCommandPayload,
+) -> Result<(), CliDiagnostic> {
     let LintCommandPayload {
         apply,
         apply_unsafe,
+        suppress,
+        reason,
+        rule,
         cli_options,
         mut linter_configuration,
         mut paths,
@@ -39,6 +57,11 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         files_configuration,
         changed,
         since,
+        changed_lines,
+        staged,
+        watch,
+        cache,
+        cache_location,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
@@ -47,6 +70,23 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
             "--apply",
             "--apply-unsafe",
         ));
+    } else if suppress && (apply || apply_unsafe) {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--suppress",
+            if apply { "--apply" } else { "--apply-unsafe" },
+        ));
+    } else if reason.is_some() && !suppress {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--reason",
+            "--suppress",
+        ));
+    } else if !rule.is_empty() && !suppress {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--rule",
+            "--suppress",
+        ));
+    } else if suppress {
+        Some(FixFileMode::ApplySuppressions)
     } else if !apply && !apply_unsafe {
         None
     } else if apply && !apply_unsafe {
@@ -89,6 +129,7 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
     });
 
     // check if support of git ignore files is enabled
+    let root_directory = configuration_path.clone();
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
     let (vcs_base_path, gitignore_matches) =
         fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
@@ -97,12 +138,37 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
-    if changed {
+    if changed_lines && !changed {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "changed-lines",
+            "changed",
+        ));
+    }
+
+    if changed && staged {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed",
+            "--staged",
+        ));
+    }
+
+    let mut changed_lines_filter = None;
+    if changed && changed_lines {
+        let changed_lines = get_changed_lines(&session.app.fs, &fs_configuration, since)?;
+        paths = changed_lines.paths();
+        changed_lines_filter = Some(changed_lines);
+    } else if changed {
         paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
     }
 
+    if staged {
+        paths = get_staged_files(&session.app.fs)?;
+    }
+
     let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "lint")?;
 
+    let configuration_hash = cache.then(|| hash_configuration(&fs_configuration));
+
     session
         .app
         .workspace
@@ -111,13 +177,28 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
             configuration: fs_configuration,
             vcs_base_path,
             gitignore_matches,
+            nested_directory: None,
         })?;
+    let configuration_hash = update_nested_settings(
+        &mut session,
+        &cli_options,
+        &nested_paths(&paths, stdin.as_ref()),
+        root_directory.as_deref(),
+        configuration_hash,
+    )?;
 
     execute_mode(
         Execution::new(TraversalMode::Lint {
             fix_file_mode,
+            suppression_reason: reason,
+            suppressed_rules: rule,
             stdin,
-        }),
+        })
+        .with_cache(configuration_hash, cache_location)
+        .with_root_directory(root_directory)
+        .with_watch(watch)
+        .with_staged(staged)
+        .with_changed_lines(changed_lines_filter),
         session,
         &cli_options,
         paths,
@@ -1,4 +1,5 @@
 use crate::cli_options::CliOptions;
+use crate::commands::MigrateSubCommand;
 use crate::diagnostics::MigrationDiagnostic;
 use crate::execute::{execute_mode, Execution, TraversalMode};
 use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
@@ -11,7 +12,7 @@ pub(crate) fn migrate(
     session: CliSession,
     cli_options: CliOptions,
     write: bool,
-    prettier: bool,
+    sub_command: Option<MigrateSubCommand>,
 ) -> Result<(), CliDiagnostic> {
     let base_path = match cli_options.config_path.as_ref() {
         None => ConfigurationBasePath::default(),
@@ -22,6 +23,7 @@ pub(crate) fn migrate(
         diagnostics: _,
         directory_path,
         file_path,
+        ..
     } = load_configuration(&session.app.fs, base_path)?;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
@@ -31,7 +33,7 @@ pub(crate) fn migrate(
                 write,
                 configuration_file_path: path,
                 configuration_directory_path: directory_path,
-                prettier,
+                sub_command,
             }),
             session,
             &cli_options,
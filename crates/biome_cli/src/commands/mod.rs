@@ -2,7 +2,7 @@ use crate::cli_options::{cli_options, CliOptions, ColorsArg};
 use crate::diagnostics::DeprecatedConfigurationFile;
 use crate::execute::Stdin;
 use crate::logging::LoggingKind;
-use crate::{CliDiagnostic, CliSession, LoggingLevel, VERSION};
+use crate::{hash_nested_configuration, CliDiagnostic, CliSession, LoggingLevel, VERSION};
 use biome_console::{markup, Console, ConsoleExt};
 use biome_diagnostics::{Diagnostic, PrintDiagnostic};
 use biome_fs::BiomePath;
@@ -13,19 +13,22 @@ use biome_service::configuration::{
     partial_formatter_configuration, partial_linter_configuration, vcs::partial_vcs_configuration,
 };
 use biome_service::configuration::{
-    LoadedConfiguration, PartialCssFormatter, PartialFilesConfiguration,
-    PartialFormatterConfiguration, PartialJavascriptFormatter, PartialJsonFormatter,
-    PartialLinterConfiguration,
+    load_parent_configurations, LoadedConfiguration, PartialCssFormatter,
+    PartialFilesConfiguration, PartialFormatterConfiguration, PartialJavascriptFormatter,
+    PartialJsonFormatter, PartialLinterConfiguration, Rules,
 };
 use biome_service::documentation::Doc;
-use biome_service::workspace::{OpenProjectParams, UpdateProjectParams};
+use biome_service::workspace::{
+    open_tsconfig, OpenProjectParams, UpdateProjectParams, UpdateSettingsParams,
+};
 use biome_service::{ConfigurationDiagnostic, PartialConfiguration, WorkspaceError};
 use bpaf::Bpaf;
 use std::ffi::OsString;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub(crate) mod check;
 pub(crate) mod ci;
+pub(crate) mod config;
 pub(crate) mod daemon;
 pub(crate) mod explain;
 pub(crate) mod format;
@@ -120,6 +123,32 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set together with `--changed`, only the diagnostics that span over the lines that
+        /// changed are reported, and a file is formatted and has its imports sorted only when the
+        /// lines that changed would be modified.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// When set to true, only the files that have been staged will be processed. Their staged
+        /// content is read, and fixes are written to the staging area, so unstaged changes are
+        /// preserved.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+
+        /// Keeps the process alive and processes the files again every time they change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Skip the files that didn't change since the last run, and print the diagnostics they
+        /// emitted then. A file is skipped when its content, the resolved configuration and the
+        /// version of Biome are the same.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The directory where Biome stores the cache. Defaults to `node_modules/.cache/biome`.
+        #[bpaf(long("cache-location"), argument("PATH"), optional)]
+        cache_location: Option<String>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -133,6 +162,23 @@ pub enum BiomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        /// Add a suppression comment for every diagnostic emitted by the lint rules, instead of
+        /// reporting it. Use it to enable a new rule without fixing the existing code first.
+        #[bpaf(long("suppress"), switch)]
+        suppress: bool,
+        /// The reason written in the suppression comments added by `--suppress`.
+        #[bpaf(
+            long("reason"),
+            argument("REASON"),
+            parse(parse_suppression_reason),
+            optional
+        )]
+        reason: Option<String>,
+        /// The rule whose diagnostics are suppressed by `--suppress`, written as `<GROUP>/<RULE>`.
+        /// It can be used multiple times. The diagnostics of all the rules are suppressed when
+        /// it isn't used.
+        #[bpaf(long("rule"), argument("GROUP/RULE"), parse(parse_rule_name), many)]
+        rule: Vec<String>,
         #[bpaf(external(partial_linter_configuration), hide_usage, optional)]
         linter_configuration: Option<PartialLinterConfiguration>,
 
@@ -159,6 +205,26 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        /// When set together with `--changed`, only the diagnostics that span over the lines that
+        /// changed are reported.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+        /// When set to true, only the files that have been staged will be processed. Their staged
+        /// content is read, and fixes are written to the staging area, so unstaged changes are
+        /// preserved.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+        /// Keeps the process alive and processes the files again every time they change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+        /// Skip the files that didn't change since the last run, and print the diagnostics they
+        /// emitted then. A file is skipped when its content, the resolved configuration and the
+        /// version of Biome are the same.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+        /// The directory where Biome stores the cache. Defaults to `node_modules/.cache/biome`.
+        #[bpaf(long("cache-location"), argument("PATH"), optional)]
+        cache_location: Option<String>,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -208,6 +274,26 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the files that have been staged will be processed. Their staged
+        /// content is read, and fixes are written to the staging area, so unstaged changes are
+        /// preserved.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+
+        /// Keeps the process alive and processes the files again every time they change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Skip the files that didn't change since the last run, and print the diagnostics they
+        /// emitted then. A file is skipped when its content, the resolved configuration and the
+        /// version of Biome are the same.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The directory where Biome stores the cache. Defaults to `node_modules/.cache/biome`.
+        #[bpaf(long("cache-location"), argument("PATH"), optional)]
+        cache_location: Option<String>,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -242,6 +328,22 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set together with `--changed`, only the diagnostics that span over the lines that
+        /// changed are reported, and a file is formatted and has its imports sorted only when the
+        /// lines that changed would be modified.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Skip the files that didn't change since the last run, and print the diagnostics they
+        /// emitted then. A file is skipped when its content, the resolved configuration and the
+        /// version of Biome are the same.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The directory where Biome stores the cache. Defaults to `node_modules/.cache/biome`.
+        #[bpaf(long("cache-location"), argument("PATH"), optional)]
+        cache_location: Option<String>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -278,6 +380,13 @@ pub enum BiomeCommand {
         sub_command: Option<MigrateSubCommand>,
     },
 
+    /// Commands to inspect the configuration of Biome.
+    #[bpaf(command)]
+    Config {
+        #[bpaf(external(config_sub_command))]
+        sub_command: ConfigSubCommand,
+    },
+
     /// Searches for Grit patterns across a project.
     #[bpaf(command, hide)] // !! Command is hidden until ready for release.
     Search {
@@ -347,6 +456,42 @@ pub enum MigrateSubCommand {
     /// It attempts to find the files `.prettierrc`/`prettier.json` and `.prettierignore`, and map the Prettier's configuration into Biome's configuration file.
     #[bpaf(command)]
     Prettier,
+    /// It attempts to find the ESLint configuration file (`.eslintrc.json`, `.eslintrc`, the `eslintConfig` field of `package.json`, or a flat configuration exported to `eslint.config.json`) and `.eslintignore`, and map the ESLint's configuration into Biome's configuration file.
+    #[bpaf(command)]
+    Eslint {
+        /// Migrate the ESLint rules to the Biome rules that are only inspired by them, and can behave differently.
+        #[bpaf(long("include-inspired"), switch)]
+        include_inspired: bool,
+        /// Migrate the ESLint rules to the Biome rules of the nursery group.
+        #[bpaf(long("include-nursery"), switch)]
+        include_nursery: bool,
+    },
+    /// It attempts to find the `.editorconfig` files of the directory of the configuration file and its parent directories, and map the indentation, the line ending and the line width of their sections into Biome's configuration file.
+    #[bpaf(command)]
+    Editorconfig,
+}
+
+#[derive(Debug, Bpaf, Clone)]
+pub enum ConfigSubCommand {
+    /// Prints the configuration that applies to a file, once `extends`, `overrides`, the arguments of the command line and the default values are merged. Each value is followed by where it comes from, and the lint rules enabled for the file are listed.
+    ///
+    /// ## Examples
+    ///
+    /// ```shell
+    /// biome config print src/index.js
+    /// ```
+    #[bpaf(command)]
+    Print {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        #[bpaf(external(partial_configuration), hide_usage, optional)]
+        configuration: Option<PartialConfiguration>,
+
+        /// The path of the file.
+        #[bpaf(positional("PATH"))]
+        path: PathBuf,
+    },
 }
 
 impl MigrateSubCommand {
@@ -365,7 +510,10 @@ impl BiomeCommand {
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. }
-            | BiomeCommand::Search { cli_options, .. } => Some(cli_options),
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Config {
+                sub_command: ConfigSubCommand::Print { cli_options, .. },
+            } => Some(cli_options),
             BiomeCommand::LspProxy(_, _)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
@@ -453,18 +601,104 @@ pub(crate) fn validate_configuration_diagnostics(
     Ok(())
 }
 
+/// Loads the configuration files of the directories that contain `paths`, and applies each of
+/// them to the files of its directory. The configuration files nested inside `paths` are applied
+/// by the traversal, when it reaches their directory.
+///
+/// `root_directory` is the directory of the configuration file that was already applied to the
+/// whole workspace. It returns `configuration_hash` updated with the nested configurations.
+pub(crate) fn update_nested_settings(
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    paths: &[OsString],
+    root_directory: Option<&Path>,
+    mut configuration_hash: Option<u64>,
+) -> Result<Option<u64>, CliDiagnostic> {
+    let paths: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
+    let nested_configurations =
+        load_parent_configurations(&session.app.fs, &paths, root_directory)?;
+
+    for loaded_configuration in nested_configurations {
+        validate_configuration_diagnostics(
+            &loaded_configuration,
+            session.app.console,
+            cli_options.verbose,
+        )?;
+        let LoadedConfiguration {
+            configuration,
+            directory_path,
+            ..
+        } = loaded_configuration;
+        let (vcs_base_path, gitignore_matches) =
+            configuration.retrieve_gitignore_matches(&session.app.fs, directory_path.as_deref())?;
+        if let (Some(hash), Some(directory)) = (configuration_hash, directory_path.as_deref()) {
+            configuration_hash = Some(hash_nested_configuration(hash, directory, &configuration));
+        }
+
+        session
+            .app
+            .workspace
+            .update_settings(UpdateSettingsParams {
+                working_directory: session.app.fs.working_directory(),
+                configuration,
+                vcs_base_path,
+                gitignore_matches,
+                nested_directory: directory_path,
+            })?;
+    }
+
+    Ok(configuration_hash)
+}
+
+/// The paths where to look for nested configuration files: the file read from `stdin`, if any,
+/// otherwise the paths passed to the command
+pub(crate) fn nested_paths(paths: &[OsString], stdin: Option<&Stdin>) -> Vec<OsString> {
+    match stdin {
+        Some(stdin) => vec![stdin.as_path().as_os_str().to_os_string()],
+        None => paths.to_vec(),
+    }
+}
+
+/// Rejects the reasons that would end the suppression comment, or need more than one line
+fn parse_suppression_reason(reason: String) -> Result<String, &'static str> {
+    if reason.contains(['\n', '\r']) || reason.contains("*/") {
+        Err("The reason of a suppression comment can't contain a line break or `*/`.")
+    } else {
+        Ok(reason)
+    }
+}
+
+/// Rejects the names that aren't `<GROUP>/<RULE>`, where `<RULE>` is a lint rule of `<GROUP>`
+fn parse_rule_name(name: String) -> Result<String, String> {
+    let is_rule = name.split_once('/').is_some_and(|(group, rule)| {
+        Rules::default()
+            .matches_diagnostic_code(Some(group), Some(rule))
+            .is_some()
+    });
+    if is_rule {
+        Ok(name)
+    } else {
+        Err(format!(
+            "The rule `{name}` doesn't exist, the rules are written as `<GROUP>/<RULE>`."
+        ))
+    }
+}
+
 fn resolve_manifest(cli_session: &CliSession) -> Result<(), WorkspaceError> {
     let fs = &*cli_session.app.fs;
     let workspace = &*cli_session.app.workspace;
 
-    let result = fs.auto_search(
-        fs.working_directory().unwrap_or_default(),
-        &["package.json"],
-        false,
-    )?;
+    let working_directory = fs.working_directory().unwrap_or_default();
+    let result = fs.auto_search(working_directory.clone(), &["package.json"], false)?;
 
     if let Some(result) = result {
-        let biome_path = BiomePath::new(result.file_path);
+        // The paths of the traversed files are relative to the working directory
+        let biome_path = BiomePath::new(
+            result
+                .file_path
+                .strip_prefix(&working_directory)
+                .unwrap_or(&result.file_path),
+        );
         workspace.open_project(OpenProjectParams {
             path: biome_path.clone(),
             content: result.content,
@@ -473,6 +707,20 @@ fn resolve_manifest(cli_session: &CliSession) -> Result<(), WorkspaceError> {
         workspace.update_current_project(UpdateProjectParams { path: biome_path })?;
     }
 
+    let result = fs.auto_search(
+        working_directory.clone(),
+        &["tsconfig.json", "jsconfig.json"],
+        false,
+    )?;
+    if let Some(result) = result {
+        // The paths of the traversed files are relative to the working directory
+        let path = result
+            .file_path
+            .strip_prefix(&working_directory)
+            .unwrap_or(&result.file_path);
+        open_tsconfig(fs, workspace, path, true)?;
+    }
+
     Ok(())
 }
 
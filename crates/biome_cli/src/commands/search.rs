@@ -67,6 +67,7 @@ pub(crate) fn search(
             configuration,
             vcs_base_path,
             gitignore_matches,
+            nested_directory: None,
         })?;
 
     let console = &mut *session.app.console;
@@ -0,0 +1,326 @@
+use crate::execute::process_file::Message;
+use crate::execute::{Execution, TraversalMode};
+use crate::VERSION;
+use biome_diagnostics::serde::Diagnostic as SerdeDiagnostic;
+use biome_diagnostics::{Error, Resource};
+use biome_fs::{FileSystem, FileSystemExt, OpenOptions};
+use biome_service::PartialConfiguration;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The directory where the cache is stored when `--cache-location` isn't provided
+const DEFAULT_CACHE_LOCATION: &str = "node_modules/.cache/biome";
+
+/// A 64-bit FNV-1a hasher.
+///
+/// The hashes are stored on disk, so they must not change between two builds of Biome. The
+/// algorithm of [std::collections::hash_map::DefaultHasher] and the [std::hash::Hash]
+/// implementations of the standard library can change between Rust releases, so the values are
+/// fed as plain bytes instead.
+pub(crate) struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    pub(crate) const fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) -> &mut Self {
+        self.write_bytes(&value.to_le_bytes());
+        self
+    }
+
+    /// Writes the length of `value` before its bytes, so that two consecutive strings can't be
+    /// confused with two other strings that have the same concatenation
+    pub(crate) fn write_str(&mut self, value: &str) -> &mut Self {
+        self.write_u64(value.len() as u64);
+        self.write_bytes(value.as_bytes());
+        self
+    }
+
+    pub(crate) fn write_path(&mut self, path: &Path) -> &mut Self {
+        self.write_str(&path.to_string_lossy())
+    }
+
+    pub(crate) const fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Computes the hash of the resolved configuration, used to invalidate the cache when the
+/// configuration changes.
+pub(crate) fn hash_configuration(configuration: &PartialConfiguration) -> u64 {
+    StableHasher::new()
+        .write_str(&serde_json::to_string(configuration).unwrap_or_default())
+        .finish()
+}
+
+/// Combines `configuration_hash` with the hash of the configuration nested in `directory`, so
+/// that the cache is also invalidated when a nested configuration file changes.
+pub(crate) fn hash_nested_configuration(
+    configuration_hash: u64,
+    directory: &Path,
+    configuration: &PartialConfiguration,
+) -> u64 {
+    StableHasher::new()
+        .write_u64(configuration_hash)
+        .write_path(directory)
+        .write_u64(hash_configuration(configuration))
+        .finish()
+}
+
+/// Reads the file at `path`, and returns its content and the hash of its content
+pub(crate) fn read_file(
+    fs: &dyn FileSystem,
+    execution: &Execution,
+    path: &Path,
+) -> Option<(String, u64)> {
+    let mut file = execution
+        .open_file(fs, path, OpenOptions::default().read(true))
+        .ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    let content_hash = StableHasher::new().write_str(&content).finish();
+    Some((content, content_hash))
+}
+
+/// Reads the file at `path` and returns the hash of its content
+pub(crate) fn hash_file(fs: &dyn FileSystem, execution: &Execution, path: &Path) -> Option<u64> {
+    read_file(fs, execution, path).map(|(_, content_hash)| content_hash)
+}
+
+/// The content of the cache file, as it's stored on disk
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheContent {
+    /// The hash of the Biome version, the options of the traversal and the resolved configuration
+    key: u64,
+    /// The entry of each file, indexed by path
+    files: BTreeMap<String, CacheEntry>,
+}
+
+/// The result of the processing of a file, stored so that it can be replayed
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CacheEntry {
+    /// The hash of the content of the file
+    hash: u64,
+    /// The diagnostics emitted for the file
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    diagnostics: Vec<SerdeDiagnostic>,
+    /// The number of diagnostics that weren't emitted because of the limit
+    #[serde(default, skip_serializing_if = "is_zero")]
+    skipped_diagnostics: u32,
+    /// Whether the processing of the file failed because of the diagnostics
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    failed: bool,
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+impl CacheEntry {
+    /// Whether the processing of the file failed because of its diagnostics
+    pub(crate) const fn failed(&self) -> bool {
+        self.failed
+    }
+
+    /// The message that replays the diagnostics of the file at `path`, if it emitted any.
+    /// `content` is the content of the file, used to print the code frames.
+    pub(crate) fn to_message(&self, path: &Path, content: String) -> Option<Message> {
+        if self.diagnostics.is_empty() && self.skipped_diagnostics == 0 {
+            return None;
+        }
+        Some(Message::Diagnostics {
+            name: path.display().to_string(),
+            content,
+            diagnostics: self.diagnostics.iter().cloned().map(Error::from).collect(),
+            skipped_diagnostics: self.skipped_diagnostics,
+        })
+    }
+}
+
+/// Persistent cache of the files that were processed during the previous runs.
+///
+/// The cache is read before the traversal starts and written once it ends. The diagnostics of
+/// each file are stored with it, and printed again when the file is skipped. Files that emitted
+/// diffs or errors are never stored, so they are processed on every run.
+pub(crate) struct FileCache {
+    /// The path of the file where the cache is stored
+    path: PathBuf,
+    /// The key of the current run. Entries saved with a different key are discarded.
+    key: u64,
+    /// The entries loaded from disk
+    previous: FxHashMap<String, CacheEntry>,
+    /// The files processed during this run, with the hash of their content and whether their
+    /// processing failed
+    processed: Mutex<FxHashMap<String, (u64, bool)>>,
+    /// The diagnostics emitted during this run, indexed by file
+    diagnostics: Mutex<FxHashMap<String, (Vec<SerdeDiagnostic>, u32)>>,
+    /// The files that emitted a diff or an error during this run
+    invalidated: Mutex<FxHashSet<String>>,
+}
+impl FileCache {
+    /// Loads the cache of the given [TraversalMode] from `location`, or from the default
+    /// location if [None].
+    ///
+    /// A cache that can't be read or that was saved with a different key is discarded. The key
+    /// changes with the options of the traversal that change the result of the processing of a
+    /// file, e.g. the fixes that are applied. See [TraversalMode::cache_key].
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        location: Option<&str>,
+        traversal_mode: &TraversalMode,
+        configuration_hash: u64,
+    ) -> Self {
+        let path = PathBuf::from(location.unwrap_or(DEFAULT_CACHE_LOCATION))
+            .join(format!("{traversal_mode}.json"));
+
+        let key = StableHasher::new()
+            .write_str(VERSION)
+            .write_str(&traversal_mode.cache_key())
+            .write_u64(configuration_hash)
+            .finish();
+
+        let previous = fs
+            .open(&path)
+            .and_then(|mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content)?;
+                Ok(content)
+            })
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheContent>(&content).ok())
+            .filter(|cache| cache.key == key)
+            .map(|cache| cache.files.into_iter().collect())
+            .unwrap_or_default();
+
+        Self {
+            path,
+            key,
+            previous,
+            processed: Mutex::default(),
+            diagnostics: Mutex::default(),
+            invalidated: Mutex::default(),
+        }
+    }
+
+    /// Whether `path` is inside the directory of the cache, which must not be traversed
+    pub(crate) fn is_cache_path(&self, path: &Path) -> bool {
+        self.path
+            .parent()
+            .map_or(false, |directory| path.starts_with(directory))
+    }
+
+    /// The entry of the file at `path` stored by the previous run, if its content didn't change
+    /// since then
+    pub(crate) fn fresh_entry(&self, path: &Path, content_hash: u64) -> Option<&CacheEntry> {
+        self.previous
+            .get(path.display().to_string().as_str())
+            .filter(|entry| entry.hash == content_hash)
+    }
+
+    /// Records a file that was handled during this run
+    pub(crate) fn record(&self, path: &Path, content_hash: u64, failed: bool) {
+        if let Ok(mut processed) = self.processed.lock() {
+            processed.insert(path.display().to_string(), (content_hash, failed));
+        }
+    }
+
+    /// Stores the diagnostics of `message`, or marks the file that emitted it so that it isn't
+    /// stored in the cache when the message can't be replayed.
+    ///
+    /// It returns the message to print, with the diagnostics in the same form they're stored.
+    pub(crate) fn record_message(&self, message: Message) -> Message {
+        let file_path = match message {
+            Message::Diagnostics {
+                name,
+                content,
+                diagnostics,
+                skipped_diagnostics,
+            } if !diagnostics.is_empty() || skipped_diagnostics > 0 => {
+                let diagnostics: Vec<_> =
+                    diagnostics.into_iter().map(SerdeDiagnostic::new).collect();
+                if let Ok(mut stored) = self.diagnostics.lock() {
+                    let (stored_diagnostics, stored_skipped_diagnostics) =
+                        stored.entry(name.clone()).or_default();
+                    stored_diagnostics.extend(diagnostics.iter().cloned());
+                    *stored_skipped_diagnostics += skipped_diagnostics;
+                }
+                return Message::Diagnostics {
+                    name,
+                    content,
+                    diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+                    skipped_diagnostics,
+                };
+            }
+            Message::Diff { ref file_name, .. } => file_name.clone(),
+            Message::Error(ref error) => match error.location().resource {
+                Some(Resource::File(file_path)) => file_path.to_string(),
+                _ => return message,
+            },
+            message => return message,
+        };
+
+        if let Ok(mut invalidated) = self.invalidated.lock() {
+            invalidated.insert(file_path);
+        }
+        message
+    }
+
+    /// Writes the files handled during this run to disk
+    pub(crate) fn persist(self, fs: &dyn FileSystem) {
+        let invalidated = self.invalidated.into_inner().unwrap_or_default();
+        let mut diagnostics = self.diagnostics.into_inner().unwrap_or_default();
+        let files = self
+            .processed
+            .into_inner()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(path, _)| !invalidated.contains(path))
+            .map(|(path, (hash, failed))| {
+                let (diagnostics, skipped_diagnostics) =
+                    diagnostics.remove(&path).unwrap_or_default();
+                let entry = CacheEntry {
+                    hash,
+                    diagnostics,
+                    skipped_diagnostics,
+                    failed,
+                };
+                (path, entry)
+            })
+            .collect();
+        let content = CacheContent {
+            key: self.key,
+            files,
+        };
+
+        let result = serde_json::to_string(&content)
+            .map_err(std::io::Error::from)
+            .and_then(|content| {
+                if let Some(directory) = self.path.parent() {
+                    fs.create_dir_all(directory)?;
+                }
+                fs.create(&self.path)?.set_content(content.as_bytes())
+            });
+        if let Err(error) = result {
+            tracing::warn!(
+                "Biome couldn't write the cache file {}: {error}",
+                self.path.display()
+            );
+        }
+    }
+}
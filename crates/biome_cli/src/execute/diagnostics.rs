@@ -29,6 +29,18 @@ pub(crate) struct CIOrganizeImportsDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "sortKeys",
+    message = "The order of the keys differs from the output"
+)]
+pub(crate) struct CISortKeysDiffDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 category = "format",
@@ -55,6 +67,19 @@ pub(crate) struct OrganizeImportsDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	category = "sortKeys",
+	severity = Information,
+	message = "The keys of the objects could be sorted:"
+)]
+pub(crate) struct SortKeysDiffDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 	category = "migrate",
@@ -1,7 +1,12 @@
+mod editorconfig;
+mod eslint;
 mod prettier;
 
+use crate::commands::MigrateSubCommand;
 use crate::diagnostics::MigrationDiagnostic;
 use crate::execute::diagnostics::{ContentDiffAdvice, MigrateDiffDiagnostic};
+use crate::execute::migrate::editorconfig::read_editorconfig_files;
+use crate::execute::migrate::eslint::{read_eslint_files, EslintMigrationOptions};
 use crate::execute::migrate::prettier::read_prettier_files;
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, ConsoleExt};
@@ -26,8 +31,14 @@ pub(crate) struct MigratePayload<'a> {
     pub(crate) configuration_file_path: PathBuf,
     pub(crate) configuration_directory_path: PathBuf,
     pub(crate) verbose: bool,
-    #[allow(unused)]
-    pub(crate) prettier: bool,
+    pub(crate) sub_command: Option<MigrateSubCommand>,
+}
+
+/// A Biome configuration migrated from the configuration of another tool
+struct MigratedConfiguration {
+    configuration: PartialConfiguration,
+    /// The name of the tool, and the file that contains the migrated ignore globs, if any
+    ignore_file: Option<(&'static str, String)>,
 }
 
 pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic> {
@@ -37,7 +48,7 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
         configuration_file_path,
         configuration_directory_path,
         verbose,
-        prettier,
+        sub_command,
     } = migrate_payload;
     let mut cache = NodeCache::default();
     let fs = &session.app.fs;
@@ -117,10 +128,51 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
 
     let new_configuration_content = tree.to_string();
 
-    if prettier {
-        let prettier_configuration = read_prettier_files(fs, console)?;
+    if let Some(sub_command) = sub_command {
+        let migrated_configuration = match sub_command {
+            MigrateSubCommand::Prettier => {
+                let prettier_configuration = read_prettier_files(fs, console)?;
+                prettier_configuration
+                    .has_configuration()
+                    .then(|| MigratedConfiguration {
+                        configuration: prettier_configuration.as_biome_configuration(),
+                        ignore_file: prettier_configuration
+                            .has_ignore_file()
+                            .then(|| ("Prettier", ".prettierignore".to_string())),
+                    })
+            }
+            MigrateSubCommand::Eslint {
+                include_inspired,
+                include_nursery,
+            } => {
+                let eslint_configuration = read_eslint_files(
+                    fs,
+                    console,
+                    &EslintMigrationOptions {
+                        include_inspired,
+                        include_nursery,
+                    },
+                )?;
+                eslint_configuration.report(console);
+                Some(MigratedConfiguration {
+                    configuration: eslint_configuration.as_biome_configuration(),
+                    ignore_file: eslint_configuration
+                        .get_ignore_path()
+                        .map(|path| ("ESLint", path.display().to_string())),
+                })
+            }
+            MigrateSubCommand::Editorconfig => {
+                let editorconfig_configuration =
+                    read_editorconfig_files(fs, configuration_directory_path.as_path())?;
+                editorconfig_configuration.report(console);
+                Some(MigratedConfiguration {
+                    configuration: editorconfig_configuration.as_biome_configuration(),
+                    ignore_file: None,
+                })
+            }
+        };
 
-        if prettier_configuration.has_configuration() {
+        if let Some(migrated_configuration) = migrated_configuration {
             let configuration = deserialize_from_json_str::<PartialConfiguration>(
                 configuration_content.as_str(),
                 JsonParserOptions::default(),
@@ -128,7 +180,7 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
             )
             .into_deserialized();
             if let Some(mut configuration) = configuration {
-                configuration.merge_with(prettier_configuration.as_biome_configuration());
+                configuration.merge_with(migrated_configuration.configuration);
 
                 let new_content = serde_json::to_string(&configuration).map_err(|err| {
                     CliDiagnostic::MigrateError(MigrationDiagnostic {
@@ -151,9 +203,9 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                     console.log(markup!{
                         <Info>"The configuration "<Emphasis>{{configuration_file_path.display().to_string()}}</Emphasis>" has been successfully migrated."</Info>
                     });
-                    if prettier_configuration.has_ignore_file() {
+                    if let Some((tool, ignore_file)) = migrated_configuration.ignore_file {
                         console.log(markup!{
-                            <Warn>"Please make sure that the globs of the "<Emphasis>".prettierignore"</Emphasis>" file still work in Biome. Prettier's globs use git globs, while Biome's globs use uni-style globs. They both seem similar, but their semantics differ."</Warn>
+                            <Warn>"Please make sure that the globs of the "<Emphasis>{ignore_file}</Emphasis>" file still work in Biome. "{tool}"'s globs use git globs, while Biome's globs use uni-style globs. They both seem similar, but their semantics differ."</Warn>
                         })
                     }
                 } else {
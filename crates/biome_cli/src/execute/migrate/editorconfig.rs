@@ -0,0 +1,507 @@
+use crate::diagnostics::MigrationDiagnostic;
+use crate::CliDiagnostic;
+use biome_console::{markup, Console, ConsoleExt};
+use biome_deserialize::StringSet;
+use biome_formatter::{LineEnding, LineWidth};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_service::configuration::{
+    OverrideFormatterConfiguration, OverridePattern, Overrides, PartialFormatterConfiguration,
+    PlainIndentStyle,
+};
+use biome_service::{DynRef, PartialConfiguration};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+const EDITORCONFIG_FILE: &str = ".editorconfig";
+
+/// The content of an `.editorconfig` file
+#[derive(Debug, Default, Eq, PartialEq)]
+struct EditorconfigFile {
+    /// Whether the search of `.editorconfig` files stops at this file
+    root: bool,
+    sections: Vec<EditorconfigSection>,
+}
+
+/// A section of an `.editorconfig` file: the properties that apply to the files matched by a glob
+#[derive(Debug, Default, Eq, PartialEq)]
+struct EditorconfigSection {
+    glob: String,
+    /// The properties in their declaration order. Keys are lowercase.
+    properties: Vec<(String, String)>,
+}
+
+/// Parses the INI-like format of EditorConfig. Malformed lines are ignored, like EditorConfig does.
+fn parse_editorconfig(content: &str) -> EditorconfigFile {
+    let mut file = EditorconfigFile::default();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(glob) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            file.sections.push(EditorconfigSection {
+                glob: glob.to_string(),
+                properties: vec![],
+            });
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let value = value.trim().to_string();
+        match file.sections.last_mut() {
+            Some(section) => section.properties.push((key, value)),
+            // Only `root` is allowed before the first section
+            None if key == "root" => file.root = value.eq_ignore_ascii_case("true"),
+            None => {}
+        }
+    }
+    file
+}
+
+/// The formatter options that can be set by EditorConfig
+#[derive(Debug, Default, Eq, PartialEq)]
+struct EditorconfigOptions {
+    indent_style: Option<PlainIndentStyle>,
+    indent_width: Option<u8>,
+    line_ending: Option<LineEnding>,
+    line_width: Option<LineWidth>,
+}
+
+impl From<&EditorconfigOptions> for OverrideFormatterConfiguration {
+    fn from(value: &EditorconfigOptions) -> Self {
+        Self {
+            indent_style: value.indent_style,
+            indent_width: value.indent_width,
+            line_ending: value.line_ending,
+            line_width: value.line_width,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct FromEditorconfigConfiguration {
+    /// The `.editorconfig` files that were read, from the closest to the farthest
+    configuration_paths: Vec<PathBuf>,
+    /// The translated options of the sections that match all the files
+    formatter: Option<PartialFormatterConfiguration>,
+    /// The translated sections
+    overrides: Vec<OverridePattern>,
+    /// The globs that Biome can't represent
+    unsupported_globs: BTreeSet<String>,
+    /// The properties that Biome doesn't support
+    unsupported_properties: BTreeSet<String>,
+    /// The properties whose value couldn't be migrated
+    invalid_values: BTreeSet<String>,
+}
+
+impl FromEditorconfigConfiguration {
+    pub(crate) fn as_biome_configuration(&self) -> PartialConfiguration {
+        PartialConfiguration {
+            formatter: self.formatter.clone(),
+            overrides: (!self.overrides.is_empty()).then(|| Overrides(self.overrides.clone())),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn report(&self, console: &mut dyn Console) {
+        let files = self
+            .configuration_paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        console.log(markup! {
+            <Info>"The following EditorConfig files were migrated: "{files}</Info>
+        });
+        if !self.unsupported_properties.is_empty() {
+            let properties = join(&self.unsupported_properties);
+            console.log(markup! {
+                <Warn>"The following EditorConfig properties don't have an equivalent Biome option, and they weren't migrated: "{properties}</Warn>
+            });
+        }
+        if !self.invalid_values.is_empty() {
+            let properties = join(&self.invalid_values);
+            console.log(markup! {
+                <Warn>"The values of the following EditorConfig properties aren't supported by Biome, and they weren't migrated: "{properties}</Warn>
+            });
+        }
+        if !self.unsupported_globs.is_empty() {
+            let globs = join(&self.unsupported_globs);
+            console.log(markup! {
+                <Warn>"The sections of the following EditorConfig globs weren't migrated, because Biome can't represent them: "{globs}</Warn>
+            });
+        }
+    }
+
+    /// Migrates the `.editorconfig` files found in `directory` and its ancestors.
+    /// `files` are ordered from the closest to the farthest, and every file is paired with its directory.
+    fn migrate(&mut self, directory: &Path, files: Vec<(PathBuf, EditorconfigFile)>) {
+        // The closest files have the highest priority, so they are migrated last
+        for (file_directory, file) in files.into_iter().rev() {
+            self.configuration_paths
+                .insert(0, file_directory.join(EDITORCONFIG_FILE));
+            let Ok(relative_directory) = directory.strip_prefix(&file_directory) else {
+                continue;
+            };
+            for section in file.sections {
+                let options = self.migrate_properties(&section.properties);
+                if options == EditorconfigOptions::default() {
+                    continue;
+                }
+                let Some(globs) = to_biome_globs(&section.glob, relative_directory) else {
+                    self.unsupported_globs.insert(section.glob);
+                    continue;
+                };
+                if globs.is_empty() {
+                    // The section only applies to files outside the project
+                    continue;
+                }
+                // The options of a section that matches all the files are the default options,
+                // unless a previous section is more specific
+                if globs.iter().all(|glob| glob == "**") && self.overrides.is_empty() {
+                    let formatter = self.formatter.get_or_insert_with(Default::default);
+                    formatter.indent_style = options.indent_style.or(formatter.indent_style);
+                    formatter.indent_width = options.indent_width.or(formatter.indent_width);
+                    formatter.line_ending = options.line_ending.or(formatter.line_ending);
+                    formatter.line_width = options.line_width.or(formatter.line_width);
+                } else {
+                    self.overrides.push(OverridePattern {
+                        include: Some(StringSet::new(globs.into_iter().collect())),
+                        formatter: Some((&options).into()),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+    }
+
+    fn migrate_properties(&mut self, properties: &[(String, String)]) -> EditorconfigOptions {
+        let mut options = EditorconfigOptions::default();
+        let mut indent_size = None;
+        let mut tab_width = None;
+        for (key, value) in properties {
+            let value = value.to_ascii_lowercase();
+            // `unset` removes the effect of the property, which is the default in Biome
+            if value == "unset" {
+                continue;
+            }
+            let is_valid = match key.as_str() {
+                "indent_style" => {
+                    options.indent_style = match value.as_str() {
+                        "tab" => Some(PlainIndentStyle::Tab),
+                        "space" => Some(PlainIndentStyle::Space),
+                        _ => None,
+                    };
+                    options.indent_style.is_some()
+                }
+                "indent_size" => {
+                    indent_size = match value.as_str() {
+                        "tab" => Some(None),
+                        value => value.parse::<u8>().ok().map(Some),
+                    };
+                    indent_size.is_some()
+                }
+                "tab_width" => {
+                    tab_width = value.parse::<u8>().ok();
+                    tab_width.is_some()
+                }
+                "end_of_line" => {
+                    options.line_ending = match value.as_str() {
+                        "lf" => Some(LineEnding::Lf),
+                        "crlf" => Some(LineEnding::Crlf),
+                        "cr" => Some(LineEnding::Cr),
+                        _ => None,
+                    };
+                    options.line_ending.is_some()
+                }
+                "max_line_length" => {
+                    if value == "off" {
+                        true
+                    } else {
+                        options.line_width = value
+                            .parse::<u16>()
+                            .ok()
+                            .and_then(|width| LineWidth::try_from(width).ok());
+                        options.line_width.is_some()
+                    }
+                }
+                _ => {
+                    self.unsupported_properties.insert(key.clone());
+                    true
+                }
+            };
+            if !is_valid {
+                self.invalid_values.insert(format!("{key} = {value}"));
+            }
+        }
+        // `indent_size = tab` uses the value of `tab_width`, and `tab_width` defaults to `indent_size`
+        options.indent_width = match indent_size {
+            Some(Some(indent_size)) => Some(indent_size),
+            Some(None) | None => tab_width,
+        };
+        options
+    }
+}
+
+fn join(values: &BTreeSet<String>) -> String {
+    values
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Converts the glob of an EditorConfig section to Biome globs, relative to the project.
+/// `relative_directory` is the path of the project relative to the directory of the `.editorconfig` file.
+///
+/// Returns [None] when the glob can't be represented, and an empty list when the glob can't
+/// match the files of the project.
+fn to_biome_globs(glob: &str, relative_directory: &Path) -> Option<Vec<String>> {
+    let mut biome_globs = vec![];
+    for glob in expand_braces(glob)? {
+        // A glob without a slash matches the files at any depth, otherwise it is relative to the
+        // directory of the `.editorconfig` file
+        if !glob.contains('/') {
+            biome_globs.push(if glob == "*" || glob == "**" {
+                "**".to_string()
+            } else {
+                format!("**/{glob}")
+            });
+            continue;
+        }
+        let mut glob = glob.strip_prefix('/').unwrap_or(&glob);
+        let mut matches_project = true;
+        for component in relative_directory.components() {
+            if glob.starts_with("**/") {
+                break;
+            }
+            let component = component.as_os_str().to_str()?;
+            match glob.split_once('/') {
+                Some((first, rest)) if first == component || first == "*" => glob = rest,
+                Some((first, _)) if first.contains(['*', '?', '[', '{']) => return None,
+                _ => {
+                    matches_project = false;
+                    break;
+                }
+            }
+        }
+        if !matches_project {
+            continue;
+        }
+        biome_globs.push(glob.to_string());
+    }
+    Some(biome_globs)
+}
+
+/// Expands the alternatives `{a,b}` of a glob, which Biome doesn't support.
+/// Returns [None] for numeric ranges `{1..3}`.
+fn expand_braces(glob: &str) -> Option<Vec<String>> {
+    let Some(start) = glob.find('{') else {
+        return Some(vec![glob.to_string()]);
+    };
+    let mut depth = 0;
+    let mut end = None;
+    for (index, char) in glob[start..].char_indices() {
+        match char {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    end = Some(start + index);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let Some(end) = end else {
+        return Some(vec![glob.to_string()]);
+    };
+    let (prefix, inner, suffix) = (&glob[..start], &glob[start + 1..end], &glob[end + 1..]);
+
+    let mut alternatives = vec![];
+    let mut depth = 0;
+    let mut alternative_start = 0;
+    for (index, char) in inner.char_indices() {
+        match char {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                alternatives.push(&inner[alternative_start..index]);
+                alternative_start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    alternatives.push(&inner[alternative_start..]);
+    if alternatives.len() == 1 {
+        if inner.contains("..") {
+            return None;
+        }
+        // A single alternative matches the braces literally
+        return Some(vec![glob.to_string()]);
+    }
+
+    let mut globs = vec![];
+    for alternative in alternatives {
+        globs.extend(expand_braces(&format!("{prefix}{alternative}{suffix}"))?);
+    }
+    Some(globs)
+}
+
+/// This function is in charge of reading the `.editorconfig` files of the directory of the Biome
+/// configuration and its ancestors, and convert them in a Biome configuration type
+pub(crate) fn read_editorconfig_files(
+    fs: &DynRef<'_, dyn FileSystem>,
+    directory: &Path,
+) -> Result<FromEditorconfigConfiguration, CliDiagnostic> {
+    let mut files = vec![];
+    for file_directory in directory.ancestors() {
+        let path = file_directory.join(EDITORCONFIG_FILE);
+        let Ok(mut file) = fs.open_with_options(&path, OpenOptions::default().read(true)) else {
+            continue;
+        };
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .map_err(CliDiagnostic::io_error)?;
+        let editorconfig = parse_editorconfig(&content);
+        let is_root = editorconfig.root;
+        files.push((file_directory.to_path_buf(), editorconfig));
+        if is_root {
+            break;
+        }
+    }
+
+    if files.is_empty() {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Biome couldn't find an EditorConfig file.".to_string(),
+        }));
+    }
+
+    let mut from_editorconfig_configuration = FromEditorconfigConfiguration::default();
+    from_editorconfig_configuration.migrate(directory, files);
+    Ok(from_editorconfig_configuration)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        parse_editorconfig, to_biome_globs, EditorconfigFile, EditorconfigSection,
+        FromEditorconfigConfiguration,
+    };
+    use biome_service::configuration::PlainIndentStyle;
+    use std::collections::BTreeSet;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn parse() {
+        let file = parse_editorconfig(
+            r#"
+# comment
+root = true
+
+[*]
+indent_style = space
+Indent_Size = 2
+
+; comment
+[*.{js,ts}]
+max_line_length = 100
+"#,
+        );
+
+        assert_eq!(
+            file,
+            EditorconfigFile {
+                root: true,
+                sections: vec![
+                    EditorconfigSection {
+                        glob: "*".to_string(),
+                        properties: vec![
+                            ("indent_style".to_string(), "space".to_string()),
+                            ("indent_size".to_string(), "2".to_string()),
+                        ],
+                    },
+                    EditorconfigSection {
+                        glob: "*.{js,ts}".to_string(),
+                        properties: vec![("max_line_length".to_string(), "100".to_string())],
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn globs() {
+        let globs = |glob: &str, directory: &str| to_biome_globs(glob, Path::new(directory));
+
+        assert_eq!(globs("*", ""), Some(vec!["**".to_string()]));
+        assert_eq!(
+            globs("*.{js,ts}", ""),
+            Some(vec!["**/*.js".to_string(), "**/*.ts".to_string()])
+        );
+        assert_eq!(globs("/lib/**.js", ""), Some(vec!["lib/**.js".to_string()]));
+        assert_eq!(
+            globs("{src,test}/{a,b}.js", ""),
+            Some(vec![
+                "src/a.js".to_string(),
+                "src/b.js".to_string(),
+                "test/a.js".to_string(),
+                "test/b.js".to_string()
+            ])
+        );
+        assert_eq!(globs("file{1..3}.js", ""), None);
+        // The glob of a parent `.editorconfig` is relative to its directory
+        assert_eq!(
+            globs("packages/app/src/*.js", "packages/app"),
+            Some(vec!["src/*.js".to_string()])
+        );
+        assert_eq!(globs("packages/other/*.js", "packages/app"), Some(vec![]));
+        assert_eq!(
+            globs("**/fixtures/*.js", "packages/app"),
+            Some(vec!["**/fixtures/*.js".to_string()])
+        );
+    }
+
+    #[test]
+    fn hierarchy() {
+        let mut configuration = FromEditorconfigConfiguration::default();
+        configuration.migrate(
+            Path::new("/project"),
+            vec![
+                (
+                    PathBuf::from("/project"),
+                    parse_editorconfig("[*]\nindent_size = 4\n[Makefile]\nindent_style = tab"),
+                ),
+                (
+                    PathBuf::from("/"),
+                    parse_editorconfig(
+                        "root = true\n[*]\nindent_style = space\nindent_size = 2\ncharset = utf-8",
+                    ),
+                ),
+            ],
+        );
+
+        let formatter = configuration.formatter.unwrap();
+        assert_eq!(formatter.indent_style, Some(PlainIndentStyle::Space));
+        assert_eq!(formatter.indent_width, Some(4));
+        assert_eq!(configuration.overrides.len(), 1);
+        assert_eq!(
+            configuration.unsupported_properties,
+            BTreeSet::from(["charset".to_string()])
+        );
+        assert_eq!(
+            configuration.configuration_paths,
+            vec![
+                PathBuf::from("/project/.editorconfig"),
+                PathBuf::from("/.editorconfig")
+            ]
+        );
+    }
+}
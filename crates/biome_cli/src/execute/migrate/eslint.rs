@@ -0,0 +1,921 @@
+use crate::diagnostics::MigrationDiagnostic;
+use crate::CliDiagnostic;
+use biome_analyze::{GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    TextNumber, VisitableType,
+};
+use biome_diagnostics::{DiagnosticExt, PrintDiagnostic};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_js_syntax::JsLanguage;
+use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
+use biome_service::{DynRef, PartialConfiguration};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// The ESLint configuration files, in the order they are looked up.
+///
+/// `eslint.config.json` is a flat configuration exported as JSON, because Biome can't evaluate
+/// JavaScript configuration files.
+const ESLINT_CONFIG_FILES: [&str; 3] = [".eslintrc.json", ".eslintrc", "eslint.config.json"];
+const ESLINT_IGNORE_FILE: &str = ".eslintignore";
+const PACKAGE_JSON: &str = "package.json";
+
+/// The shared configurations whose rules are covered by the recommended rules of Biome
+const RECOMMENDED_PRESETS: [&str; 8] = [
+    "eslint:recommended",
+    "plugin:@typescript-eslint/eslint-recommended",
+    "plugin:@typescript-eslint/recommended",
+    "plugin:import/recommended",
+    "plugin:jsx-a11y/recommended",
+    "plugin:react/recommended",
+    "plugin:react-hooks/recommended",
+    "plugin:unicorn/recommended",
+];
+/// The shared configurations that only turn off the stylistic rules, which Biome doesn't have
+const FORMATTING_PRESETS: [&str; 2] = ["prettier", "plugin:prettier/recommended"];
+
+/// Any JSON value of an ESLint configuration file.
+///
+/// The configuration of ESLint accepts too many shapes to be described by a type, so it's read
+/// as a plain JSON value. Object members keep their order, because the last configuration of a
+/// rule wins.
+#[derive(Debug)]
+struct EslintValue(Value);
+
+impl Deserializable for EslintValue {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(EslintValueVisitor, name, diagnostics)
+    }
+}
+
+struct EslintValueVisitor;
+impl DeserializationVisitor for EslintValueVisitor {
+    type Output = EslintValue;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::all();
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(EslintValue(Value::Null))
+    }
+
+    fn visit_bool(
+        self,
+        value: bool,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(EslintValue(Value::Bool(value)))
+    }
+
+    fn visit_number(
+        self,
+        value: TextNumber,
+        range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        match serde_json::from_str(value.text()) {
+            Ok(number) => Some(EslintValue(Value::Number(number))),
+            Err(_) => {
+                diagnostics.push(
+                    DeserializationDiagnostic::new(markup! {
+                        "The number "<Emphasis>{value.text()}</Emphasis>" isn't supported."
+                    })
+                    .with_range(range),
+                );
+                None
+            }
+        }
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(EslintValue(Value::String(value.text().to_string())))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let items = items
+            .flatten()
+            .filter_map(|item| EslintValue::deserialize(&item, name, diagnostics))
+            .map(|item| item.0)
+            .collect();
+        Some(EslintValue(Value::Array(items)))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Map::new();
+        for (key, value) in members.flatten() {
+            let Some(key) = String::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(value) = EslintValue::deserialize(&value, name, diagnostics) {
+                result.insert(key, value.0);
+            }
+        }
+        Some(EslintValue(Value::Object(result)))
+    }
+}
+
+/// A Biome rule that implements an ESLint rule
+struct MigratedRule {
+    group: &'static str,
+    name: &'static str,
+    is_inspired: bool,
+}
+
+/// The Biome rules, indexed by the name of the ESLint rule they implement
+#[derive(Default)]
+struct EslintRules(BTreeMap<String, Vec<MigratedRule>>);
+
+impl EslintRules {
+    fn new() -> Self {
+        let mut rules = Self::default();
+        biome_js_analyze::visit_registry(&mut rules);
+        rules
+    }
+
+    /// The Biome rules of an ESLint rule. The rules of the TypeScript plugin that extend a core
+    /// rule fallback to the Biome rules of the core rule.
+    fn get(&self, eslint_rule_name: &str) -> Option<&[MigratedRule]> {
+        self.0
+            .get(eslint_rule_name)
+            .or_else(|| {
+                let core_rule_name = eslint_rule_name.strip_prefix("@typescript-eslint/")?;
+                self.0.get(core_rule_name)
+            })
+            .map(Vec::as_slice)
+    }
+}
+
+impl RegistryVisitor<JsLanguage> for EslintRules {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        if R::METADATA.deprecated.is_some() {
+            return;
+        }
+        let Some(eslint_rule_name) = R::METADATA
+            .source
+            .as_ref()
+            .and_then(|source| source.to_eslint_rule_name())
+        else {
+            return;
+        };
+        self.0
+            .entry(eslint_rule_name)
+            .or_default()
+            .push(MigratedRule {
+                group: <R::Group as RuleGroup>::NAME,
+                name: R::METADATA.name,
+                is_inspired: R::METADATA
+                    .source_kind
+                    .as_ref()
+                    .is_some_and(|kind| kind.is_inspired()),
+            });
+    }
+}
+
+/// Options of the ESLint migration
+#[derive(Debug, Default)]
+pub(crate) struct EslintMigrationOptions {
+    /// Migrate the ESLint rules to the Biome rules that are only inspired by them
+    pub(crate) include_inspired: bool,
+    /// Migrate the ESLint rules to the Biome rules of the nursery group
+    pub(crate) include_nursery: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct FromEslintConfiguration {
+    /// Path of the ESLint configuration file
+    configuration_path: Option<PathBuf>,
+    /// Path of the file that contains the ignore patterns, if any were migrated
+    ignore_path: Option<PathBuf>,
+
+    /// The translated Biome configuration, from the ESLint configuration
+    configuration: PartialConfiguration,
+
+    /// The ESLint rules that don't have an equivalent Biome rule
+    unsupported_rules: BTreeSet<String>,
+    /// The ESLint rules that only have an equivalent Biome rule that is inspired by them
+    inspired_rules: BTreeSet<String>,
+    /// The ESLint rules that only have an equivalent Biome rule in the nursery group
+    nursery_rules: BTreeSet<String>,
+    /// The ESLint rules whose options couldn't be migrated
+    unsupported_options: BTreeSet<String>,
+    /// The ESLint rules whose options were migrated, except some of them
+    partially_migrated_options: BTreeSet<String>,
+    /// The shared configurations listed in `extends` that couldn't be migrated
+    unsupported_extends: BTreeSet<String>,
+}
+
+impl FromEslintConfiguration {
+    pub(crate) fn as_biome_configuration(&self) -> PartialConfiguration {
+        self.configuration.clone()
+    }
+
+    pub(crate) fn get_configuration_path(&self) -> Option<&Path> {
+        self.configuration_path.as_deref()
+    }
+
+    pub(crate) fn get_ignore_path(&self) -> Option<&Path> {
+        self.ignore_path.as_deref()
+    }
+
+    /// Prints what couldn't be migrated
+    pub(crate) fn report(&self, console: &mut dyn Console) {
+        if !self.unsupported_extends.is_empty() {
+            let configurations = join(&self.unsupported_extends);
+            console.log(markup! {
+                <Warn>"The following configurations listed in "<Emphasis>"extends"</Emphasis>" weren't migrated, only the rules configured in the ESLint configuration file are: "{configurations}</Warn>
+            });
+        }
+        if !self.unsupported_rules.is_empty() {
+            let rules = join(&self.unsupported_rules);
+            console.log(markup! {
+                <Warn>"The following ESLint rules don't have an equivalent Biome rule, and they weren't migrated: "{rules}</Warn>
+            });
+        }
+        if !self.inspired_rules.is_empty() {
+            let rules = join(&self.inspired_rules);
+            console.log(markup! {
+                <Warn>"The following ESLint rules have an equivalent Biome rule that is only inspired by them, and they weren't migrated: "{rules}". Use the option "<Emphasis>"--include-inspired"</Emphasis>" to migrate them."</Warn>
+            });
+        }
+        if !self.nursery_rules.is_empty() {
+            let rules = join(&self.nursery_rules);
+            console.log(markup! {
+                <Warn>"The following ESLint rules have an equivalent Biome rule that is in the nursery group, and they weren't migrated: "{rules}". Use the option "<Emphasis>"--include-nursery"</Emphasis>" to migrate them."</Warn>
+            });
+        }
+        if !self.unsupported_options.is_empty() {
+            let rules = join(&self.unsupported_options);
+            console.log(markup! {
+                <Warn>"The options of the following ESLint rules weren't migrated, because Biome doesn't support them: "{rules}</Warn>
+            });
+        }
+        if !self.partially_migrated_options.is_empty() {
+            let rules = join(&self.partially_migrated_options);
+            console.log(markup! {
+                <Warn>"Some options of the following ESLint rules weren't migrated, because Biome doesn't support them: "{rules}</Warn>
+            });
+        }
+    }
+
+    /// Migrates a legacy configuration, or one of the objects of a flat configuration, into
+    /// a map of Biome configuration
+    fn migrate_configuration_object(
+        &mut self,
+        eslint_configuration: &Map<String, Value>,
+        rules_index: &EslintRules,
+        options: &EslintMigrationOptions,
+        is_override: bool,
+    ) -> Map<String, Value> {
+        let mut result = Map::new();
+        let mut linter = Map::new();
+        if !is_override {
+            linter.insert("enabled".to_string(), Value::Bool(true));
+        }
+
+        let mut biome_rules = Map::new();
+        if let Some(extends) = eslint_configuration.get("extends") {
+            self.migrate_extends(extends, &mut biome_rules);
+        }
+        if let Some(rules) = eslint_configuration.get("rules").and_then(Value::as_object) {
+            for (eslint_rule_name, eslint_rule) in rules {
+                self.migrate_rule(
+                    eslint_rule_name,
+                    eslint_rule,
+                    rules_index,
+                    options,
+                    &mut biome_rules,
+                );
+            }
+        }
+        if !biome_rules.is_empty() {
+            linter.insert("rules".to_string(), Value::Object(biome_rules));
+        }
+
+        if !linter.is_empty() {
+            result.insert("linter".to_string(), Value::Object(linter));
+        }
+
+        // Legacy configurations put the globals at the root, flat configurations put them
+        // in the language options
+        let globals = eslint_configuration
+            .get("globals")
+            .or_else(|| eslint_configuration.get("languageOptions")?.get("globals"))
+            .and_then(Value::as_object);
+        if let Some(globals) = globals {
+            let globals = globals
+                .iter()
+                .filter(|(_, value)| value.as_str() != Some("off"))
+                .map(|(name, _)| Value::String(name.clone()))
+                .collect::<Vec<_>>();
+            if !globals.is_empty() {
+                result.insert("javascript".to_string(), json!({ "globals": globals }));
+            }
+        }
+
+        result
+    }
+
+    /// Enables the Biome presets that cover the shared configurations listed in `extends`
+    fn migrate_extends(&mut self, extends: &Value, biome_rules: &mut Map<String, Value>) {
+        let extends = match extends {
+            Value::String(_) => std::slice::from_ref(extends),
+            Value::Array(extends) => extends.as_slice(),
+            _ => &[],
+        };
+        for preset in extends.iter().filter_map(Value::as_str) {
+            if preset == "eslint:all" {
+                // `all` and `recommended` can't be both enabled
+                biome_rules.remove("recommended");
+                biome_rules.insert("all".to_string(), Value::Bool(true));
+            } else if RECOMMENDED_PRESETS.contains(&preset) {
+                if !biome_rules.contains_key("all") {
+                    biome_rules.insert("recommended".to_string(), Value::Bool(true));
+                }
+            } else if !FORMATTING_PRESETS.contains(&preset) {
+                self.unsupported_extends.insert(preset.to_string());
+            }
+        }
+    }
+
+    fn migrate_rule(
+        &mut self,
+        eslint_rule_name: &str,
+        eslint_rule: &Value,
+        rules_index: &EslintRules,
+        options: &EslintMigrationOptions,
+        biome_rules: &mut Map<String, Value>,
+    ) {
+        let Some((level, rule_options)) = parse_rule(eslint_rule) else {
+            return;
+        };
+        let Some(migrated_rules) = rules_index.get(eslint_rule_name) else {
+            self.unsupported_rules.insert(eslint_rule_name.to_string());
+            return;
+        };
+
+        let mut is_migrated = false;
+        for migrated_rule in migrated_rules {
+            if migrated_rule.is_inspired && !options.include_inspired {
+                continue;
+            }
+            if migrated_rule.group == "nursery" && !options.include_nursery {
+                continue;
+            }
+            is_migrated = true;
+
+            let configuration = if rule_options.is_empty() || level == "off" {
+                Value::String(level.to_string())
+            } else if let Some((options, is_complete)) =
+                migrate_rule_options(migrated_rule.name, rule_options)
+            {
+                if !is_complete {
+                    self.partially_migrated_options
+                        .insert(eslint_rule_name.to_string());
+                }
+                json!({ "level": level, "options": options })
+            } else {
+                self.unsupported_options
+                    .insert(eslint_rule_name.to_string());
+                Value::String(level.to_string())
+            };
+
+            let group = biome_rules
+                .entry(migrated_rule.group)
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Some(group) = group.as_object_mut() {
+                group.insert(migrated_rule.name.to_string(), configuration);
+            }
+        }
+
+        if !is_migrated {
+            if migrated_rules.iter().any(|rule| rule.group == "nursery") {
+                self.nursery_rules.insert(eslint_rule_name.to_string());
+            } else {
+                self.inspired_rules.insert(eslint_rule_name.to_string());
+            }
+        }
+    }
+
+    /// Migrates the ESLint configuration into a JSON representation of the Biome configuration
+    fn migrate(
+        &mut self,
+        eslint_configuration: &Value,
+        ignore_patterns: Vec<Value>,
+        options: &EslintMigrationOptions,
+    ) -> Result<Value, CliDiagnostic> {
+        let rules_index = EslintRules::new();
+        let mut ignore_patterns = ignore_patterns;
+        let mut overrides = Vec::new();
+
+        let mut result = match eslint_configuration {
+            // A flat configuration is a list of configuration objects, that apply to all the
+            // files unless they have `files` or `ignores`
+            Value::Array(configurations) => {
+                let mut result = Map::new();
+                for configuration in configurations.iter().filter_map(Value::as_object) {
+                    let files = configuration.get("files").map(to_globs);
+                    let ignores = configuration.get("ignores").map(to_globs);
+                    // An object with only `ignores` ignores the files globally
+                    if files.is_none()
+                        && configuration
+                            .keys()
+                            .all(|key| key == "ignores" || key == "name")
+                    {
+                        ignore_patterns.extend(ignores.unwrap_or_default());
+                    } else if files.is_none() && ignores.is_none() {
+                        let migrated = self.migrate_configuration_object(
+                            configuration,
+                            &rules_index,
+                            options,
+                            false,
+                        );
+                        merge_json(&mut result, migrated);
+                    } else {
+                        overrides.push(self.migrate_override(
+                            configuration,
+                            files,
+                            ignores,
+                            &rules_index,
+                            options,
+                        ));
+                    }
+                }
+                result
+            }
+            Value::Object(configuration) => {
+                if let Some(patterns) = configuration.get("ignorePatterns") {
+                    ignore_patterns.extend(to_globs(patterns));
+                    self.ignore_path = self.configuration_path.clone();
+                }
+                let result =
+                    self.migrate_configuration_object(configuration, &rules_index, options, false);
+                if let Some(eslint_overrides) =
+                    configuration.get("overrides").and_then(Value::as_array)
+                {
+                    for eslint_override in eslint_overrides.iter().filter_map(Value::as_object) {
+                        let files = eslint_override.get("files").map(to_globs);
+                        let excluded_files = eslint_override.get("excludedFiles").map(to_globs);
+                        overrides.push(self.migrate_override(
+                            eslint_override,
+                            files,
+                            excluded_files,
+                            &rules_index,
+                            options,
+                        ));
+                    }
+                }
+                result
+            }
+            _ => {
+                return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                    reason: "The ESLint configuration must be an object, or a list of objects."
+                        .to_string(),
+                }))
+            }
+        };
+
+        if !ignore_patterns.is_empty() {
+            let linter = result
+                .entry("linter")
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Some(linter) = linter.as_object_mut() {
+                linter.insert("ignore".to_string(), Value::Array(ignore_patterns));
+            }
+        }
+        if !overrides.is_empty() {
+            result.insert("overrides".to_string(), Value::Array(overrides));
+        }
+
+        Ok(Value::Object(result))
+    }
+
+    fn migrate_override(
+        &mut self,
+        eslint_override: &Map<String, Value>,
+        include: Option<Vec<Value>>,
+        ignore: Option<Vec<Value>>,
+        rules_index: &EslintRules,
+        options: &EslintMigrationOptions,
+    ) -> Value {
+        let mut result =
+            self.migrate_configuration_object(eslint_override, rules_index, options, true);
+        if let Some(ignore) = ignore {
+            result.insert("ignore".to_string(), Value::Array(ignore));
+        }
+        result.insert(
+            "include".to_string(),
+            Value::Array(include.unwrap_or_else(|| vec![Value::String("**".to_string())])),
+        );
+        Value::Object(result)
+    }
+}
+
+/// Reads the level and the options of an ESLint rule. ESLint accepts a severity, as a number or
+/// as a string, or an array with the severity followed by the options.
+fn parse_rule(value: &Value) -> Option<(&'static str, &[Value])> {
+    let (severity, options) = match value {
+        Value::Array(items) => (items.first()?, &items[1..]),
+        _ => (value, &[] as &[Value]),
+    };
+    let level = match severity {
+        Value::Number(number) => match number.as_u64()? {
+            0 => "off",
+            1 => "warn",
+            2 => "error",
+            _ => return None,
+        },
+        Value::String(severity) => match severity.as_str() {
+            "off" => "off",
+            "warn" => "warn",
+            "error" => "error",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some((level, options))
+}
+
+/// Translates the options of an ESLint rule into the options of a Biome rule, and tells whether
+/// all the options were translated.
+///
+/// It returns [None] when the Biome rule has no equivalent for the options.
+fn migrate_rule_options(biome_rule_name: &str, options: &[Value]) -> Option<(Value, bool)> {
+    match biome_rule_name {
+        "noRestrictedGlobals" => {
+            // ["name", { "name": "name", "message": "..." }]
+            let denied_globals = options
+                .iter()
+                .filter_map(|option| match option {
+                    Value::String(name) => Some(name.as_str()),
+                    Value::Object(option) => option.get("name")?.as_str(),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            // Biome doesn't support the custom messages
+            let is_complete = options
+                .iter()
+                .all(|option| option.is_string() || has_only_keys(option, &["name"]));
+            (!denied_globals.is_empty())
+                .then(|| (json!({ "deniedGlobals": denied_globals }), is_complete))
+        }
+        "noRestrictedImports" => {
+            // ["name", { "name": "name", "message": "..." }] or [{ "paths": [...] }]
+            let (paths, mut is_complete) = match options {
+                [Value::Object(option)] if option.contains_key("paths") => (
+                    option.get("paths")?.as_array()?.as_slice(),
+                    option.len() == 1,
+                ),
+                _ => (options, true),
+            };
+            let mut restricted = Map::new();
+            for path in paths {
+                let (name, message) = match path {
+                    Value::String(name) => (name.as_str(), None),
+                    Value::Object(path) => (
+                        path.get("name")?.as_str()?,
+                        path.get("message").and_then(Value::as_str),
+                    ),
+                    _ => return None,
+                };
+                is_complete &= path.is_string() || has_only_keys(path, &["name", "message"]);
+                let message = message.map_or_else(
+                    || format!("'{name}' import is restricted from being used."),
+                    str::to_string,
+                );
+                restricted.insert(name.to_string(), Value::String(message));
+            }
+            (!restricted.is_empty()).then(|| (json!({ "paths": restricted }), is_complete))
+        }
+        "noExcessiveCognitiveComplexity" => {
+            // [15]
+            let threshold = options.first()?.as_u64()?;
+            Some((
+                json!({ "maxAllowedComplexity": threshold }),
+                options.len() == 1,
+            ))
+        }
+        "noImportCycles" => {
+            // [{ "maxDepth": 3, "ignoreExternal": true }]
+            let option = options.first()?;
+            let max_depth = option
+                .get("maxDepth")?
+                .as_u64()
+                .filter(|depth| *depth > 0)?;
+            Some((
+                json!({ "maxDepth": max_depth }),
+                options.len() == 1 && has_only_keys(option, &["maxDepth"]),
+            ))
+        }
+        "useConsistentArrayType" => {
+            // [{ "default": "array" | "generic" | "array-simple", "readonly": "..." }]
+            let option = options.first()?;
+            let syntax = match option.get("default")?.as_str()? {
+                "array" => "shorthand",
+                "generic" => "generic",
+                _ => return None,
+            };
+            Some((
+                json!({ "syntax": syntax }),
+                options.len() == 1 && has_only_keys(option, &["default"]),
+            ))
+        }
+        "useFilenamingConvention" => {
+            // [{ "case": "kebabCase" }] or [{ "cases": { "kebabCase": true } }]
+            let option = options.first()?;
+            let cases = match (option.get("case"), option.get("cases")) {
+                (Some(case), _) => vec![case.as_str()?],
+                (None, Some(cases)) => cases
+                    .as_object()?
+                    .iter()
+                    .filter(|(_, enabled)| enabled.as_bool() == Some(true))
+                    .map(|(case, _)| case.as_str())
+                    .collect(),
+                (None, None) => return None,
+            };
+            let filename_cases = cases
+                .into_iter()
+                .map(|case| match case {
+                    "camelCase" => Some("camelCase"),
+                    "kebabCase" => Some("kebab-case"),
+                    "pascalCase" => Some("PascalCase"),
+                    "snakeCase" => Some("snake_case"),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some((
+                json!({ "filenameCases": filename_cases }),
+                options.len() == 1 && has_only_keys(option, &["case", "cases"]),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Whether `value` is an object whose members are all named in `keys`
+fn has_only_keys(value: &Value, keys: &[&str]) -> bool {
+    value
+        .as_object()
+        .is_some_and(|object| object.keys().all(|key| keys.contains(&key.as_str())))
+}
+
+/// ESLint accepts a single glob or a list of globs
+fn to_globs(value: &Value) -> Vec<Value> {
+    match value {
+        Value::String(_) => vec![value.clone()],
+        Value::Array(globs) => globs
+            .iter()
+            .filter(|glob| glob.is_string())
+            .cloned()
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Merges the members of `other` into `target`, objects are merged recursively
+fn merge_json(target: &mut Map<String, Value>, other: Map<String, Value>) {
+    for (key, value) in other {
+        match (target.get_mut(&key), value) {
+            (Some(Value::Object(target)), Value::Object(value)) => merge_json(target, value),
+            (Some(Value::Array(target)), Value::Array(value)) => target.extend(value),
+            (_, value) => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
+fn join(rules: &BTreeSet<String>) -> String {
+    rules
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn read_file(
+    fs: &DynRef<'_, dyn FileSystem>,
+    path: &Path,
+) -> Result<Option<String>, CliDiagnostic> {
+    let Ok(mut file) = fs.open_with_options(path, OpenOptions::default().read(true)) else {
+        return Ok(None);
+    };
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(CliDiagnostic::io_error)?;
+    Ok(Some(content))
+}
+
+/// This function is in charge of reading the ESLint files, deserialize their contents and
+/// convert them in a Biome configuration type
+pub(crate) fn read_eslint_files(
+    fs: &DynRef<'_, dyn FileSystem>,
+    console: &mut dyn Console,
+    options: &EslintMigrationOptions,
+) -> Result<FromEslintConfiguration, CliDiagnostic> {
+    let mut from_eslint_configuration = FromEslintConfiguration::default();
+
+    let mut eslint_config_content = None;
+    for config_name in ESLINT_CONFIG_FILES {
+        let path = Path::new(config_name);
+        if let Some(content) = read_file(fs, path)? {
+            from_eslint_configuration.configuration_path = Some(path.to_path_buf());
+            eslint_config_content = Some(content);
+            break;
+        }
+    }
+    // The configuration can be stored in the `eslintConfig` field of the `package.json` file
+    let is_package_json = eslint_config_content.is_none();
+    if is_package_json {
+        let path = Path::new(PACKAGE_JSON);
+        if let Some(content) = read_file(fs, path)? {
+            from_eslint_configuration.configuration_path = Some(path.to_path_buf());
+            eslint_config_content = Some(content);
+        }
+    }
+
+    let Some(eslint_config_content) = eslint_config_content else {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Biome couldn't find an ESLint configuration file.".to_string(),
+        }));
+    };
+
+    let deserialized = deserialize_from_json_str::<EslintValue>(
+        eslint_config_content.as_str(),
+        JsonParserOptions::default()
+            .with_allow_trailing_commas()
+            .with_allow_comments(),
+        "",
+    );
+    if deserialized.has_errors() {
+        let diagnostics = deserialized.into_diagnostics();
+        for diagnostic in diagnostics {
+            let diagnostic = if let Some(path) = from_eslint_configuration.get_configuration_path()
+            {
+                diagnostic.with_file_path(path.display().to_string())
+            } else {
+                diagnostic
+            };
+            console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+        }
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not deserialize the ESLint configuration file".to_string(),
+        }));
+    }
+    let Some(EslintValue(mut eslint_configuration)) = deserialized.into_deserialized() else {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not deserialize the ESLint configuration file".to_string(),
+        }));
+    };
+    if is_package_json {
+        eslint_configuration = match eslint_configuration.get_mut("eslintConfig") {
+            Some(eslint_config) => eslint_config.take(),
+            None => {
+                return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                    reason: "Biome couldn't find an ESLint configuration file.".to_string(),
+                }))
+            }
+        };
+    }
+
+    let mut ignore_patterns = Vec::new();
+    let path = Path::new(ESLINT_IGNORE_FILE);
+    if let Some(ignore_file_content) = read_file(fs, path)? {
+        ignore_patterns.extend(
+            ignore_file_content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .filter(|line| !line.starts_with('#'))
+                .map(|line| Value::String(line.to_string())),
+        );
+        from_eslint_configuration.ignore_path = Some(path.to_path_buf());
+    }
+
+    let biome_configuration =
+        from_eslint_configuration.migrate(&eslint_configuration, ignore_patterns, options)?;
+
+    let deserialized = deserialize_from_json_str::<PartialConfiguration>(
+        biome_configuration.to_string().as_str(),
+        JsonParserOptions::default(),
+        "",
+    );
+    if deserialized.has_errors() {
+        for diagnostic in deserialized.into_diagnostics() {
+            console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+        }
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "The ESLint configuration couldn't be migrated".to_string(),
+        }));
+    }
+    from_eslint_configuration.configuration = deserialized.into_deserialized().unwrap_or_default();
+
+    Ok(from_eslint_configuration)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{migrate_rule_options, parse_rule, EslintRules};
+    use serde_json::json;
+
+    #[test]
+    fn parse_rule_severities() {
+        assert_eq!(parse_rule(&json!(0)), Some(("off", [].as_slice())));
+        assert_eq!(parse_rule(&json!("warn")), Some(("warn", [].as_slice())));
+        assert_eq!(
+            parse_rule(&json!([2, "always"])),
+            Some(("error", [json!("always")].as_slice()))
+        );
+        assert_eq!(parse_rule(&json!("unknown")), None);
+    }
+
+    #[test]
+    fn index_rules_by_eslint_name() {
+        let rules = EslintRules::new();
+        let no_debugger = rules.get("no-debugger").unwrap();
+        assert_eq!(no_debugger[0].group, "suspicious");
+        assert_eq!(no_debugger[0].name, "noDebugger");
+        assert!(rules.get("jsx-a11y/alt-text").is_some());
+        assert!(rules.get("unknown-rule").is_none());
+    }
+
+    #[test]
+    fn migrate_options() {
+        assert_eq!(
+            migrate_rule_options(
+                "noRestrictedGlobals",
+                &[json!("event"), json!({ "name": "fdescribe" })]
+            ),
+            Some((json!({ "deniedGlobals": ["event", "fdescribe"] }), true))
+        );
+        assert_eq!(
+            migrate_rule_options(
+                "noRestrictedGlobals",
+                &[json!({ "name": "event", "message": "Use the parameter" })]
+            ),
+            Some((json!({ "deniedGlobals": ["event"] }), false))
+        );
+        assert_eq!(
+            migrate_rule_options("useFilenamingConvention", &[json!({ "case": "kebabCase" })]),
+            Some((json!({ "filenameCases": ["kebab-case"] }), true))
+        );
+        assert_eq!(
+            migrate_rule_options(
+                "noImportCycles",
+                &[json!({ "maxDepth": 3, "ignoreExternal": true })]
+            ),
+            Some((json!({ "maxDepth": 3 }), false))
+        );
+        assert_eq!(
+            migrate_rule_options(
+                "useConsistentArrayType",
+                &[json!({ "default": "array-simple" })]
+            ),
+            None
+        );
+    }
+}
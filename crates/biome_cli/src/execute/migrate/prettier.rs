@@ -2,16 +2,22 @@ use crate::diagnostics::MigrationDiagnostic;
 use crate::CliDiagnostic;
 use biome_console::{markup, Console, ConsoleExt};
 use biome_deserialize::json::deserialize_from_json_str;
-use biome_deserialize::StringSet;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor,
+    StringSet, VisitableType,
+};
 use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::{DiagnosticExt, PrintDiagnostic};
 use biome_formatter::{AttributePosition, LineEnding, LineWidth, QuoteStyle};
 use biome_fs::{FileSystem, OpenOptions};
 use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, Semicolons, TrailingComma};
+use biome_json_formatter::context::TrailingCommas;
 use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
 use biome_service::configuration::{
-    PartialFormatterConfiguration, PartialJavascriptConfiguration, PartialJavascriptFormatter,
-    PlainIndentStyle,
+    OverrideFormatterConfiguration, OverridePattern, Overrides, PartialCssConfiguration,
+    PartialCssFormatter, PartialFormatterConfiguration, PartialJavascriptConfiguration,
+    PartialJavascriptFormatter, PartialJsonConfiguration, PartialJsonFormatter, PlainIndentStyle,
 };
 use biome_service::{DynRef, PartialConfiguration};
 use indexmap::IndexSet;
@@ -35,7 +41,7 @@ pub(crate) struct PrettierConfiguration {
     /// https://prettier.io/docs/en/options#bracket-spcing
     bracket_spacing: bool,
     /// https://prettier.io/docs/en/options#bracket-line
-    bracket_line: bool,
+    bracket_same_line: bool,
     /// https://prettier.io/docs/en/options#quote-props
     quote_props: QuoteProps,
     /// https://prettier.io/docs/en/options#jsx-quotes
@@ -44,6 +50,10 @@ pub(crate) struct PrettierConfiguration {
     arrow_parens: ArrowParens,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: EndOfLine,
+    /// https://prettier.io/docs/en/options#single-attribute-per-line
+    single_attribute_per_line: bool,
+    /// https://prettier.io/docs/en/configuration#configuration-overrides
+    overrides: Vec<PrettierOverride>,
 }
 
 impl Default for PrettierConfiguration {
@@ -54,14 +64,134 @@ impl Default for PrettierConfiguration {
             use_tabs: false,
             trailing_comma: PrettierTrailingComma::default(),
             tab_width: 2,
-            semi: false,
-            single_quote: true,
+            semi: true,
+            single_quote: false,
             bracket_spacing: true,
-            bracket_line: false,
+            bracket_same_line: false,
             quote_props: QuoteProps::default(),
             jsx_single_quote: false,
             arrow_parens: ArrowParens::default(),
             end_of_line: EndOfLine::default(),
+            single_attribute_per_line: false,
+            overrides: vec![],
+        }
+    }
+}
+
+/// An entry of the `overrides` field of a Prettier configuration
+#[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
+#[deserializable(unknown_fields = "allow")]
+struct PrettierOverride {
+    /// The globs of the files where the options apply
+    files: PrettierGlobs,
+    /// The globs of the files excluded from `files`
+    excluded_files: PrettierGlobs,
+    /// The options that override the ones of the configuration
+    options: PrettierOverrideOptions,
+}
+
+/// The options of an override, with the names of all the options that are set,
+/// so the ones that can't be migrated can be reported
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct PrettierOverrideOptions {
+    options: PrettierOptions,
+    names: Vec<String>,
+}
+
+impl Deserializable for PrettierOverrideOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let options = PrettierOptions::deserialize(value, name, diagnostics)?;
+        let names = value.deserialize(OptionNamesVisitor, name, diagnostics)?;
+        Some(Self { options, names })
+    }
+}
+
+struct OptionNamesVisitor;
+impl DeserializationVisitor for OptionNamesVisitor {
+    type Output = Vec<String>;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(
+            members
+                .flatten()
+                .filter_map(|(key, _)| String::deserialize(&key, "", diagnostics))
+                .collect(),
+        )
+    }
+}
+
+/// The names of the options that Biome can migrate
+const PRETTIER_SUPPORTED_OPTIONS: [&str; 13] = [
+    "printWidth",
+    "useTabs",
+    "trailingComma",
+    "tabWidth",
+    "semi",
+    "singleQuote",
+    "bracketSpacing",
+    "bracketSameLine",
+    "quoteProps",
+    "jsxSingleQuote",
+    "arrowParens",
+    "endOfLine",
+    "singleAttributePerLine",
+];
+
+/// The options of an override. Only the options that are set are migrated.
+#[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
+#[deserializable(unknown_fields = "allow")]
+struct PrettierOptions {
+    print_width: Option<u16>,
+    use_tabs: Option<bool>,
+    trailing_comma: Option<PrettierTrailingComma>,
+    tab_width: Option<u8>,
+    semi: Option<bool>,
+    single_quote: Option<bool>,
+    bracket_spacing: Option<bool>,
+    bracket_same_line: Option<bool>,
+    quote_props: Option<QuoteProps>,
+    jsx_single_quote: Option<bool>,
+    arrow_parens: Option<ArrowParens>,
+    end_of_line: Option<EndOfLine>,
+    single_attribute_per_line: Option<bool>,
+}
+
+/// Globs of Prettier can be a single string or an array of strings
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct PrettierGlobs(Vec<String>);
+
+impl Deserializable for PrettierGlobs {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        if value.is_type(VisitableType::STR) {
+            String::deserialize(value, name, diagnostics).map(|glob| Self(vec![glob]))
+        } else {
+            Vec::deserialize(value, name, diagnostics).map(Self)
+        }
+    }
+}
+
+impl PrettierGlobs {
+    fn to_string_set(&self) -> Option<StringSet> {
+        if self.0.is_empty() {
+            None
+        } else {
+            Some(StringSet::new(self.0.iter().cloned().collect()))
         }
     }
 }
@@ -95,6 +225,7 @@ enum QuoteProps {
     #[default]
     #[deserializable(rename = "as-needed")]
     AsNeeded,
+    Consistent,
     Preserve,
 }
 
@@ -120,6 +251,15 @@ impl From<PrettierTrailingComma> for TrailingComma {
     }
 }
 
+impl From<PrettierTrailingComma> for TrailingCommas {
+    fn from(value: PrettierTrailingComma) -> Self {
+        match value {
+            PrettierTrailingComma::All => Self::All,
+            PrettierTrailingComma::None | PrettierTrailingComma::Es5 => Self::None,
+        }
+    }
+}
+
 impl From<ArrowParens> for ArrowParentheses {
     fn from(value: ArrowParens) -> Self {
         match value {
@@ -143,74 +283,190 @@ impl From<EndOfLine> for LineEnding {
 impl From<QuoteProps> for QuoteProperties {
     fn from(value: QuoteProps) -> Self {
         match value {
-            QuoteProps::AsNeeded => Self::AsNeeded,
+            QuoteProps::AsNeeded | QuoteProps::Consistent => Self::AsNeeded,
             QuoteProps::Preserve => Self::Preserve,
         }
     }
 }
 
-impl TryFrom<PrettierConfiguration> for PartialFormatterConfiguration {
-    type Error = String;
-    fn try_from(value: PrettierConfiguration) -> Result<Self, Self::Error> {
-        let line_width = LineWidth::try_from(value.print_width).map_err(|err| err.to_string())?;
-        let indent_style = if value.use_tabs {
-            PlainIndentStyle::Tab
-        } else {
-            PlainIndentStyle::Space
-        };
-        Ok(Self {
-            indent_width: Some(value.tab_width),
-            line_width: Some(line_width),
-            indent_style: Some(indent_style),
-            line_ending: Some(value.end_of_line.into()),
-            attribute_position: Some(AttributePosition::default()),
-            format_with_errors: Some(false),
-            ignore: None,
-            include: None,
-            enabled: Some(true),
-            // deprecated
-            indent_size: None,
-        })
+impl From<PrettierConfiguration> for PrettierOptions {
+    fn from(value: PrettierConfiguration) -> Self {
+        Self {
+            print_width: Some(value.print_width),
+            use_tabs: Some(value.use_tabs),
+            trailing_comma: Some(value.trailing_comma),
+            tab_width: Some(value.tab_width),
+            semi: Some(value.semi),
+            single_quote: Some(value.single_quote),
+            bracket_spacing: Some(value.bracket_spacing),
+            bracket_same_line: Some(value.bracket_same_line),
+            quote_props: Some(value.quote_props),
+            jsx_single_quote: Some(value.jsx_single_quote),
+            arrow_parens: Some(value.arrow_parens),
+            end_of_line: Some(value.end_of_line),
+            single_attribute_per_line: Some(value.single_attribute_per_line),
+        }
     }
 }
 
-impl From<PrettierConfiguration> for PartialJavascriptFormatter {
-    fn from(value: PrettierConfiguration) -> Self {
-        let semicolons = if value.semi {
-            Semicolons::Always
-        } else {
-            Semicolons::AsNeeded
+fn quote_style(single_quote: bool) -> QuoteStyle {
+    if single_quote {
+        QuoteStyle::Single
+    } else {
+        QuoteStyle::Double
+    }
+}
+
+impl PrettierOptions {
+    fn line_width(&self) -> Result<Option<LineWidth>, String> {
+        self.print_width
+            .map(|print_width| LineWidth::try_from(print_width).map_err(|err| err.to_string()))
+            .transpose()
+    }
+
+    fn indent_style(&self) -> Option<PlainIndentStyle> {
+        self.use_tabs.map(|use_tabs| {
+            if use_tabs {
+                PlainIndentStyle::Tab
+            } else {
+                PlainIndentStyle::Space
+            }
+        })
+    }
+
+    fn attribute_position(&self) -> Option<AttributePosition> {
+        self.single_attribute_per_line
+            .map(|single_attribute_per_line| {
+                if single_attribute_per_line {
+                    AttributePosition::Multiline
+                } else {
+                    AttributePosition::Auto
+                }
+            })
+    }
+
+    /// The options that apply to every language
+    fn to_override_formatter(&self) -> Result<Option<OverrideFormatterConfiguration>, String> {
+        let formatter = OverrideFormatterConfiguration {
+            indent_width: self.tab_width,
+            line_width: self.line_width()?,
+            indent_style: self.indent_style(),
+            line_ending: self.end_of_line.clone().map(LineEnding::from),
+            attribute_position: self.attribute_position(),
+            ..Default::default()
         };
-        let quote_style = if value.single_quote {
-            QuoteStyle::Single
-        } else {
-            QuoteStyle::Double
+        Ok((formatter != OverrideFormatterConfiguration::default()).then_some(formatter))
+    }
+
+    fn to_javascript_formatter(&self) -> Option<PartialJavascriptFormatter> {
+        let formatter = PartialJavascriptFormatter {
+            bracket_same_line: self.bracket_same_line,
+            arrow_parentheses: self.arrow_parens.clone().map(ArrowParentheses::from),
+            semicolons: self.semi.map(|semi| {
+                if semi {
+                    Semicolons::Always
+                } else {
+                    Semicolons::AsNeeded
+                }
+            }),
+            trailing_comma: self.trailing_comma.clone().map(TrailingComma::from),
+            quote_style: self.single_quote.map(quote_style),
+            quote_properties: self.quote_props.clone().map(QuoteProperties::from),
+            bracket_spacing: self.bracket_spacing,
+            jsx_quote_style: self.jsx_single_quote.map(quote_style),
+            attribute_position: self.attribute_position(),
+            ..Default::default()
         };
-        let jsx_quote_style = if value.jsx_single_quote {
-            QuoteStyle::Single
-        } else {
-            QuoteStyle::Double
+        (formatter != PartialJavascriptFormatter::default()).then_some(formatter)
+    }
+
+    /// Prettier respects `trailingComma` only in the files parsed as JSON with comments
+    fn to_json_formatter(&self) -> Option<PartialJsonFormatter> {
+        self.trailing_comma
+            .clone()
+            .map(|trailing_comma| PartialJsonFormatter {
+                trailing_commas: Some(trailing_comma.into()),
+                ..Default::default()
+            })
+    }
+
+    fn to_css_formatter(&self) -> Option<PartialCssFormatter> {
+        self.single_quote.map(|single_quote| PartialCssFormatter {
+            quote_style: Some(quote_style(single_quote)),
+            ..Default::default()
+        })
+    }
+
+    /// Prints a warning for each option that Biome doesn't support
+    fn report_unsupported(&self, console: &mut dyn Console) {
+        if self.end_of_line == Some(EndOfLine::Auto) {
+            console.log(markup! {
+                <Warn>"Prettier's `\"endOfLine\": \"auto\"` option is not supported in Biome. The default `\"lf\"` option is used instead."</Warn>
+            });
+        }
+        if self.quote_props == Some(QuoteProps::Consistent) {
+            console.log(markup! {
+                <Warn>"Prettier's `\"quoteProps\": \"consistent\"` option is not supported in Biome. The `\"asNeeded\"` option is used instead."</Warn>
+            });
+        }
+    }
+}
+
+/// The languages targeted by the globs of a Prettier override
+#[derive(Debug, Default, Eq, PartialEq)]
+struct OverrideTargets {
+    javascript: bool,
+    json: bool,
+    /// Whether all the targeted JSON files are JSON files with comments
+    jsonc: bool,
+    css: bool,
+}
+
+impl OverrideTargets {
+    const ALL: Self = Self {
+        javascript: true,
+        json: true,
+        jsonc: false,
+        css: true,
+    };
+
+    /// Guesses the targeted languages from the extensions matched by the globs.
+    /// A glob that doesn't match a specific extension targets all the languages.
+    fn from_globs(globs: &[String]) -> Self {
+        let mut targets = Self {
+            jsonc: true,
+            ..Default::default()
         };
-        Self {
-            indent_width: None,
-            line_width: None,
-            indent_style: None,
-            line_ending: None,
-            enabled: None,
-            // deprecated
-            indent_size: None,
-
-            // js ones
-            bracket_same_line: Some(value.bracket_line),
-            arrow_parentheses: Some(value.arrow_parens.into()),
-            semicolons: Some(semicolons),
-            trailing_comma: Some(value.trailing_comma.into()),
-            quote_style: Some(quote_style),
-            quote_properties: Some(value.quote_props.into()),
-            bracket_spacing: Some(value.bracket_spacing),
-            jsx_quote_style: Some(jsx_quote_style),
-            attribute_position: Some(AttributePosition::default()),
+        for glob in globs {
+            let file_name = glob.rsplit('/').next().unwrap_or(glob);
+            let Some((_, extension)) = file_name.rsplit_once('.') else {
+                return Self::ALL;
+            };
+            let extensions = match extension
+                .strip_prefix('{')
+                .and_then(|extension| extension.strip_suffix('}'))
+            {
+                Some(extensions) => extensions.split(',').collect(),
+                None => vec![extension],
+            };
+            for extension in extensions {
+                match extension {
+                    "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "mts" | "cts" => {
+                        targets.javascript = true
+                    }
+                    "json" | "json5" => {
+                        targets.json = true;
+                        targets.jsonc = false;
+                    }
+                    "jsonc" => targets.json = true,
+                    "css" => targets.css = true,
+                    _ if extension.contains('*') => return Self::ALL,
+                    _ => {}
+                }
+            }
         }
+        targets.jsonc &= targets.json;
+        targets
     }
 }
 
@@ -219,7 +475,6 @@ pub(crate) struct FromPrettierConfiguration {
     /// Path of the Prettier configuration file
     configuration_path: Option<PathBuf>,
     /// Path of the `.prettierignore` file
-    #[allow(unused)]
     ignore_path: Option<PathBuf>,
 
     /// The translated Biome configuration, from the Prettier configuration
@@ -227,18 +482,103 @@ pub(crate) struct FromPrettierConfiguration {
 
     /// The translated Biome configuration, from the Prettier configuration
     javascript_formatter_configuration: Option<PartialJavascriptFormatter>,
+
+    /// The translated Biome configuration, from the Prettier configuration
+    css_formatter_configuration: Option<PartialCssFormatter>,
+
+    /// The translated Biome overrides, from the Prettier overrides
+    overrides: Vec<OverridePattern>,
 }
 
 impl FromPrettierConfiguration {
     pub(crate) fn store_configuration(
         &mut self,
-        (formatter, javascript_formatter): (
-            PartialFormatterConfiguration,
-            PartialJavascriptFormatter,
-        ),
-    ) {
-        self.formatter_configuration = Some(formatter);
-        self.javascript_formatter_configuration = Some(javascript_formatter);
+        configuration: PrettierConfiguration,
+        console: &mut dyn Console,
+    ) -> Result<(), String> {
+        let overrides = configuration.overrides.clone();
+        let options = PrettierOptions::from(configuration);
+        options.report_unsupported(console);
+
+        let attribute_position = options.attribute_position();
+        let formatter = options.to_override_formatter()?.unwrap_or_default();
+        self.formatter_configuration = Some(PartialFormatterConfiguration {
+            indent_width: formatter.indent_width,
+            line_width: formatter.line_width,
+            indent_style: formatter.indent_style,
+            line_ending: formatter.line_ending,
+            attribute_position,
+            format_with_errors: Some(false),
+            enabled: Some(true),
+            ..Default::default()
+        });
+        self.javascript_formatter_configuration = options.to_javascript_formatter();
+        self.css_formatter_configuration = options.to_css_formatter();
+
+        for prettier_override in overrides {
+            let PrettierOverrideOptions { options, names } = prettier_override.options;
+            options.report_unsupported(console);
+
+            let targets = OverrideTargets::from_globs(&prettier_override.files.0);
+            let mut biome_override = OverridePattern {
+                include: prettier_override.files.to_string_set(),
+                ignore: prettier_override.excluded_files.to_string_set(),
+                formatter: options.to_override_formatter()?,
+                ..Default::default()
+            };
+            if targets.javascript {
+                biome_override.javascript = options.to_javascript_formatter().map(|formatter| {
+                    PartialJavascriptConfiguration {
+                        formatter: Some(formatter),
+                        ..Default::default()
+                    }
+                });
+            }
+            if targets.jsonc {
+                biome_override.json =
+                    options
+                        .to_json_formatter()
+                        .map(|formatter| PartialJsonConfiguration {
+                            formatter: Some(formatter),
+                            ..Default::default()
+                        });
+            }
+            if targets.css {
+                biome_override.css =
+                    options
+                        .to_css_formatter()
+                        .map(|formatter| PartialCssConfiguration {
+                            formatter: Some(formatter),
+                            ..Default::default()
+                        });
+            }
+            let has_options = biome_override.formatter.is_some()
+                || biome_override.javascript.is_some()
+                || biome_override.json.is_some()
+                || biome_override.css.is_some();
+            let files = prettier_override.files.0.join(", ");
+            if has_options {
+                self.overrides.push(biome_override);
+                let unsupported_names = names
+                    .iter()
+                    .filter(|name| !PRETTIER_SUPPORTED_OPTIONS.contains(&name.as_str()))
+                    .map(String::as_str)
+                    .collect::<Vec<_>>();
+                if !unsupported_names.is_empty() {
+                    let unsupported_names = unsupported_names.join(", ");
+                    console.log(markup! {
+                        <Warn>"The following options of the override of "<Emphasis>{files}</Emphasis>" weren't migrated, because Biome doesn't support them: "{unsupported_names}</Warn>
+                    });
+                }
+            } else if !names.is_empty() {
+                let names = names.join(", ");
+                console.log(markup! {
+                    <Warn>"The override of "<Emphasis>{files}</Emphasis>" wasn't migrated, because none of its options apply to the languages supported by Biome: "{names}</Warn>
+                });
+            }
+        }
+
+        Ok(())
     }
 
     pub(crate) fn store_ignored_globs(&mut self, value: StringSet) {
@@ -268,6 +608,15 @@ impl FromPrettierConfiguration {
                 ..Default::default()
             });
         }
+        if self.css_formatter_configuration.is_some() {
+            configuration.css = Some(PartialCssConfiguration {
+                formatter: self.css_formatter_configuration.clone(),
+                ..Default::default()
+            });
+        }
+        if !self.overrides.is_empty() {
+            configuration.overrides = Some(Overrides(self.overrides.clone()));
+        }
 
         configuration
     }
@@ -285,46 +634,176 @@ impl FromPrettierConfiguration {
     }
 }
 
-const PRETTIER_CONFIG_FILES: [&str; 2] = [".prettierrc", ".prettierrc.json"];
+/// The formats of the Prettier configuration files that Biome can read
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum PrettierConfigurationFormat {
+    Json,
+    /// JSON5 allows unquoted keys and single-quoted strings
+    Json5,
+    Yaml,
+    /// `.prettierrc` can contain either JSON or YAML
+    JsonOrYaml,
+}
+
+const PRETTIER_CONFIG_FILES: [(&str, PrettierConfigurationFormat); 5] = [
+    (".prettierrc", PrettierConfigurationFormat::JsonOrYaml),
+    (".prettierrc.json", PrettierConfigurationFormat::Json),
+    (".prettierrc.yaml", PrettierConfigurationFormat::Yaml),
+    (".prettierrc.yml", PrettierConfigurationFormat::Yaml),
+    (".prettierrc.json5", PrettierConfigurationFormat::Json5),
+];
+/// Configuration files that need to be evaluated, which Biome can't do
+const PRETTIER_UNSUPPORTED_CONFIG_FILES: [&str; 9] = [
+    ".prettierrc.js",
+    "prettier.config.js",
+    ".prettierrc.ts",
+    ".prettierrc.mjs",
+    "prettier.config.mjs",
+    ".prettierrc.cjs",
+    "prettier.config.cjs",
+    ".prettierrc.toml",
+    "prettier.config.ts",
+];
 const PRETTIER_IGNORE_FILE: &str = ".prettierignore";
-/// This function is in charge of reading prettier files, deserialize its contents and convert them in a Biome configuration type
-pub(crate) fn read_prettier_files(
+const PACKAGE_JSON: &str = "package.json";
+
+fn read_file(
     fs: &DynRef<'_, dyn FileSystem>,
-    console: &mut dyn Console,
-) -> Result<FromPrettierConfiguration, CliDiagnostic> {
-    let mut from_prettier_configuration = FromPrettierConfiguration::default();
-    let mut prettier_config_content = String::new();
-    for config_name in PRETTIER_CONFIG_FILES {
-        let open_options = OpenOptions::default().read(true);
-        let path = Path::new(config_name);
-        let file = fs.open_with_options(path, open_options);
-        match file {
-            Ok(mut file) => {
-                let result = file.read_to_string(&mut prettier_config_content);
-                if let Err(err) = result {
-                    return Err(CliDiagnostic::io_error(err));
-                }
+    path: &Path,
+) -> Result<Option<String>, CliDiagnostic> {
+    let Ok(mut file) = fs.open_with_options(path, OpenOptions::default().read(true)) else {
+        return Ok(None);
+    };
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(CliDiagnostic::io_error)?;
+    Ok(Some(content))
+}
+
+fn migrate_error(reason: impl Into<String>) -> CliDiagnostic {
+    CliDiagnostic::MigrateError(MigrationDiagnostic {
+        reason: reason.into(),
+    })
+}
+
+/// Finds the Prettier configuration, and returns its content as JSON,
+/// with the options to parse it
+fn find_prettier_configuration(
+    fs: &DynRef<'_, dyn FileSystem>,
+    from_prettier_configuration: &mut FromPrettierConfiguration,
+) -> Result<(String, JsonParserOptions), CliDiagnostic> {
+    let json_options = JsonParserOptions::default()
+        .with_allow_trailing_commas()
+        .with_allow_comments();
+    // The configuration can be stored in the `prettier` field of the `package.json` file
+    let path = Path::new(PACKAGE_JSON);
+    if let Some(content) = read_file(fs, path)? {
+        let package_json = serde_json::from_str::<serde_json::Value>(&content).map_err(|err| {
+            migrate_error(format!("Could not parse the package.json file: {err}"))
+        })?;
+        match package_json.get("prettier") {
+            Some(serde_json::Value::String(shared_configuration)) => {
+                return Err(migrate_error(format!(
+                    "The Prettier configuration is a shared configuration ({shared_configuration}), Biome can't migrate it."
+                )))
+            }
+            Some(configuration) => {
                 from_prettier_configuration.store_configuration_path(path);
+                return Ok((configuration.to_string(), json_options));
+            }
+            None => {}
+        }
+    }
 
-                break;
+    for (config_name, format) in PRETTIER_CONFIG_FILES {
+        let path = Path::new(config_name);
+        let Some(content) = read_file(fs, path)? else {
+            continue;
+        };
+        from_prettier_configuration.store_configuration_path(path);
+        let is_json = match format {
+            PrettierConfigurationFormat::Json => true,
+            PrettierConfigurationFormat::Json5 => {
+                return Ok((content, JsonParserOptions::default().with_json5()));
             }
-            Err(_) => {
-                continue;
+            PrettierConfigurationFormat::Yaml => false,
+            PrettierConfigurationFormat::JsonOrYaml => {
+                let content = content.trim_start();
+                content.starts_with('{') || content.starts_with("//") || content.starts_with("/*")
             }
+        };
+        if is_json {
+            return Ok((content, json_options));
         }
+        let configuration = serde_yaml::from_str::<serde_json::Value>(&content).map_err(|err| {
+            migrate_error(format!(
+                "Could not parse the Prettier configuration file {config_name}: {err}"
+            ))
+        })?;
+        return Ok((configuration.to_string(), json_options));
     }
 
-    if prettier_config_content.is_empty() {
-        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
-            reason: "Biome couldn't find a Prettier configuration file.".to_string(),
-        }));
+    for config_name in PRETTIER_UNSUPPORTED_CONFIG_FILES {
+        if fs.path_exists(Path::new(config_name)) {
+            return Err(migrate_error(format!(
+                "Biome can't read the Prettier configuration file {config_name}, only the JSON and YAML configuration files are supported."
+            )));
+        }
+    }
+
+    Err(migrate_error(
+        "Biome couldn't find a Prettier configuration file.",
+    ))
+}
+
+/// A pattern of `.prettierignore`, which follows the semantics of `.gitignore`
+#[derive(Debug, Eq, PartialEq)]
+enum IgnorePattern {
+    Glob(String),
+    /// Biome doesn't support patterns that re-include files
+    Negated(String),
+}
+
+/// Converts a line of `.prettierignore` to a Biome glob:
+/// - a pattern that doesn't contain a slash matches at any depth;
+/// - a leading slash anchors the pattern to the directory of the ignore file;
+/// - a trailing slash matches the content of a directory.
+fn parse_ignore_line(line: &str) -> Option<IgnorePattern> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
     }
+    if line.starts_with('!') {
+        return Some(IgnorePattern::Negated(line.to_string()));
+    }
+    let line = line.strip_prefix('\\').unwrap_or(line);
+    let (pattern, is_directory) = match line.strip_suffix('/') {
+        Some(pattern) => (pattern, true),
+        None => (line, false),
+    };
+    let mut glob = match pattern.strip_prefix('/') {
+        Some(pattern) => pattern.to_string(),
+        None if pattern.contains('/') || pattern.starts_with("**") => pattern.to_string(),
+        None => format!("**/{pattern}"),
+    };
+    if is_directory {
+        glob.push_str("/**");
+    }
+    Some(IgnorePattern::Glob(glob))
+}
+
+/// This function is in charge of reading prettier files, deserialize its contents and convert them in a Biome configuration type
+pub(crate) fn read_prettier_files(
+    fs: &DynRef<'_, dyn FileSystem>,
+    console: &mut dyn Console,
+) -> Result<FromPrettierConfiguration, CliDiagnostic> {
+    let mut from_prettier_configuration = FromPrettierConfiguration::default();
+    let (prettier_config_content, parser_options) =
+        find_prettier_configuration(fs, &mut from_prettier_configuration)?;
 
     let deserialized = deserialize_from_json_str::<PrettierConfiguration>(
         prettier_config_content.as_str(),
-        JsonParserOptions::default()
-            .with_allow_trailing_commas()
-            .with_allow_comments(),
+        parser_options,
         "",
     );
 
@@ -339,49 +818,41 @@ pub(crate) fn read_prettier_files(
                 };
             console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
         }
-        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
-            reason: "Could not deserialize the Prettier configuration file".to_string(),
-        }));
+        return Err(migrate_error(
+            "Could not deserialize the Prettier configuration file",
+        ));
     } else {
         let prettier_configuration = deserialized.into_deserialized();
         if let Some(prettier_configuration) = prettier_configuration {
-            if prettier_configuration.end_of_line == EndOfLine::Auto {
-                console.log(markup! {
-                    <Warn>"Prettier's `\"endOfLine\": \"auto\"` option is not supported in Biome. The default `\"lf\"` option is used instead."</Warn>
-                });
-            }
-            let formatter_configuration = prettier_configuration
-                .clone()
-                .try_into()
-                .map_err(|err| CliDiagnostic::MigrateError(MigrationDiagnostic { reason: err }))?;
-            let javascript_configuration = prettier_configuration.into();
             from_prettier_configuration
-                .store_configuration((formatter_configuration, javascript_configuration));
+                .store_configuration(prettier_configuration, console)
+                .map_err(migrate_error)?;
         }
     }
 
-    let mut ignore_file_content = String::new();
-    let open_options = OpenOptions::default().read(true);
     let path = Path::new(PRETTIER_IGNORE_FILE);
-    let file = fs.open_with_options(path, open_options);
-    if let Ok(mut file) = file {
-        let result = file.read_to_string(&mut ignore_file_content);
-        if let Err(err) = result {
-            return Err(CliDiagnostic::io_error(err));
-        }
-        from_prettier_configuration.store_ignore_path(path)
-    }
+    if let Some(ignore_file_content) = read_file(fs, path)? {
+        from_prettier_configuration.store_ignore_path(path);
 
-    if !ignore_file_content.is_empty() {
-        let lines = StringSet::new(
-            ignore_file_content
-                .lines()
-                .filter(|line| !line.is_empty())
-                .filter(|line| !line.starts_with('#'))
-                .map(String::from)
-                .collect::<IndexSet<_>>(),
-        );
-        from_prettier_configuration.store_ignored_globs(lines);
+        let mut globs = IndexSet::new();
+        let mut negated_patterns = vec![];
+        for pattern in ignore_file_content.lines().filter_map(parse_ignore_line) {
+            match pattern {
+                IgnorePattern::Glob(glob) => {
+                    globs.insert(glob);
+                }
+                IgnorePattern::Negated(pattern) => negated_patterns.push(pattern),
+            }
+        }
+        if !negated_patterns.is_empty() {
+            let patterns = negated_patterns.join(", ");
+            console.log(markup! {
+                <Warn>"The following patterns of "<Emphasis>{PRETTIER_IGNORE_FILE}</Emphasis>" weren't migrated, because Biome doesn't support negated patterns: "{patterns}</Warn>
+            });
+        }
+        if !globs.is_empty() {
+            from_prettier_configuration.store_ignored_globs(StringSet::new(globs));
+        }
     }
 
     Ok(from_prettier_configuration)
@@ -389,7 +860,10 @@ pub(crate) fn read_prettier_files(
 
 #[cfg(test)]
 mod test {
-    use crate::execute::migrate::prettier::{PrettierConfiguration, PrettierTrailingComma};
+    use crate::execute::migrate::prettier::{
+        parse_ignore_line, IgnorePattern, OverrideTargets, PrettierConfiguration, PrettierGlobs,
+        PrettierOptions, PrettierOverride, PrettierOverrideOptions, PrettierTrailingComma,
+    };
     use biome_deserialize::json::deserialize_from_json_str;
     use biome_json_parser::JsonParserOptions;
 
@@ -446,4 +920,106 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn overrides() {
+        let configuration = deserialize_from_json_str::<PrettierConfiguration>(
+            r#"
+{
+  "semi": false,
+  "overrides": [
+    { "files": "*.test.js", "options": { "semi": true } },
+    { "files": ["legacy/**/*.js"], "excludedFiles": "legacy/vendor/**", "options": { "tabWidth": 4 } }
+  ]
+}
+            "#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        assert_eq!(
+            configuration.overrides,
+            vec![
+                PrettierOverride {
+                    files: PrettierGlobs(vec!["*.test.js".to_string()]),
+                    options: PrettierOverrideOptions {
+                        options: PrettierOptions {
+                            semi: Some(true),
+                            ..PrettierOptions::default()
+                        },
+                        names: vec!["semi".to_string()],
+                    },
+                    ..PrettierOverride::default()
+                },
+                PrettierOverride {
+                    files: PrettierGlobs(vec!["legacy/**/*.js".to_string()]),
+                    excluded_files: PrettierGlobs(vec!["legacy/vendor/**".to_string()]),
+                    options: PrettierOverrideOptions {
+                        options: PrettierOptions {
+                            tab_width: Some(4),
+                            ..PrettierOptions::default()
+                        },
+                        names: vec!["tabWidth".to_string()],
+                    },
+                },
+            ]
+        )
+    }
+
+    #[test]
+    fn override_targets() {
+        let targets = |globs: &[&str]| {
+            OverrideTargets::from_globs(
+                &globs
+                    .iter()
+                    .map(|glob| (*glob).to_string())
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        assert_eq!(
+            targets(&["*.{js,ts}"]),
+            OverrideTargets {
+                javascript: true,
+                ..OverrideTargets::default()
+            }
+        );
+        assert_eq!(
+            targets(&["tsconfig.jsonc", ".vscode/*.jsonc"]),
+            OverrideTargets {
+                json: true,
+                jsonc: true,
+                ..OverrideTargets::default()
+            }
+        );
+        assert_eq!(
+            targets(&["*.json", "*.jsonc"]),
+            OverrideTargets {
+                json: true,
+                ..OverrideTargets::default()
+            }
+        );
+        assert_eq!(targets(&["legacy/**"]), OverrideTargets::ALL);
+        assert_eq!(targets(&["*.md"]), OverrideTargets::default());
+    }
+
+    #[test]
+    fn ignore_lines() {
+        let glob = |glob: &str| Some(IgnorePattern::Glob(glob.to_string()));
+
+        assert_eq!(parse_ignore_line("# comment"), None);
+        assert_eq!(parse_ignore_line("   "), None);
+        assert_eq!(parse_ignore_line("dist/**"), glob("dist/**"));
+        assert_eq!(parse_ignore_line("/build"), glob("build"));
+        assert_eq!(parse_ignore_line("coverage/"), glob("**/coverage/**"));
+        assert_eq!(parse_ignore_line("/out/"), glob("out/**"));
+        assert_eq!(parse_ignore_line("*.min.js  "), glob("**/*.min.js"));
+        assert_eq!(parse_ignore_line("\\#file.js"), glob("**/#file.js"));
+        assert_eq!(
+            parse_ignore_line("!keep.js"),
+            Some(IgnorePattern::Negated("!keep.js".to_string()))
+        );
+    }
 }
@@ -1,18 +1,28 @@
+mod cache;
 mod diagnostics;
 mod migrate;
+mod module_graph;
+mod nested;
 mod process_file;
 mod std_in;
 mod traverse;
+mod watch;
 
+pub(crate) use cache::{hash_configuration, hash_nested_configuration};
+pub(crate) use traverse::init_thread_pool;
+
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
+use crate::commands::MigrateSubCommand;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::traverse;
 use crate::{CliDiagnostic, CliSession};
 use biome_diagnostics::{category, Category};
-use biome_fs::BiomePath;
+use biome_fs::{BiomePath, File, FileSystem, OpenOptions};
 use biome_service::workspace::{FeatureName, FeaturesBuilder, FixFileMode};
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
+use std::io;
 use std::path::{Path, PathBuf};
 
 /// Useful information during the traversal of files and virtual content
@@ -25,6 +35,25 @@ pub(crate) struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u16,
+
+    /// The hash of the resolved configuration. It's [None] when the cache is disabled
+    configuration_hash: Option<u64>,
+
+    /// The directory where the cache is stored, when it isn't the default one
+    cache_location: Option<String>,
+
+    /// Whether the traversal should be executed again every time the files change
+    watch: bool,
+
+    /// Whether the files should be read from, and written to, the staging area of the VCS
+    staged: bool,
+
+    /// When set, only the diagnostics that span over the lines that changed are reported
+    changed_lines: Option<ChangedLines>,
+
+    /// The directory of the root configuration file. The configuration files found there
+    /// aren't nested configuration files.
+    root_directory: Option<PathBuf>,
 }
 
 impl Execution {
@@ -58,7 +87,7 @@ pub(crate) struct Stdin(
 );
 
 impl Stdin {
-    fn as_path(&self) -> &Path {
+    pub(crate) fn as_path(&self) -> &Path {
         self.0.as_path()
     }
 
@@ -94,6 +123,12 @@ pub(crate) enum TraversalMode {
         /// It's [None] if the `check` command is called without `--apply` or `--apply-suggested`
         /// arguments.
         fix_file_mode: Option<FixFileMode>,
+        /// The reason written in the suppression comments when the command is called with
+        /// `--suppress`
+        suppression_reason: Option<String>,
+        /// The rules whose diagnostics are suppressed when the command is called with
+        /// `--suppress`, written as `<GROUP>/<RULE>`. All the rules when it's empty.
+        suppressed_rules: Vec<String>,
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
@@ -123,8 +158,8 @@ pub(crate) enum TraversalMode {
         configuration_file_path: PathBuf,
         /// The path directory where `biome.json` is placed
         configuration_directory_path: PathBuf,
-        /// Migrate from prettier
-        prettier: bool,
+        /// Migrate from another tool, e.g. Prettier, ESLint or EditorConfig
+        sub_command: Option<MigrateSubCommand>,
     },
     /// This mode is enabled when running the command `biome search`
     Search {
@@ -153,6 +188,45 @@ impl Display for TraversalMode {
     }
 }
 
+impl TraversalMode {
+    /// Describes the command and the options that change the result of the processing of a
+    /// file, so that the cache of a run isn't replayed by a run that fixes, suppresses or writes
+    /// the files differently
+    pub(crate) fn cache_key(&self) -> String {
+        fn fix_file_mode_key(fix_file_mode: &Option<FixFileMode>) -> &'static str {
+            match fix_file_mode {
+                None => "none",
+                Some(FixFileMode::SafeFixes) => "safe",
+                Some(FixFileMode::SafeAndUnsafeFixes) => "unsafe",
+                Some(FixFileMode::ApplySuppressions) => "suppressions",
+            }
+        }
+
+        match self {
+            TraversalMode::Check { fix_file_mode, .. } => {
+                format!("check fix={}", fix_file_mode_key(fix_file_mode))
+            }
+            TraversalMode::Lint {
+                fix_file_mode,
+                suppression_reason,
+                suppressed_rules,
+                ..
+            } => format!(
+                "lint fix={} reason={} rules={}",
+                fix_file_mode_key(fix_file_mode),
+                suppression_reason.as_deref().unwrap_or_default(),
+                suppressed_rules.join(",")
+            ),
+            TraversalMode::Format {
+                ignore_errors,
+                write,
+                ..
+            } => format!("format ignore_errors={ignore_errors} write={write}"),
+            _ => self.to_string(),
+        }
+    }
+}
+
 /// Tells to the execution of the traversal how the information should be reported
 #[derive(Copy, Clone, Default)]
 pub(crate) enum ReportMode {
@@ -169,6 +243,12 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            configuration_hash: None,
+            cache_location: None,
+            watch: false,
+            staged: false,
+            changed_lines: None,
+            root_directory: None,
         }
     }
 
@@ -188,6 +268,12 @@ impl Execution {
                 },
             },
             max_diagnostics: 20,
+            configuration_hash: None,
+            cache_location: None,
+            watch: false,
+            staged: false,
+            changed_lines: None,
+            root_directory: None,
         }
     }
 
@@ -197,6 +283,90 @@ impl Execution {
             traversal_mode,
             report_mode,
             max_diagnostics: 20,
+            configuration_hash: None,
+            cache_location: None,
+            watch: false,
+            staged: false,
+            changed_lines: None,
+            root_directory: None,
+        }
+    }
+
+    /// Enables the persistent cache when a `configuration_hash` is provided. The hash is used to
+    /// invalidate the files that were stored with a different configuration. The cache is stored
+    /// in `cache_location`, or in the default location if [None].
+    pub(crate) fn with_cache(
+        mut self,
+        configuration_hash: Option<u64>,
+        cache_location: Option<String>,
+    ) -> Self {
+        self.configuration_hash = configuration_hash;
+        self.cache_location = cache_location;
+        self
+    }
+
+    pub(crate) fn configuration_hash(&self) -> Option<u64> {
+        self.configuration_hash
+    }
+
+    pub(crate) fn cache_location(&self) -> Option<&str> {
+        self.cache_location.as_deref()
+    }
+
+    /// Keeps the process alive after the first traversal, and processes the files again
+    /// when they change
+    pub(crate) fn with_watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
+    pub(crate) const fn is_watch(&self) -> bool {
+        self.watch
+    }
+
+    /// Reads and writes the content of the files that is stored in the staging area of the VCS,
+    /// so that the changes that weren't staged are preserved
+    pub(crate) fn with_staged(mut self, staged: bool) -> Self {
+        self.staged = staged;
+        self
+    }
+
+    pub(crate) const fn is_staged(&self) -> bool {
+        self.staged
+    }
+
+    /// Reports only the diagnostics that span over the given [ChangedLines]
+    pub(crate) fn with_changed_lines(mut self, changed_lines: Option<ChangedLines>) -> Self {
+        self.changed_lines = changed_lines;
+        self
+    }
+
+    pub(crate) fn changed_lines(&self) -> Option<&ChangedLines> {
+        self.changed_lines.as_ref()
+    }
+
+    /// Sets the directory of the root configuration file, whose configuration was already
+    /// applied to the whole workspace
+    pub(crate) fn with_root_directory(mut self, root_directory: Option<PathBuf>) -> Self {
+        self.root_directory = root_directory;
+        self
+    }
+
+    pub(crate) fn root_directory(&self) -> Option<&Path> {
+        self.root_directory.as_deref()
+    }
+
+    /// Opens the file at `path`, from the staging area when the files are staged
+    pub(crate) fn open_file(
+        &self,
+        fs: &dyn FileSystem,
+        path: &Path,
+        options: OpenOptions,
+    ) -> io::Result<Box<dyn File>> {
+        if self.staged {
+            fs.open_staged_with_options(path, options)
+        } else {
+            fs.open_with_options(path, options)
         }
     }
 
@@ -225,6 +395,34 @@ impl Execution {
         }
     }
 
+    /// The reason written in the suppression comments added by [FixFileMode::ApplySuppressions]
+    pub(crate) fn suppression_reason(&self) -> Option<String> {
+        match &self.traversal_mode {
+            TraversalMode::Lint {
+                suppression_reason, ..
+            } => suppression_reason.clone(),
+            TraversalMode::Check { .. }
+            | TraversalMode::Format { .. }
+            | TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. } => None,
+        }
+    }
+
+    /// The rules whose diagnostics are suppressed by [FixFileMode::ApplySuppressions]
+    pub(crate) fn suppressed_rules(&self) -> Vec<String> {
+        match &self.traversal_mode {
+            TraversalMode::Lint {
+                suppressed_rules, ..
+            } => suppressed_rules.clone(),
+            TraversalMode::Check { .. }
+            | TraversalMode::Format { .. }
+            | TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. } => Vec::new(),
+        }
+    }
+
     pub(crate) fn as_diagnostic_category(&self) -> &'static Category {
         match self.traversal_mode {
             TraversalMode::Check { .. } => category!("check"),
@@ -305,12 +503,23 @@ impl Execution {
 /// or handles the stdin file.
 pub(crate) fn execute_mode(
     mut mode: Execution,
-    session: CliSession,
+    mut session: CliSession,
     cli_options: &CliOptions,
     paths: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
     mode.max_diagnostics = cli_options.max_diagnostics;
 
+    if mode.is_watch() && mode.as_stdin_file().is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+
+    if mode.is_watch() && mode.is_staged() {
+        return Err(CliDiagnostic::incompatible_arguments("--watch", "--staged"));
+    }
+
     // don't do any traversal if there's some content coming from stdin
     if let Some(stdin) = mode.as_stdin_file() {
         let biome_path = BiomePath::new(stdin.as_path());
@@ -325,7 +534,7 @@ pub(crate) fn execute_mode(
         write,
         configuration_file_path,
         configuration_directory_path,
-        prettier,
+        sub_command,
     } = mode.traversal_mode
     {
         let payload = MigratePayload {
@@ -334,10 +543,12 @@ pub(crate) fn execute_mode(
             configuration_file_path,
             configuration_directory_path,
             verbose: cli_options.verbose,
-            prettier,
+            sub_command,
         };
         migrate::run(payload)
+    } else if mode.is_watch() {
+        watch::run(mode, session, cli_options, paths)
     } else {
-        traverse(mode, session, cli_options, paths)
+        traverse(&mode, &mut session, cli_options, paths)
     }
 }
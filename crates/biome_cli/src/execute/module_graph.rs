@@ -0,0 +1,37 @@
+use crate::Execution;
+use biome_fs::FileSystem;
+use biome_service::workspace::{open_module_graph, open_node_js_workspace};
+use biome_service::Workspace;
+use std::path::{Path, PathBuf};
+
+/// Registers the `package.json` files of the monorepo, and the files of the project in the
+/// module graph of the workspace, when one of the enabled rules needs them. See
+/// [open_node_js_workspace] and [open_module_graph].
+///
+/// The root of the project is the directory of the configuration file, or the working directory.
+/// The paths of the files are relative to the working directory, like the paths of the
+/// traversed files. Returns `true` when the files were registered.
+pub(crate) fn register_modules(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    execution: &Execution,
+    paths: &[PathBuf],
+) -> bool {
+    let working_directory = fs.working_directory();
+    let project_root = execution
+        .root_directory()
+        .and_then(|root_directory| match working_directory.as_deref() {
+            Some(working_directory) if root_directory.is_absolute() => {
+                root_directory.strip_prefix(working_directory).ok()
+            }
+            _ => Some(root_directory),
+        })
+        .filter(|project_root| !project_root.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+
+    // Without the packages of the monorepo, the files use the manifest of the current project
+    let directory = working_directory.clone().unwrap_or_default();
+    let _ = open_node_js_workspace(fs, workspace, &directory, Some(&directory));
+    // The files that can't be registered are reported when they are processed
+    open_module_graph(fs, workspace, project_root, paths, execution.is_staged()).unwrap_or_default()
+}
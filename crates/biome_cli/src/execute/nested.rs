@@ -0,0 +1,119 @@
+use crate::diagnostics::DeprecatedConfigurationFile;
+use crate::execute::cache::{hash_configuration, StableHasher};
+use biome_diagnostics::Error;
+use biome_fs::FileSystem;
+use biome_service::configuration::NestedConfigurationDiscovery;
+use biome_service::Workspace;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Applies the configuration files nested inside the inputs of the traversal, as soon as the
+/// traversal reaches their directory
+pub(crate) struct NestedSettings<'ctx> {
+    fs: &'ctx dyn FileSystem,
+    workspace: &'ctx dyn Workspace,
+    discovery: NestedConfigurationDiscovery,
+    state: Mutex<NestedSettingsState>,
+}
+
+#[derive(Default)]
+struct NestedSettingsState {
+    /// The hash of each nested configuration that was applied, indexed by its directory
+    hashes: FxHashMap<PathBuf, u64>,
+    /// The configuration files whose diagnostics were already reported
+    reported_files: FxHashSet<PathBuf>,
+}
+
+impl<'ctx> NestedSettings<'ctx> {
+    pub(crate) fn new(
+        fs: &'ctx dyn FileSystem,
+        workspace: &'ctx dyn Workspace,
+        inputs: &[OsString],
+        root_directory: Option<&Path>,
+    ) -> Self {
+        let inputs: Vec<_> = inputs.iter().map(PathBuf::from).collect();
+        Self {
+            fs,
+            workspace,
+            discovery: NestedConfigurationDiscovery::new(
+                fs.working_directory(),
+                &inputs,
+                root_directory.map(Path::to_path_buf),
+            ),
+            state: Mutex::default(),
+        }
+    }
+
+    /// Applies the configuration files of the directories of `path` that weren't searched yet,
+    /// and returns the diagnostics they emitted
+    pub(crate) fn discover(&self, path: &Path) -> Vec<Error> {
+        let mut diagnostics = Vec::new();
+        // The configuration files are recorded before the search of their directory completes,
+        // so that the other threads see them as reported as soon as they are applied
+        self.discovery
+            .discover(self.fs, self.workspace, path, |loaded_configuration| {
+                let loaded_configuration = match loaded_configuration {
+                    Ok(loaded_configuration) => loaded_configuration,
+                    Err(error) => {
+                        diagnostics.push(error.into());
+                        return;
+                    }
+                };
+                if let Some(file_name) = loaded_configuration
+                    .file_path
+                    .as_ref()
+                    .and_then(|file_path| file_path.file_name())
+                    .and_then(|file_name| file_name.to_str())
+                {
+                    if file_name == "rome.json" {
+                        diagnostics.push(DeprecatedConfigurationFile::new(file_name).into());
+                    }
+                }
+                if let Ok(mut state) = self.state.lock() {
+                    if let (false, Some(directory)) = (
+                        loaded_configuration.has_errors(),
+                        loaded_configuration.directory_path.as_ref(),
+                    ) {
+                        state.hashes.insert(
+                            directory.clone(),
+                            hash_configuration(&loaded_configuration.configuration),
+                        );
+                    }
+                    if let (false, Some(file_path)) = (
+                        loaded_configuration.diagnostics.is_empty(),
+                        loaded_configuration.file_path,
+                    ) {
+                        state.reported_files.insert(file_path);
+                    }
+                }
+                diagnostics.extend(loaded_configuration.diagnostics);
+            });
+        diagnostics
+    }
+
+    /// Whether `path` is a nested configuration file whose diagnostics were already reported,
+    /// so processing it would report them again
+    pub(crate) fn is_reported(&self, path: &Path) -> bool {
+        self.state
+            .lock()
+            .map_or(false, |state| state.reported_files.contains(path))
+    }
+
+    /// Combines `content_hash`, the hash of the file at `path`, with the hashes of the nested
+    /// configurations that apply to it, so that the cache is invalidated when they change
+    pub(crate) fn hash_file(&self, path: &Path, content_hash: u64) -> u64 {
+        let Ok(state) = self.state.lock() else {
+            return content_hash;
+        };
+        let mut hasher = StableHasher::new();
+        hasher.write_u64(content_hash);
+        for directory in path.ancestors().skip(1) {
+            if let Some(configuration_hash) = state.hashes.get(directory) {
+                hasher.write_path(directory).write_u64(*configuration_hash);
+            }
+        }
+        hasher.finish()
+    }
+}
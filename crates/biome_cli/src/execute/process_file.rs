@@ -75,6 +75,8 @@ impl Message {
 pub(crate) enum DiffKind {
     Format,
     OrganizeImports,
+    /// The keys of the JSON objects, sorted when the imports are organized
+    SortKeys,
 }
 
 impl<D> From<D> for Message
@@ -109,6 +109,12 @@ pub(crate) fn format_with_guard<'ctx>(
             }
 
             if output != input {
+                // Only the files whose lines that changed would be rewritten are processed
+                if ctx.execution.changed_lines().is_some_and(|changed_lines| {
+                    !changed_lines.intersects_edit(&workspace_file.path, &input, &output)
+                }) {
+                    return Ok(FileStatus::Unchanged);
+                }
                 if should_write {
                     workspace_file.update_file(output)?;
                 } else {
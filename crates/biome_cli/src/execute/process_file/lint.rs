@@ -1,9 +1,13 @@
 use crate::execute::diagnostics::ResultExt;
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
-use biome_diagnostics::{category, Error};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
-use biome_service::workspace::RuleCategories;
+use biome_diagnostics::{category, Diagnostic, Error, Severity};
+use biome_fs::BiomePath;
+use biome_service::extension_error;
+use biome_service::file_handlers::{
+    AstroFileHandler, DocumentFileSource, SvelteFileHandler, VueFileHandler,
+};
+use biome_service::workspace::{open_json_schema, FixFileMode, RuleCategories};
 use std::path::Path;
 use std::sync::atomic::Ordering;
 
@@ -21,11 +25,30 @@ pub(crate) fn lint_with_guard<'ctx>(
         move || {
             let mut errors = 0;
             let mut input = workspace_file.input()?;
+            open_json_schema(ctx.fs, ctx.workspace, &workspace_file.path).with_file_path_and_code(
+                workspace_file.path.display().to_string(),
+                category!("lint"),
+            )?;
             let mut changed = false;
-            if let Some(fix_mode) = ctx.execution.as_fix_file_mode() {
+            let fix_mode = ctx.execution.as_fix_file_mode().filter(|fix_mode| {
+                // The JSON files don't support the suppression comments: their diagnostics are
+                // reported instead
+                let is_unsupported = matches!(fix_mode, FixFileMode::ApplySuppressions)
+                    && DocumentFileSource::from_path(&workspace_file.path).is_json_like();
+                if is_unsupported {
+                    ctx.push_message(extension_error(&BiomePath::new(&workspace_file.path)));
+                }
+                !is_unsupported
+            });
+            if let Some(fix_mode) = fix_mode {
                 let fix_result = workspace_file
                     .guard()
-                    .fix_file(*fix_mode, false)
+                    .fix_file(
+                        *fix_mode,
+                        false,
+                        ctx.execution.suppression_reason(),
+                        ctx.execution.suppressed_rules(),
+                    )
                     .with_file_path_and_code(
                         workspace_file.path.display().to_string(),
                         category!("lint"),
@@ -57,18 +80,40 @@ pub(crate) fn lint_with_guard<'ctx>(
                 errors = fix_result.errors;
             }
 
-            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
-            let pull_diagnostics_result = workspace_file
+            let changed_lines = ctx.execution.changed_lines();
+            // The diagnostics are filtered after they are pulled, so all of them are needed
+            let max_diagnostics = if changed_lines.is_some() {
+                u32::MAX
+            } else {
+                ctx.remaining_diagnostics.load(Ordering::Relaxed).into()
+            };
+            let mut pull_diagnostics_result = workspace_file
                 .guard()
                 .pull_diagnostics(
                     RuleCategories::LINT | RuleCategories::SYNTAX,
-                    max_diagnostics.into(),
+                    max_diagnostics,
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("lint"),
                 )?;
 
+            if let Some(changed_lines) = changed_lines {
+                // Only the diagnostics of the changed lines are reported, the errors of the
+                // other lines don't fail the command
+                pull_diagnostics_result.diagnostics.retain(|diagnostic| {
+                    diagnostic.location().span.map_or(true, |span| {
+                        changed_lines.intersects(&workspace_file.path, &input, span)
+                    })
+                });
+                errors = 0;
+                pull_diagnostics_result.errors = pull_diagnostics_result
+                    .diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.severity() >= Severity::Error)
+                    .count();
+            }
+
             let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
                 && pull_diagnostics_result.skipped_diagnostics == 0;
             errors += pull_diagnostics_result.errors;
@@ -4,7 +4,9 @@ use crate::execute::process_file::{
     DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
 };
 use biome_diagnostics::category;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{
+    AstroFileHandler, DocumentFileSource, SvelteFileHandler, VueFileHandler,
+};
 
 /// Lints a single file and returns a [FileResult]
 pub(crate) fn organize_imports_with_guard<'ctx>(
@@ -48,6 +50,12 @@ pub(crate) fn organize_imports_with_guard<'ctx>(
             }
 
             if output != input {
+                // Only the files whose lines that changed would be rewritten are processed
+                if ctx.execution.changed_lines().is_some_and(|changed_lines| {
+                    !changed_lines.intersects_edit(&workspace_file.path, &input, &output)
+                }) {
+                    return Ok(FileStatus::Unchanged);
+                }
                 if ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe() {
                     workspace_file.update_file(output)?;
                 } else {
@@ -55,7 +63,13 @@ pub(crate) fn organize_imports_with_guard<'ctx>(
                         file_name: workspace_file.path.display().to_string(),
                         old: input,
                         new: output,
-                        diff_kind: DiffKind::OrganizeImports,
+                        diff_kind: if DocumentFileSource::from_path(&workspace_file.path)
+                            .is_json_like()
+                        {
+                            DiffKind::SortKeys
+                        } else {
+                            DiffKind::OrganizeImports
+                        },
                     }));
                 }
                 Ok(FileStatus::Changed)
@@ -14,8 +14,8 @@ pub(crate) struct WorkspaceFile<'ctx, 'app> {
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
-    /// It attempts to read the file from disk, creating a [FileGuard] and
-    /// saving these information internally
+    /// It attempts to read the file from disk, or from the staging area of the VCS, creating a
+    /// [FileGuard] and saving these information internally
     pub(crate) fn new(
         ctx: &SharedTraversalOptions<'ctx, 'app>,
         path: &Path,
@@ -25,8 +25,8 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             .read(true)
             .write(ctx.execution.requires_write_access());
         let mut file = ctx
-            .fs
-            .open_with_options(path, open_options)
+            .execution
+            .open_file(ctx.fs, path, open_options)
             .with_file_path(path.display().to_string())?;
 
         let mut input = String::new();
@@ -1,6 +1,7 @@
 //! In here, there are the operations that run via standard input
 //!
 use crate::execute::diagnostics::{ContentDiffAdvice, FormatDiffDiagnostic};
+use crate::execute::module_graph::register_modules;
 use crate::execute::Execution;
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, ConsoleExt};
@@ -8,8 +9,9 @@ use biome_diagnostics::Diagnostic;
 use biome_diagnostics::PrintDiagnostic;
 use biome_fs::BiomePath;
 use biome_service::workspace::{
-    ChangeFileParams, FeaturesBuilder, FixFileParams, FormatFileParams, OpenFileParams,
-    OrganizeImportsParams, PullDiagnosticsParams, RuleCategories, SupportsFeatureParams,
+    open_json_schema, ChangeFileParams, FeaturesBuilder, FixFileParams, FormatFileParams,
+    OpenFileParams, OrganizeImportsParams, PullDiagnosticsParams, RuleCategories,
+    SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
@@ -67,6 +69,14 @@ pub(crate) fn run<'a>(
         let mut diagnostics = Vec::new();
         let mut new_content = Cow::Borrowed(content);
 
+        // The imports of the content are resolved to the files of the project
+        register_modules(
+            &*session.app.fs,
+            workspace,
+            mode,
+            &[biome_path.to_path_buf()],
+        );
+
         workspace.open_file(OpenFileParams {
             path: biome_path.clone(),
             version: 0,
@@ -103,6 +113,8 @@ pub(crate) fn run<'a>(
                     fix_file_mode: *fix_file_mode,
                     path: biome_path.clone(),
                     should_format: mode.is_check() && file_features.supports_format(),
+                    suppression_reason: mode.suppression_reason(),
+                    suppressed_rules: mode.suppressed_rules(),
                 })?;
                 if fix_file_result.code != new_content {
                     version += 1;
@@ -132,6 +144,7 @@ pub(crate) fn run<'a>(
         }
 
         if !mode.is_check_apply_unsafe() {
+            open_json_schema(&*session.app.fs, workspace, biome_path.as_path())?;
             let result = workspace.pull_diagnostics(PullDiagnosticsParams {
                 categories: RuleCategories::LINT | RuleCategories::SYNTAX,
                 path: biome_path.clone(),
@@ -1,9 +1,13 @@
+use super::cache::{read_file, FileCache};
+use super::module_graph::register_modules;
+use super::nested::NestedSettings;
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
 use super::ExecutionEnvironment;
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
-    CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice,
-    FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, CISortKeysDiffDiagnostic,
+    ContentDiffAdvice, FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    SortKeysDiffDiagnostic,
 };
 use crate::{CliDiagnostic, CliSession, Execution, FormatterReportSummary, Report, TraversalMode};
 use biome_console::fmt::Formatter;
@@ -139,14 +143,14 @@ impl<'a> fmt::Display for SummaryResult<'a> {
     }
 }
 
-///
+/// Traverses the `inputs` and processes the files according to the [Execution], then prints a
+/// summary of the results
 pub(crate) fn traverse(
-    execution: Execution,
-    session: CliSession,
+    execution: &Execution,
+    session: &mut CliSession,
     cli_options: &CliOptions,
     inputs: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
-    init_thread_pool();
     if inputs.is_empty()
         && execution.as_stdin_file().is_none()
         && !cli_options.no_errors_on_unmatched
@@ -168,12 +172,46 @@ pub(crate) fn traverse(
     let workspace = &*session.app.workspace;
     let console = &mut *session.app.console;
 
+    let nested_settings = matches!(
+        execution.traversal_mode(),
+        TraversalMode::Format { .. }
+            | TraversalMode::Lint { .. }
+            | TraversalMode::Check { .. }
+            | TraversalMode::CI { .. }
+    )
+    .then(|| NestedSettings::new(fs, workspace, &inputs, execution.root_directory()));
+
     let max_diagnostics = execution.get_max_diagnostics();
     let remaining_diagnostics = AtomicU16::new(max_diagnostics);
 
     let mut report = Report::default();
 
-    let printer = DiagnosticsPrinter::new(&execution)
+    let has_module_graph = matches!(
+        execution.traversal_mode(),
+        TraversalMode::Lint { .. } | TraversalMode::Check { .. } | TraversalMode::CI { .. }
+    ) && register_modules(
+        fs,
+        workspace,
+        execution,
+        &inputs.iter().map(PathBuf::from).collect::<Vec<_>>(),
+    );
+
+    // The diagnostics outside the changed lines aren't reported, but the files that emit them
+    // must not be stored as if they were clean. The diagnostics of the rules that query the
+    // module graph depend on the other files, so they can't be cached either.
+    let cache = execution
+        .configuration_hash()
+        .filter(|_| execution.changed_lines().is_none() && !has_module_graph)
+        .map(|configuration_hash| {
+            FileCache::load(
+                fs,
+                execution.cache_location(),
+                execution.traversal_mode(),
+                configuration_hash,
+            )
+        });
+
+    let printer = DiagnosticsPrinter::new(execution)
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
         .with_max_diagnostics(max_diagnostics);
@@ -194,13 +232,15 @@ pub(crate) fn traverse(
             &TraversalOptions {
                 fs,
                 workspace,
-                execution: &execution,
+                execution,
                 interner,
                 changed: &changed,
                 unchanged: &unchanged,
                 skipped: &skipped,
                 messages: sender,
                 remaining_diagnostics: &remaining_diagnostics,
+                cache: cache.as_ref(),
+                nested_settings: nested_settings.as_ref(),
             },
         );
         // wait for the main thread to finish
@@ -209,6 +249,10 @@ pub(crate) fn traverse(
         elapsed
     });
 
+    if let Some(cache) = cache {
+        cache.persist(fs);
+    }
+
     let errors = printer.errors();
     let warnings = printer.warnings();
     let changed = changed.load(Ordering::Relaxed);
@@ -281,8 +325,10 @@ pub(crate) fn traverse(
 
 /// This function will setup the global Rayon thread pool the first time it's called
 ///
-/// This is currently only used to assign friendly debug names to the threads of the pool
-fn init_thread_pool() {
+/// This is currently only used to assign friendly debug names to the threads of the pool.
+/// It must be called before any traversal of the file system, because the traversal starts the
+/// global pool.
+pub(crate) fn init_thread_pool() {
     static INIT_ONCE: Once = Once::new();
     INIT_ONCE.call_once(|| {
         rayon::ThreadPoolBuilder::new()
@@ -564,6 +610,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
                                 }
+                                DiffKind::SortKeys => {
+                                    let diag = CISortKeysDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(Error::from(diag))
+                                }
                             };
                         } else {
                             match diff_kind {
@@ -587,6 +643,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
                                 }
+                                DiffKind::SortKeys => {
+                                    let diag = SortKeysDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(Error::from(diag))
+                                }
                             };
                         }
                     }
@@ -655,6 +721,10 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
     pub(crate) remaining_diagnostics: &'ctx AtomicU16,
+    /// The persistent cache, if enabled
+    cache: Option<&'ctx FileCache>,
+    /// Applies the nested configuration files found during the traversal
+    nested_settings: Option<&'ctx NestedSettings<'ctx>>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
@@ -667,7 +737,11 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
 
     /// Send a message to the display thread
     pub(crate) fn push_message(&self, msg: impl Into<Message>) {
-        self.messages.send(msg.into()).ok();
+        let mut msg = msg.into();
+        if let Some(cache) = self.cache {
+            msg = cache.record_message(msg);
+        }
+        self.messages.send(msg).ok();
     }
 
     pub(crate) fn miss_handler_err(&self, err: WorkspaceError, biome_path: &BiomePath) {
@@ -695,6 +769,24 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     }
 
     fn can_handle(&self, biome_path: &BiomePath) -> bool {
+        if self
+            .cache
+            .map_or(false, |cache| cache.is_cache_path(biome_path))
+        {
+            return false;
+        }
+
+        // The settings of the nested configuration files must be applied before checking
+        // whether the path is ignored
+        if let Some(nested_settings) = self.nested_settings {
+            for diagnostic in nested_settings.discover(biome_path) {
+                self.push_diagnostic(diagnostic);
+            }
+            if nested_settings.is_reported(biome_path) {
+                return false;
+            }
+        }
+
         if !self.fs.path_is_file(biome_path.as_path()) {
             // handle:
             // - directories
@@ -763,15 +855,45 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
 /// in a [catch_unwind] block and emit diagnostics in case of error (either the
 /// traversal function returns Err or panics)
 fn handle_file(ctx: &TraversalOptions, path: &Path) {
+    let file = ctx
+        .cache
+        .and_then(|_| read_file(ctx.fs, ctx.execution, path))
+        .map(|(content, content_hash)| {
+            let content_hash = ctx.nested_settings.map_or(content_hash, |nested_settings| {
+                nested_settings.hash_file(path, content_hash)
+            });
+            (content, content_hash)
+        });
+    let content_hash = file.as_ref().map(|(_, content_hash)| *content_hash);
+    if let (Some(cache), Some((content, content_hash))) = (ctx.cache, file) {
+        if let Some(entry) = cache.fresh_entry(path, content_hash) {
+            cache.record(path, content_hash, entry.failed());
+            ctx.increment_unchanged();
+            if let Some(message) = entry.to_message(path, content) {
+                ctx.push_message(message);
+            }
+            if entry.failed() {
+                ctx.push_message(Message::Failure);
+            }
+            return;
+        }
+    }
+
     match catch_unwind(move || process_file(ctx, path)) {
         Ok(Ok(FileStatus::Changed)) => {
             ctx.increment_changed();
         }
         Ok(Ok(FileStatus::Unchanged)) => {
             ctx.increment_unchanged();
+            if let (Some(cache), Some(content_hash)) = (ctx.cache, content_hash) {
+                cache.record(path, content_hash, false);
+            }
         }
         Ok(Ok(FileStatus::Message(msg))) => {
             ctx.increment_unchanged();
+            if let (Some(cache), Some(content_hash)) = (ctx.cache, content_hash) {
+                cache.record(path, content_hash, matches!(msg, Message::Failure));
+            }
             ctx.push_message(msg);
         }
         Ok(Ok(FileStatus::Protected(file_path))) => {
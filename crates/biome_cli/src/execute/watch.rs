@@ -0,0 +1,238 @@
+//! In here, there's the implementation of the `--watch` mode: the files are processed once,
+//! then the process stays alive and processes again the files that change on disk.
+use crate::cli_options::CliOptions;
+use crate::execute::cache::hash_file;
+use crate::execute::traverse::traverse;
+use crate::execute::Execution;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::{Diagnostic, PrintDiagnostic};
+use crossbeam::channel::{unbounded, Receiver};
+use notify::event::{EventKind, ModifyKind};
+use notify::{Event, RecursiveMode, Watcher};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How long the watcher waits for more events before processing the changed files.
+/// Editors and formatters usually emit many events when saving a single file.
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(100);
+
+pub(crate) fn run(
+    execution: Execution,
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    if paths.is_empty() {
+        // Nothing would ever be watched, e.g. `--changed` didn't find any file: process the
+        // inputs once, which reports the missing inputs, instead of waiting forever
+        return traverse(&execution, &mut session, cli_options, paths);
+    }
+
+    let (sender, receiver) = unbounded();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        if let Ok(event) = event {
+            sender.send(event).ok();
+        }
+    })
+    .map_err(into_io_error)?;
+
+    for path in &paths {
+        watcher
+            .watch(Path::new(path), RecursiveMode::Recursive)
+            .map_err(into_io_error)?;
+    }
+
+    let result = traverse(&execution, &mut session, cli_options, paths);
+    print_result(&mut session, cli_options, result);
+
+    watch_changes(&execution, &mut session, cli_options, &receiver)
+}
+
+/// Processes again the files of each batch of events received from `receiver`, until the
+/// watcher is dropped
+fn watch_changes(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    receiver: &Receiver<Event>,
+) -> Result<(), CliDiagnostic> {
+    // The files processed after a change might be ignored or unsupported, which is not an error
+    let cli_options = CliOptions {
+        no_errors_on_unmatched: true,
+        ..cli_options.clone()
+    };
+    let working_directory = session.app.fs.working_directory();
+    let mut content_hashes = FxHashMap::default();
+
+    loop {
+        session.app.console.log(markup! {
+            <Info>"Watching for file changes..."</Info>
+        });
+
+        let Some(changed_paths) = receive_changed_paths(receiver) else {
+            // The watcher was dropped
+            return Ok(());
+        };
+
+        let fs = &*session.app.fs;
+        let changed_paths: Vec<PathBuf> = changed_paths
+            .into_iter()
+            .map(|path| match working_directory.as_deref() {
+                Some(working_directory) => path
+                    .strip_prefix(working_directory)
+                    .map(PathBuf::from)
+                    .unwrap_or(path),
+                None => path,
+            })
+            .filter(|path| fs.path_is_file(path))
+            // Writing the fixes on disk emits new events: skip the files that didn't change
+            // since the last time they were processed
+            .filter(|path| {
+                hash_file(fs, execution, path).map_or(true, |content_hash| {
+                    content_hashes.get(path) != Some(&content_hash)
+                })
+            })
+            .collect();
+
+        if changed_paths.is_empty() {
+            continue;
+        }
+
+        let files = changed_paths.len();
+        session.app.console.log(markup! {
+            <Info>"Detected changes in "{files}" file(s)."</Info>
+        });
+
+        let inputs = changed_paths
+            .iter()
+            .map(|path| OsString::from(path.as_os_str()))
+            .collect();
+        let result = traverse(execution, session, &cli_options, inputs);
+        print_result(session, &cli_options, result);
+
+        let fs = &*session.app.fs;
+        for path in changed_paths {
+            if let Some(content_hash) = hash_file(fs, execution, &path) {
+                content_hashes.insert(path, content_hash);
+            }
+        }
+    }
+}
+
+/// Waits for the next batch of file system events, and returns the paths that were created
+/// or modified. It returns [None] when the watcher is disconnected.
+fn receive_changed_paths(receiver: &Receiver<Event>) -> Option<FxHashSet<PathBuf>> {
+    let mut changed_paths = FxHashSet::default();
+    let mut event = receiver.recv().ok()?;
+    loop {
+        if matches!(
+            event.kind,
+            EventKind::Create(_)
+                | EventKind::Modify(
+                    ModifyKind::Any | ModifyKind::Data(_) | ModifyKind::Name(_) | ModifyKind::Other
+                )
+        ) {
+            changed_paths.extend(event.paths);
+        }
+
+        match receiver.recv_timeout(DEBOUNCE_DURATION) {
+            Ok(next_event) => event = next_event,
+            Err(_) => return Some(changed_paths),
+        }
+    }
+}
+
+/// Prints the diagnostic of a traversal that failed. In watch mode, errors don't terminate
+/// the process.
+fn print_result(
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    result: Result<(), CliDiagnostic>,
+) {
+    if let Err(diagnostic) = result {
+        let console = &mut *session.app.console;
+        if diagnostic.tags().is_verbose() && cli_options.verbose {
+            console.error(markup! {{PrintDiagnostic::verbose(&diagnostic)}})
+        } else {
+            console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}})
+        }
+    }
+}
+
+fn into_io_error(error: notify::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{watch_changes, DEBOUNCE_DURATION};
+    use crate::cli_options::cli_options;
+    use crate::execute::{Execution, TraversalMode};
+    use crate::CliSession;
+    use biome_console::BufferConsole;
+    use biome_fs::{FileSystemExt, MemoryFileSystem};
+    use biome_service::{workspace, App, DynRef, WorkspaceRef};
+    use bpaf::Parser;
+    use crossbeam::channel::unbounded;
+    use notify::event::{DataChange, EventKind, ModifyKind};
+    use notify::Event;
+    use std::path::{Path, PathBuf};
+    use std::thread;
+
+    #[test]
+    fn processes_again_the_changed_files() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+        let file_path = Path::new("file.js");
+        fs.insert(file_path.into(), "statement(  )".as_bytes());
+
+        let (sender, receiver) = unbounded();
+        let watcher = thread::spawn(move || {
+            let event = Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
+                .add_path(PathBuf::from("file.js"));
+            sender.send(event).unwrap();
+            // Let the events be debounced before the watcher is dropped
+            thread::sleep(DEBOUNCE_DURATION * 2);
+        });
+
+        let execution = Execution::new(TraversalMode::Format {
+            ignore_errors: false,
+            write: true,
+            stdin: None,
+        });
+        let cli_options = cli_options()
+            .to_options()
+            .run_inner(&[] as &[&str])
+            .unwrap();
+        let mut session = CliSession {
+            app: App::new(
+                DynRef::Borrowed(&mut fs),
+                &mut console,
+                WorkspaceRef::Owned(workspace::server()),
+            ),
+        };
+        let result = watch_changes(&execution, &mut session, &cli_options, &receiver);
+        watcher.join().unwrap();
+        assert!(result.is_ok());
+        drop(session);
+
+        let mut content = String::new();
+        fs.open(file_path)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "statement();\n");
+
+        let output: String = console
+            .out_buffer
+            .iter()
+            .flat_map(|message| message.content.0.iter())
+            .map(|node| node.content.as_str())
+            .collect();
+        assert!(output.contains("Detected changes in 1 file(s)."));
+    }
+}
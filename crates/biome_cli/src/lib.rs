@@ -28,10 +28,14 @@ use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
+use crate::commands::ConfigSubCommand;
 pub use crate::commands::{biome_command, BiomeCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
-pub(crate) use execute::{execute_mode, Execution, TraversalMode};
+pub(crate) use execute::{
+    execute_mode, hash_configuration, hash_nested_configuration, init_thread_pool, Execution,
+    TraversalMode,
+};
 pub use panic::setup_panic_handler;
 pub use reports::{
     formatter::{FormatterReport, FormatterReportFileDetail, FormatterReportSummary},
@@ -73,6 +77,7 @@ impl<'app> CliSession<'app> {
         if has_metrics {
             crate::metrics::init_metrics();
         }
+        init_thread_pool();
 
         let result = match command {
             BiomeCommand::Version(_) => commands::version::full_version(self),
@@ -93,6 +98,11 @@ impl<'app> CliSession<'app> {
                 formatter_enabled,
                 changed,
                 since,
+                changed_lines,
+                staged,
+                watch,
+                cache,
+                cache_location,
             } => commands::check::check(
                 self,
                 CheckCommandPayload {
@@ -107,11 +117,19 @@ impl<'app> CliSession<'app> {
                     formatter_enabled,
                     changed,
                     since,
+                    changed_lines,
+                    staged,
+                    watch,
+                    cache,
+                    cache_location,
                 },
             ),
             BiomeCommand::Lint {
                 apply,
                 apply_unsafe,
+                suppress,
+                reason,
+                rule,
                 cli_options,
                 linter_configuration,
                 paths,
@@ -120,11 +138,19 @@ impl<'app> CliSession<'app> {
                 files_configuration,
                 changed,
                 since,
+                changed_lines,
+                staged,
+                watch,
+                cache,
+                cache_location,
             } => commands::lint::lint(
                 self,
                 LintCommandPayload {
                     apply_unsafe,
                     apply,
+                    suppress,
+                    reason,
+                    rule,
                     cli_options,
                     linter_configuration,
                     paths,
@@ -133,6 +159,11 @@ impl<'app> CliSession<'app> {
                     files_configuration,
                     changed,
                     since,
+                    changed_lines,
+                    staged,
+                    watch,
+                    cache,
+                    cache_location,
                 },
             ),
             BiomeCommand::Ci {
@@ -144,6 +175,9 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                changed_lines,
+                cache,
+                cache_location,
             } => commands::ci::ci(
                 self,
                 CiCommandPayload {
@@ -155,6 +189,9 @@ impl<'app> CliSession<'app> {
                     cli_options,
                     changed,
                     since,
+                    changed_lines,
+                    cache,
+                    cache_location,
                 },
             ),
             BiomeCommand::Format {
@@ -170,6 +207,10 @@ impl<'app> CliSession<'app> {
                 css_formatter,
                 changed,
                 since,
+                staged,
+                watch,
+                cache,
+                cache_location,
             } => commands::format::format(
                 self,
                 FormatCommandPayload {
@@ -185,6 +226,10 @@ impl<'app> CliSession<'app> {
                     css_formatter,
                     changed,
                     since,
+                    staged,
+                    watch,
+                    cache,
+                    cache_location,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
@@ -194,14 +239,14 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 write,
                 sub_command,
-            } => commands::migrate::migrate(
-                self,
-                cli_options,
-                write,
-                sub_command
-                    .map(|sub_command| sub_command.is_prettier())
-                    .unwrap_or_default(),
-            ),
+            } => commands::migrate::migrate(self, cli_options, write, sub_command),
+            BiomeCommand::Config { sub_command } => match sub_command {
+                ConfigSubCommand::Print {
+                    cli_options,
+                    configuration,
+                    path,
+                } => commands::config::print(self, cli_options, configuration, path),
+            },
             BiomeCommand::Search {
                 cli_options,
                 files_configuration,
@@ -0,0 +1,230 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const CACHE_FILE: &str = "node_modules/.cache/biome/check.json";
+
+fn read_cached_files(fs: &MemoryFileSystem, cache_file: &Path) -> Vec<String> {
+    let mut file = fs.open(cache_file).expect("the cache file to exist");
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("the cache file to be readable");
+    let cache: serde_json::Value = serde_json::from_str(&content).expect("valid JSON");
+    cache["files"]
+        .as_object()
+        .expect("a map of files")
+        .keys()
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn stores_files_with_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("clean.js").into(), "statement();\n".as_bytes());
+    fs.insert(Path::new("dirty.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--cache"), ("clean.js"), ("dirty.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(
+        read_cached_files(&fs, Path::new(CACHE_FILE)),
+        vec![String::from("clean.js"), String::from("dirty.js")]
+    );
+}
+
+#[test]
+fn replays_the_diagnostics_of_cached_files() {
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(Path::new("file.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from([("lint"), ("--cache"), ("file.js")].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let cache_file = Path::new("node_modules/.cache/biome/lint.json");
+    let mut content = String::new();
+    fs.open(cache_file)
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .expect("the cache file to be readable");
+    let cache: serde_json::Value = serde_json::from_str(&content).expect("valid JSON");
+    assert_eq!(
+        cache["files"]["file.js"]["diagnostics"][0]["category"],
+        "lint/suspicious/noDebugger"
+    );
+
+    // The second run reads the diagnostics from the cache
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--cache"), ("file.js")].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // The content of the cache depends on the version of Biome
+    fs.remove(cache_file);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "replays_the_diagnostics_of_cached_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_write_cache_when_disabled() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("clean.js").into(), "statement();\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("clean.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(fs.open(Path::new(CACHE_FILE)).is_err());
+}
+
+#[test]
+fn uses_cache_location() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("clean.js").into(), "statement();\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--cache"),
+                ("--cache-location=.biome-cache"),
+                ("clean.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(
+        read_cached_files(&fs, Path::new(".biome-cache/lint.json")),
+        vec![String::from("clean.js")]
+    );
+}
+
+#[test]
+fn reports_diagnostics_after_the_configuration_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), "debugger;\n".as_bytes());
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--cache"), ("file.js")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The file didn't change, but the configuration did
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--cache"), ("file.js")].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn reports_diagnostics_after_the_file_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), "statement();\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--cache"), ("file.js")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.insert(Path::new("file.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--cache"), ("file.js")].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(
+        read_cached_files(&fs, Path::new("node_modules/.cache/biome/lint.json")),
+        vec![String::from("file.js")]
+    );
+}
+
+#[test]
+fn processes_the_cached_files_when_the_fixes_change() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), "debugger;\n".as_bytes());
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--cache"), ("file.js")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The file only emitted a warning, so it was stored, but this run must insert the comment
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--cache"), ("--suppress"), ("file.js")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs.open(Path::new("file.js")).expect("the file to exist");
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("the file to be readable");
+    assert!(
+        content.contains("biome-ignore lint/suspicious/noDebugger"),
+        "the file wasn't suppressed: {content}"
+    );
+}
@@ -358,3 +358,189 @@ fn allows_reverting_fields_in_extended_config_to_default() {
         result,
     ));
 }
+
+#[test]
+fn extends_resolves_package_with_exports() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/package.json").into(),
+        r#"{
+    "name": "@acme/biome-config",
+    "exports": {
+        ".": "./biome.json",
+        "./strict": "./configs/strict.json"
+    }
+}"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/biome.json").into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/configs/strict.json").into(),
+        r#"{ "extends": ["../biome.json"], "formatter": { "lineWidth": 20 } }"#,
+    );
+
+    let biome_json = Path::new("biome.json");
+    fs.insert(
+        biome_json.into(),
+        r#"{ "extends": ["@acme/biome-config/strict"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(
+        test_file.into(),
+        r#"debugger; const a = ["lorem", "ipsum"]; "#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_resolves_package_with_exports",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_shows_the_chain_of_an_invalid_extended_config() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/package.json").into(),
+        r#"{ "name": "@acme/biome-config", "exports": "./biome.json" }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/biome.json").into(),
+        r#"{ "extends": ["./base.json"] }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/base.json").into(),
+        r#"{ "formatter": { "indentStyle": "spaces" } }"#,
+    );
+
+    let biome_json = Path::new("biome.json");
+    fs.insert(
+        biome_json.into(),
+        r#"{ "extends": ["@acme/biome-config"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"const a = 1;"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_shows_the_chain_of_an_invalid_extended_config",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_should_raise_an_error_for_a_cycle() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["./base.json"] }"#,
+    );
+    fs.insert(
+        Path::new("base.json").into(),
+        r#"{ "extends": ["./biome.json"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"const a = 1;"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_should_raise_an_error_for_a_cycle",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_reports_the_chains_of_conflicting_values() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/package.json").into(),
+        r#"{ "name": "@acme/biome-config", "exports": "./biome.json" }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/biome.json").into(),
+        r#"{ "extends": ["./base.json"], "formatter": { "lineWidth": 100 } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@acme/biome-config/base.json").into(),
+        r#"{ "formatter": { "indentStyle": "tab", "lineWidth": 80 } }"#,
+    );
+    fs.insert(
+        Path::new("format.json").into(),
+        r#"{ "formatter": { "indentStyle": "space" } }"#,
+    );
+
+    let biome_json = Path::new("biome.json");
+    fs.insert(
+        biome_json.into(),
+        r#"{ "extends": ["@acme/biome-config", "./format.json"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "const a = 1;\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), test_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_reports_the_chains_of_conflicting_values",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -2,6 +2,7 @@
 //! case that affects many commands
 
 mod biome_json_support;
+mod cache;
 mod config_extends;
 mod config_path;
 mod cts_files;
@@ -10,6 +11,8 @@ mod handle_astro_files;
 mod handle_svelte_files;
 mod handle_vue_files;
 mod included_files;
+mod nested_configuration;
+mod overrides_files;
 mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
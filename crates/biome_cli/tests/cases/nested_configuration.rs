@@ -0,0 +1,209 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "function f() {\nreturn 'a';\n}\n";
+const FORMATTED_WITH_TABS: &str = "function f() {\n\treturn \"a\";\n}\n";
+const FORMATTED_WITH_SPACES: &str = "function f() {\n    return \"a\";\n}\n";
+
+#[test]
+fn formats_files_with_the_nested_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), r#"{}"#);
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space", "indentWidth": 4 } }"#,
+    );
+
+    let root_file = Path::new("src/file.js");
+    fs.insert(root_file.into(), UNFORMATTED);
+    let nested_file = Path::new("packages/a/src/file.js");
+    fs.insert(nested_file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                root_file.as_os_str().to_str().unwrap(),
+                nested_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, root_file, FORMATTED_WITH_TABS);
+    assert_file_contents(&fs, nested_file, FORMATTED_WITH_SPACES);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "formats_files_with_the_nested_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn discovers_nested_configuration_inside_directories() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), r#"{}"#);
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#,
+    );
+
+    let root_file = Path::new("packages/file.js");
+    fs.insert(root_file.into(), "debugger;\n");
+    let nested_file = Path::new("packages/a/file.js");
+    fs.insert(nested_file.into(), "debugger;\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("packages")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "discovers_nested_configuration_inside_directories",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_invalid_nested_configuration_inside_directories() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), r#"{}"#);
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "linter": { "unknownKey": true } }"#,
+    );
+
+    let nested_file = Path::new("packages/a/file.js");
+    fs.insert(nested_file.into(), "let a = 1;\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("packages")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_invalid_nested_configuration_inside_directories",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_configuration_ignores_files_relative_to_its_directory() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), r#"{}"#);
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "files": { "ignore": ["generated/**"] } }"#,
+    );
+
+    let ignored_file = Path::new("packages/a/generated/file.js");
+    fs.insert(ignored_file.into(), "debugger;\n");
+    let root_file = Path::new("generated/file.js");
+    fs.insert(root_file.into(), "debugger;\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ignored_file.as_os_str().to_str().unwrap(),
+                root_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_configuration_ignores_files_relative_to_its_directory",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_configuration_extends_the_root_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space", "indentWidth": 4 } }"#,
+    );
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{
+    "extends": ["../../biome.json"],
+    "javascript": { "formatter": { "quoteStyle": "single" } }
+}"#,
+    );
+
+    let root_file = Path::new("src/file.js");
+    fs.insert(root_file.into(), UNFORMATTED);
+    let nested_file = Path::new("packages/a/src/file.js");
+    fs.insert(nested_file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                root_file.as_os_str().to_str().unwrap(),
+                nested_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, root_file, FORMATTED_WITH_SPACES);
+    assert_file_contents(&fs, nested_file, "function f() {\n    return 'a';\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_configuration_extends_the_root_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,147 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn does_raise_max_size_for_included_files() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "files": { "maxSize": 16 },
+  "overrides": [{ "include": ["fixtures/**"], "files": { "maxSize": 1024 } }]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.js");
+    fs.insert(test.into(), "statement1();\nstatement2();".as_bytes());
+
+    let test2 = Path::new("fixtures/test2.js");
+    fs.insert(test2.into(), "statement1();\nstatement2();".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                test.as_os_str().to_str().unwrap(),
+                test2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_raise_max_size_for_included_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_ignore_unknown_included_files() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "overrides": [{ "include": ["assets/**"], "files": { "ignoreUnknown": true } }]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.txt");
+    fs.insert(test.into(), "content".as_bytes());
+
+    let test2 = Path::new("assets/test2.txt");
+    fs.insert(test2.into(), "content".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--verbose",
+                test.as_os_str().to_str().unwrap(),
+                test2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_ignore_unknown_included_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_allow_comments_in_included_json_files() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "overrides": [{ "include": ["tsconfig*.json"], "json": { "parser": { "allowComments": true } } }]
+}
+"#
+        .as_bytes(),
+    );
+
+    let tsconfig = Path::new("tsconfig.base.json");
+    fs.insert(
+        tsconfig.into(),
+        "{\n  // Shared options\n  \"compilerOptions\": {}\n}\n".as_bytes(),
+    );
+
+    let other = Path::new("package.json");
+    fs.insert(
+        other.into(),
+        "{\n  // Comments aren't allowed\n  \"name\": \"test\"\n}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                tsconfig.as_os_str().to_str().unwrap(),
+                other.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_allow_comments_in_included_json_files",
+        fs,
+        console,
+        result,
+    ));
+}
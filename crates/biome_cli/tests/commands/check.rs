@@ -17,7 +17,7 @@ use crate::configs::{
 use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
 use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
 use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
-use biome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
+use biome_fs::{ChangedFile, ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
 use biome_service::DynRef;
 
 const ERRORS: &str = r#"
@@ -2846,3 +2846,321 @@ fn should_show_formatter_diagnostics_for_files_ignored_by_linter() {
         result,
     ));
 }
+
+#[test]
+fn watch_and_stdin_file_path_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push("debugger;".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--watch"), ("--stdin-file-path"), ("mock.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "watch_and_stdin_file_path_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn watch_and_staged_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--watch"), ("--staged")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "watch_and_staged_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn watch_without_paths_exits() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--watch")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "watch_without_paths_exits",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn should_only_format_files_whose_changed_lines_are_unformatted() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_lines(Box::new(|| {
+        vec![
+            ChangedFile {
+                path: String::from("changed.js"),
+                lines: vec![2..3],
+            },
+            ChangedFile {
+                path: String::from("unchanged.js"),
+                lines: vec![2..3],
+            },
+        ]
+    }));
+
+    fs.insert(
+        Path::new("changed.js").into(),
+        "statement();\nstatement(  );\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("unchanged.js").into(),
+        "statement(  );\nstatement();\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), "--changed", "--changed-lines", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_format_files_whose_changed_lines_are_unformatted",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_reports_unsorted_json_keys() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "json": { "sortKeys": { "enabled": true } } }"#.as_bytes(),
+    );
+    let file_path = Path::new("config.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+	"server": {
+		"port": 8080,
+		"host": "localhost"
+	},
+	"name": "api"
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_reports_unsorted_json_keys",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_apply_sorts_json_keys() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "json": { "sortKeys": { "enabled": true } } }"#.as_bytes(),
+    );
+    let file_path = Path::new("package.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+	"version": "1.0.0",
+	"dependencies": {
+		"react-dom": "^18.2.0",
+		"react": "^18.2.0"
+	},
+	"name": "@acme/ui"
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        r#"{
+	"name": "@acme/ui",
+	"version": "1.0.0",
+	"dependencies": {
+		"react": "^18.2.0",
+		"react-dom": "^18.2.0"
+	}
+}
+"#
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_apply_sorts_json_keys",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_apply_sorts_json_keys_in_the_order_of_the_last_override() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+	"json": { "sortKeys": { "enabled": true, "order": ["version"] } },
+	"overrides": [
+		{ "include": ["config.json"], "json": { "sortKeys": { "enabled": false } } },
+		{ "include": ["config.json"], "json": { "sortKeys": { "enabled": true, "order": ["name"] } } }
+	]
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("config.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+	"version": "1.0.0",
+	"zone": "eu",
+	"author": "acme",
+	"name": "api"
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        r#"{
+	"name": "api",
+	"author": "acme",
+	"version": "1.0.0",
+	"zone": "eu"
+}
+"#
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_apply_sorts_json_keys_in_the_order_of_the_last_override",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_does_not_sort_json_keys_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("config.json");
+    fs.insert(
+        file_path.into(),
+        "{\n\t\"b\": 1,\n\t\"a\": 2\n}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_does_not_sort_json_keys_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,120 @@
+use bpaf::Args;
+use std::path::Path;
+
+use crate::snap_test::SnapshotPayload;
+use crate::{assert_cli_snapshot, run_cli};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+
+#[test]
+fn config_print_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_shows_the_source_of_each_value() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("base.json").into(),
+        r#"{
+    "formatter": { "indentStyle": "space", "lineWidth": 100 },
+    "files": { "ignore": ["dist"] }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "extends": ["base.json"],
+    "formatter": { "lineWidth": 120 },
+    "files": { "ignore": ["build"] },
+    "linter": {
+        "rules": {
+            "recommended": false,
+            "style": { "noVar": "error" }
+        }
+    },
+    "overrides": [
+        {
+            "include": ["src/**"],
+            "javascript": { "formatter": { "quoteStyle": "single" } },
+            "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } }
+        },
+        {
+            "include": ["test/**"],
+            "formatter": { "lineWidth": 80 }
+        }
+    ]
+}"#
+        .as_bytes(),
+    );
+    fs.insert(Path::new("src/index.js").into(), "debugger;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "--indent-width=4", "src/index.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_shows_the_source_of_each_value",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_uses_the_nested_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "lineWidth": 120 } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/lib/biome.json").into(),
+        r#"{ "linter": { "enabled": false } }"#.as_bytes(),
+    );
+    fs.insert(Path::new("packages/lib/index.js").into(), "a;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "packages/lib/index.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_uses_the_nested_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
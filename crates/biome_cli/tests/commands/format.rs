@@ -2221,6 +2221,95 @@ fn format_jsonc_files() {
     ));
 }
 
+#[test]
+fn format_json5_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let code = r#"// comment
+{unquoted: 'single', hex: 0xFF, leading: .5, trailing: 5., infinity: -Infinity, "nan": NaN,}
+"#;
+    let file_path = Path::new("file.json5");
+    fs.insert(file_path.into(), code.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "// comment\n{\n\tunquoted: 'single',\n\thex: 0xff,\n\tleading: 0.5,\n\ttrailing: 5,\n\tinfinity: -Infinity,\n\t\"nan\": NaN\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_json5_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_json5_quote_style() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+    "json": {
+        "formatter": { "quoteStyle": "single" }
+    }
+}"#;
+    let json5_file_path = Path::new("file.json5");
+    fs.insert(
+        json5_file_path.into(),
+        r#"{ "key": "it's a \"value\"" }"#.as_bytes(),
+    );
+    let json_file_path = Path::new("file.json");
+    fs.insert(json_file_path.into(), r#"{ "key": "value" }"#.as_bytes());
+    fs.insert(Path::new("biome.json").into(), config_json.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                json5_file_path.as_os_str().to_str().unwrap(),
+                json_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, json5_file_path, "{ 'key': 'it\\'s a \"value\"' }\n");
+    // Only JSON5 allows single quotes
+    assert_file_contents(&fs, json_file_path, "{ \"key\": \"value\" }\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_json5_quote_style",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn format_json_when_allow_trailing_commas() {
     let mut fs = MemoryFileSystem::default();
@@ -3517,3 +3606,36 @@ fn should_format_files_in_folders_ignored_by_linter() {
         result,
     ));
 }
+
+#[test]
+fn should_only_format_staged_files_when_staged_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_staged_files(Box::new(|| vec![String::from("staged.js")]));
+
+    let staged_file_path = Path::new("staged.js");
+    fs.insert(staged_file_path.into(), UNFORMATTED.as_bytes());
+
+    let unstaged_file_path = Path::new("unstaged.js");
+    fs.insert(unstaged_file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--staged", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, staged_file_path, FORMATTED);
+    assert_file_contents(&fs, unstaged_file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_format_staged_files_when_staged_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
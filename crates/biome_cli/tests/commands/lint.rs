@@ -17,7 +17,7 @@ use crate::configs::{
 use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
 use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
 use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
-use biome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
+use biome_fs::{ChangedFile, ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
 use biome_service::DynRef;
 
 const ERRORS: &str = r#"
@@ -3053,3 +3053,1060 @@ import "lodash";
         result,
     ));
 }
+
+#[test]
+fn should_only_process_staged_files_when_staged_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_staged_files(Box::new(|| vec![String::from("staged.js")]));
+
+    let staged_file_path = Path::new("staged.js");
+    fs.insert(
+        staged_file_path.into(),
+        r#"console.log('staged');"#.as_bytes(),
+    );
+
+    let unstaged_file_path = Path::new("file2.js");
+    fs.insert(unstaged_file_path.into(), r#"debugger;"#.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--staged"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_process_staged_files_when_staged_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_changed_and_staged_flags_are_used() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), r#"console.log('file');"#.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--changed",
+                "--since=main",
+                "--staged",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_changed_and_staged_flags_are_used",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_only_report_diagnostics_of_changed_lines_when_changed_lines_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_lines(Box::new(|| {
+        vec![ChangedFile {
+            path: String::from("file.js"),
+            lines: vec![2..3, 4..5],
+        }]
+    }));
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nstatement();\nstatement();\ndebugger;\n".as_bytes(),
+    );
+
+    let file_path2 = Path::new("file2.js");
+    fs.insert(file_path2.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--changed", "--changed-lines", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_report_diagnostics_of_changed_lines_when_changed_lines_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_not_error_for_diagnostics_outside_changed_lines() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_lines(Box::new(|| {
+        vec![ChangedFile {
+            path: String::from("file.js"),
+            lines: vec![2..4, 5..6],
+        }]
+    }));
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nstatement();\nstatement();\ndebugger;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--changed", "--changed-lines", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_not_error_for_diagnostics_outside_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_changed_lines_flag_is_used_without_changed() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), r#"console.log('file');"#.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--changed-lines",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_changed_lines_flag_is_used_without_changed",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_adds_suppression_comments() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nfunction f() {\n    if (a == b) {}\n}\n".as_bytes(),
+    );
+    let jsx_path = Path::new("file.jsx");
+    fs.insert(
+        jsx_path.into(),
+        "const element = (\n    <div>\n        <img src=\"image.png\" />\n    </div>\n);\n"
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--suppress"),
+                file_path.as_os_str().to_str().unwrap(),
+                jsx_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_adds_suppression_comments",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_with_reason() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--suppress"),
+                ("--reason=legacy code"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        "// biome-ignore lint/suspicious/noDebugger: legacy code\ndebugger;\n"
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_with_reason",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_stops_when_a_comment_does_not_silence_the_diagnostic() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    // The comment can't be placed on the line of the second part of the template
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "const t = `\n` == b;\ndebugger;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--suppress"),
+                ("--reason=legacy code"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        "// biome-ignore lint/suspicious/noDoubleEquals: legacy code\nconst t = `\n` == b;\n// biome-ignore lint/suspicious/noDebugger: legacy code\ndebugger;\n"
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_stops_when_a_comment_does_not_silence_the_diagnostic",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_suppress_is_used_with_apply() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--suppress",
+                "--apply",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_suppress_is_used_with_apply",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_reason_is_used_without_suppress() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--reason=legacy code",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_reason_is_used_without_suppress",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_only_the_selected_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\nif (a == b) {}\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--suppress"),
+                ("--rule=suspicious/noDebugger"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        "// biome-ignore lint/suspicious/noDebugger: <explanation>\ndebugger;\nif (a == b) {}\n"
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_only_the_selected_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_rule_does_not_exist() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--suppress",
+                "--rule=noDebugger",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_rule_does_not_exist",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_reason_ends_the_comment() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--suppress",
+                "--reason=legacy */ code",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_reason_ends_the_comment",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_reports_the_json_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.json");
+    fs.insert(file_path.into(), r#"{ "a": 1, "a": 2 }"#.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--suppress"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_reports_the_json_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_resolves_the_imports_of_the_project() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUnresolvedImports": "error",
+                "noUnresolvedNamedImports": "error"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/utils.js").into(),
+        "export const sum = (a, b) => a + b;\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/styles.css").into(),
+        ".button { color: red; }\n".as_bytes(),
+    );
+    let file_path = Path::new("src/index.js");
+    fs.insert(
+        file_path.into(),
+        r#"import { sum, max } from "./utils.js";
+import { parse } from "./parser.js";
+import "./styles.css";
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_resolves_the_imports_of_the_project",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_stdin_resolves_the_imports_of_the_project() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUnresolvedImports": "error"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/utils.js").into(),
+        "export const sum = (a, b) => a + b;\n".as_bytes(),
+    );
+    console.in_buffer.push(
+        r#"import { sum } from "./utils.js";
+import { parse } from "./parser.js";
+"#
+        .to_string(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--stdin-file-path"), ("src/index.js")].as_slice()),
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_stdin_resolves_the_imports_of_the_project",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_resolves_the_path_aliases_of_the_tsconfig() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUndeclaredDependencies": "error",
+                "noUnresolvedImports": "error"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "dependencies": { "react": "1.0.0" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("tsconfig.json").into(),
+        r#"{
+    // The path aliases are declared by the base configuration
+    "extends": "./tsconfig.base.json",
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("tsconfig.base.json").into(),
+        r#"{ "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/utils.js").into(),
+        "export const sum = (a, b) => a + b;\n".as_bytes(),
+    );
+    let file_path = Path::new("src/index.js");
+    fs.insert(
+        file_path.into(),
+        r#"import { sum } from "@/utils.js";
+import { parse } from "@/parser.js";
+import React from "react";
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_resolves_the_path_aliases_of_the_tsconfig",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_resolves_the_path_aliases_of_the_nearest_tsconfig() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUnresolvedImports": "error"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("tsconfig.json").into(),
+        r#"{ "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/app/tsconfig.json").into(),
+        r#"{ "compilerOptions": { "paths": { "~/*": ["./lib/*"] } } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/app/lib/utils.js").into(),
+        "export const sum = (a, b) => a + b;\n".as_bytes(),
+    );
+    let file_path = Path::new("packages/app/index.js");
+    fs.insert(
+        file_path.into(),
+        r#"import { sum } from "~/utils.js";
+import { parse } from "~/parser.js";
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_resolves_the_path_aliases_of_the_nearest_tsconfig",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_uses_the_manifest_of_the_package_in_a_monorepo() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUndeclaredDependencies": "error"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "name": "monorepo", "workspaces": ["packages/*"] }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/ui/package.json").into(),
+        r#"{ "name": "@acme/ui", "dependencies": { "react": "1.0.0" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/app/package.json").into(),
+        r#"{ "name": "@acme/app", "dependencies": { "lodash": "1.0.0" } }"#.as_bytes(),
+    );
+    let file_path = Path::new("packages/app/src/index.js");
+    fs.insert(
+        file_path.into(),
+        r#"import { Button } from "@acme/ui";
+import { merge } from "lodash";
+import React from "react";
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_uses_the_manifest_of_the_package_in_a_monorepo",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_skips_the_ignored_packages_of_a_monorepo() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "files": {
+        "ignore": ["packages/legacy"]
+    },
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUndeclaredDependencies": "error"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "name": "monorepo", "workspaces": ["packages/*"] }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/legacy/package.json").into(),
+        r#"{ "name": "@acme/legacy" }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/ui/package.json").into(),
+        r#"{ "name": "@acme/ui" }"#.as_bytes(),
+    );
+    let file_path = Path::new("packages/ui/src/index.js");
+    fs.insert(
+        file_path.into(),
+        r#"import { Button } from "@acme/legacy";
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_skips_the_ignored_packages_of_a_monorepo",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_package_json_and_apply_the_fixes() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noInvalidDependencyVersions": "error",
+                "useSortedPackageJsonKeys": "error"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("package.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+    "version": "1.0.0",
+    "name": "@acme/ui",
+    "dependencies": {
+        "react-dom": "^18.2.0",
+        "react": "^18.2.0.1"
+    }
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_package_json_and_apply_the_fixes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_validates_json_files_against_the_configured_schemas() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "json": {
+        "schemas": [
+            { "include": ["config/*.json"], "schema": "./schemas/service.json" }
+        ]
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("schemas/service.json").into(),
+        r#"{
+    "type": "object",
+    "properties": {
+        "name": { "type": "string" },
+        "port": { "type": "integer" },
+        "level": { "enum": ["error", "warn", "info"] }
+    },
+    "required": ["name"],
+    "additionalProperties": false
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("config/api.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+    "port": "8080",
+    "level": "debug",
+    "timeout": 30
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_validates_json_files_against_the_configured_schemas",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reports_the_unsupported_patterns_of_a_schema() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "json": {
+        "schemas": [
+            { "include": ["config/*.json"], "schema": "./schemas/service.json" }
+        ]
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("schemas/service.json").into(),
+        r#"{
+    "type": "object",
+    "properties": {
+        "name": { "type": "string", "pattern": "^(?!internal-)" }
+    }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("config/api.json");
+    fs.insert(file_path.into(), r#"{ "name": "api" }"#.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_the_unsupported_patterns_of_a_schema",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_validates_json_files_against_their_schema_key() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(
+        Path::new("schemas/service.json").into(),
+        r#"{
+    "type": "object",
+    "properties": {
+        "name": { "type": "string" },
+        "replicas": { "type": "integer", "minimum": 1 }
+    },
+    "additionalProperties": false
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("config/api.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+    "$schema": "../schemas/service.json",
+    "name": "api",
+    "replicas": 0
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_validates_json_files_against_their_schema_key",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -133,6 +133,45 @@ fn should_create_biome_json_file() {
     ));
 }
 
+#[test]
+fn migrate_rule_names_and_options() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{
+    "linter": {
+        "rules": {
+            "correctness": {
+                "useHookAtTopLevel": { "level": "error", "options": { "hooks": [] } }
+            },
+            "nursery": {
+                "noExcessiveComplexity": "error",
+                "useImportType": "warn"
+            }
+        }
+    }
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_rule_names_and_options",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn prettier_migrate() {
     let mut fs = MemoryFileSystem::default();
@@ -315,7 +354,7 @@ fn prettier_migrate_yml_file() {
         Args::from([("migrate"), "prettier"].as_slice()),
     );
 
-    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -326,6 +365,76 @@ fn prettier_migrate_yml_file() {
     ));
 }
 
+#[test]
+fn prettier_migrate_json5_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let prettier = r#"{
+    // The comments and the trailing commas are allowed
+    "useTabs": true,
+    "trailingComma": "es5",
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc.json5");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_json5_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_json5_syntax() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let prettier = r#"{
+    // JSON5 allows unquoted keys and single-quoted strings
+    useTabs: true,
+    trailingComma: 'es5',
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc.json5");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_json5_syntax",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn prettier_migrate_write() {
     let mut fs = MemoryFileSystem::default();
@@ -429,3 +538,504 @@ fn prettier_migrate_write_biome_jsonc() {
         result,
     ));
 }
+
+#[test]
+fn prettier_migrate_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let prettier = r#"{
+    "semi": false,
+    "singleQuote": true,
+    "overrides": [
+        {
+            "files": ["legacy/**/*.js"],
+            "excludedFiles": "legacy/vendor/**",
+            "options": { "semi": true, "tabWidth": 4, "embeddedLanguageFormatting": "off" }
+        },
+        {
+            "files": "*.jsonc",
+            "options": { "trailingComma": "all", "printWidth": 120 }
+        },
+        {
+            "files": "*.css",
+            "options": { "singleQuote": false }
+        },
+        {
+            "files": "*.md",
+            "options": { "proseWrap": "always" }
+        }
+    ]
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc.json");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_yaml_file_with_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let prettier = r#"# Prettier configuration
+printWidth: 100
+quoteProps: consistent
+overrides:
+  - files:
+      - "*.test.ts"
+      - "*.spec.ts"
+    options:
+      printWidth: 120
+      arrowParens: avoid
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc.yaml");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_yaml_file_with_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_package_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let package_json = r#"{
+    "name": "project",
+    "prettier": { "useTabs": true, "bracketSameLine": true, "singleAttributePerLine": true }
+}"#;
+    let prettier_ignore = r#"
+# Build output
+/dist
+coverage/
+*.min.js
+!vendor/keep.min.js
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let package_json_path = Path::new("package.json");
+    fs.insert(package_json_path.into(), package_json.as_bytes());
+
+    let prettier_ignore_path = Path::new(".prettierignore");
+    fs.insert(prettier_ignore_path.into(), prettier_ignore.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_package_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_javascript_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let prettier = r#"module.exports = { semi: false };"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new("prettier.config.js");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_javascript_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_migrate() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let eslint = r#"{
+    // comments are allowed
+    "extends": ["eslint:recommended", "prettier", "plugin:vue/recommended"],
+    "ignorePatterns": ["dist/**"],
+    "globals": { "jQuery": "readonly", "legacy": "off" },
+    "rules": {
+        "no-debugger": "off",
+        "eqeqeq": 1,
+        "no-unused-vars": ["error"],
+        "@typescript-eslint/no-unused-vars": "warn",
+        "no-restricted-globals": ["error", "event", { "name": "fdescribe", "message": "Use describe" }],
+        "curly": ["error", "multi"],
+        "@typescript-eslint/no-this-alias": "error",
+        "prefer-arrow-callback": "error",
+        "no-dupe-else-if": "error",
+        "unknown-plugin/unknown-rule": "error",
+    },
+    "overrides": [
+        {
+            "files": ["*.test.js"],
+            "excludedFiles": "*.snap.js",
+            "rules": { "jest/no-focused-tests": "off", "no-console": "off" }
+        }
+    ]
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let eslint_path = Path::new(".eslintrc.json");
+    fs.insert(eslint_path.into(), eslint.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "eslint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_migrate",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_migrate_include_inspired_and_nursery() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{}"#;
+    let eslint = r#"{
+    "rules": {
+        "prefer-arrow-callback": "error",
+        "no-dupe-else-if": "warn",
+        "no-restricted-imports": ["error", "lodash", { "name": "moment", "message": "Use date-fns" }]
+    }
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let eslint_path = Path::new(".eslintrc");
+    fs.insert(eslint_path.into(), eslint.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("migrate"),
+                "eslint",
+                "--include-inspired",
+                "--include-nursery",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_migrate_include_inspired_and_nursery",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_migrate_flat_config() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{}"#;
+    let eslint = r#"[
+    { "ignores": ["build/**"] },
+    {
+        "languageOptions": { "globals": { "process": "readonly" } },
+        "rules": { "no-debugger": "error", "no-var": "warn" }
+    },
+    { "rules": { "no-var": "error" } },
+    {
+        "files": ["**/*.ts"],
+        "ignores": ["**/*.d.ts"],
+        "rules": { "@typescript-eslint/no-explicit-any": "error" }
+    }
+]"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let eslint_path = Path::new("eslint.config.json");
+    fs.insert(eslint_path.into(), eslint.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "eslint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_migrate_flat_config",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_migrate_package_json_and_ignore_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{}"#;
+    let package_json = r#"{
+    "name": "project",
+    "eslintConfig": { "rules": { "no-debugger": 2 } }
+}"#;
+    let eslint_ignore = r#"
+# generated files
+generated/**
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let package_json_path = Path::new("package.json");
+    fs.insert(package_json_path.into(), package_json.as_bytes());
+
+    let eslint_ignore_path = Path::new(".eslintignore");
+    fs.insert(eslint_ignore_path.into(), eslint_ignore.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "eslint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_migrate_package_json_and_ignore_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_migrate_no_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "eslint"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_migrate_no_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn editorconfig_migrate() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let editorconfig = r#"
+root = true
+
+[*]
+indent_style = space
+indent_size = 2
+end_of_line = lf
+charset = utf-8
+insert_final_newline = true
+
+[*.{js,ts}]
+max_line_length = 100
+
+[Makefile]
+indent_style = tab
+
+[/legacy/**]
+indent_size = 4
+end_of_line = crlf
+
+[file{1..3}.js]
+indent_size = 8
+
+[*.md]
+max_line_length = off
+trim_trailing_whitespace = false
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let editorconfig_path = Path::new(".editorconfig");
+    fs.insert(editorconfig_path.into(), editorconfig.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "editorconfig"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "editorconfig_migrate",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn editorconfig_migrate_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let editorconfig = r#"
+[*]
+indent_style = tab
+indent_size = tab
+tab_width = 4
+max_line_length = 120
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let editorconfig_path = Path::new(".editorconfig");
+    fs.insert(editorconfig_path.into(), editorconfig.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "editorconfig", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "editorconfig_migrate_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn editorconfig_migrate_no_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "editorconfig"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "editorconfig_migrate_no_file",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,5 +1,6 @@
 mod check;
 mod ci;
+mod config;
 mod explain;
 mod format;
 mod init;
@@ -112,6 +112,7 @@ define_categories! {
     "lint/nursery/noColorInvalidHex": "https://biomejs.dev/linter/rules/no-color-invalid-hex",
     "lint/nursery/noConsole": "https://biomejs.dev/linter/rules/no-console",
     "lint/nursery/noDoneCallback": "https://biomejs.dev/linter/rules/no-done-callback",
+    "lint/nursery/noDuplicateDependencies": "https://biomejs.dev/linter/rules/no-duplicate-dependencies",
     "lint/nursery/noDuplicateElseIf": "https://biomejs.dev/linter/rules/no-duplicate-else-if",
     "lint/nursery/noDuplicateJsonKeys": "https://biomejs.dev/linter/rules/no-duplicate-json-keys",
     "lint/nursery/noDuplicateTestHooks": "https://biomejs.dev/linter/rules/no-duplicate-test-hooks",
@@ -119,6 +120,10 @@ define_categories! {
     "lint/nursery/noExcessiveNestedTestSuites": "https://biomejs.dev/linter/rules/no-excessive-nested-test-suites",
     "lint/nursery/noExportsInTest": "https://biomejs.dev/linter/rules/no-exports-in-test",
     "lint/nursery/noFocusedTests": "https://biomejs.dev/linter/rules/no-focused-tests",
+    "lint/nursery/noImportCycles": "https://biomejs.dev/linter/rules/no-import-cycles",
+    "lint/nursery/noInvalidDependencyVersions": "https://biomejs.dev/linter/rules/no-invalid-dependency-versions",
+    "lint/nursery/noInvalidPackageEngines": "https://biomejs.dev/linter/rules/no-invalid-package-engines",
+    "lint/nursery/noInvalidPackageExports": "https://biomejs.dev/linter/rules/no-invalid-package-exports",
     "lint/nursery/noMisplacedAssertion": "https://biomejs.dev/linter/rules/no-misplaced-assertion",
     "lint/nursery/noNamespaceImport": "https://biomejs.dev/linter/rules/no-namespace-import",
     "lint/nursery/noNodejsModules": "https://biomejs.dev/linter/rules/no-nodejs-modules",
@@ -128,12 +133,19 @@ define_categories! {
     "lint/nursery/noSuspiciousSemicolonInJsx": "https://biomejs.dev/linter/rules/no-suspicious-semicolon-in-jsx",
     "lint/nursery/noTypeOnlyImportAttributes": "https://biomejs.dev/linter/rules/no-type-only-import-attributes",
     "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
+    "lint/nursery/noUnresolvedDefaultImports": "https://biomejs.dev/linter/rules/no-unresolved-default-imports",
+    "lint/nursery/noUnresolvedImports": "https://biomejs.dev/linter/rules/no-unresolved-imports",
+    "lint/nursery/noUnresolvedNamedImports": "https://biomejs.dev/linter/rules/no-unresolved-named-imports",
+    "lint/nursery/noUnusedExports": "https://biomejs.dev/linter/rules/no-unused-exports",
+    "lint/nursery/noUnusedFiles": "https://biomejs.dev/linter/rules/no-unused-files",
     "lint/nursery/noUselessTernary": "https://biomejs.dev/linter/rules/no-useless-ternary",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
     "lint/nursery/useJsxKeyInIterable": "https://biomejs.dev/linter/rules/use-jsx-key-in-iterable",
     "lint/nursery/useNodeAssertStrict": "https://biomejs.dev/linter/rules/use-node-assert-strict",
+    "lint/nursery/usePackageTypes": "https://biomejs.dev/linter/rules/use-package-types",
     "lint/nursery/useSortedClasses": "https://biomejs.dev/linter/rules/use-sorted-classes",
+    "lint/nursery/useSortedPackageJsonKeys": "https://biomejs.dev/linter/rules/use-sorted-package-json-keys",
     "lint/performance/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
     "lint/security/noDangerouslySetInnerHtml": "https://biomejs.dev/linter/rules/no-dangerously-set-inner-html",
@@ -238,10 +250,12 @@ define_categories! {
     "ci",
     "configuration",
     "organizeImports",
+    "sortKeys",
     "migrate",
     "deserialize",
     "project",
     "search",
+    "jsonSchema",
     "internalError/io",
     "internalError/fs",
     "internalError/panic",
@@ -267,6 +281,8 @@ define_categories! {
     "suppressions/unknownGroup",
     "suppressions/unknownRule",
     "suppressions/unused",
+    "suppressions/unmatched",
+    "suppressions/misplaced",
     "suppressions/deprecatedSuppressionComment",
 
     // Used in tests and examples
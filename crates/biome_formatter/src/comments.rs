@@ -82,8 +82,13 @@ use self::{builder::CommentsBuilderVisitor, map::CommentsMap};
 use crate::formatter::Formatter;
 use crate::{buffer::Buffer, write};
 use crate::{CstFormatContext, FormatResult, FormatRule, TextSize, TransformSourceMap};
+use biome_diagnostics::category;
 use biome_rowan::syntax::SyntaxElementKey;
-use biome_rowan::{Language, SyntaxNode, SyntaxToken, SyntaxTriviaPieceComments};
+use biome_rowan::{
+    Direction, Language, SyntaxNode, SyntaxToken, SyntaxTriviaPiece, SyntaxTriviaPieceComments,
+    TextRange,
+};
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 use rustc_hash::FxHashSet;
 #[cfg(debug_assertions)]
 use std::cell::{Cell, RefCell};
@@ -759,6 +764,20 @@ impl<L: Language> CommentPlacement<L> {
     }
 }
 
+/// The kind of a suppression comment that suppresses the formatting of a range of code.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RangeSuppressionKind {
+    /// A `biome-ignore-start format:` comment, the code that follows it is formatted verbatim.
+    Start,
+
+    /// A `biome-ignore-end format:` comment, ends the range started by the previous [RangeSuppressionKind::Start] comment.
+    End,
+
+    /// A `biome-ignore-all format:` comment, the whole file is formatted verbatim. It must be placed
+    /// at the top of the file.
+    All,
+}
+
 /// Defines how to format comments for a specific [Language].
 pub trait CommentStyle: Default {
     type Language: Language;
@@ -768,6 +787,30 @@ pub trait CommentStyle: Default {
         false
     }
 
+    /// Returns the [RangeSuppressionKind] if a comment with the given `text` is a `biome-ignore-start format:`,
+    /// `biome-ignore-end format:`, or `biome-ignore-all format:` suppression comment.
+    fn get_range_suppression_kind(text: &str) -> Option<RangeSuppressionKind> {
+        parse_suppression_comment(text)
+            .filter_map(Result::ok)
+            .find_map(|suppression| {
+                let is_format = suppression
+                    .categories
+                    .iter()
+                    .any(|(key, _)| *key == category!("format"));
+                match suppression.scope {
+                    SuppressionScope::RangeStart if is_format => Some(RangeSuppressionKind::Start),
+                    // A bare `biome-ignore-end` ends all the suppressed ranges
+                    SuppressionScope::RangeEnd
+                        if is_format || suppression.categories.is_empty() =>
+                    {
+                        Some(RangeSuppressionKind::End)
+                    }
+                    SuppressionScope::File if is_format => Some(RangeSuppressionKind::All),
+                    _ => None,
+                }
+            })
+    }
+
     /// Returns the (kind)[CommentKind] of the comment
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind;
 
@@ -818,11 +861,13 @@ impl<L: Language> Comments<L> {
         let builder = CommentsBuilderVisitor::new(style, source_map);
 
         let (comments, skipped) = builder.visit(root);
+        let suppressed_ranges = Self::collect_suppressed_ranges::<Style>(root);
 
         Self {
             data: Rc::new(CommentsData {
                 root: Some(root.clone()),
                 is_suppression: Style::is_suppression,
+                suppressed_ranges,
 
                 comments,
                 with_skipped: skipped,
@@ -832,6 +877,56 @@ impl<L: Language> Comments<L> {
         }
     }
 
+    /// Returns the ranges of the code between a `biome-ignore-start format:` and a `biome-ignore-end format:`
+    /// comment, or after a `biome-ignore-all format:` comment.
+    ///
+    /// A range that isn't ended extends to the end of the file. A `biome-ignore-all format:` comment
+    /// applies only when it's in the header of the file, before the first token.
+    fn collect_suppressed_ranges<Style>(root: &SyntaxNode<L>) -> Vec<TextRange>
+    where
+        Style: CommentStyle<Language = L>,
+    {
+        let end_of_file = root.text_range().end();
+        let mut suppressed_ranges = Vec::new();
+        let mut range_start = None;
+
+        for (index, token) in root.descendants_tokens(Direction::Next).enumerate() {
+            let is_header = |piece: &SyntaxTriviaPiece<L>| {
+                index == 0 && piece.text_range().end() <= token.text_trimmed_range().start()
+            };
+            let comments = token
+                .leading_trivia()
+                .pieces()
+                .chain(token.trailing_trivia().pieces())
+                .filter_map(|piece| Some((is_header(&piece), piece.as_comments()?)));
+
+            for (is_header, comment) in comments {
+                match Style::get_range_suppression_kind(comment.text()) {
+                    Some(RangeSuppressionKind::Start) => {
+                        range_start.get_or_insert(comment.text_range().end());
+                    }
+                    Some(RangeSuppressionKind::End) => {
+                        if let Some(start) = range_start.take() {
+                            suppressed_ranges
+                                .push(TextRange::new(start, comment.text_range().start()));
+                        }
+                    }
+                    Some(RangeSuppressionKind::All) if is_header => {
+                        suppressed_ranges
+                            .push(TextRange::new(comment.text_range().end(), end_of_file));
+                    }
+                    Some(RangeSuppressionKind::All) | None => {}
+                }
+            }
+        }
+
+        if let Some(start) = range_start {
+            suppressed_ranges.push(TextRange::new(start, end_of_file));
+        }
+
+        suppressed_ranges
+    }
+
     /// Returns `true` if the given `node` has any [leading](self#leading-comments) or [trailing](self#trailing-comments) comments.
     #[inline]
     pub fn has_comments(&self, node: &SyntaxNode<L>) -> bool {
@@ -923,12 +1018,35 @@ impl<L: Language> Comments<L> {
     ///
     /// Returns `true` for the expression statement but `false` for the call expression because the
     /// call expression is nested inside of the expression statement.
+    ///
+    /// It also returns `true` if `node` is inside a range suppressed by a `biome-ignore-start format:` or
+    /// `biome-ignore-all format:` comment.
     pub fn is_suppressed(&self, node: &SyntaxNode<L>) -> bool {
         self.mark_suppression_checked(node);
         let is_suppression = self.data.is_suppression;
 
         self.leading_dangling_trailing_comments(node)
             .any(|comment| is_suppression(comment.piece().text()))
+            || self.is_in_suppressed_range(node)
+    }
+
+    /// Returns `true` if `node` is inside a range suppressed by a `biome-ignore-start format:` or
+    /// `biome-ignore-all format:` comment.
+    fn is_in_suppressed_range(&self, node: &SyntaxNode<L>) -> bool {
+        use biome_rowan::SyntaxKind;
+
+        // Roots and lists are formatted through their children: formatting them verbatim would drop
+        // the leading comments of their first child
+        if self.data.suppressed_ranges.is_empty() || node.kind().is_list() || node.kind().is_root()
+        {
+            return false;
+        }
+
+        let node_range = node.text_trimmed_range();
+        self.data
+            .suppressed_ranges
+            .iter()
+            .any(|range| range.contains_range(node_range))
     }
 
     #[cfg(not(debug_assertions))]
@@ -1034,6 +1152,9 @@ struct CommentsData<L: Language> {
 
     is_suppression: fn(&str) -> bool,
 
+    /// The ranges of code suppressed by `biome-ignore-start format:` and `biome-ignore-all format:` comments
+    suppressed_ranges: Vec<TextRange>,
+
     /// Stores all leading node comments by node
     comments: CommentsMap<SyntaxElementKey, SourceComment<L>>,
     with_skipped: FxHashSet<SyntaxElementKey>,
@@ -1055,6 +1176,7 @@ impl<L: Language> Default for CommentsData<L> {
         Self {
             root: None,
             is_suppression: |_| false,
+            suppressed_ranges: Default::default(),
             comments: Default::default(),
             with_skipped: Default::default(),
             #[cfg(debug_assertions)]
@@ -3,9 +3,10 @@ use biome_diagnostics::{console, Advices, Diagnostic, LogCategory, Visit};
 use biome_diagnostics::{Error, Severity};
 pub use memory::{ErrorEntry, MemoryFileSystem};
 pub use os::OsFileSystem;
-use oxc_resolver::{Resolution, ResolveError};
+use oxc_resolver::{Resolution, ResolveError, ResolveOptions};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use std::ops::Range;
 use std::panic::RefUnwindSafe;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -35,8 +36,32 @@ impl ConfigName {
     }
 }
 
+/// The options used to resolve the configuration files listed in `extends`, such as
+/// `@acme/biome-config` or `@acme/biome-config/strict`.
+///
+/// The specifiers are resolved like Node.js does: the `exports` field of the `package.json` of a
+/// package takes precedence over its `main` field.
+pub(crate) fn configuration_resolve_options() -> ResolveOptions {
+    ResolveOptions {
+        condition_names: vec!["node".to_string(), "import".to_string()],
+        extensions: vec![".json".to_string(), ".jsonc".to_string()],
+        main_files: vec!["biome".to_string(), "index".to_string()],
+        ..ResolveOptions::default()
+    }
+}
+
 type AutoSearchResultAlias = Result<Option<AutoSearchResult>, FileSystemDiagnostic>;
 
+/// The lines of a file that changed, compared to a base reference of the VCS
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChangedFile {
+    /// The path of the file, as printed by the VCS
+    pub path: String,
+    /// The one-based ranges of the lines that were added or modified. The end of each range is
+    /// exclusive.
+    pub lines: Vec<Range<u32>>,
+}
+
 pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// It opens a file with the given set of options
     fn open_with_options(&self, path: &Path, options: OpenOptions) -> io::Result<Box<dyn File>>;
@@ -62,6 +87,9 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Checks if the given path is a regular file
     fn path_is_file(&self, path: &Path) -> bool;
 
+    /// Recursively creates a directory and all of its missing parent components
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+
     /// Method that takes a path to a folder `file_path`, and a `file_name`. It attempts to find
     /// and read the file from that folder and if not found, it reads the parent directories recursively
     /// until:
@@ -174,6 +202,25 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
 
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>>;
 
+    /// Returns the lines that were added or modified in each file that changed, compared to
+    /// `base`
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedFile>>;
+
+    /// Returns the files that were added to the staging area of the VCS, relative to the
+    /// working directory
+    fn get_staged_files(&self) -> io::Result<Vec<String>>;
+
+    /// It opens the version of the file that is stored in the staging area of the VCS, with the
+    /// given set of options.
+    ///
+    /// The content written to the returned [File] is stored in the staging area. The working
+    /// tree is updated too, unless it contains changes that weren't staged.
+    fn open_staged_with_options(
+        &self,
+        path: &Path,
+        options: OpenOptions,
+    ) -> io::Result<Box<dyn File>>;
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -343,10 +390,30 @@ where
         T::path_is_file(self, path)
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        T::create_dir_all(self, path)
+    }
+
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
         T::get_changed_files(self, base)
     }
 
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedFile>> {
+        T::get_changed_lines(self, base)
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        T::get_staged_files(self)
+    }
+
+    fn open_staged_with_options(
+        &self,
+        path: &Path,
+        options: OpenOptions,
+    ) -> io::Result<Box<dyn File>> {
+        T::open_staged_with_options(self, path, options)
+    }
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -1,9 +1,9 @@
-use oxc_resolver::{Resolution, ResolveError};
+use oxc_resolver::{FileMetadata, Resolution, ResolveError, ResolverGeneric};
 use rustc_hash::FxHashMap;
 use std::collections::hash_map::{Entry, IntoIter};
 use std::io;
 use std::panic::{AssertUnwindSafe, RefUnwindSafe};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::str;
 use std::sync::Arc;
 
@@ -13,7 +13,10 @@ use parking_lot::{lock_api::ArcMutexGuard, Mutex, RawMutex, RwLock};
 use crate::fs::OpenOptions;
 use crate::{BiomePath, FileSystem, TraversalContext, TraversalScope};
 
-use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
+use super::{
+    configuration_resolve_options, BoxedTraversal, ChangedFile, ErrorKind, File,
+    FileSystemDiagnostic,
+};
 
 type OnGetChangedFiles = Option<
     Arc<
@@ -23,12 +26,22 @@ type OnGetChangedFiles = Option<
     >,
 >;
 
+type OnGetChangedLines = Option<
+    Arc<
+        AssertUnwindSafe<
+            Mutex<Option<Box<dyn FnOnce() -> Vec<ChangedFile> + Send + 'static + RefUnwindSafe>>>,
+        >,
+    >,
+>;
+
 /// Fully in-memory file system, stores the content of all known files in a hashmap
 pub struct MemoryFileSystem {
     files: AssertUnwindSafe<RwLock<FxHashMap<PathBuf, FileEntry>>>,
     errors: FxHashMap<PathBuf, ErrorEntry>,
     allow_write: bool,
     on_get_changed_files: OnGetChangedFiles,
+    on_get_staged_files: OnGetChangedFiles,
+    on_get_changed_lines: OnGetChangedLines,
 }
 
 impl Default for MemoryFileSystem {
@@ -40,6 +53,12 @@ impl Default for MemoryFileSystem {
             on_get_changed_files: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
                 Vec::new,
             )))))),
+            on_get_staged_files: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
+                Vec::new,
+            )))))),
+            on_get_changed_lines: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
+                Vec::new,
+            )))))),
         }
     }
 }
@@ -110,6 +129,35 @@ impl MemoryFileSystem {
     ) {
         self.on_get_changed_files = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
     }
+
+    pub fn set_on_get_staged_files(
+        &mut self,
+        cfn: Box<dyn FnOnce() -> Vec<String> + Send + RefUnwindSafe + 'static>,
+    ) {
+        self.on_get_staged_files = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
+    }
+
+    pub fn set_on_get_changed_lines(
+        &mut self,
+        cfn: Box<dyn FnOnce() -> Vec<ChangedFile> + Send + RefUnwindSafe + 'static>,
+    ) {
+        self.on_get_changed_lines = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
+    }
+}
+
+/// Lexically resolves the `..` components of `path`, like the file system of the OS does for
+/// existing directories
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
 }
 
 impl FileSystem for MemoryFileSystem {
@@ -151,7 +199,7 @@ impl FileSystem for MemoryFileSystem {
             }
         } else {
             let files = self.files.0.read();
-            let entry = files.get(path).ok_or_else(|| {
+            let entry = files.get(&normalize_path(path)).ok_or_else(|| {
                 io::Error::new(
                     io::ErrorKind::NotFound,
                     format!("path {path:?} does not exists in memory filesystem"),
@@ -188,7 +236,19 @@ impl FileSystem for MemoryFileSystem {
 
     fn path_is_file(&self, path: &Path) -> bool {
         let files = self.files.0.read();
-        files.get(path).is_some()
+        files.get(&normalize_path(path)).is_some()
+    }
+
+    fn create_dir_all(&self, _path: &Path) -> io::Result<()> {
+        // Directories are implicit in the memory file system
+        if self.allow_write {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "cannot create directories in read-only filesystem",
+            ))
+        }
     }
 
     fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
@@ -201,12 +261,98 @@ impl FileSystem for MemoryFileSystem {
         Ok(cb())
     }
 
+    fn get_changed_lines(&self, _base: &str) -> io::Result<Vec<ChangedFile>> {
+        let cb_arc = self.on_get_changed_lines.as_ref().unwrap().clone();
+
+        let mut cb_guard = cb_arc.lock();
+
+        let cb = cb_guard.take().unwrap();
+
+        Ok(cb())
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        let cb_arc = self.on_get_staged_files.as_ref().unwrap().clone();
+
+        let mut cb_guard = cb_arc.lock();
+
+        let cb = cb_guard.take().unwrap();
+
+        Ok(cb())
+    }
+
+    fn open_staged_with_options(
+        &self,
+        path: &Path,
+        options: OpenOptions,
+    ) -> io::Result<Box<dyn File>> {
+        // The memory file system doesn't have a staging area, its files are always staged
+        self.open_with_options(path, options)
+    }
+
     fn resolve_configuration(
         &self,
-        _specifier: &str,
-        _path: Option<&Path>,
+        specifier: &str,
+        path: Option<&Path>,
     ) -> Result<Resolution, ResolveError> {
-        todo!()
+        let files = self
+            .files
+            .0
+            .read()
+            .iter()
+            .map(|(path, entry)| (resolver_path(path), entry.lock().clone()))
+            .collect();
+        let resolver = ResolverGeneric::new_with_file_system(
+            MemoryResolverFileSystem { files },
+            configuration_resolve_options(),
+        );
+        resolver.resolve(path.unwrap_or(Path::new("")), specifier)
+    }
+}
+
+/// A snapshot of the files of a [MemoryFileSystem], used to resolve the specifiers of packages
+#[derive(Default)]
+struct MemoryResolverFileSystem {
+    files: FxHashMap<PathBuf, Vec<u8>>,
+}
+
+/// Normalizes `path` like [normalize_path], and strips the `.` components that the resolver
+/// adds when it joins paths
+fn resolver_path(path: &Path) -> PathBuf {
+    normalize_path(path)
+        .components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
+}
+
+impl oxc_resolver::FileSystem for MemoryResolverFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let content = self
+            .files
+            .get(&resolver_path(path))
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        String::from_utf8(content.clone())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        let path = resolver_path(path);
+        if self.files.contains_key(&path) {
+            Ok(FileMetadata::new(true, false, false))
+        } else if self.files.keys().any(|file| file.starts_with(&path)) {
+            // Directories are implicit in the memory file system
+            Ok(FileMetadata::new(false, true, false))
+        } else {
+            Err(io::Error::from(io::ErrorKind::NotFound))
+        }
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.metadata(path)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        Ok(resolver_path(path))
     }
 }
 
@@ -1,17 +1,20 @@
 //! Implementation of the [FileSystem] and related traits for the underlying OS filesystem
-use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
+use super::{
+    configuration_resolve_options, BoxedTraversal, ChangedFile, ErrorKind, File,
+    FileSystemDiagnostic,
+};
 use crate::fs::OpenOptions;
 use crate::{
     fs::{TraversalContext, TraversalScope},
     BiomePath, FileSystem,
 };
 use biome_diagnostics::{adapters::IoError, DiagnosticExt, Error, Severity};
-use oxc_resolver::{Resolution, ResolveError, ResolveOptions, Resolver};
+use oxc_resolver::{Resolution, ResolveError, Resolver};
 use rayon::{scope, Scope};
 use std::ffi::OsStr;
 use std::fs::{DirEntry, FileType};
 use std::panic::AssertUnwindSafe;
-use std::process::Command;
+use std::process::{Command, Output, Stdio};
 use std::{
     env, fs,
     io::{self, ErrorKind as IoErrorKind, Read, Seek, Write},
@@ -31,11 +34,9 @@ impl OsFileSystem {
     pub fn new(working_directory: PathBuf) -> Self {
         Self {
             working_directory: Some(working_directory),
-            configuration_resolver: AssertUnwindSafe(Resolver::new(ResolveOptions {
-                condition_names: vec!["node".to_string(), "import".to_string()],
-                extensions: vec!["*.json".to_string(), "*.jsonc".to_string()],
-                ..ResolveOptions::default()
-            })),
+            configuration_resolver: AssertUnwindSafe(
+                Resolver::new(configuration_resolve_options()),
+            ),
         }
     }
 }
@@ -44,11 +45,9 @@ impl Default for OsFileSystem {
     fn default() -> Self {
         Self {
             working_directory: env::current_dir().ok(),
-            configuration_resolver: AssertUnwindSafe(Resolver::new(ResolveOptions {
-                condition_names: vec!["node".to_string(), "import".to_string()],
-                extensions: vec!["*.json".to_string(), "*.jsonc".to_string()],
-                ..ResolveOptions::default()
-            })),
+            configuration_resolver: AssertUnwindSafe(
+                Resolver::new(configuration_resolve_options()),
+            ),
         }
     }
 }
@@ -83,6 +82,10 @@ impl FileSystem for OsFileSystem {
         path.is_file()
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path)
+    }
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -114,6 +117,197 @@ impl FileSystem for OsFileSystem {
             .map(|l| l.to_string())
             .collect())
     }
+
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedFile>> {
+        let output = Command::new("git")
+            .arg("diff")
+            // Only the changed lines are needed, without context
+            .arg("--unified=0")
+            .arg("--no-color")
+            .arg("--no-ext-diff")
+            .arg("--no-prefix")
+            // The paths are printed relative to the working directory
+            .arg("--relative")
+            .arg("--diff-filter=ACMR")
+            .arg(format!("{}...HEAD", base))
+            .output()?;
+        check_git_status(&output)?;
+
+        Ok(parse_changed_lines(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        let output = Command::new("git")
+            .arg("diff")
+            .arg("--name-only")
+            .arg("--cached")
+            // The paths are printed relative to the working directory
+            .arg("--relative")
+            .arg("--diff-filter=ACMR")
+            .output()?;
+        check_git_status(&output)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|l| l.to_string())
+            .collect())
+    }
+
+    fn open_staged_with_options(
+        &self,
+        path: &Path,
+        options: OpenOptions,
+    ) -> io::Result<Box<dyn File>> {
+        tracing::debug_span!("OsFileSystem::open_staged_with_options", path = ?path, options = ?options)
+            .in_scope(move || -> io::Result<Box<dyn File>> {
+                let mut fs_options = fs::File::options();
+                let mut worktree = OsFile {
+                    inner: options.into_fs_options(&mut fs_options).open(path)?,
+                    version: 0,
+                };
+
+                // The git commands run in the directory of the file, so that they find its
+                // repository whatever the current directory is
+                let directory = path
+                    .parent()
+                    .filter(|directory| !directory.as_os_str().is_empty())
+                    .unwrap_or(Path::new("."));
+                let file_name = path.file_name().unwrap_or(path.as_os_str());
+                let repository = run_git(
+                    &[OsStr::new("rev-parse"), OsStr::new("--show-toplevel")],
+                    directory,
+                    None,
+                )?;
+                let repository = PathBuf::from(repository.trim_end());
+
+                // The output has the shape `<mode> <object> <stage>\t<path>`, where the path
+                // is relative to the root of the repository
+                let entry = run_git(
+                    &[
+                        OsStr::new("ls-files"),
+                        OsStr::new("--stage"),
+                        OsStr::new("--full-name"),
+                        OsStr::new("--"),
+                        file_name,
+                    ],
+                    directory,
+                    None,
+                )?;
+                let (mode, object, index_path) = entry
+                    .lines()
+                    .next()
+                    .and_then(|line| {
+                        let (info, index_path) = line.split_once('\t')?;
+                        let mut info = info.split(' ');
+                        Some((info.next()?, info.next()?, index_path))
+                    })
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            IoErrorKind::NotFound,
+                            format!("The file {} isn't staged", path.display()),
+                        )
+                    })?;
+
+                let staged_content = run_git(
+                    &[OsStr::new("cat-file"), OsStr::new("blob"), OsStr::new(object)],
+                    &repository,
+                    None,
+                )?;
+                let mut worktree_content = String::new();
+                worktree.read_to_string(&mut worktree_content)?;
+
+                Ok(Box::new(OsStagedFile {
+                    in_sync: worktree_content == staged_content,
+                    worktree,
+                    repository,
+                    mode: mode.to_string(),
+                    index_path: index_path.to_string(),
+                    content: staged_content,
+                    version: 0,
+                }))
+            })
+    }
+}
+
+/// Extracts the lines added or modified in each file from the output of `git diff --unified=0`
+fn parse_changed_lines(diff: &str) -> Vec<ChangedFile> {
+    let mut changed_files: Vec<ChangedFile> = Vec::new();
+    // The header of a file can't be confused with its content only before the first hunk
+    let mut in_header = false;
+    for line in diff.lines() {
+        if line.starts_with("diff ") {
+            in_header = true;
+        } else if in_header && line.starts_with("+++ ") {
+            let path = &line["+++ ".len()..];
+            if path != "/dev/null" {
+                changed_files.push(ChangedFile {
+                    path: path.to_string(),
+                    lines: Vec::new(),
+                });
+            }
+        } else if line.starts_with("@@ ") {
+            in_header = false;
+            // The hunk header has the shape `@@ -<start>[,<count>] +<start>[,<count>] @@`
+            let Some(new_lines) = line
+                .split_whitespace()
+                .find_map(|range| range.strip_prefix('+'))
+            else {
+                continue;
+            };
+            let (start, count) = match new_lines.split_once(',') {
+                Some((start, count)) => (start.parse::<u32>(), count.parse::<u32>()),
+                None => (new_lines.parse::<u32>(), Ok(1)),
+            };
+            if let (Ok(start), Ok(count), Some(changed_file)) =
+                (start, count, changed_files.last_mut())
+            {
+                // Hunks that only remove lines don't have any line in the new file
+                if count > 0 {
+                    changed_file.lines.push(start..start + count);
+                }
+            }
+        }
+    }
+    changed_files
+}
+
+/// Runs a git command in `current_dir`, and returns its standard output
+fn run_git(args: &[&OsStr], current_dir: &Path, stdin: Option<&[u8]>) -> io::Result<String> {
+    let mut command = Command::new("git");
+    command
+        .args(args)
+        .current_dir(current_dir)
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = command.spawn()?;
+    if let (Some(content), Some(mut child_stdin)) = (stdin, child.stdin.take()) {
+        child_stdin.write_all(content)?;
+    }
+    let output = child.wait_with_output()?;
+    check_git_status(&output)?;
+
+    String::from_utf8(output.stdout)
+        .map_err(|error| io::Error::new(IoErrorKind::InvalidData, error))
+}
+
+/// Returns the error printed by git when the command failed
+fn check_git_status(output: &Output) -> io::Result<()> {
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            IoErrorKind::Other,
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
 }
 
 struct OsFile {
@@ -151,6 +345,69 @@ impl File for OsFile {
     }
 }
 
+/// A file whose content is read from, and written to, the staging area of git
+struct OsStagedFile {
+    /// The file in the working tree
+    worktree: OsFile,
+    /// The root directory of the repository that contains the file
+    repository: PathBuf,
+    /// Whether the working tree has the same content of the staging area. When it doesn't,
+    /// the working tree contains changes that weren't staged, and it must not be overwritten.
+    in_sync: bool,
+    /// The mode of the file in the index, e.g. `100644`
+    mode: String,
+    /// The path of the file, relative to the root of the repository
+    index_path: String,
+    /// The content of the file in the staging area
+    content: String,
+    version: i32,
+}
+
+impl File for OsStagedFile {
+    fn read_to_string(&mut self, buffer: &mut String) -> io::Result<()> {
+        buffer.push_str(&self.content);
+        Ok(())
+    }
+
+    fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
+        tracing::trace_span!("OsStagedFile::set_content").in_scope(move || {
+            let object = run_git(
+                &[
+                    OsStr::new("hash-object"),
+                    OsStr::new("-w"),
+                    OsStr::new("--stdin"),
+                    OsStr::new("--path"),
+                    OsStr::new(&self.index_path),
+                ],
+                &self.repository,
+                Some(content),
+            )?;
+            let cache_info = format!("{},{},{}", self.mode, object.trim(), self.index_path);
+            run_git(
+                &[
+                    OsStr::new("update-index"),
+                    OsStr::new("--cacheinfo"),
+                    OsStr::new(&cache_info),
+                ],
+                &self.repository,
+                None,
+            )?;
+
+            if self.in_sync {
+                self.worktree.set_content(content)?;
+            }
+
+            self.content = String::from_utf8_lossy(content).to_string();
+            self.version += 1;
+            Ok(())
+        })
+    }
+
+    fn file_version(&self) -> i32 {
+        self.version
+    }
+}
+
 #[repr(transparent)]
 pub struct OsTraversalScope<'scope> {
     scope: Scope<'scope>,
@@ -419,3 +676,135 @@ impl From<FileType> for ErrorKind {
         Self::UnknownFileType
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_changed_lines, run_git, OsFileSystem};
+    use crate::{ChangedFile, FileSystem, OpenOptions};
+    use std::ffi::OsStr;
+    use std::path::{Path, PathBuf};
+    use std::{env, fs, process};
+
+    /// Creates an empty git repository in a new temporary directory
+    fn create_repository(name: &str) -> PathBuf {
+        let repository = env::temp_dir().join(format!("biome_fs_{name}_{}", process::id()));
+        fs::remove_dir_all(&repository).ok();
+        fs::create_dir_all(&repository).unwrap();
+        run_git(
+            &[OsStr::new("init"), OsStr::new("--quiet")],
+            &repository,
+            None,
+        )
+        .unwrap();
+        repository
+    }
+
+    fn staged_content(repository: &Path, index_path: &str) -> String {
+        run_git(
+            &[OsStr::new("show"), OsStr::new(&format!(":{index_path}"))],
+            repository,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn writes_staged_content_of_partially_staged_file() {
+        let repository = create_repository("partially_staged");
+        let file_path = repository.join("src").join("file.js");
+        fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+        fs::write(&file_path, "statement(  )\n").unwrap();
+        run_git(
+            &[OsStr::new("add"), OsStr::new("src/file.js")],
+            &repository,
+            None,
+        )
+        .unwrap();
+        // A change that isn't staged
+        fs::write(&file_path, "statement(  )\nunstaged(  )\n").unwrap();
+
+        let mut file = OsFileSystem::default()
+            .open_staged_with_options(&file_path, OpenOptions::default().read(true).write(true))
+            .unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        assert_eq!(content, "statement(  )\n");
+
+        file.set_content(b"statement();\n").unwrap();
+        drop(file);
+
+        assert_eq!(staged_content(&repository, "src/file.js"), "statement();\n");
+        assert_eq!(
+            fs::read_to_string(&file_path).unwrap(),
+            "statement(  )\nunstaged(  )\n"
+        );
+
+        fs::remove_dir_all(&repository).ok();
+    }
+
+    #[test]
+    fn writes_staged_content_to_working_tree_when_in_sync() {
+        let repository = create_repository("fully_staged");
+        let file_path = repository.join("file.js");
+        fs::write(&file_path, "statement(  )\n").unwrap();
+        run_git(
+            &[OsStr::new("add"), OsStr::new("file.js")],
+            &repository,
+            None,
+        )
+        .unwrap();
+
+        let mut file = OsFileSystem::default()
+            .open_staged_with_options(&file_path, OpenOptions::default().read(true).write(true))
+            .unwrap();
+        file.set_content(b"statement();\n").unwrap();
+        drop(file);
+
+        assert_eq!(staged_content(&repository, "file.js"), "statement();\n");
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), "statement();\n");
+
+        fs::remove_dir_all(&repository).ok();
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn parses_changed_lines() {
+        let diff = r#"diff --git src/a.js src/a.js
+index 4a1f3a2..9c8e5d1 100644
+--- src/a.js
++++ src/a.js
+@@ -2 +2 @@ const a = 1;
+-const b = 1;
++const b = 2;
+@@ -10,0 +11,3 @@ function f() {
++++ not a header;
++let c;
++let d;
+@@ -20,2 +22,0 @@ function g() {
+-let e;
+-let f;
+diff --git src/new.js src/new.js
+new file mode 100644
+index 0000000..e69de29
+--- /dev/null
++++ src/new.js
+@@ -0,0 +1,2 @@
++let g;
++let h;
+"#;
+
+        assert_eq!(
+            parse_changed_lines(diff),
+            vec![
+                ChangedFile {
+                    path: "src/a.js".to_string(),
+                    lines: vec![2..3, 11..14],
+                },
+                ChangedFile {
+                    path: "src/new.js".to_string(),
+                    lines: vec![1..3],
+                },
+            ]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::{cell::Cell, cmp::Ordering, collections::BTreeMap, iter, mem::take};
+use std::{cell::Cell, cmp::Ordering, collections::BTreeMap, iter, mem::take, sync::Arc};
 
 use biome_analyze::{
     context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, SourceActionKind,
@@ -10,6 +10,7 @@ use biome_js_syntax::{
     AnyJsImportClause, AnyJsModuleItem, AnyJsNamedImportSpecifier, JsImport, JsLanguage, JsModule,
     JsSyntaxToken, TextRange, TriviaPieceKind, T,
 };
+use biome_project::TsConfigJson;
 use biome_rowan::{
     chain_trivia_pieces, syntax::SyntaxTrivia, AstNode, AstNodeExt, AstNodeList, AstSeparatedList,
     BatchMutationExt, SyntaxTriviaPiece, TokenText, TriviaPiece,
@@ -21,6 +22,9 @@ declare_rule! {
     /// Provides a whole-source code action to sort the imports in the file
     /// using import groups and natural ordering.
     ///
+    /// The path aliases declared by the `paths` of the `tsconfig.json` are grouped
+    /// after the libraries and before the relative imports.
+    ///
     /// ## Examples
     ///
     /// ```js
@@ -57,6 +61,7 @@ impl Rule for OrganizeImports {
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let root = ctx.query();
+        let tsconfig = ctx.get_service::<Arc<TsConfigJson>>();
         let mut groups = Vec::new();
         let mut first_node = None;
         let mut nodes = BTreeMap::new();
@@ -89,8 +94,16 @@ impl Rule for OrganizeImports {
                 first_node = Some(import.clone());
             }
 
+            let source = import.source_text().ok()?;
+            let category = match tsconfig {
+                Some(tsconfig) if tsconfig.is_alias(source.text()) => ImportCategory::Alias,
+                _ => ImportCategory::from(source.text()),
+            };
             nodes
-                .entry(ImportKey(import.source_text().ok()?))
+                .entry(ImportKey {
+                    text: source,
+                    category,
+                })
                 .or_default()
                 .push(ImportNode::from(import));
         }
@@ -310,7 +323,7 @@ impl From<JsImport> for ImportNode {
                 let trailing_separator = element.trailing_separator.ok()?;
                 separator_count += usize::from(trailing_separator.is_some());
 
-                result.insert(ImportKey(key), (node, trailing_separator));
+                result.insert(ImportKey::from(key), (node, trailing_separator));
             }
 
             Some(result)
@@ -543,18 +556,26 @@ fn leading_trivia_iter(
 }
 
 #[derive(Debug)]
-struct ImportKey(TokenText);
+struct ImportKey {
+    text: TokenText,
+    category: ImportCategory,
+}
+
+impl From<TokenText> for ImportKey {
+    fn from(text: TokenText) -> Self {
+        let category = ImportCategory::from(text.text());
+        Self { text, category }
+    }
+}
 
 impl Ord for ImportKey {
     fn cmp(&self, other: &Self) -> Ordering {
-        let own_category = ImportCategory::from(self.0.text());
-        let other_category = ImportCategory::from(other.0.text());
-        if own_category != other_category {
-            return own_category.cmp(&other_category);
+        if self.category != other.category {
+            return self.category.cmp(&other.category);
         }
 
         // Sort imports using natural ordering
-        natord::compare(&self.0, &other.0)
+        natord::compare(&self.text, &other.text)
     }
 }
 
@@ -568,7 +589,7 @@ impl Eq for ImportKey {}
 
 impl PartialEq for ImportKey {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+        self.text == other.text
     }
 }
 
@@ -576,7 +597,7 @@ impl PartialEq for ImportKey {
 ///
 /// The rationale for this is that imports "further away" from the source file
 /// are listed before imports closer to the source file.
-#[derive(Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
 enum ImportCategory {
     /// Anything with an explicit `bun:` prefix.
     Bun,
@@ -593,15 +614,15 @@ enum ImportCategory {
     /// Imports from an absolute URL such as supported by browsers.
     Url,
     /// Anything without explicit protocol specifier is assumed to be a library
-    /// import. Because we currently do not have configuration for this, this
-    /// may (incorrectly) include source imports through custom import mappings
-    /// as well.
+    /// import, unless it's a path alias of the `tsconfig.json`.
     Library,
     /// Absolute file imports `/<path>`.
     Absolute,
     /// Node allows specifying an import map with name prefixed with `#`.
     /// See https://nodejs.org/api/packages.html#subpath-imports
     SharpImport,
+    /// Path aliases declared by the `paths` of the `tsconfig.json`, such as `@app/<path>`.
+    Alias,
     /// Relative file imports `./<path>`.
     Relative,
 }
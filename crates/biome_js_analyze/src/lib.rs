@@ -9,8 +9,8 @@ use biome_analyze::{
 use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Diagnostic, Error as DiagnosticError};
 use biome_js_syntax::{JsFileSource, JsLanguage};
-use biome_project::PackageJson;
-use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
+use biome_project::{NodeJsWorkspace, PackageJson, TsConfigJson};
+use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic, SuppressionScope};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::{borrow::Cow, error::Error};
@@ -29,6 +29,11 @@ pub mod utils;
 
 pub use crate::registry::visit_registry;
 pub use crate::services::control_flow::ControlFlowGraph;
+pub use crate::services::manifest::MANIFEST_RULES;
+pub use crate::services::module_graph::{
+    ImportedSymbol, ModuleExport, ModuleExports, ModuleGraph, ModuleGraphServices, ModuleImport,
+    ModuleImportKind, ModuleImporter, ModuleInfo, Modules, MODULE_GRAPH_RULES,
+};
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
@@ -45,6 +50,19 @@ pub fn metadata() -> &'static MetadataRegistry {
     &METADATA
 }
 
+/// The services of the project of the analyzed file, that the rules can query
+#[derive(Clone, Default)]
+pub struct JsAnalyzerServices {
+    /// The `package.json` of the package of the file
+    pub manifest: Option<PackageJson>,
+    /// The packages of the workspace of the project
+    pub workspace: Option<Arc<NodeJsWorkspace>>,
+    /// The `tsconfig.json` that applies to the file
+    pub tsconfig: Option<Arc<TsConfigJson>>,
+    /// The graph of the modules of the project
+    pub module_graph: Option<Arc<ModuleGraph>>,
+}
+
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action.
@@ -57,7 +75,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
-    manifest: Option<PackageJson>,
+    project_services: JsAnalyzerServices,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -76,6 +94,14 @@ where
                     if comment.is_legacy {
                         result.push(Ok(SuppressionKind::Deprecated));
                     }
+                    match comment.scope {
+                        SuppressionScope::Next => {}
+                        SuppressionScope::RangeStart => {
+                            result.push(Ok(SuppressionKind::RangeStart));
+                        }
+                        SuppressionScope::RangeEnd => result.push(Ok(SuppressionKind::RangeEnd)),
+                        SuppressionScope::File => result.push(Ok(SuppressionKind::File)),
+                    }
                     comment.categories
                 }
                 Err(err) => {
@@ -127,9 +153,24 @@ where
 
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(AriaProperties));
+    let JsAnalyzerServices {
+        manifest,
+        workspace,
+        tsconfig,
+        module_graph,
+    } = project_services;
     if let Some(manifest) = manifest {
         services.insert_service(Arc::new(manifest));
     }
+    if let Some(workspace) = workspace {
+        services.insert_service(workspace);
+    }
+    if let Some(tsconfig) = tsconfig {
+        services.insert_service(tsconfig);
+    }
+    if let Some(module_graph) = module_graph {
+        services.insert_service(module_graph);
+    }
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -150,7 +191,7 @@ pub fn analyze<'a, F, B>(
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
-    manifest: Option<PackageJson>,
+    project_services: JsAnalyzerServices,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -163,7 +204,7 @@ where
         |_| {},
         options,
         source_type,
-        manifest,
+        project_services,
         emit_signal,
     )
 }
@@ -238,7 +279,7 @@ mod tests {
 
     use crate::lint::correctness::use_exhaustive_dependencies::{Hook, HooksOptions};
     use crate::react::hooks::StableHookResult;
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    use crate::{analyze, AnalysisFilter, ControlFlow, JsAnalyzerServices};
 
     // #[ignore]
     #[test]
@@ -283,7 +324,7 @@ mod tests {
             },
             &options,
             JsFileSource::tsx(),
-            None,
+            JsAnalyzerServices::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -375,7 +416,7 @@ mod tests {
             AnalysisFilter::default(),
             &options,
             JsFileSource::js_module(),
-            None,
+            JsAnalyzerServices::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -461,7 +502,7 @@ mod tests {
             filter,
             &options,
             JsFileSource::js_module(),
-            None,
+            JsAnalyzerServices::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
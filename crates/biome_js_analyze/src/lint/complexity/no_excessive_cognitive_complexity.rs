@@ -74,6 +74,7 @@ declare_rule! {
         name: "noExcessiveCognitiveComplexity",
         source: RuleSource::EslintSonarJs("cognitive-complexity"),
         recommended: false,
+        previous_names: &["nursery/noExcessiveComplexity"],
     }
 }
 
@@ -8,9 +8,12 @@ use biome_js_factory::make;
 use biome_js_semantic::ReferencesExtensions;
 use biome_js_syntax::{
     binding_ext::AnyJsBindingDeclaration, AnyJsCombinedSpecifier, AnyJsImportClause,
-    JsIdentifierBinding, JsImport, JsLanguage, JsNamedImportSpecifierList, JsSyntaxNode, T,
+    JsIdentifierBinding, JsImport, JsLanguage, JsNamedImportSpecifierList, JsSyntaxNode,
+    JsxTagExpression, T,
 };
+use biome_project::TsConfigJson;
 use biome_rowan::{AstNode, AstSeparatedList, BatchMutation, BatchMutationExt};
+use std::sync::Arc;
 
 declare_rule! {
     /// Disallow unused imports.
@@ -19,6 +22,9 @@ declare_rule! {
     /// The code fix can remove comments associated with an `import`.
     /// See the last invalid example.
     ///
+    /// When the `tsconfig.json` compiles JSX with the classic runtime (`"jsx": "react"`),
+    /// the import of `React` is used by the JSX elements of the module.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -82,6 +88,10 @@ impl Rule for NoUnusedImports {
             return None;
         }
 
+        if is_used_by_jsx(ctx, binding) {
+            return None;
+        }
+
         let model = ctx.model();
         binding.all_references(model).next().is_none().then_some(())
     }
@@ -212,6 +222,24 @@ fn remove_import_specifier(
     Some(())
 }
 
+/// Whether `binding` is the `React` binding that the JSX elements of the module are compiled to
+/// with the classic runtime
+fn is_used_by_jsx(ctx: &RuleContext<NoUnusedImports>, binding: &JsIdentifierBinding) -> bool {
+    let is_classic_runtime = ctx
+        .get_service::<Arc<TsConfigJson>>()
+        .and_then(|tsconfig| tsconfig.compiler_options.jsx)
+        .is_some_and(|jsx| jsx.is_classic_runtime());
+    is_classic_runtime
+        && binding
+            .name_token()
+            .is_ok_and(|name| name.text_trimmed() == "React")
+        && ctx
+            .root()
+            .syntax()
+            .descendants()
+            .any(|node| JsxTagExpression::can_cast(node.kind()))
+}
+
 const fn is_import(declaration: &AnyJsBindingDeclaration) -> bool {
     matches!(
         declaration,
@@ -1,11 +1,11 @@
 use crate::react::hooks::{is_react_component, is_react_hook, is_react_hook_call};
 use crate::services::semantic::{SemanticModelBuilderVisitor, SemanticServices};
-use biome_analyze::RuleSource;
 use biome_analyze::{
     context::RuleContext, declare_rule, AddVisitor, FromServices, MissingServicesDiagnostic, Phase,
     Phases, QueryMatch, Queryable, Rule, RuleDiagnostic, RuleKey, ServiceBag, Visitor,
     VisitorContext, VisitorFinishContext,
 };
+use biome_analyze::{RuleOptionMigration, RuleSource};
 use biome_console::markup;
 use biome_deserialize::{
     Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
@@ -67,6 +67,7 @@ declare_rule! {
         name: "useHookAtTopLevel",
         source: RuleSource::EslintReactHooks("rules-of-hooks"),
         recommended: false,
+        option_migrations: &[RuleOptionMigration::Remove("hooks")],
     }
 }
 
@@ -11,6 +11,7 @@ pub mod no_evolving_any;
 pub mod no_excessive_nested_test_suites;
 pub mod no_exports_in_test;
 pub mod no_focused_tests;
+pub mod no_import_cycles;
 pub mod no_misplaced_assertion;
 pub mod no_namespace_import;
 pub mod no_nodejs_modules;
@@ -19,6 +20,11 @@ pub mod no_restricted_imports;
 pub mod no_skipped_tests;
 pub mod no_suspicious_semicolon_in_jsx;
 pub mod no_undeclared_dependencies;
+pub mod no_unresolved_default_imports;
+pub mod no_unresolved_imports;
+pub mod no_unresolved_named_imports;
+pub mod no_unused_exports;
+pub mod no_unused_files;
 pub mod no_useless_ternary;
 pub mod use_import_restrictions;
 pub mod use_jsx_key_in_iterable;
@@ -38,6 +44,7 @@ declare_group! {
             self :: no_excessive_nested_test_suites :: NoExcessiveNestedTestSuites ,
             self :: no_exports_in_test :: NoExportsInTest ,
             self :: no_focused_tests :: NoFocusedTests ,
+            self :: no_import_cycles :: NoImportCycles ,
             self :: no_misplaced_assertion :: NoMisplacedAssertion ,
             self :: no_namespace_import :: NoNamespaceImport ,
             self :: no_nodejs_modules :: NoNodejsModules ,
@@ -46,6 +53,11 @@ declare_group! {
             self :: no_skipped_tests :: NoSkippedTests ,
             self :: no_suspicious_semicolon_in_jsx :: NoSuspiciousSemicolonInJsx ,
             self :: no_undeclared_dependencies :: NoUndeclaredDependencies ,
+            self :: no_unresolved_default_imports :: NoUnresolvedDefaultImports ,
+            self :: no_unresolved_imports :: NoUnresolvedImports ,
+            self :: no_unresolved_named_imports :: NoUnresolvedNamedImports ,
+            self :: no_unused_exports :: NoUnusedExports ,
+            self :: no_unused_files :: NoUnusedFiles ,
             self :: no_useless_ternary :: NoUselessTernary ,
             self :: use_import_restrictions :: UseImportRestrictions ,
             self :: use_jsx_key_in_iterable :: UseJsxKeyInIterable ,
@@ -0,0 +1,259 @@
+use crate::services::module_graph::{
+    normalize_path, relative_to_importer, ModuleGraph, ModuleImport, ModuleImportKind, Modules,
+};
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::TextRange;
+use rustc_hash::{FxHashMap, FxHashSet};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::num::NonZeroU16;
+use std::path::{Path, PathBuf};
+
+declare_rule! {
+    /// Disallow imports that create a cycle of modules.
+    ///
+    /// A cycle of imports makes the order in which the modules are evaluated depend on the module that is imported first.
+    /// A module of the cycle can then read the exports of another module before they are initialized,
+    /// and bundlers can split the modules of the cycle into chunks that fail to load.
+    ///
+    /// The rule reports each import of a module that leads back to the importing module,
+    /// and lists the modules of the shortest cycle it belongs to.
+    /// Only the modules of the project are followed: the dependencies installed in `node_modules` are ignored.
+    /// Dynamic imports, `import("./a.js")`, are ignored because they don't take part in the evaluation of the modules.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// // a.js
+    /// import { b } from "./b.js";
+    /// export const a = 0;
+    ///
+    /// // b.js
+    /// import { a } from "./a.js";
+    /// export const b = a + 1;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// // a.js
+    /// import { b } from "./b.js";
+    /// export const a = 0;
+    ///
+    /// // b.js
+    /// export const b = 1;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "maxDepth": 5,
+    ///         "ignoreTypes": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### maxDepth
+    ///
+    /// The maximum number of modules of a reported cycle.
+    /// The longer cycles aren't reported, which also limits the number of modules the rule visits.
+    /// By default, the cycles of any length are reported.
+    ///
+    /// ### ignoreTypes
+    ///
+    /// Whether the type-only imports, like `import type { A } from "./a.js"`, are ignored.
+    /// They are removed by the compilers, so they can't cause issues at runtime.
+    /// The default is `true`.
+    pub NoImportCycles {
+        version: "next",
+        name: "noImportCycles",
+        source: RuleSource::EslintImport("no-cycle"),
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noImportCycles`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ImportCyclesOptions {
+    /// The maximum number of modules of a reported cycle. All the cycles are reported when it's
+    /// not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<NonZeroU16>,
+
+    /// Whether the type-only imports are ignored.
+    #[serde(default = "enabled", skip_serializing_if = "is_enabled")]
+    pub ignore_types: bool,
+}
+
+impl Default for ImportCyclesOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: None,
+            ignore_types: true,
+        }
+    }
+}
+
+const fn enabled() -> bool {
+    true
+}
+
+const fn is_enabled(value: &bool) -> bool {
+    *value
+}
+
+impl ImportCyclesOptions {
+    /// Whether `import` is followed to find the cycles
+    fn follows(&self, import: &ModuleImport) -> bool {
+        import.kind != ModuleImportKind::Dynamic && !(self.ignore_types && import.is_type_only)
+    }
+}
+
+pub struct ImportCycle {
+    /// The range of the specifier of the import that starts the cycle
+    range: TextRange,
+    /// The modules of the cycle, starting and ending with the importing module
+    modules: Vec<PathBuf>,
+}
+
+impl Rule for NoImportCycles {
+    type Query = Modules<AnyJsRoot>;
+    type State = ImportCycle;
+    type Signals = Vec<Self::State>;
+    type Options = ImportCyclesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let module_graph = ctx.module_graph();
+        let options = ctx.options();
+        let importer = normalize_path(ctx.file_path());
+        let Some(module) = module_graph.module(&importer) else {
+            return Vec::new();
+        };
+        let components = module_graph.components(!options.ignore_types);
+        let Some(&component) = components.get(&importer) else {
+            return Vec::new();
+        };
+        module
+            .imports
+            .iter()
+            .filter(|import| options.follows(import))
+            .filter_map(|import| {
+                let resolved = module_graph.resolve(&importer, &import.specifier)?;
+                // Only the imports of the modules of the same component lead back to the importer
+                if components.get(&resolved) != Some(&component) {
+                    return None;
+                }
+                let modules = find_cycle(module_graph, &importer, resolved, options, |path| {
+                    components.get(path) == Some(&component)
+                })?;
+                Some(ImportCycle {
+                    range: import.range,
+                    modules,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let count = state.modules.len() - 1;
+        if count == 1 {
+            return Some(RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This module imports itself."
+                },
+            ));
+        }
+        let modules: Vec<_> = state
+            .modules
+            .iter()
+            .map(|path| {
+                relative_to_importer(ctx.file_path(), path)
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This import creates a cycle of "{count}" modules."
+                },
+            )
+            .footer_list(markup! { "The modules of the cycle are imported in this order:" }, &modules)
+            .note(markup! {
+                "Move the code that the modules share to a module that doesn't import them, or import it dynamically."
+            }),
+        )
+    }
+}
+
+/// Returns the shortest chain of imports from `start` back to `importer`, as the list of the
+/// modules of the cycle starting and ending with `importer`. Only the modules for which
+/// `is_in_component` returns `true` are visited.
+fn find_cycle(
+    module_graph: &ModuleGraph,
+    importer: &Path,
+    start: PathBuf,
+    options: &ImportCyclesOptions,
+    is_in_component: impl Fn(&Path) -> bool,
+) -> Option<Vec<PathBuf>> {
+    let max_depth = options
+        .max_depth
+        .map(|max_depth| usize::from(max_depth.get()));
+    // The module that imports each visited module in the shortest chain
+    let mut parents: FxHashMap<PathBuf, PathBuf> = FxHashMap::default();
+    let mut visited = FxHashSet::default();
+    // The modules to visit, with the number of imports that lead to them
+    let mut queue = VecDeque::new();
+    visited.insert(start.clone());
+    queue.push_back((start, 1));
+
+    while let Some((path, depth)) = queue.pop_front() {
+        if path == importer {
+            let mut modules = vec![path.clone()];
+            let mut current = &path;
+            while let Some(parent) = parents.get(current) {
+                modules.push(parent.clone());
+                current = parent;
+            }
+            modules.push(importer.to_path_buf());
+            modules.reverse();
+            return Some(modules);
+        }
+        if max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            continue;
+        }
+        let Some(module) = module_graph.module(&path) else {
+            continue;
+        };
+        for import in module
+            .imports
+            .iter()
+            .filter(|import| options.follows(import))
+        {
+            let Some(resolved) = module_graph.resolve(&path, &import.specifier) else {
+                continue;
+            };
+            if is_in_component(&resolved) && visited.insert(resolved.clone()) {
+                parents.insert(resolved.clone(), path.clone());
+                queue.push_back((resolved, depth + 1));
+            }
+        }
+    }
+    None
+}
@@ -1,15 +1,23 @@
+use crate::services::module_graph::normalize_path;
 use biome_analyze::context::RuleContext;
 use biome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
 use biome_console::markup;
 use biome_deserialize_macros::Deserializable;
 use biome_js_syntax::{inner_string_text, AnyJsImportSpecifierLike};
+use biome_project::TsConfigJson;
 use biome_rowan::TextRange;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 declare_rule! {
     /// Disallow specified modules when loaded by import or require.
     ///
+    /// When a restricted path is a path alias declared by the `paths` of the `tsconfig.json`,
+    /// the other specifiers that resolve to the same module are restricted too:
+    /// the relative paths and the other path aliases.
+    ///
     /// ## Options
     ///
     /// ```json
@@ -51,11 +59,25 @@ impl Rule for NoRestrictedImports {
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let module_name = ctx.query().module_name_token()?;
         let inner_text = inner_string_text(&module_name);
+        let paths = &ctx.options().paths;
 
-        ctx.options()
-            .paths
-            .get(inner_text.text())
-            .map(|message| (module_name.text_trimmed_range(), message.to_string()))
+        let message = paths.get(inner_text.text()).or_else(|| {
+            let tsconfig = ctx.get_service::<Arc<TsConfigJson>>()?;
+            let targets = resolve_targets(tsconfig, ctx.file_path(), inner_text.text());
+            if targets.is_empty() {
+                return None;
+            }
+            paths
+                .iter()
+                .find(|(path, _)| {
+                    tsconfig.is_alias(path)
+                        && resolve_targets(tsconfig, ctx.file_path(), path)
+                            .iter()
+                            .any(|target| targets.contains(target))
+                })
+                .map(|(_, message)| message)
+        })?;
+        Some((module_name.text_trimmed_range(), message.to_string()))
     }
 
     fn diagnostic(_ctx: &RuleContext<Self>, (span, text): &Self::State) -> Option<RuleDiagnostic> {
@@ -68,3 +90,19 @@ impl Rule for NoRestrictedImports {
         ))
     }
 }
+
+/// Returns the modules, without their extension, that `specifier` refers to when it's a path
+/// alias of `tsconfig` or a relative path
+fn resolve_targets(tsconfig: &TsConfigJson, importer: &Path, specifier: &str) -> Vec<PathBuf> {
+    let targets = if tsconfig.is_alias(specifier) {
+        tsconfig.resolve(specifier)
+    } else if specifier.starts_with('.') {
+        vec![importer.parent().unwrap_or(Path::new("")).join(specifier)]
+    } else {
+        Vec::new()
+    };
+    targets
+        .iter()
+        .map(|target| normalize_path(target).with_extension(""))
+        .collect()
+}
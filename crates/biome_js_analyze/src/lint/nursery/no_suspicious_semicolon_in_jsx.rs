@@ -46,6 +46,7 @@ declare_rule! {
         version: "next",
         name: "noSuspiciousSemicolonInJsx",
         recommended: true,
+        previous_names: &["nursery/noSemicolonInJsx"],
     }
 }
 
@@ -2,7 +2,9 @@ use crate::services::manifest::Manifest;
 use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
 use biome_console::markup;
 use biome_js_syntax::{AnyJsImportSpecifierLike, TsExternalModuleDeclaration};
+use biome_project::{NodeJsWorkspace, TsConfigJson};
 use biome_rowan::{AstNode, SyntaxNodeOptionExt};
+use std::sync::Arc;
 
 declare_rule! {
     /// Disallow the use of dependencies that aren't specified in the `package.json`.
@@ -10,7 +12,13 @@ declare_rule! {
     /// Indirect dependencies will trigger the rule because they aren't declared in the `package.json`. This means that if package `@org/foo` has a dependency on `lodash`, and then you use
     /// `import "lodash"` somewhere in your project, the rule will trigger a diagnostic for this import.
     ///
-    /// The rule ignores imports using a protocol such as `node:`, `bun:`, `jsr:`, `https:`.
+    /// The rule ignores imports using a protocol such as `node:`, `bun:`, `jsr:`, `https:`,
+    /// and the path aliases declared in the option `paths` of the `tsconfig.json`.
+    ///
+    /// In a monorepo, the dependencies of a file are the ones of the `package.json` of its package,
+    /// the nearest one among its parent directories.
+    /// The packages of the monorepo, declared with the field `workspaces` of the root `package.json`
+    /// or with the `pnpm-workspace.yaml`, can be imported without being declared.
     ///
     /// ## Examples
     ///
@@ -62,6 +70,14 @@ impl Rule for NoUndeclaredDependencies {
             return None;
         }
 
+        // Ignore the path aliases, like `@app/utils`, that refer to the files of the project
+        if ctx
+            .get_service::<Arc<TsConfigJson>>()
+            .is_some_and(|tsconfig| tsconfig.is_alias(text))
+        {
+            return None;
+        }
+
         let mut parts = text.split('/');
         let mut pointer = 0;
         if let Some(maybe_scope) = parts.next() {
@@ -74,6 +90,13 @@ impl Rule for NoUndeclaredDependencies {
         }
         let package_name = &text[..pointer];
 
+        if ctx
+            .get_service::<Arc<NodeJsWorkspace>>()
+            .is_some_and(|workspace| workspace.is_workspace_package(package_name))
+        {
+            return None;
+        }
+
         if ctx.is_dependency(package_name)
             || ctx.is_dev_dependency(package_name)
             || ctx.is_peer_dependency(package_name)
@@ -0,0 +1,107 @@
+use crate::services::module_graph::{ImportedSymbol, ModuleImportKind, ModuleInfo, Modules};
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::TextRange;
+
+declare_rule! {
+    /// Disallow default imports of modules that don't have a default export.
+    ///
+    /// The rule ignores the imports of the dependencies installed in `node_modules`,
+    /// the imports that can't be resolved, which are reported by [noUnresolvedImports](https://biomejs.dev/linter/rules/no-unresolved-imports/),
+    /// and the CommonJS modules, whose default export is always `module.exports`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// // a.js
+    /// export const a = 0;
+    ///
+    /// // b.js
+    /// import a from "./a.js";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// // a.js
+    /// export default function a() {}
+    ///
+    /// // b.js
+    /// import a from "./a.js";
+    /// ```
+    pub NoUnresolvedDefaultImports {
+        version: "next",
+        name: "noUnresolvedDefaultImports",
+        source: RuleSource::EslintImport("default"),
+        recommended: false,
+    }
+}
+
+pub struct UnresolvedDefaultImport {
+    /// The range of the default import
+    range: TextRange,
+    specifier: String,
+}
+
+impl Rule for NoUnresolvedDefaultImports {
+    type Query = Modules<AnyJsRoot>;
+    type State = UnresolvedDefaultImport;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let module_graph = ctx.module_graph();
+        let importer = ctx.file_path();
+        let mut signals = Vec::new();
+        let imports = ModuleInfo::from_root(ctx.query())
+            .imports
+            .into_iter()
+            .filter(|import| {
+                matches!(
+                    import.kind,
+                    ModuleImportKind::Static | ModuleImportKind::ReExport
+                )
+            });
+        for import in imports {
+            let Some(module) = module_graph
+                .resolve(importer, &import.specifier)
+                .and_then(|resolved| module_graph.module(&resolved))
+            else {
+                continue;
+            };
+            if module.is_commonjs || module.has_dynamic_exports || module.has_default_export() {
+                continue;
+            }
+            for symbol in &import.symbols {
+                if let ImportedSymbol::Named { name, range } = symbol {
+                    if name == "default" {
+                        signals.push(UnresolvedDefaultImport {
+                            range: *range,
+                            specifier: import.specifier.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let specifier = state.specifier.as_str();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The module "<Emphasis>{specifier}</Emphasis>" doesn't have a default export."
+                },
+            )
+            .note(markup! {
+                "Import a named export of the module instead, or add a default export to the module."
+            }),
+        )
+    }
+}
@@ -0,0 +1,120 @@
+use crate::services::module_graph::{relative_to_importer, ModuleInfo, Modules};
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::TextRange;
+use std::path::PathBuf;
+
+declare_rule! {
+    /// Disallow imports of files of the project that don't exist.
+    ///
+    /// The rule resolves the relative and the absolute specifiers, and the specifiers that start with
+    /// the name of a package of the project, to the files of the project.
+    /// The specifiers of the dependencies installed in `node_modules` are ignored.
+    ///
+    /// A specifier is resolved like Node.js and TypeScript resolve it:
+    /// the extension and the index file of a directory can be omitted,
+    /// a TypeScript file can be imported with the `.js` extension,
+    /// and the entry points of a package are read from the `exports` and the `main` fields of its `package.json`.
+    ///
+    /// The rule also reports the specifiers that resolve to a file only when the case of its name is ignored,
+    /// because they fail to resolve on the case-sensitive file systems.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// import { a } from "./missing.js";
+    /// ```
+    ///
+    /// ```js,ignore
+    /// // The file is named `utils.js`
+    /// import { b } from "./Utils.js";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// import { a } from "./a.js";
+    /// ```
+    ///
+    /// ```js,ignore
+    /// import { useState } from "react";
+    /// ```
+    pub NoUnresolvedImports {
+        version: "next",
+        name: "noUnresolvedImports",
+        source: RuleSource::EslintImport("no-unresolved"),
+        recommended: false,
+    }
+}
+
+pub struct UnresolvedImport {
+    /// The range of the specifier
+    range: TextRange,
+    specifier: String,
+    /// The file that the specifier refers to when the case is ignored, relative to the directory
+    /// of the importing file when it's inside it
+    case_insensitive_match: Option<PathBuf>,
+}
+
+impl Rule for NoUnresolvedImports {
+    type Query = Modules<AnyJsRoot>;
+    type State = UnresolvedImport;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let module_graph = ctx.module_graph();
+        let importer = ctx.file_path();
+        ModuleInfo::from_root(ctx.query())
+            .imports
+            .into_iter()
+            .filter(|import| {
+                module_graph.is_project_specifier(importer, &import.specifier)
+                    && module_graph.resolve(importer, &import.specifier).is_none()
+            })
+            .map(|import| {
+                let case_insensitive_match = module_graph
+                    .resolve_ignoring_case(importer, &import.specifier)
+                    .map(|path| relative_to_importer(importer, &path));
+                UnresolvedImport {
+                    range: import.range,
+                    specifier: import.specifier,
+                    case_insensitive_match,
+                }
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let specifier = state.specifier.as_str();
+        let diagnostic = match &state.case_insensitive_match {
+            Some(path) => {
+                let path = path.to_string_lossy();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "The case of the specifier "<Emphasis>{specifier}</Emphasis>" doesn't match the case of the file it refers to."
+                    },
+                )
+                .note(markup! {
+                    "The file is "<Emphasis>{path.as_ref()}</Emphasis>". The import fails on the case-sensitive file systems."
+                })
+            }
+            None => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The specifier "<Emphasis>{specifier}</Emphasis>" can't be resolved to a file of the project."
+                },
+            )
+            .note(markup! {
+                "Check that the file exists, or fix the path of the specifier."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::services::module_graph::{ImportedSymbol, ModuleImportKind, ModuleInfo, Modules};
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::TextRange;
+
+declare_rule! {
+    /// Disallow named imports that aren't exported by the imported module.
+    ///
+    /// The rule checks the named imports and the named re-exports of the modules of the project.
+    /// The symbols that a module re-exports with `export * from` are followed to the re-exported modules.
+    ///
+    /// The rule ignores the imports of the dependencies installed in `node_modules`,
+    /// the imports that can't be resolved, which are reported by [noUnresolvedImports](https://biomejs.dev/linter/rules/no-unresolved-imports/),
+    /// and the modules whose exports can't be fully known, like a module that assigns the result of a call to `module.exports`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// // a.js
+    /// export const a = 0;
+    ///
+    /// // b.js
+    /// import { b } from "./a.js";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// // a.js
+    /// export const a = 0;
+    ///
+    /// // b.js
+    /// import { a } from "./a.js";
+    /// ```
+    pub NoUnresolvedNamedImports {
+        version: "next",
+        name: "noUnresolvedNamedImports",
+        source: RuleSource::EslintImport("named"),
+        recommended: false,
+    }
+}
+
+pub struct UnresolvedNamedImport {
+    /// The range of the imported name
+    range: TextRange,
+    name: String,
+    specifier: String,
+}
+
+impl Rule for NoUnresolvedNamedImports {
+    type Query = Modules<AnyJsRoot>;
+    type State = UnresolvedNamedImport;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let module_graph = ctx.module_graph();
+        let importer = ctx.file_path();
+        let mut signals = Vec::new();
+        let imports = ModuleInfo::from_root(ctx.query())
+            .imports
+            .into_iter()
+            .filter(|import| {
+                matches!(
+                    import.kind,
+                    ModuleImportKind::Static | ModuleImportKind::ReExport
+                )
+            });
+        for import in imports {
+            let Some(exports) = module_graph
+                .resolve(importer, &import.specifier)
+                .and_then(|resolved| module_graph.exports(&resolved))
+            else {
+                continue;
+            };
+            if !exports.is_complete {
+                continue;
+            }
+            for symbol in import.symbols {
+                let ImportedSymbol::Named { name, range } = symbol else {
+                    continue;
+                };
+                // The default imports are checked by `noUnresolvedDefaultImports`
+                if name != "default" && !exports.names.contains(&name) {
+                    signals.push(UnresolvedNamedImport {
+                        range,
+                        name,
+                        specifier: import.specifier.clone(),
+                    });
+                }
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.as_str();
+        let specifier = state.specifier.as_str();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The module "<Emphasis>{specifier}</Emphasis>" doesn't export "<Emphasis>{name}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Check the name of the import, or export it from the module."
+            }),
+        )
+    }
+}
@@ -0,0 +1,130 @@
+use crate::services::module_graph::{normalize_path, ModuleExport, ModuleInfo, Modules};
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::AnyJsRoot;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow exports that aren't imported by any module of the project.
+    ///
+    /// An export that no module imports is dead code, or a symbol that should be private to its module.
+    ///
+    /// The exports of the entry points of the project are its public API, so they are never reported.
+    /// By default, the entry points are the files declared by the fields `exports`, `main` and `bin`
+    /// of the `package.json` files of the project.
+    /// The symbols that an entry point re-exports with `export * from` are used too.
+    ///
+    /// An export is used by a dynamic import or a `require` call of its module,
+    /// because the rule can't know which symbols they read.
+    ///
+    /// The rule doesn't report anything when the project has no entry points.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// // utils.js, imported with `import { sum } from "./utils.js"`
+    /// export function sum(a, b) {
+    ///     return a + b;
+    /// }
+    ///
+    /// export function max(a, b) {
+    ///     return a > b ? a : b;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// // utils.js, imported with `import { sum } from "./utils.js"`
+    /// export function sum(a, b) {
+    ///     return a + b;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "entryPoints": ["./src/index.ts", "./src/cli.ts"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### entryPoints
+    ///
+    /// The entry points of the project, which replace the ones declared by the `package.json` files.
+    /// The paths are relative to the directory of each `package.json` of the project,
+    /// and they can omit the extension and the `index` file like an import specifier.
+    pub NoUnusedExports {
+        version: "next",
+        name: "noUnusedExports",
+        recommended: false,
+    }
+}
+
+/// Options for the rules `noUnusedExports` and `noUnusedFiles`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntryPointsOptions {
+    /// The entry points of the project, relative to the directory of each `package.json`.
+    /// The fields `exports`, `main` and `bin` of the `package.json` files are used when it's
+    /// empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entry_points: Vec<String>,
+}
+
+impl Rule for NoUnusedExports {
+    type Query = Modules<AnyJsRoot>;
+    type State = ModuleExport;
+    type Signals = Vec<Self::State>;
+    type Options = EntryPointsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let module_graph = ctx.module_graph();
+        let path = normalize_path(ctx.file_path());
+        if !module_graph.contains(&path) {
+            return Vec::new();
+        }
+        let entry_points = module_graph.entry_points(&ctx.options().entry_points);
+        if entry_points.is_empty() || entry_points.contains(&path) {
+            return Vec::new();
+        }
+        ModuleInfo::from_root(ctx.query())
+            .exports
+            .into_iter()
+            .filter(|export| !module_graph.is_export_used(&path, &export.name, &entry_points))
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, export: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = if export.name == "default" {
+            RuleDiagnostic::new(
+                rule_category!(),
+                export.range,
+                markup! {
+                    "The default export isn't imported by any module of the project."
+                },
+            )
+        } else {
+            let name = export.name.as_str();
+            RuleDiagnostic::new(
+                rule_category!(),
+                export.range,
+                markup! {
+                    "The export "<Emphasis>{name}</Emphasis>" isn't imported by any module of the project."
+                },
+            )
+        };
+        Some(diagnostic.note(markup! {
+            "Remove the export, or the exported code if the module doesn't use it either."
+        }))
+    }
+}
@@ -0,0 +1,84 @@
+use crate::lint::nursery::no_unused_exports::EntryPointsOptions;
+use crate::services::module_graph::{normalize_path, Modules};
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::TextRange;
+
+declare_rule! {
+    /// Disallow modules that can't be reached from the entry points of the project.
+    ///
+    /// A module is reachable when an entry point imports it, directly or through other modules,
+    /// with any kind of import: a static import, a re-export, a dynamic import or a `require` call.
+    /// A module that can't be reached is never executed, so it can be removed.
+    ///
+    /// By default, the entry points are the files declared by the fields `exports`, `main` and `bin`
+    /// of the `package.json` files of the project.
+    /// The files that are run directly, like the tests and the configuration files of the tools,
+    /// can be listed in the option `entryPoints`, or the rule can be disabled for them with `overrides`.
+    ///
+    /// The rule doesn't report anything when the project has no entry points.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// // legacy.js, which isn't imported by any module
+    /// export function parse() {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "entryPoints": ["./src/index.ts", "./scripts/build.js"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### entryPoints
+    ///
+    /// The entry points of the project, which replace the ones declared by the `package.json` files.
+    /// The paths are relative to the directory of each `package.json` of the project,
+    /// and they can omit the extension and the `index` file like an import specifier.
+    pub NoUnusedFiles {
+        version: "next",
+        name: "noUnusedFiles",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnusedFiles {
+    type Query = Modules<AnyJsRoot>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = EntryPointsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let module_graph = ctx.module_graph();
+        let path = normalize_path(ctx.file_path());
+        if !module_graph.contains(&path) {
+            return None;
+        }
+        let reachable = module_graph.reachable(&ctx.options().entry_points);
+        (!reachable.is_empty() && !reachable.contains(&path)).then_some(())
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                None as Option<TextRange>,
+                markup! {
+                    "This module can't be reached from the entry points of the project."
+                },
+            )
+            .note(markup! {
+                "Remove the module if it isn't used, or add it to the entry points with the option "<Emphasis>"entryPoints"</Emphasis>"."
+            }),
+        )
+    }
+}
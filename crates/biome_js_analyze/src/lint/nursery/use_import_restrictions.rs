@@ -3,7 +3,10 @@ use biome_analyze::{
 };
 use biome_console::markup;
 use biome_js_syntax::JsModuleSource;
-use biome_rowan::{AstNode, TokenText};
+use biome_project::TsConfigJson;
+use biome_rowan::AstNode;
+use std::path::{Component, Path};
+use std::sync::Arc;
 
 const INDEX_BASENAMES: &[&str] = &["index", "mod"];
 
@@ -25,6 +28,8 @@ declare_rule! {
     /// Notes:
     ///
     /// * This rule only applies to relative imports. External dependencies are exempted.
+    /// * The path aliases declared by the `paths` of the `tsconfig.json` are checked like the
+    ///   relative import of the module they resolve to.
     /// * This rule only applies to imports for JavaScript and TypeScript files. Imports for
     ///   resources such as images or CSS files are exempted.
     ///
@@ -93,7 +98,15 @@ impl Rule for UseImportRestrictions {
             return None;
         };
 
-        get_restricted_import(&path)
+        if let Some(tsconfig) = ctx.get_service::<Arc<TsConfigJson>>() {
+            if tsconfig.is_alias(path.text()) {
+                let target = tsconfig.resolve(path.text()).into_iter().next()?;
+                let directory = ctx.file_path().parent()?;
+                return get_restricted_import(&relative_path(directory, &target)?);
+            }
+        }
+
+        get_restricted_import(path.text())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
@@ -123,12 +136,12 @@ pub struct ImportRestrictionsState {
     suggestion: String,
 }
 
-fn get_restricted_import(module_path: &TokenText) -> Option<ImportRestrictionsState> {
+fn get_restricted_import(module_path: &str) -> Option<ImportRestrictionsState> {
     if !module_path.starts_with('.') {
         return None;
     }
 
-    let mut path_parts: Vec<_> = module_path.text().split('/').collect();
+    let mut path_parts: Vec<_> = module_path.split('/').collect();
     let mut index_filename = None;
 
     if let Some(extension) = get_extension(&path_parts) {
@@ -189,3 +202,28 @@ fn get_extension<'a>(path_parts: &'_ [&'a str]) -> Option<&'a str> {
         _ => None,
     })
 }
+
+/// Returns the relative import specifier of `target` from a module of `directory`
+fn relative_path(directory: &Path, target: &Path) -> Option<String> {
+    let directory: Vec<_> = directory
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect();
+    let target: Vec<_> = target
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect();
+    let common = directory
+        .iter()
+        .zip(&target)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut parts: Vec<&str> = vec![".."; directory.len() - common];
+    if parts.is_empty() {
+        parts.push(".");
+    }
+    for component in &target[common..] {
+        parts.push(component.as_os_str().to_str()?);
+    }
+    Some(parts.join("/"))
+}
@@ -111,6 +111,8 @@ pub type NoImplicitBoolean =
     <lint::style::no_implicit_boolean::NoImplicitBoolean as biome_analyze::Rule>::Options;
 pub type NoImportAssign =
     <lint::suspicious::no_import_assign::NoImportAssign as biome_analyze::Rule>::Options;
+pub type NoImportCycles =
+    <lint::nursery::no_import_cycles::NoImportCycles as biome_analyze::Rule>::Options;
 pub type NoInferrableTypes =
     <lint::style::no_inferrable_types::NoInferrableTypes as biome_analyze::Rule>::Options;
 pub type NoInnerDeclarations =
@@ -195,12 +197,20 @@ pub type NoUnreachable =
     <lint::correctness::no_unreachable::NoUnreachable as biome_analyze::Rule>::Options;
 pub type NoUnreachableSuper =
     <lint::correctness::no_unreachable_super::NoUnreachableSuper as biome_analyze::Rule>::Options;
+pub type NoUnresolvedDefaultImports = < lint :: nursery :: no_unresolved_default_imports :: NoUnresolvedDefaultImports as biome_analyze :: Rule > :: Options ;
+pub type NoUnresolvedImports =
+    <lint::nursery::no_unresolved_imports::NoUnresolvedImports as biome_analyze::Rule>::Options;
+pub type NoUnresolvedNamedImports = < lint :: nursery :: no_unresolved_named_imports :: NoUnresolvedNamedImports as biome_analyze :: Rule > :: Options ;
 pub type NoUnsafeDeclarationMerging = < lint :: suspicious :: no_unsafe_declaration_merging :: NoUnsafeDeclarationMerging as biome_analyze :: Rule > :: Options ;
 pub type NoUnsafeFinally =
     <lint::correctness::no_unsafe_finally::NoUnsafeFinally as biome_analyze::Rule>::Options;
 pub type NoUnsafeNegation =
     <lint::suspicious::no_unsafe_negation::NoUnsafeNegation as biome_analyze::Rule>::Options;
 pub type NoUnsafeOptionalChaining = < lint :: correctness :: no_unsafe_optional_chaining :: NoUnsafeOptionalChaining as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedExports =
+    <lint::nursery::no_unused_exports::NoUnusedExports as biome_analyze::Rule>::Options;
+pub type NoUnusedFiles =
+    <lint::nursery::no_unused_files::NoUnusedFiles as biome_analyze::Rule>::Options;
 pub type NoUnusedImports =
     <lint::correctness::no_unused_imports::NoUnusedImports as biome_analyze::Rule>::Options;
 pub type NoUnusedLabels =
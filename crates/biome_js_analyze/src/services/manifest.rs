@@ -1,12 +1,17 @@
 use biome_analyze::{
     AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
-    RuleKey, ServiceBag, SyntaxVisitor,
+    RuleFilter, RuleKey, ServiceBag, SyntaxVisitor,
 };
 use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode};
 use biome_project::PackageJson;
 use biome_rowan::AstNode;
 use std::sync::Arc;
 
+/// The rules that query the `package.json` of the package of the file, and the packages of the
+/// monorepo
+pub const MANIFEST_RULES: &[RuleFilter<'static>] =
+    &[RuleFilter::Rule("nursery", "noUndeclaredDependencies")];
+
 #[derive(Debug, Clone)]
 pub struct ManifestServices {
     pub(crate) manifest: Arc<PackageJson>,
@@ -3,3 +3,4 @@ pub mod control_flow;
 pub mod semantic;
 
 pub mod manifest;
+pub mod module_graph;
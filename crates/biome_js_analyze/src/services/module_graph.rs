@@ -0,0 +1,1609 @@
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleFilter, RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_js_syntax::{
+    AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern, AnyJsCombinedSpecifier,
+    AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportDefaultDeclaration,
+    AnyJsExportNamedSpecifier, AnyJsExpression, AnyJsImportClause, AnyJsImportSpecifierLike,
+    AnyJsNamedImportSpecifier, AnyJsObjectMember, AnyJsRoot, AnyTsModuleName,
+    JsAssignmentExpression, JsCallExpression, JsExport, JsIdentifierBinding, JsImport,
+    JsImportCallExpression, JsLanguage, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarationClause,
+    TextRange,
+};
+use biome_project::{PackageJson, TsConfigJson};
+use biome_rowan::{AstNode, AstSeparatedList};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// The rules that query the [ModuleGraph].
+///
+/// The workspace only builds the graph of the project when one of them is enabled.
+pub const MODULE_GRAPH_RULES: &[RuleFilter<'static>] = &[
+    RuleFilter::Rule("nursery", "noImportCycles"),
+    RuleFilter::Rule("nursery", "noUnresolvedDefaultImports"),
+    RuleFilter::Rule("nursery", "noUnresolvedImports"),
+    RuleFilter::Rule("nursery", "noUnresolvedNamedImports"),
+    RuleFilter::Rule("nursery", "noUnusedExports"),
+    RuleFilter::Rule("nursery", "noUnusedFiles"),
+];
+
+/// The extensions probed when a specifier doesn't point to an existing file
+const EXTENSIONS: &[&str] = &[
+    "js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx", "d.ts", "json",
+];
+
+/// The conditions of the `exports` of a `package.json` that are used to resolve its entry points
+const CONDITIONS: &[&str] = &["import", "require", "node", "default"];
+
+/// How a module is imported
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ModuleImportKind {
+    /// `import { a } from "./a.js"`
+    Static,
+    /// `export { a } from "./a.js"` and `export * as ns from "./a.js"`
+    ReExport,
+    /// `export * from "./a.js"`, which re-exports the named exports of the other module
+    ReExportAll,
+    /// `import("./a.js")`
+    Dynamic,
+    /// `require("./a.js")`
+    Require,
+}
+
+/// A symbol imported from a module
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ImportedSymbol {
+    /// A named import, where the default import is named `default`
+    Named {
+        name: String,
+        /// The range of the name in the importing module
+        range: TextRange,
+    },
+    /// A namespace import, `import * as ns from "./a.js"`, or a re-export of all the symbols
+    All,
+}
+
+/// An import, a re-export, a dynamic import or a `require` call of a module
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ModuleImport {
+    /// The specifier of the module, without its quotes
+    pub specifier: String,
+    /// The range of the specifier, with its quotes
+    pub range: TextRange,
+    pub kind: ModuleImportKind,
+    /// Whether only types are imported, like in `import type { A } from "./a.js"`
+    pub is_type_only: bool,
+    /// The imported symbols, empty for side-effect imports, dynamic imports and `require` calls
+    pub symbols: Vec<ImportedSymbol>,
+}
+
+/// A symbol exported by a module
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ModuleExport {
+    /// The name of the export, `default` for the default export
+    pub name: String,
+    /// The range of the exported name
+    pub range: TextRange,
+    /// Whether only a type is exported, like an interface
+    pub is_type_only: bool,
+}
+
+/// The imports and the exports of a module
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ModuleInfo {
+    pub imports: Vec<ModuleImport>,
+    pub exports: Vec<ModuleExport>,
+    /// Whether `module.exports` is assigned to a value whose properties aren't known, like
+    /// `module.exports = require("./a.js")`
+    pub has_dynamic_exports: bool,
+    /// Whether the module exports its symbols with `exports` or `module.exports`, in which case
+    /// `module.exports` is its default export
+    pub is_commonjs: bool,
+}
+
+impl ModuleInfo {
+    /// Collects the imports and the exports of `root`
+    pub fn from_root(root: &AnyJsRoot) -> Self {
+        let mut info = Self::default();
+        for node in root.syntax().descendants() {
+            if let Some(import) = JsImport::cast_ref(&node) {
+                info.collect_import(&import);
+            } else if let Some(export) = JsExport::cast_ref(&node) {
+                info.collect_export(&export);
+            } else if let Some(call) = JsCallExpression::cast_ref(&node) {
+                info.collect_specifier_like(
+                    &AnyJsImportSpecifierLike::JsCallExpression(call),
+                    ModuleImportKind::Require,
+                );
+            } else if let Some(call) = JsImportCallExpression::cast_ref(&node) {
+                info.collect_specifier_like(
+                    &AnyJsImportSpecifierLike::JsImportCallExpression(call),
+                    ModuleImportKind::Dynamic,
+                );
+            } else if let Some(assignment) = JsAssignmentExpression::cast_ref(&node) {
+                info.collect_commonjs_export(&assignment);
+            }
+        }
+        info
+    }
+
+    /// Whether the module has a default export
+    pub fn has_default_export(&self) -> bool {
+        self.exports.iter().any(|export| export.name == "default")
+    }
+
+    fn collect_import(&mut self, import: &JsImport) {
+        let Ok(clause) = import.import_clause() else {
+            return;
+        };
+        let Ok(source) = clause.source() else {
+            return;
+        };
+        let Ok(specifier) = source.inner_string_text() else {
+            return;
+        };
+        let mut symbols = Vec::new();
+        match &clause {
+            AnyJsImportClause::JsImportBareClause(_) => {}
+            AnyJsImportClause::JsImportDefaultClause(clause) => {
+                if let Ok(specifier) = clause.default_specifier() {
+                    push_default_symbol(&mut symbols, specifier.range());
+                }
+            }
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                if let Ok(specifiers) = clause.named_specifiers() {
+                    push_named_symbols(&mut symbols, specifiers.specifiers().iter().flatten());
+                }
+            }
+            AnyJsImportClause::JsImportNamespaceClause(_) => symbols.push(ImportedSymbol::All),
+            AnyJsImportClause::JsImportCombinedClause(clause) => {
+                if let Ok(specifier) = clause.default_specifier() {
+                    push_default_symbol(&mut symbols, specifier.range());
+                }
+                match clause.specifier() {
+                    Ok(AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers)) => {
+                        push_named_symbols(&mut symbols, specifiers.specifiers().iter().flatten());
+                    }
+                    Ok(AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(_)) => {
+                        symbols.push(ImportedSymbol::All);
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+        self.imports.push(ModuleImport {
+            specifier: specifier.to_string(),
+            range: source.range(),
+            kind: ModuleImportKind::Static,
+            is_type_only: clause.type_token().is_some(),
+            symbols,
+        });
+    }
+
+    fn collect_export(&mut self, export: &JsExport) {
+        let Ok(clause) = export.export_clause() else {
+            return;
+        };
+        match clause {
+            AnyJsExportClause::AnyJsDeclarationClause(declaration) => {
+                self.collect_declaration(&declaration);
+            }
+            AnyJsExportClause::TsExportDeclareClause(clause) => {
+                if let Ok(declaration) = clause.declaration() {
+                    self.collect_declaration(&declaration);
+                }
+            }
+            AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+                let is_type_only = matches!(
+                    clause.declaration(),
+                    Ok(AnyJsExportDefaultDeclaration::TsInterfaceDeclaration(_))
+                );
+                if let Ok(token) = clause.default_token() {
+                    self.push_export("default", token.text_trimmed_range(), is_type_only);
+                }
+            }
+            AnyJsExportClause::JsExportDefaultExpressionClause(clause) => {
+                if let Ok(token) = clause.default_token() {
+                    self.push_export("default", token.text_trimmed_range(), false);
+                }
+            }
+            AnyJsExportClause::TsExportAssignmentClause(clause) => {
+                if let Ok(token) = clause.eq_token() {
+                    self.push_export("default", token.text_trimmed_range(), false);
+                }
+            }
+            AnyJsExportClause::TsExportAsNamespaceClause(_) => {}
+            AnyJsExportClause::JsExportNamedClause(clause) => {
+                let is_clause_type_only = clause.type_token().is_some();
+                for specifier in clause.specifiers().iter().flatten() {
+                    let is_type_only = is_clause_type_only || specifier.type_token().is_some();
+                    match specifier {
+                        AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                            if let Ok(token) = specifier.name().and_then(|name| name.value_token())
+                            {
+                                self.push_export(
+                                    token.text_trimmed(),
+                                    token.text_trimmed_range(),
+                                    is_type_only,
+                                );
+                            }
+                        }
+                        AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                            if let Ok(name) = specifier.exported_name() {
+                                if let Ok(text) = name.inner_string_text() {
+                                    self.push_export(text.text(), name.range(), is_type_only);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            AnyJsExportClause::JsExportNamedFromClause(clause) => {
+                let is_clause_type_only = clause.type_token().is_some();
+                let mut symbols = Vec::new();
+                for specifier in clause.specifiers().iter().flatten() {
+                    let Ok(source_name) = specifier.source_name() else {
+                        continue;
+                    };
+                    let Ok(source_text) = source_name.inner_string_text() else {
+                        continue;
+                    };
+                    symbols.push(ImportedSymbol::Named {
+                        name: source_text.to_string(),
+                        range: source_name.range(),
+                    });
+                    let is_type_only = is_clause_type_only || specifier.type_token().is_some();
+                    match specifier
+                        .export_as()
+                        .and_then(|export_as| export_as.exported_name().ok())
+                    {
+                        Some(exported_name) => {
+                            if let Ok(text) = exported_name.inner_string_text() {
+                                self.push_export(text.text(), exported_name.range(), is_type_only);
+                            }
+                        }
+                        None => {
+                            self.push_export(source_text.text(), source_name.range(), is_type_only)
+                        }
+                    }
+                }
+                if let Ok(source) = clause.source() {
+                    if let Ok(specifier) = source.inner_string_text() {
+                        self.imports.push(ModuleImport {
+                            specifier: specifier.to_string(),
+                            range: source.range(),
+                            kind: ModuleImportKind::ReExport,
+                            is_type_only: is_clause_type_only,
+                            symbols,
+                        });
+                    }
+                }
+            }
+            AnyJsExportClause::JsExportFromClause(clause) => {
+                let is_type_only = clause.type_token().is_some();
+                let kind = match clause
+                    .export_as()
+                    .and_then(|export_as| export_as.exported_name().ok())
+                {
+                    Some(exported_name) => {
+                        if let Ok(text) = exported_name.inner_string_text() {
+                            self.push_export(text.text(), exported_name.range(), is_type_only);
+                        }
+                        ModuleImportKind::ReExport
+                    }
+                    None => ModuleImportKind::ReExportAll,
+                };
+                if let Ok(source) = clause.source() {
+                    if let Ok(specifier) = source.inner_string_text() {
+                        self.imports.push(ModuleImport {
+                            specifier: specifier.to_string(),
+                            range: source.range(),
+                            kind,
+                            is_type_only,
+                            symbols: vec![ImportedSymbol::All],
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    fn collect_declaration(&mut self, declaration: &AnyJsDeclarationClause) {
+        match declaration {
+            AnyJsDeclarationClause::JsClassDeclaration(declaration) => {
+                self.push_binding(declaration.id().ok(), false);
+            }
+            AnyJsDeclarationClause::JsFunctionDeclaration(declaration) => {
+                self.push_binding(declaration.id().ok(), false);
+            }
+            AnyJsDeclarationClause::TsDeclareFunctionDeclaration(declaration) => {
+                self.push_binding(declaration.id().ok(), false);
+            }
+            AnyJsDeclarationClause::TsEnumDeclaration(declaration) => {
+                self.push_binding(declaration.id().ok(), false);
+            }
+            AnyJsDeclarationClause::TsImportEqualsDeclaration(declaration) => {
+                self.push_binding(declaration.id().ok(), declaration.type_token().is_some());
+            }
+            AnyJsDeclarationClause::JsVariableDeclarationClause(clause) => {
+                self.collect_variable_declaration(clause);
+            }
+            AnyJsDeclarationClause::TsInterfaceDeclaration(declaration) => {
+                if let Ok(id) = declaration.id() {
+                    if let Ok(token) = id.name_token() {
+                        self.push_export(token.text_trimmed(), id.range(), true);
+                    }
+                }
+            }
+            AnyJsDeclarationClause::TsTypeAliasDeclaration(declaration) => {
+                if let Ok(id) = declaration.binding_identifier() {
+                    if let Ok(token) = id.name_token() {
+                        self.push_export(token.text_trimmed(), id.range(), true);
+                    }
+                }
+            }
+            AnyJsDeclarationClause::TsModuleDeclaration(declaration) => {
+                if let Ok(AnyTsModuleName::TsIdentifierBinding(id)) = declaration.name() {
+                    if let Ok(token) = id.name_token() {
+                        self.push_export(token.text_trimmed(), id.range(), false);
+                    }
+                }
+            }
+            AnyJsDeclarationClause::TsExternalModuleDeclaration(_)
+            | AnyJsDeclarationClause::TsGlobalDeclaration(_) => {}
+        }
+    }
+
+    fn collect_variable_declaration(&mut self, clause: &JsVariableDeclarationClause) {
+        let Ok(declaration) = clause.declaration() else {
+            return;
+        };
+        for declarator in declaration.declarators().iter().flatten() {
+            let Ok(id) = declarator.id() else {
+                continue;
+            };
+            if let AnyJsBindingPattern::AnyJsBinding(binding) = id {
+                self.push_binding(Some(binding), false);
+                continue;
+            }
+            // The bindings of the default values, like `b` in `{ a = (b) => b }`, aren't exported
+            let bindings = id
+                .syntax()
+                .descendants()
+                .filter_map(JsIdentifierBinding::cast)
+                .filter(|binding| {
+                    !binding
+                        .syntax()
+                        .ancestors()
+                        .take_while(|ancestor| ancestor != id.syntax())
+                        .any(|ancestor| ancestor.kind() == JsSyntaxKind::JS_INITIALIZER_CLAUSE)
+                });
+            for binding in bindings {
+                if let Ok(token) = binding.name_token() {
+                    self.push_export(token.text_trimmed(), binding.range(), false);
+                }
+            }
+        }
+    }
+
+    /// Collects `exports.a = ...`, `module.exports.a = ...` and `module.exports = ...`
+    fn collect_commonjs_export(&mut self, assignment: &JsAssignmentExpression) {
+        let Ok(AnyJsAssignmentPattern::AnyJsAssignment(left)) = assignment.left() else {
+            return;
+        };
+        let Some(member) = left.as_js_static_member_assignment() else {
+            return;
+        };
+        let (Ok(object), Ok(name)) = (member.object(), member.member()) else {
+            return;
+        };
+        let Ok(name) = name.value_token() else {
+            return;
+        };
+        if is_module_exports(&object) || is_reference(&object, "exports") {
+            self.is_commonjs = true;
+            self.push_export(name.text_trimmed(), name.text_trimmed_range(), false);
+        } else if is_reference(&object, "module") && name.text_trimmed() == "exports" {
+            self.is_commonjs = true;
+            self.push_export("default", name.text_trimmed_range(), false);
+            match assignment.right() {
+                Ok(AnyJsExpression::JsObjectExpression(object)) => {
+                    for member in object.members().iter().flatten() {
+                        let name = match &member {
+                            AnyJsObjectMember::JsPropertyObjectMember(member) => member
+                                .name()
+                                .ok()
+                                .and_then(|name| Some((name.name()?, name.range()))),
+                            AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => member
+                                .name()
+                                .ok()
+                                .and_then(|name| Some((name.name().ok()?, name.range()))),
+                            AnyJsObjectMember::JsMethodObjectMember(member) => member
+                                .name()
+                                .ok()
+                                .and_then(|name| Some((name.name()?, name.range()))),
+                            _ => None,
+                        };
+                        match name {
+                            Some((name, range)) => self.push_export(name.text(), range, false),
+                            // A spread or a computed name
+                            None => self.has_dynamic_exports = true,
+                        }
+                    }
+                }
+                _ => self.has_dynamic_exports = true,
+            }
+        }
+    }
+
+    fn collect_specifier_like(&mut self, node: &AnyJsImportSpecifierLike, kind: ModuleImportKind) {
+        let (Some(specifier), Some(token)) = (node.inner_string_text(), node.module_name_token())
+        else {
+            return;
+        };
+        self.imports.push(ModuleImport {
+            specifier: specifier.to_string(),
+            range: token.text_trimmed_range(),
+            kind,
+            is_type_only: false,
+            symbols: Vec::new(),
+        });
+    }
+
+    fn push_binding(&mut self, binding: Option<AnyJsBinding>, is_type_only: bool) {
+        let Some(AnyJsBinding::JsIdentifierBinding(binding)) = binding else {
+            return;
+        };
+        if let Ok(token) = binding.name_token() {
+            self.push_export(token.text_trimmed(), binding.range(), is_type_only);
+        }
+    }
+
+    fn push_export(&mut self, name: &str, range: TextRange, is_type_only: bool) {
+        self.exports.push(ModuleExport {
+            name: name.to_string(),
+            range,
+            is_type_only,
+        });
+    }
+}
+
+fn push_default_symbol(symbols: &mut Vec<ImportedSymbol>, range: TextRange) {
+    symbols.push(ImportedSymbol::Named {
+        name: "default".to_string(),
+        range,
+    });
+}
+
+fn push_named_symbols(
+    symbols: &mut Vec<ImportedSymbol>,
+    specifiers: impl Iterator<Item = AnyJsNamedImportSpecifier>,
+) {
+    for specifier in specifiers {
+        let name = match &specifier {
+            AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                specifier.name().ok().and_then(|name| {
+                    let text = name.inner_string_text().ok()?;
+                    Some((text.to_string(), name.range()))
+                })
+            }
+            AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => specifier
+                .local_name()
+                .ok()
+                .and_then(|name| name.as_js_identifier_binding()?.name_token().ok())
+                .map(|token| (token.text_trimmed().to_string(), token.text_trimmed_range())),
+            AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
+        };
+        if let Some((name, range)) = name {
+            symbols.push(ImportedSymbol::Named { name, range });
+        }
+    }
+}
+
+fn is_reference(expression: &AnyJsExpression, name: &str) -> bool {
+    expression
+        .as_js_identifier_expression()
+        .and_then(|expression| expression.name().ok())
+        .is_some_and(|reference| reference.has_name(name))
+}
+
+fn is_module_exports(expression: &AnyJsExpression) -> bool {
+    let Some(member) = expression.as_js_static_member_expression() else {
+        return false;
+    };
+    member
+        .object()
+        .is_ok_and(|object| is_reference(&object, "module"))
+        && member
+            .member()
+            .and_then(|member| member.value_token())
+            .is_ok_and(|token| token.text_trimmed() == "exports")
+}
+
+/// The symbols exported by a module, including the ones of the modules it re-exports with
+/// `export * from`
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ModuleExports {
+    pub names: BTreeSet<String>,
+    /// `false` when the module re-exports the symbols of a module that isn't part of the graph,
+    /// or assigns a value to `module.exports` whose properties aren't known
+    pub is_complete: bool,
+}
+
+/// The modules of the project and the packages that contain them.
+///
+/// The workspace fills the graph while it traverses the project, and the rules query it to
+/// resolve the specifiers of the imports to the files of the project.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    modules: RwLock<FxHashMap<PathBuf, Arc<ModuleInfo>>>,
+    /// The other files of the project, like stylesheets and images, which can be imported
+    /// but whose exports aren't known
+    files: RwLock<FxHashSet<PathBuf>>,
+    /// The packages of the project, by the directory of their `package.json`
+    packages: RwLock<FxHashMap<PathBuf, Arc<PackageJson>>>,
+    /// The `tsconfig.json` files of the project, by their directory. The path aliases of the
+    /// nearest one are resolved like the packages.
+    tsconfigs: RwLock<FxHashMap<PathBuf, Arc<TsConfigJson>>>,
+    /// The `tsconfig.json` of the files that aren't inside the directory of another one
+    default_tsconfig: RwLock<Option<Arc<TsConfigJson>>>,
+    /// The data computed from the whole graph, dropped when the graph changes
+    cache: RwLock<GraphCache>,
+}
+
+/// A module that imports another module or file
+#[derive(Debug, Clone)]
+pub struct ModuleImporter {
+    /// The path of the importing module
+    pub path: PathBuf,
+    pub import: ModuleImport,
+}
+
+#[derive(Debug, Default)]
+struct GraphCache {
+    /// The modules that import each module or file
+    importers: Option<Arc<FxHashMap<PathBuf, Vec<ModuleImporter>>>>,
+    /// The modules and the files reachable from the entry points, with the configured entry
+    /// points they were computed for
+    reachable: Option<(Vec<String>, Arc<FxHashSet<PathBuf>>)>,
+    /// The strongly connected component of each module, with whether the type-only imports
+    /// were followed to compute them
+    components: Option<(bool, Arc<FxHashMap<PathBuf, usize>>)>,
+}
+
+impl ModuleGraph {
+    /// Adds the module at `path`, or replaces it
+    pub fn insert_module(&self, path: &Path, info: ModuleInfo) {
+        let path = normalize_path(path);
+        let mut modules = self.modules.write().unwrap();
+        // The files that are opened after the project was registered usually didn't change
+        if modules.get(&path).is_some_and(|module| **module == info) {
+            return;
+        }
+        modules.insert(path, Arc::new(info));
+        drop(modules);
+        self.invalidate();
+    }
+
+    /// Adds a file that isn't a module of the graph, but can be imported
+    pub fn insert_file(&self, path: &Path) {
+        if self.files.write().unwrap().insert(normalize_path(path)) {
+            self.invalidate();
+        }
+    }
+
+    pub fn remove_module(&self, path: &Path) {
+        let path = normalize_path(path);
+        self.modules.write().unwrap().remove(&path);
+        self.files.write().unwrap().remove(&path);
+        self.invalidate();
+    }
+
+    /// Adds the package whose `package.json` is in `directory`. Its name can then be used
+    /// as a specifier, and its entry points are the roots of the graph.
+    pub fn insert_package(&self, directory: &Path, manifest: PackageJson) {
+        self.packages
+            .write()
+            .unwrap()
+            .insert(normalize_path(directory), Arc::new(manifest));
+        self.invalidate();
+    }
+
+    /// Sets the `tsconfig.json` of the files that aren't inside the directory of a
+    /// `tsconfig.json` added with [ModuleGraph::insert_tsconfig]
+    pub fn set_tsconfig(&self, tsconfig: Option<Arc<TsConfigJson>>) {
+        *self.default_tsconfig.write().unwrap() = tsconfig;
+        self.invalidate();
+    }
+
+    /// Adds the `tsconfig.json` of `directory`, which applies to the files of the directory
+    pub fn insert_tsconfig(&self, directory: &Path, tsconfig: Arc<TsConfigJson>) {
+        self.tsconfigs
+            .write()
+            .unwrap()
+            .insert(normalize_path(directory), tsconfig);
+        self.invalidate();
+    }
+
+    /// Returns the `tsconfig.json` of the file at `path`: the one of its nearest directory, or
+    /// the one set with [ModuleGraph::set_tsconfig]. Its options `paths` and `baseUrl` are used
+    /// to resolve the non-relative specifiers.
+    pub fn tsconfig(&self, path: &Path) -> Option<Arc<TsConfigJson>> {
+        let path = normalize_path(path);
+        let tsconfigs = self.tsconfigs.read().unwrap();
+        path.ancestors()
+            .skip(1)
+            .find_map(|directory| tsconfigs.get(directory))
+            .cloned()
+            .or_else(|| self.default_tsconfig.read().unwrap().clone())
+    }
+
+    fn invalidate(&self) {
+        *self.cache.write().unwrap() = GraphCache::default();
+    }
+
+    /// Returns the imports and the exports of the module at `path`
+    pub fn module(&self, path: &Path) -> Option<Arc<ModuleInfo>> {
+        self.modules
+            .read()
+            .unwrap()
+            .get(&normalize_path(path))
+            .cloned()
+    }
+
+    /// Whether the module at `path` is part of the graph
+    pub fn contains(&self, path: &Path) -> bool {
+        self.modules
+            .read()
+            .unwrap()
+            .contains_key(&normalize_path(path))
+    }
+
+    /// Whether a module or a file at `path` is part of the graph
+    fn exists(&self, path: &Path) -> bool {
+        self.modules.read().unwrap().contains_key(path) || self.files.read().unwrap().contains(path)
+    }
+
+    /// Returns the paths of the modules of the graph
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.modules.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Whether `specifier`, imported by the module at `importer`, refers to a file of the
+    /// project: it's a relative or an absolute path, a path alias of the `tsconfig.json` of
+    /// `importer`, or it starts with the name of a package of the project.
+    ///
+    /// The other specifiers refer to dependencies, which aren't part of the graph.
+    pub fn is_project_specifier(&self, importer: &Path, specifier: &str) -> bool {
+        is_path_specifier(specifier)
+            || self
+                .tsconfig(importer)
+                .is_some_and(|tsconfig| tsconfig.is_alias(specifier))
+            || {
+                let (name, _) = split_package_specifier(specifier);
+                self.packages
+                    .read()
+                    .unwrap()
+                    .values()
+                    .any(|manifest| manifest.name.as_deref() == Some(name))
+            }
+    }
+
+    /// Returns the path of the module or the file that `specifier`, imported by the module at
+    /// `importer`, refers to.
+    ///
+    /// Returns [None] when the specifier can't be resolved to a file of the graph,
+    /// which is the case of the dependencies installed in `node_modules`.
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        self.resolve_with(importer, specifier, |path| self.exists(path))
+    }
+
+    /// Returns the path of the module or the file that `specifier` refers to when the case of
+    /// the names of the files and the directories is ignored.
+    ///
+    /// It's used to report the specifiers that can be resolved only by the case-insensitive
+    /// file systems.
+    pub fn resolve_ignoring_case(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        let paths: FxHashMap<String, PathBuf> = {
+            let modules = self.modules.read().unwrap();
+            let files = self.files.read().unwrap();
+            modules
+                .keys()
+                .chain(files.iter())
+                .map(|path| (lowercase_path(path), path.clone()))
+                .collect()
+        };
+        let resolved = self.resolve_with(importer, specifier, |path| {
+            paths.contains_key(&lowercase_path(path))
+        })?;
+        paths.get(&lowercase_path(&resolved)).cloned()
+    }
+
+    fn resolve_with(
+        &self,
+        importer: &Path,
+        specifier: &str,
+        exists: impl Fn(&Path) -> bool,
+    ) -> Option<PathBuf> {
+        if is_path_specifier(specifier) {
+            let directory = importer.parent().unwrap_or(Path::new(""));
+            probe(&normalize_path(&directory.join(specifier)), exists)
+        } else {
+            // TypeScript tries the path aliases before the packages
+            let aliased = self
+                .tsconfig(importer)
+                .map(|tsconfig| tsconfig.resolve(specifier))
+                .unwrap_or_default();
+            aliased
+                .iter()
+                .find_map(|path| probe(path, &exists))
+                .or_else(|| self.resolve_package(specifier, &exists))
+        }
+    }
+
+    /// Returns the symbols exported by the module at `path`
+    pub fn exports(&self, path: &Path) -> Option<ModuleExports> {
+        let mut exports = ModuleExports {
+            names: BTreeSet::new(),
+            is_complete: true,
+        };
+        let mut visited = FxHashSet::default();
+        self.collect_exports(&normalize_path(path), true, &mut exports, &mut visited)?;
+        Some(exports)
+    }
+
+    fn collect_exports(
+        &self,
+        path: &Path,
+        with_default: bool,
+        exports: &mut ModuleExports,
+        visited: &mut FxHashSet<PathBuf>,
+    ) -> Option<()> {
+        if !visited.insert(path.to_path_buf()) {
+            return Some(());
+        }
+        let info = self.module(path)?;
+        exports.is_complete &= !info.has_dynamic_exports;
+        exports.names.extend(
+            info.exports
+                .iter()
+                .filter(|export| with_default || export.name != "default")
+                .map(|export| export.name.clone()),
+        );
+        let star_exports = info
+            .imports
+            .iter()
+            .filter(|import| import.kind == ModuleImportKind::ReExportAll);
+        for import in star_exports {
+            match self.resolve(path, &import.specifier) {
+                // `export *` doesn't re-export the default export
+                Some(resolved) => {
+                    if self
+                        .collect_exports(&resolved, false, exports, visited)
+                        .is_none()
+                    {
+                        exports.is_complete = false;
+                    }
+                }
+                None => exports.is_complete = false,
+            }
+        }
+        Some(())
+    }
+
+    fn resolve_package(&self, specifier: &str, exists: impl Fn(&Path) -> bool) -> Option<PathBuf> {
+        let (name, subpath) = split_package_specifier(specifier);
+        let packages = self.packages.read().unwrap();
+        let (directory, manifest) = packages
+            .iter()
+            .find(|(_, manifest)| manifest.name.as_deref() == Some(name))?;
+        let target = match &manifest.exports {
+            Some(exports) => exports.resolve(&format!(".{subpath}"), CONDITIONS)?,
+            None if subpath.is_empty() => manifest
+                .main
+                .clone()
+                .unwrap_or_else(|| "./index".to_string()),
+            None => format!(".{subpath}"),
+        };
+        let path = normalize_path(&directory.join(target));
+        drop(packages);
+        probe(&path, exists)
+    }
+
+    /// Returns the entry points of the packages of the project: the files declared by the
+    /// fields `exports`, `main` and `bin` of their `package.json`, or their `index` file when
+    /// none is declared.
+    ///
+    /// When `configured` isn't empty, its paths, relative to the directory of each package, are
+    /// the entry points instead.
+    pub fn entry_points(&self, configured: &[String]) -> FxHashSet<PathBuf> {
+        let candidates: Vec<PathBuf> = self
+            .packages
+            .read()
+            .unwrap()
+            .iter()
+            .flat_map(|(directory, manifest)| {
+                let mut paths: Vec<&str> = configured.iter().map(String::as_str).collect();
+                if paths.is_empty() {
+                    if let Some(exports) = &manifest.exports {
+                        paths.extend(exports.targets());
+                    }
+                    paths.extend(manifest.main.as_deref());
+                    paths.extend(manifest.bin.paths().iter().map(String::as_str));
+                }
+                if paths.is_empty() {
+                    paths.push("./index");
+                }
+                paths
+                    .into_iter()
+                    .map(|path| normalize_path(&directory.join(path)))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        candidates
+            .iter()
+            .filter_map(|candidate| probe(candidate, |path| self.exists(path)))
+            .collect()
+    }
+
+    /// Returns the modules and the files that can be reached from the entry points, by
+    /// following all the imports. See [ModuleGraph::entry_points] for `configured`.
+    pub fn reachable(&self, configured: &[String]) -> Arc<FxHashSet<PathBuf>> {
+        if let Some((cached_configuration, reachable)) = &self.cache.read().unwrap().reachable {
+            if cached_configuration.as_slice() == configured {
+                return reachable.clone();
+            }
+        }
+
+        let mut queue: Vec<PathBuf> = self.entry_points(configured).into_iter().collect();
+        let mut reachable: FxHashSet<PathBuf> = queue.iter().cloned().collect();
+        while let Some(path) = queue.pop() {
+            let Some(module) = self.module(&path) else {
+                continue;
+            };
+            for import in &module.imports {
+                if let Some(resolved) = self.resolve(&path, &import.specifier) {
+                    if reachable.insert(resolved.clone()) {
+                        queue.push(resolved);
+                    }
+                }
+            }
+        }
+
+        let reachable = Arc::new(reachable);
+        self.cache.write().unwrap().reachable = Some((configured.to_vec(), reachable.clone()));
+        reachable
+    }
+
+    /// Returns the strongly connected component of each module: two modules are in the same
+    /// component when each of them leads to the other by following the imports. A module is in
+    /// a cycle of imports when it imports a module of its own component.
+    ///
+    /// The dynamic imports aren't followed, since they don't take part in the evaluation of the
+    /// modules, and the type-only imports are followed when `include_type_imports` is `true`.
+    pub fn components(&self, include_type_imports: bool) -> Arc<FxHashMap<PathBuf, usize>> {
+        if let Some((cached_include_type_imports, components)) =
+            &self.cache.read().unwrap().components
+        {
+            if *cached_include_type_imports == include_type_imports {
+                return components.clone();
+            }
+        }
+
+        let components = Arc::new(self.compute_components(include_type_imports));
+        self.cache.write().unwrap().components = Some((include_type_imports, components.clone()));
+        components
+    }
+
+    /// Computes the strongly connected components with Tarjan's algorithm, whose depth-first
+    /// search is run with an explicit stack to support the long chains of imports
+    fn compute_components(&self, include_type_imports: bool) -> FxHashMap<PathBuf, usize> {
+        let modules: Vec<_> = self
+            .modules
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(path, module)| (path.clone(), module.clone()))
+            .collect();
+        let indices_by_path: FxHashMap<&Path, usize> = modules
+            .iter()
+            .enumerate()
+            .map(|(index, (path, _))| (path.as_path(), index))
+            .collect();
+        let edges: Vec<Vec<usize>> = modules
+            .iter()
+            .map(|(path, module)| {
+                module
+                    .imports
+                    .iter()
+                    .filter(|import| {
+                        import.kind != ModuleImportKind::Dynamic
+                            && (include_type_imports || !import.is_type_only)
+                    })
+                    .filter_map(|import| self.resolve(path, &import.specifier))
+                    .filter_map(|resolved| indices_by_path.get(resolved.as_path()).copied())
+                    .collect()
+            })
+            .collect();
+
+        const UNVISITED: usize = usize::MAX;
+        // The order in which the modules are visited
+        let mut visit_indices = vec![UNVISITED; modules.len()];
+        // The smallest visit index of the modules that each module leads to on the stack
+        let mut low_links = vec![0; modules.len()];
+        let mut is_on_stack = vec![false; modules.len()];
+        let mut stack = Vec::new();
+        let mut components = vec![0; modules.len()];
+        let mut visit_count = 0;
+        let mut component_count = 0;
+
+        for root in 0..modules.len() {
+            if visit_indices[root] != UNVISITED {
+                continue;
+            }
+            // The modules of the depth-first search, with the position of their next import
+            let mut calls = vec![(root, 0)];
+            visit_indices[root] = visit_count;
+            low_links[root] = visit_count;
+            visit_count += 1;
+            stack.push(root);
+            is_on_stack[root] = true;
+
+            while let Some((module, edge)) = calls.last_mut() {
+                let module = *module;
+                if let Some(&next) = edges[module].get(*edge) {
+                    *edge += 1;
+                    if visit_indices[next] == UNVISITED {
+                        visit_indices[next] = visit_count;
+                        low_links[next] = visit_count;
+                        visit_count += 1;
+                        stack.push(next);
+                        is_on_stack[next] = true;
+                        calls.push((next, 0));
+                    } else if is_on_stack[next] {
+                        low_links[module] = low_links[module].min(visit_indices[next]);
+                    }
+                    continue;
+                }
+
+                calls.pop();
+                if let Some((parent, _)) = calls.last() {
+                    low_links[*parent] = low_links[*parent].min(low_links[module]);
+                }
+                // The module is the first visited module of its component
+                if low_links[module] == visit_indices[module] {
+                    while let Some(member) = stack.pop() {
+                        is_on_stack[member] = false;
+                        components[member] = component_count;
+                        if member == module {
+                            break;
+                        }
+                    }
+                    component_count += 1;
+                }
+            }
+        }
+
+        modules
+            .into_iter()
+            .zip(components)
+            .map(|((path, _), component)| (path, component))
+            .collect()
+    }
+
+    /// Returns the modules that import the module or the file at `path`
+    pub fn importers(&self, path: &Path) -> Vec<ModuleImporter> {
+        self.importers_by_path()
+            .get(&normalize_path(path))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn importers_by_path(&self) -> Arc<FxHashMap<PathBuf, Vec<ModuleImporter>>> {
+        if let Some(importers) = &self.cache.read().unwrap().importers {
+            return importers.clone();
+        }
+
+        let modules: Vec<_> = self
+            .modules
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(path, module)| (path.clone(), module.clone()))
+            .collect();
+        let mut importers: FxHashMap<PathBuf, Vec<ModuleImporter>> = FxHashMap::default();
+        for (path, module) in modules {
+            for import in &module.imports {
+                if let Some(resolved) = self.resolve(&path, &import.specifier) {
+                    importers.entry(resolved).or_default().push(ModuleImporter {
+                        path: path.clone(),
+                        import: import.clone(),
+                    });
+                }
+            }
+        }
+
+        let importers = Arc::new(importers);
+        self.cache.write().unwrap().importers = Some(importers.clone());
+        importers
+    }
+
+    /// Whether the export `name` of the module at `path` is imported by another module of the
+    /// graph. The symbols re-exported with `export * from` are used when the re-exporting module
+    /// is one of `entry_points`, or when they are imported from it.
+    ///
+    /// The dynamic imports and the `require` calls use all the exports of the module.
+    pub fn is_export_used(
+        &self,
+        path: &Path,
+        name: &str,
+        entry_points: &FxHashSet<PathBuf>,
+    ) -> bool {
+        let mut visited = FxHashSet::default();
+        self.is_export_used_with(&normalize_path(path), name, entry_points, &mut visited)
+    }
+
+    fn is_export_used_with(
+        &self,
+        path: &Path,
+        name: &str,
+        entry_points: &FxHashSet<PathBuf>,
+        visited: &mut FxHashSet<PathBuf>,
+    ) -> bool {
+        if !visited.insert(path.to_path_buf()) {
+            return false;
+        }
+        self.importers(path)
+            .iter()
+            .any(|importer| match importer.import.kind {
+                ModuleImportKind::Dynamic | ModuleImportKind::Require => true,
+                ModuleImportKind::Static | ModuleImportKind::ReExport => {
+                    importer.import.symbols.iter().any(|symbol| match symbol {
+                        ImportedSymbol::Named { name: imported, .. } => imported == name,
+                        ImportedSymbol::All => true,
+                    })
+                }
+                // `export *` doesn't re-export the default export
+                ModuleImportKind::ReExportAll => {
+                    name != "default"
+                        && (entry_points.contains(&importer.path)
+                            || self.is_export_used_with(
+                                &importer.path,
+                                name,
+                                entry_points,
+                                visited,
+                            ))
+                }
+            })
+    }
+}
+
+/// Returns the first existing path among `path`, `path` with an extension, and
+/// the index files of the directory `path`
+fn probe(path: &Path, exists: impl Fn(&Path) -> bool) -> Option<PathBuf> {
+    if exists(path) {
+        return Some(path.to_path_buf());
+    }
+    let path_text = path.to_str()?;
+    let mut candidates = EXTENSIONS
+        .iter()
+        .map(|extension| PathBuf::from(format!("{path_text}.{extension}")))
+        // TypeScript allows to import `./a.ts` as `./a.js`
+        .chain(typescript_candidates(path_text))
+        .chain(
+            EXTENSIONS
+                .iter()
+                .map(|extension| path.join(format!("index.{extension}"))),
+        );
+    candidates.find(|candidate| exists(candidate))
+}
+
+fn lowercase_path(path: &Path) -> String {
+    path.to_string_lossy().to_lowercase()
+}
+
+fn typescript_candidates(path: &str) -> impl Iterator<Item = PathBuf> + '_ {
+    [
+        (".js", &["ts", "tsx", "d.ts"][..]),
+        (".jsx", &["tsx"][..]),
+        (".mjs", &["mts", "d.mts"][..]),
+        (".cjs", &["cts", "d.cts"][..]),
+    ]
+    .into_iter()
+    .filter_map(move |(extension, replacements)| {
+        let stem = path.strip_suffix(extension)?;
+        Some(
+            replacements
+                .iter()
+                .map(move |replacement| PathBuf::from(format!("{stem}.{replacement}"))),
+        )
+    })
+    .flatten()
+}
+
+/// Whether `specifier` is a relative or an absolute path
+fn is_path_specifier(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || Path::new(specifier).is_absolute()
+}
+
+/// Splits `@scope/name/subpath` into `@scope/name` and `/subpath`
+fn split_package_specifier(specifier: &str) -> (&str, &str) {
+    let name_end = if specifier.starts_with('@') {
+        specifier
+            .match_indices('/')
+            .nth(1)
+            .map_or(specifier.len(), |(index, _)| index)
+    } else {
+        specifier.find('/').unwrap_or(specifier.len())
+    };
+    specifier.split_at(name_end)
+}
+
+/// Returns `path` relative to the directory of `importer` when it's inside it, to show it in
+/// the diagnostics of `importer`
+pub(crate) fn relative_to_importer(importer: &Path, path: &Path) -> PathBuf {
+    let directory = normalize_path(importer.parent().unwrap_or(Path::new("")));
+    match path.strip_prefix(directory) {
+        Ok(relative) => Path::new(".").join(relative),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+/// Removes the `.` components of `path` and resolves its `..` components, without accessing
+/// the file system
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(result.components().next_back(), Some(Component::Normal(_))) {
+                    result.pop();
+                } else {
+                    result.push(component);
+                }
+            }
+            _ => result.push(component),
+        }
+    }
+    result
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuleGraphServices {
+    module_graph: Arc<ModuleGraph>,
+}
+
+impl ModuleGraphServices {
+    pub fn module_graph(&self) -> &ModuleGraph {
+        &self.module_graph
+    }
+}
+
+impl FromServices for ModuleGraphServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> biome_diagnostics::Result<Self, MissingServicesDiagnostic> {
+        let module_graph: &Arc<ModuleGraph> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["ModuleGraph"])
+        })?;
+
+        Ok(Self {
+            module_graph: module_graph.clone(),
+        })
+    }
+}
+
+impl Phase for ModuleGraphServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the module graph** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct Modules<N>(pub N);
+
+impl<N> Queryable for Modules<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Input = JsSyntaxNode;
+    type Output = N;
+
+    type Language = JsLanguage;
+    type Services = ModuleGraphServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, _: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ModuleGraph, ModuleImportKind, ModuleInfo};
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+    use biome_project::{CompilerOptions, PackageExports, PackageJson, TsConfigJson};
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    fn module_info(source: &str) -> ModuleInfo {
+        let parsed = parse(source, JsFileSource::ts(), JsParserOptions::default());
+        ModuleInfo::from_root(&parsed.tree())
+    }
+
+    fn export_names(info: &ModuleInfo) -> Vec<&str> {
+        info.exports
+            .iter()
+            .map(|export| export.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn collects_the_exports() {
+        let info = module_info(
+            r#"export const a = 1, { b, c: [d = (e) => e] } = f;
+export function g() {}
+export class H {}
+export interface I {}
+export type J = string;
+export enum K {}
+export default function () {}
+const l = 1;
+export { l, l as m };
+export { n as o } from "./n";
+export * as p from "./p";
+export * from "./q";"#,
+        );
+        assert_eq!(
+            export_names(&info),
+            ["a", "b", "d", "g", "H", "I", "J", "K", "default", "l", "m", "o", "p"]
+        );
+        assert!(info.exports[5].is_type_only);
+        assert!(!info.exports[4].is_type_only);
+    }
+
+    #[test]
+    fn collects_the_commonjs_exports() {
+        let info = module_info(
+            r#"exports.a = 1;
+module.exports.b = 2;
+module.exports = { c, d: 1, e() {} };"#,
+        );
+        assert_eq!(export_names(&info), ["a", "b", "default", "c", "d", "e"]);
+        assert!(!info.has_dynamic_exports);
+        assert!(info.is_commonjs);
+
+        let info = module_info(r#"module.exports = require("./a");"#);
+        assert!(info.has_dynamic_exports);
+    }
+
+    #[test]
+    fn collects_the_imports() {
+        let info = module_info(
+            r#"import a, { b, c as d } from "./a";
+import type { E } from "./e";
+import * as f from "./f";
+import "./g";
+export { h } from "./h";
+export * from "./i";
+const j = require("./j");
+const k = import("./k");"#,
+        );
+        let imports: Vec<_> = info
+            .imports
+            .iter()
+            .map(|import| (import.specifier.as_str(), import.kind, import.is_type_only))
+            .collect();
+        assert_eq!(
+            imports,
+            [
+                ("./a", ModuleImportKind::Static, false),
+                ("./e", ModuleImportKind::Static, true),
+                ("./f", ModuleImportKind::Static, false),
+                ("./g", ModuleImportKind::Static, false),
+                ("./h", ModuleImportKind::ReExport, false),
+                ("./i", ModuleImportKind::ReExportAll, false),
+                ("./j", ModuleImportKind::Require, false),
+                ("./k", ModuleImportKind::Dynamic, false),
+            ]
+        );
+        assert_eq!(info.imports[0].symbols.len(), 3);
+    }
+
+    #[test]
+    fn resolves_the_relative_specifiers() {
+        let graph = ModuleGraph::default();
+        for path in [
+            "./src/index.ts",
+            "src/utils/index.js",
+            "src/button.tsx",
+            "lib/a.mjs",
+        ] {
+            graph.insert_module(Path::new(path), ModuleInfo::default());
+        }
+        let importer = Path::new("src/index.ts");
+
+        assert_eq!(
+            graph.resolve(importer, "./utils"),
+            Some(PathBuf::from("src/utils/index.js"))
+        );
+        assert_eq!(
+            graph.resolve(importer, "./button.js"),
+            Some(PathBuf::from("src/button.tsx"))
+        );
+        assert_eq!(
+            graph.resolve(importer, "../lib/a.mjs"),
+            Some(PathBuf::from("lib/a.mjs"))
+        );
+        assert_eq!(
+            graph.resolve(Path::new("./lib/a.mjs"), "../src"),
+            Some(PathBuf::from("src/index.ts"))
+        );
+        assert_eq!(graph.resolve(importer, "./missing"), None);
+        assert_eq!(graph.resolve(importer, "react"), None);
+    }
+
+    #[test]
+    fn resolves_the_files_and_ignores_the_case() {
+        let graph = ModuleGraph::default();
+        graph.insert_module(Path::new("src/Button.tsx"), ModuleInfo::default());
+        graph.insert_file(Path::new("src/styles.css"));
+        let importer = Path::new("src/index.ts");
+
+        assert_eq!(
+            graph.resolve(importer, "./styles.css"),
+            Some(PathBuf::from("src/styles.css"))
+        );
+        assert!(graph.exports(Path::new("src/styles.css")).is_none());
+        assert_eq!(graph.resolve(importer, "./button"), None);
+        assert_eq!(
+            graph.resolve_ignoring_case(importer, "./button"),
+            Some(PathBuf::from("src/Button.tsx"))
+        );
+        assert!(graph.is_project_specifier(importer, "./button"));
+        assert!(!graph.is_project_specifier(importer, "react"));
+    }
+
+    #[test]
+    fn resolves_the_packages() {
+        let graph = ModuleGraph::default();
+        for path in [
+            "packages/ui/src/index.ts",
+            "packages/ui/src/button.ts",
+            "packages/utils/main.js",
+        ] {
+            graph.insert_module(Path::new(path), ModuleInfo::default());
+        }
+        let exports = PackageExports::Map(vec![
+            (
+                ".".to_string(),
+                PackageExports::Map(vec![
+                    (
+                        "types".to_string(),
+                        PackageExports::Path("./src/index.d.ts".to_string()),
+                    ),
+                    (
+                        "import".to_string(),
+                        PackageExports::Path("./src/index.ts".to_string()),
+                    ),
+                ]),
+            ),
+            (
+                "./*".to_string(),
+                PackageExports::Path("./src/*.ts".to_string()),
+            ),
+        ]);
+        graph.insert_package(
+            Path::new("packages/ui"),
+            PackageJson {
+                name: Some("@acme/ui".to_string()),
+                exports: Some(exports),
+                ..PackageJson::default()
+            },
+        );
+        graph.insert_package(
+            Path::new("packages/utils"),
+            PackageJson {
+                name: Some("utils".to_string()),
+                main: Some("main.js".to_string()),
+                ..PackageJson::default()
+            },
+        );
+        let importer = Path::new("app/index.js");
+
+        assert_eq!(
+            graph.resolve(importer, "@acme/ui"),
+            Some(PathBuf::from("packages/ui/src/index.ts"))
+        );
+        assert_eq!(
+            graph.resolve(importer, "@acme/ui/button"),
+            Some(PathBuf::from("packages/ui/src/button.ts"))
+        );
+        assert_eq!(
+            graph.resolve(importer, "utils"),
+            Some(PathBuf::from("packages/utils/main.js"))
+        );
+        assert_eq!(graph.resolve(importer, "@acme/other"), None);
+    }
+
+    #[test]
+    fn resolves_the_path_aliases() {
+        let graph = ModuleGraph::default();
+        for path in ["src/components/button.tsx", "src/utils/index.ts"] {
+            graph.insert_module(Path::new(path), ModuleInfo::default());
+        }
+        let importer = Path::new("app/index.js");
+        assert_eq!(graph.resolve(importer, "@/utils"), None);
+
+        graph.set_tsconfig(Some(Arc::new(TsConfigJson {
+            compiler_options: CompilerOptions {
+                base_url: Some(PathBuf::from("src")),
+                paths: Some(vec![("@/*".to_string(), vec!["./*".to_string()])]),
+                ..CompilerOptions::default()
+            },
+            ..TsConfigJson::default()
+        })));
+
+        assert_eq!(
+            graph.resolve(importer, "@/utils"),
+            Some(PathBuf::from("src/utils/index.ts"))
+        );
+        assert_eq!(
+            graph.resolve(importer, "components/button.js"),
+            Some(PathBuf::from("src/components/button.tsx"))
+        );
+        assert!(graph.is_project_specifier(importer, "@/missing"));
+        assert_eq!(graph.resolve(importer, "@/missing"), None);
+    }
+
+    #[test]
+    fn resolves_the_path_aliases_of_the_nearest_tsconfig() {
+        let graph = ModuleGraph::default();
+        for path in ["src/utils.ts", "packages/app/src/utils.ts"] {
+            graph.insert_module(Path::new(path), ModuleInfo::default());
+        }
+        let tsconfig = |base_url: &str| {
+            Arc::new(TsConfigJson {
+                compiler_options: CompilerOptions {
+                    base_url: Some(PathBuf::from(base_url)),
+                    paths: Some(vec![("@/*".to_string(), vec!["./*".to_string()])]),
+                    ..CompilerOptions::default()
+                },
+                ..TsConfigJson::default()
+            })
+        };
+        graph.set_tsconfig(Some(tsconfig("src")));
+        graph.insert_tsconfig(Path::new("packages/app"), tsconfig("packages/app/src"));
+
+        assert_eq!(
+            graph.resolve(Path::new("index.js"), "@/utils"),
+            Some(PathBuf::from("src/utils.ts"))
+        );
+        assert_eq!(
+            graph.resolve(Path::new("packages/app/index.js"), "@/utils"),
+            Some(PathBuf::from("packages/app/src/utils.ts"))
+        );
+    }
+
+    #[test]
+    fn collects_the_re_exported_symbols() {
+        let graph = ModuleGraph::default();
+        graph.insert_module(
+            Path::new("index.js"),
+            module_info(r#"export * from "./a"; export * from "./b"; export default 1;"#),
+        );
+        graph.insert_module(
+            Path::new("a.js"),
+            module_info(r#"export const a = 1; export default 2; export * from "./index";"#),
+        );
+        graph.insert_module(Path::new("b.js"), module_info(r#"export * from "lodash";"#));
+
+        let exports = graph.exports(Path::new("index.js")).unwrap();
+        assert_eq!(
+            exports.names.iter().map(String::as_str).collect::<Vec<_>>(),
+            ["a", "default"]
+        );
+        assert!(!exports.is_complete);
+
+        let exports = graph.exports(Path::new("a.js")).unwrap();
+        assert!(exports.names.contains("a"));
+        assert!(graph.exports(Path::new("missing.js")).is_none());
+    }
+
+    #[test]
+    fn finds_the_reachable_modules_and_the_used_exports() {
+        let graph = ModuleGraph::default();
+        graph.insert_module(
+            Path::new("index.js"),
+            module_info(r#"export * from "./a"; export { b } from "./b";"#),
+        );
+        graph.insert_module(
+            Path::new("a.js"),
+            module_info(r#"export const a = 1; export default 2; export * from "./c";"#),
+        );
+        graph.insert_module(
+            Path::new("b.js"),
+            module_info(r#"export const b = 1, unused = 2; const d = import("./d");"#),
+        );
+        graph.insert_module(Path::new("c.js"), module_info("export const c = 1;"));
+        graph.insert_module(Path::new("d.js"), module_info("export const d = 1;"));
+        graph.insert_module(Path::new("dead.js"), module_info(r#"import "./b";"#));
+        graph.insert_package(
+            Path::new(""),
+            PackageJson {
+                main: Some("./index.js".to_string()),
+                ..PackageJson::default()
+            },
+        );
+
+        let entry_points = graph.entry_points(&[]);
+        assert_eq!(
+            entry_points.into_iter().collect::<Vec<_>>(),
+            [PathBuf::from("index.js")]
+        );
+        let reachable = graph.reachable(&[]);
+        for path in ["index.js", "a.js", "b.js", "c.js", "d.js"] {
+            assert!(reachable.contains(Path::new(path)), "{path}");
+        }
+        assert!(!reachable.contains(Path::new("dead.js")));
+        let reachable = graph.reachable(&["./dead".to_string()]);
+        assert!(reachable.contains(Path::new("b.js")));
+        assert!(!reachable.contains(Path::new("a.js")));
+
+        let entry_points = graph.entry_points(&[]);
+        let is_used =
+            |path: &str, name: &str| graph.is_export_used(Path::new(path), name, &entry_points);
+        assert!(is_used("a.js", "a"));
+        assert!(!is_used("a.js", "default"));
+        assert!(is_used("b.js", "b"));
+        assert!(!is_used("b.js", "unused"));
+        assert!(is_used("c.js", "c"));
+        assert!(is_used("d.js", "d"));
+    }
+
+    #[test]
+    fn finds_the_components_of_the_import_cycles() {
+        let graph = ModuleGraph::default();
+        graph.insert_module(Path::new("a.ts"), module_info(r#"import "./b";"#));
+        graph.insert_module(
+            Path::new("b.ts"),
+            module_info(r#"import "./c"; import type { D } from "./d";"#),
+        );
+        graph.insert_module(Path::new("c.ts"), module_info(r#"import "./a";"#));
+        graph.insert_module(
+            Path::new("d.ts"),
+            module_info(r#"import "./b"; const e = import("./e");"#),
+        );
+        graph.insert_module(Path::new("e.ts"), module_info(r#"import "./d";"#));
+
+        let components = graph.components(false);
+        let component = |path: &str| components[Path::new(path)];
+        assert_eq!(component("a.ts"), component("b.ts"));
+        assert_eq!(component("a.ts"), component("c.ts"));
+        assert_ne!(component("a.ts"), component("d.ts"));
+        assert_ne!(component("d.ts"), component("e.ts"));
+
+        let components = graph.components(true);
+        let component = |path: &str| components[Path::new(path)];
+        assert_eq!(component("a.ts"), component("d.ts"));
+        assert_ne!(component("d.ts"), component("e.ts"));
+    }
+}
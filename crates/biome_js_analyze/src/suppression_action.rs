@@ -22,6 +22,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
         token_offset,
         mutation,
         suppression_text,
+        suppression_reason,
         diagnostic_text_range,
     } = payload;
     // retrieve the most suited, most left token where the diagnostics was emitted
@@ -61,7 +62,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                 let jsx_comment = jsx_expression_child(
                     token(T!['{']).with_trailing_trivia([(
                         TriviaPieceKind::SingleLineComment,
-                        format!("/* {}: <explanation> */", suppression_text).as_str(),
+                        format!("/* {}: {} */", suppression_text, suppression_reason).as_str(),
                     )]),
                     token(T!['}']),
                 )
@@ -98,7 +99,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                         (TriviaPieceKind::Newline, "\n"),
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {}: <explanation>", suppression_text).as_str(),
+                            format!("// {}: {}", suppression_text, suppression_reason).as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -106,7 +107,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                     new_token = new_token.with_leading_trivia([
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {}: <explanation>", suppression_text).as_str(),
+                            format!("// {}: {}", suppression_text, suppression_reason).as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -121,7 +122,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                         (TriviaPieceKind::Newline, "\n"),
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {}: <explanation>", suppression_text).as_str(),
+                            format!("// {}: {}", suppression_text, suppression_reason).as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -130,7 +131,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                         (TriviaPieceKind::Newline, "\n"),
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {}: <explanation>", suppression_text).as_str(),
+                            format!("// {}: {}", suppression_text, suppression_reason).as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -139,12 +140,12 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                 new_token = new_token.with_trailing_trivia([
                     (
                         TriviaPieceKind::SingleLineComment,
-                        format!("// {}: <explanation>", suppression_text).as_str(),
+                        format!("// {}: {}", suppression_text, suppression_reason).as_str(),
                     ),
                     (TriviaPieceKind::Newline, "\n"),
                 ])
             } else {
-                let comment = format!("// {}: <explanation>", suppression_text);
+                let comment = format!("// {}: {}", suppression_text, suppression_reason);
                 let mut trivia = vec![
                     (TriviaPieceKind::SingleLineComment, comment.as_str()),
                     (TriviaPieceKind::Newline, "\n"),
@@ -7,6 +7,7 @@ use biome_js_syntax::{
     JsVariableStatement, JsxChildList, T,
 };
 use biome_rowan::{chain_trivia_pieces, AstNode, AstSeparatedList, BatchMutation};
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 
 pub trait JsBatchMutation {
     /// Removes the declarator, and:
@@ -54,6 +55,21 @@ pub trait JsBatchMutation {
         I: IntoIterator<Item = AnyJsxChild>;
 }
 
+/// Whether the leading trivia of `node` has a `// biome-ignore-start`,
+/// `// biome-ignore-end` or `// biome-ignore-all` suppression comment
+fn has_range_suppression_comment(node: &JsSyntaxNode) -> bool {
+    node.first_leading_trivia().is_some_and(|trivia| {
+        trivia
+            .pieces()
+            .filter_map(|piece| piece.as_comments())
+            .any(|comment| {
+                parse_suppression_comment(comment.text())
+                    .filter_map(Result::ok)
+                    .any(|suppression| suppression.scope != SuppressionScope::Next)
+            })
+    })
+}
+
 fn remove_js_formal_parameter_from_js_parameter_list(
     batch: &mut BatchMutation<JsLanguage>,
     parameter: &JsFormalParameter,
@@ -228,6 +244,10 @@ impl JsBatchMutation for BatchMutation<JsLanguage> {
             return;
         };
         if JsStatementList::can_cast(parent.kind()) || JsModuleItemList::can_cast(parent.kind()) {
+            // The comments that start or end a suppression range must outlive the statement
+            if has_range_suppression_comment(node.syntax()) {
+                self.transfer_leading_trivia_to_sibling(node.syntax());
+            }
             self.remove_node(node);
         } else {
             self.replace_node(node, make::js_empty_statement(make::token(T![;])).into());
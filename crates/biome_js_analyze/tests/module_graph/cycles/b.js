@@ -0,0 +1,3 @@
+import { main } from "../invalid.js";
+
+export const b = main;
@@ -0,0 +1 @@
+export * from "./deep3.js";
@@ -0,0 +1,3 @@
+const { depth } = require("../maxDepth.js");
+
+exports.depth = depth;
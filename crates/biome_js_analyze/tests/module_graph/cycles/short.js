@@ -0,0 +1 @@
+import "../maxDepth.js";
@@ -0,0 +1,4 @@
+import type { Options } from "../ignoreTypes.js";
+import type { Props } from "../typeImports.js";
+
+export type Config = Options | Props;
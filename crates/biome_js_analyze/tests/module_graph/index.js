@@ -0,0 +1,3 @@
+export * from "./src/utils.js";
+export { Button } from "./src/components/button";
+export { setup } from "./src/setup.js";
@@ -0,0 +1,2 @@
+export default function render() {}
+export const version = "1.0.0";
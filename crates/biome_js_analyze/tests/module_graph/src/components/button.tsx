@@ -0,0 +1,9 @@
+export interface ButtonProps {
+	label: string;
+}
+
+export function Button(props: ButtonProps) {
+	return props.label;
+}
+
+export default Button;
@@ -0,0 +1 @@
+exports.parse = (text) => JSON.parse(text);
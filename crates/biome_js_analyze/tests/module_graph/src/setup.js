@@ -0,0 +1,3 @@
+import { config } from "../valid.js";
+
+export const setup = () => config;
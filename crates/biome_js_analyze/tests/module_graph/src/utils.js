@@ -0,0 +1,4 @@
+export const sum = (a, b) => a + b;
+export function max(a, b) {
+	return a > b ? a : b;
+}
@@ -1,14 +1,20 @@
-use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
+use biome_analyze::{
+    AnalysisFilter, AnalyzerAction, AnalyzerOptions, ControlFlow, Never, RuleFilter,
+};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_js_analyze::{JsAnalyzerServices, ModuleGraph, ModuleInfo, MODULE_GRAPH_RULES};
 use biome_js_parser::{parse, JsParserOptions};
-use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_js_syntax::{AnyJsRoot, JsFileSource, JsLanguage};
+use biome_project::TsConfigJson;
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
-    has_bogus_nodes_or_empty_slots, load_manifest, parse_test_path, register_leak_checker,
-    scripts_from_json, write_analyzer_snapshot, CheckActionType,
+    has_bogus_nodes_or_empty_slots, load_manifest, load_package_json, load_tsconfig,
+    load_workspace, parse_test_path, register_leak_checker, scripts_from_json,
+    write_analyzer_snapshot, CheckActionType,
 };
+use std::sync::Arc;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{cjs,js,jsx,tsx,ts,json,jsonc}", crate::run_test, "module"}
@@ -19,6 +25,10 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
 
     let input_file = Path::new(input);
     let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
+    if file_name == "package.json" {
+        // The manifests of the packages of a monorepo, loaded by `load_workspace`
+        return;
+    }
 
     let (group, rule) = parse_test_path(input_file);
     if rule == "specs" || rule == "suppression" {
@@ -104,11 +114,24 @@ pub(crate) fn analyze_and_snap(
 
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
-    let options = create_analyzer_options(input_file, &mut diagnostics);
+    let mut options = create_analyzer_options(input_file, &mut diagnostics);
     let manifest = load_manifest(input_file, &mut diagnostics);
+    let workspace = load_workspace(input_file, &mut diagnostics).map(Arc::new);
+    let tsconfig = load_tsconfig(input_file).map(Arc::new);
+    let module_graph = load_module_graph(&filter, &root, file_name, &mut options, &mut diagnostics);
 
-    let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+    let (_, errors) = biome_js_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        source_type,
+        JsAnalyzerServices {
+            manifest,
+            workspace,
+            tsconfig,
+            module_graph,
+        },
+        |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     if check_action_type.is_suppression() {
@@ -164,7 +187,8 @@ pub(crate) fn analyze_and_snap(
             }
 
             ControlFlow::<Never>::Continue(())
-        });
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -181,6 +205,54 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
+/// Builds the module graph of the project in `tests/module_graph` when `filter` enables a rule
+/// that queries it. The test file is added to the root of the project.
+fn load_module_graph(
+    filter: &AnalysisFilter,
+    root: &AnyJsRoot,
+    file_name: &str,
+    options: &mut AnalyzerOptions,
+    diagnostics: &mut Vec<String>,
+) -> Option<Arc<ModuleGraph>> {
+    let queries_module_graph = filter
+        .enabled_rules?
+        .iter()
+        .any(|rule| MODULE_GRAPH_RULES.contains(rule));
+    if !queries_module_graph {
+        return None;
+    }
+
+    let project = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/module_graph");
+    let module_graph = ModuleGraph::default();
+    let tsconfig = TsConfigJson::load(&project.join("tsconfig.json"), |path| {
+        read_to_string(path).ok()
+    });
+    module_graph.set_tsconfig(tsconfig.map(Arc::new));
+    let mut directories = vec![project.clone()];
+    while let Some(directory) = directories.pop() {
+        for entry in std::fs::read_dir(&directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                directories.push(path);
+            } else if path.file_name() == Some(OsStr::new("package.json")) {
+                if let Some(manifest) = load_package_json(&path, diagnostics) {
+                    module_graph.insert_package(&directory, manifest);
+                }
+            } else if let Ok(source_type) = JsFileSource::try_from(path.as_path()) {
+                let code = read_to_string(&path).unwrap();
+                let parsed = parse(&code, source_type, JsParserOptions::default());
+                module_graph.insert_module(&path, ModuleInfo::from_root(&parsed.tree()));
+            } else {
+                module_graph.insert_file(&path);
+            }
+        }
+    }
+
+    options.file_path = project.join(file_name);
+    module_graph.insert_module(&options.file_path, ModuleInfo::from_root(root));
+    Some(Arc::new(module_graph))
+}
+
 fn check_code_action(
     path: &Path,
     source: &str,
@@ -0,0 +1,5 @@
+// `React` is used by the JSX elements
+import React from "react";
+import { useState } from "react";
+
+export const App = () => <div>Hello</div>;
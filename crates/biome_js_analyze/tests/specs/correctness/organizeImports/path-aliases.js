@@ -0,0 +1,7 @@
+import { Button } from "./Button";
+import { logger } from "@app/logger";
+import { formatDate } from "~/utils/date";
+import { reduce } from "lodash";
+import { Message } from "../Message";
+import { config } from "@app/config";
+import React from "react";
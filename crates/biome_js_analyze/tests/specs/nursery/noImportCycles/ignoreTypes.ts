@@ -0,0 +1,5 @@
+import type { Config } from "./cycles/types.ts";
+
+export interface Options {
+	config: Config;
+}
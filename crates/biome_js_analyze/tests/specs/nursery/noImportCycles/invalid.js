@@ -0,0 +1,5 @@
+import { a } from "./cycles/a.js";
+import { b } from "./cycles/b.js";
+import "./invalid.js";
+
+export const main = a + b;
@@ -0,0 +1,4 @@
+import { depth } from "./cycles/deep1.js";
+import "./cycles/short.js";
+
+export const main = depth;
@@ -0,0 +1,6 @@
+/* should not generate diagnostics */
+import type { Config } from "./cycles/types.ts";
+
+export interface Props {
+	config: Config;
+}
@@ -0,0 +1,6 @@
+/* should not generate diagnostics */
+import { load } from "./cycles/lazy.js";
+import { sum } from "./src/utils.js";
+import { a } from "./cycles/a.js";
+import { useState } from "react";
+import { missing } from "./missing.js";
@@ -0,0 +1,5 @@
+import { legacy } from "@app/legacy";
+import { legacyUtils } from "./src/legacy.js";
+import { legacyHelpers } from "~/legacy";
+import { utils } from "@app/utils";
+import { moreUtils } from "./src/utils.js";
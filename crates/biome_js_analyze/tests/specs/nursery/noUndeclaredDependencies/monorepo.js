@@ -0,0 +1,5 @@
+import { Button } from "@monorepo/ui";
+import { sum } from "@monorepo/utils/math";
+import React from "react";
+// The tools aren't packages of the monorepo
+import { build } from "scripts";
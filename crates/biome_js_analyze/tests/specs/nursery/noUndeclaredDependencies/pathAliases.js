@@ -0,0 +1,5 @@
+import { Button } from "@app/components/button";
+import { sum } from "~utils";
+import React from "react";
+// `lodash` isn't a path alias
+import { debounce } from "lodash";
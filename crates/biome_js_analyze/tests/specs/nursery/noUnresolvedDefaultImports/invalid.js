@@ -0,0 +1,5 @@
+import utils from "./src/utils.js";
+import components, { Button } from "./src/components";
+import fixture from "fixture";
+import { default as sum } from "fixture/utils";
+export { default } from "./src/utils.js";
@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+import Button from "./src/components/button";
+import render from "@fixture/ui";
+import legacy from "./src/legacy.cjs";
+import * as utils from "./src/utils.js";
+import React from "react";
+import missing from "./missing.js";
+export { default } from "./src/components/button";
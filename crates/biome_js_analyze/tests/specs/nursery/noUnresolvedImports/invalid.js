@@ -0,0 +1,10 @@
+import { a } from "./missing.js";
+import "./src/missing.css";
+import { sum } from "./src/Utils.js";
+import { Button } from "./SRC/components/button";
+import { parse } from "fixture/missing";
+import { render } from "@fixture/ui/lib/missing.js";
+import { config } from "@/missing.js";
+export * from "../missing";
+const lazy = import("./lazy.js");
+const legacy = require("./src/legacy.js");
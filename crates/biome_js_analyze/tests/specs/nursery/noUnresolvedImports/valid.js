@@ -0,0 +1,17 @@
+/* should not generate diagnostics */
+import { sum } from "./src/utils.js";
+import { max } from "./src/utils";
+import { Button } from "./src/components/button.js";
+import { ButtonProps } from "./src/components";
+import "./src/styles.css";
+import { setup } from "@/setup.js";
+import { parse } from "./src/legacy.cjs";
+import fixture from "fixture";
+import { sum as add } from "fixture/utils";
+import render from "@fixture/ui";
+import react from "react";
+import assert from "node:assert";
+export * from "./index.js";
+const lazy = import("./src/utils.js");
+const legacy = require("./src/legacy.cjs");
+const lodash = require("lodash");
@@ -0,0 +1,8 @@
+import { min } from "./src/utils.js";
+import { sum, min as minimum } from "./src/utils.js";
+import { Button, Link } from "./src/components/button";
+import { Input } from "./src/components";
+import { max, Link as Anchor } from "fixture";
+import { version, name } from "@fixture/ui";
+import { stringify } from "./src/legacy.cjs";
+export { avg } from "./src/utils.js";
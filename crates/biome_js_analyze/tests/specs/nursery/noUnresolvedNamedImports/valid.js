@@ -0,0 +1,11 @@
+/* should not generate diagnostics */
+import { sum, max } from "./src/utils.js";
+import { Button, ButtonProps } from "./src/components";
+import { default as Button2 } from "./src/components/button";
+import { sum as add, Button as Component } from "fixture";
+import { version } from "@fixture/ui";
+import { parse } from "./src/legacy.cjs";
+import * as utils from "./src/utils.js";
+import { useState } from "react";
+import { a } from "./missing.js";
+export { max } from "./src/utils.js";
@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+export const unused = 1;
+export default function () {}
@@ -0,0 +1,6 @@
+// `main` is imported by `cycles/b.js`
+export const main = 0;
+export const unused = 1;
+export function helper() {}
+export class Parser {}
+export default function () {}
@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+// `config` is imported by `src/setup.js`
+export const config = {};
@@ -0,0 +1,2 @@
+// Only `cycles/b.js` imports the module, and no entry point imports `cycles/b.js`
+export const main = 0;
@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+// The module is imported by `src/setup.js`, which the entry point `index.js` re-exports
+export const config = {};
@@ -0,0 +1,10 @@
+// Resolves to `./src/index.js`
+import { app } from "@app/index.js";
+// Resolves to `./src/foo.js`
+import { fooPackageVariable } from "@app/foo.js";
+// Resolves to `../shared/index.js`
+import { shared } from "@shared/index.js";
+// Resolves to `../shared/utils/bar.js`
+import { barPackageVariable } from "@shared/utils/bar.js";
+// Resources are exempt
+import logo from "@app/logo.png";
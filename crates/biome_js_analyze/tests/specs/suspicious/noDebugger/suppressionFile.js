@@ -0,0 +1,5 @@
+// biome-ignore-all lint/suspicious/noDebugger: generated file
+debugger;
+function f() {
+	debugger;
+}
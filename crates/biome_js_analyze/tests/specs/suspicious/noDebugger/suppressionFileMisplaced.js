@@ -0,0 +1,3 @@
+debugger;
+// biome-ignore-all lint/suspicious/noDebugger: must be at the top of the file
+debugger;
@@ -0,0 +1,12 @@
+debugger;
+// biome-ignore-start lint/suspicious/noDebugger: generated code
+debugger;
+function f() {
+	debugger;
+}
+// biome-ignore-end lint/suspicious/noDebugger: generated code
+debugger;
+// biome-ignore-start lint: vendored code
+debugger;
+// biome-ignore-end
+debugger;
@@ -0,0 +1,18 @@
+// biome-ignore lint/suspicious/noDebugger: not needed anymore
+const a = 1;
+const b = 2; // biome-ignore lint/suspicious/noDebugger: not needed anymore
+
+// biome-ignore lint/suspicious/noUnknownRule: the rule doesn't exist
+const c = 3;
+// biome-ignore-end
+const d = 4;
+
+// biome-ignore-start lint/suspicious/noDebugger: not needed anymore
+const e = 5;
+// biome-ignore-end lint/suspicious/noDebugger: not needed anymore
+
+// biome-ignore lint/suspicious/noDoubleEquals: the rule is disabled
+a == b;
+
+// biome-ignore-start lint/suspicious/noDebugger: never ended
+debugger;
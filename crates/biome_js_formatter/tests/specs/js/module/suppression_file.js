@@ -0,0 +1,6 @@
+// biome-ignore-all format: generated file
+const   matrix   =   [
+    1, 0,
+    0, 1,
+];
+function   f(  ) {   return   1 }
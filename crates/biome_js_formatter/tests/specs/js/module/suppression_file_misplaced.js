@@ -0,0 +1,6 @@
+const   matrix   =   [
+    1, 0,
+    0, 1,
+];
+// biome-ignore-all format: must be at the top of the file
+function   f(  ) {   return   1 }
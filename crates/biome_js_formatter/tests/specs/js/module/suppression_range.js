@@ -0,0 +1,17 @@
+const   formatted   =   1;
+
+// biome-ignore-start format: generated code
+const   matrix   =   [
+    1, 0,
+    0, 1,
+];
+function   f(  ) {   return   1 }
+// biome-ignore-end format: generated code
+
+const   alsoFormatted   =   2;
+
+// biome-ignore-start format: vendored code
+const   notFormatted   =   3;
+// biome-ignore-end
+
+const   formattedAgain   =   4;
@@ -0,0 +1,4 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub mod correctness;
+::biome_analyze::declare_category! { pub Assists { kind : Action , groups : [self :: correctness :: Correctness ,] } }
@@ -0,0 +1,316 @@
+use crate::package_json::{is_package_json, DEPENDENCY_FIELDS, KEY_ORDER};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, SourceActionKind,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_json_factory::make;
+use biome_json_syntax::{
+    AnyJsonValue, JsonArrayValue, JsonLanguage, JsonMember, JsonObjectValue, JsonRoot, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxTriviaPiece, TokenText};
+use std::cmp::Ordering;
+use std::path::Path;
+
+declare_rule! {
+    /// Provides a whole-source code action to sort the keys of the JSON objects.
+    ///
+    /// The keys are sorted alphabetically, in all the objects of the document.
+    ///
+    /// Some files have a canonical order of keys instead:
+    /// - the fields of a `package.json` follow the order used by
+    ///   [sort-package-json](https://github.com/keithamus/sort-package-json), and the packages of
+    ///   its dependencies are sorted alphabetically. The other objects of the manifest, such as
+    ///   `exports`, keep their order because it's meaningful;
+    /// - the fields of a `tsconfig.json` or a `jsconfig.json` follow the order of the
+    ///   TypeScript documentation, and its `compilerOptions` are sorted alphabetically.
+    ///
+    /// The keys that aren't part of a canonical order follow the known keys, in their order.
+    ///
+    /// The comments attached to a member move with it.
+    ///
+    /// ## Options
+    ///
+    /// The option `json.sortKeys.order` of the configuration lists the keys that come first in
+    /// the objects of the other documents, in this order. The other keys follow them
+    /// alphabetically.
+    ///
+    /// ```json
+    /// {
+    ///     "json": {
+    ///         "sortKeys": {
+    ///             "enabled": true,
+    ///             "order": ["name", "version"]
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// ```json
+    /// {
+    ///     "vite": "^5.0.0",
+    ///     "@vitejs/plugin-react": "^4.2.0",
+    ///     "typescript": "^5.3.0"
+    /// }
+    /// ```
+    pub UseSortedKeys {
+        version: "next",
+        name: "useSortedKeys",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseSortedKeys {
+    type Query = Ast<JsonRoot>;
+    type State = AnyJsonValue;
+    type Signals = Option<Self::State>;
+    type Options = UseSortedKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let value = ctx.query().value().ok()?;
+        let document = Document::from_path(ctx.file_path(), &ctx.options().order);
+        sort_value(&value, document, &mut Vec::new())
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(ctx.query().value().ok()?, state.clone());
+        Some(JsonRuleAction {
+            category: ActionCategory::Source(SourceActionKind::OrganizeImports),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Sort the keys of the objects." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// The options of the rule [UseSortedKeys]
+#[derive(Clone, Debug, Default)]
+pub struct UseSortedKeysOptions {
+    /// The keys that come first in the objects of the documents that don't have a canonical
+    /// order, in this order
+    pub order: Vec<String>,
+}
+
+/// The canonical order of the fields of a `tsconfig.json`, based on the order of the
+/// [TypeScript documentation](https://www.typescriptlang.org/tsconfig).
+/// The other fields follow them.
+const TSCONFIG_KEY_ORDER: &[&str] = &[
+    "$schema",
+    "display",
+    "extends",
+    "compileOnSave",
+    "compilerOptions",
+    "files",
+    "include",
+    "exclude",
+    "references",
+    "watchOptions",
+    "typeAcquisition",
+];
+
+/// The kinds of documents that have their own order of keys
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Document<'a> {
+    PackageJson,
+    TsConfig,
+    /// The other documents, with the keys that come first in their objects
+    Other(&'a [String]),
+}
+
+impl<'a> Document<'a> {
+    fn from_path(path: &Path, order: &'a [String]) -> Self {
+        if is_package_json(path) {
+            Self::PackageJson
+        } else if path
+            .file_name()
+            .is_some_and(|name| name == "tsconfig.json" || name == "jsconfig.json")
+        {
+            Self::TsConfig
+        } else {
+            Self::Other(order)
+        }
+    }
+
+    /// Returns the order of the keys of the object found at `path`, or [None] when the object
+    /// keeps its order
+    fn key_order(self, path: &[TokenText]) -> Option<KeyOrder<'a>> {
+        match (self, path) {
+            (Self::Other([]), _) => Some(KeyOrder::Alphabetical),
+            (Self::Other(order), _) => Some(KeyOrder::Configured(order)),
+            (Self::PackageJson, []) => Some(KeyOrder::Canonical(KEY_ORDER)),
+            (Self::PackageJson, [field]) if DEPENDENCY_FIELDS.contains(&field.text()) => {
+                Some(KeyOrder::Alphabetical)
+            }
+            (Self::TsConfig, []) => Some(KeyOrder::Canonical(TSCONFIG_KEY_ORDER)),
+            (Self::TsConfig, [field]) if field.text() == "compilerOptions" => {
+                Some(KeyOrder::Alphabetical)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum KeyOrder<'a> {
+    /// The keys listed in this order come first, the other keys keep their order after them
+    Canonical(&'static [&'static str]),
+    /// The keys listed in this order come first, the other keys follow them alphabetically
+    Configured(&'a [String]),
+    Alphabetical,
+}
+
+impl KeyOrder<'_> {
+    fn compare(self, first: &Entry, second: &Entry) -> Ordering {
+        let (first, second) = (first.name.text(), second.name.text());
+        match self {
+            Self::Canonical(order) => {
+                canonical_index(order, first).cmp(&canonical_index(order, second))
+            }
+            Self::Configured(order) => canonical_index(order, first)
+                .cmp(&canonical_index(order, second))
+                .then_with(|| first.cmp(second)),
+            Self::Alphabetical => first.cmp(second),
+        }
+    }
+}
+
+/// Returns `value` with the keys of its objects sorted, when they aren't sorted yet.
+/// `path` holds the keys of the members that lead to `value`.
+fn sort_value(
+    value: &AnyJsonValue,
+    document: Document<'_>,
+    path: &mut Vec<TokenText>,
+) -> Option<AnyJsonValue> {
+    match value {
+        AnyJsonValue::JsonObjectValue(object) => {
+            sort_object(object, document, path).map(AnyJsonValue::JsonObjectValue)
+        }
+        AnyJsonValue::JsonArrayValue(array) => {
+            sort_array(array, document, path).map(AnyJsonValue::JsonArrayValue)
+        }
+        _ => None,
+    }
+}
+
+fn sort_array(
+    array: &JsonArrayValue,
+    document: Document<'_>,
+    path: &mut Vec<TokenText>,
+) -> Option<JsonArrayValue> {
+    let list = array.elements();
+    let mut changed = false;
+    let mut elements = Vec::with_capacity(list.len());
+    for element in list.iter() {
+        let element = element.ok()?;
+        if let Some(sorted) = sort_value(&element, document, path) {
+            changed = true;
+            elements.push(sorted);
+        } else {
+            elements.push(element);
+        }
+    }
+    if !changed {
+        return None;
+    }
+    let separators = list.separators().collect::<Result<Vec<_>, _>>().ok()?;
+    Some(
+        array
+            .clone()
+            .with_elements(make::json_array_element_list(elements, separators)),
+    )
+}
+
+fn sort_object(
+    object: &JsonObjectValue,
+    document: Document<'_>,
+    path: &mut Vec<TokenText>,
+) -> Option<JsonObjectValue> {
+    let list = object.json_member_list();
+    let mut changed = false;
+    let mut entries = Vec::with_capacity(list.len());
+    for element in list.elements() {
+        let mut member = element.node().ok()?.clone();
+        let name = member.name().ok()?.inner_string_text().ok()?;
+        if let Ok(value) = member.value() {
+            path.push(name.clone());
+            let sorted = sort_value(&value, document, path);
+            path.pop();
+            if let Some(sorted) = sorted {
+                changed = true;
+                member = member.with_value(sorted);
+            }
+        }
+        // The comment that follows a member is attached to its comma, or to the last token of
+        // the member when it's the last one
+        let trailing_trivia = match element.trailing_separator().ok()? {
+            Some(separator) => separator.trailing_trivia().pieces().collect(),
+            None => {
+                let trivia = member.syntax().last_trailing_trivia()?.pieces().collect();
+                member = member.with_trailing_trivia_pieces([])?;
+                trivia
+            }
+        };
+        entries.push(Entry {
+            name,
+            member,
+            trailing_trivia,
+        });
+    }
+
+    if let Some(order) = document.key_order(path) {
+        let is_sorted = entries
+            .windows(2)
+            .all(|pair| order.compare(&pair[0], &pair[1]) != Ordering::Greater);
+        if !is_sorted {
+            changed = true;
+            // The sort is stable, so the keys that compare equal keep their order
+            entries.sort_by(|first, second| order.compare(first, second));
+        }
+    }
+    if !changed {
+        return None;
+    }
+
+    let has_trailing_separator = list.trailing_separator().is_some();
+    let last_index = entries.len().saturating_sub(1);
+    let mut members = Vec::with_capacity(entries.len());
+    let mut separators = Vec::with_capacity(entries.len());
+    for (index, entry) in entries.into_iter().enumerate() {
+        let Entry {
+            member,
+            trailing_trivia,
+            ..
+        } = entry;
+        if index < last_index || has_trailing_separator {
+            separators.push(make::token(T![,]).with_trailing_trivia_pieces(trailing_trivia));
+            members.push(member);
+        } else {
+            members.push(member.with_trailing_trivia_pieces(trailing_trivia)?);
+        }
+    }
+    Some(
+        object
+            .clone()
+            .with_json_member_list(make::json_member_list(members, separators)),
+    )
+}
+
+/// A member of an object, detached from the comment that follows it
+struct Entry {
+    name: TokenText,
+    member: JsonMember,
+    trailing_trivia: Vec<SyntaxTriviaPiece<JsonLanguage>>,
+}
+
+/// Returns the position of `name` in `order`, or the length of `order` when it isn't listed
+fn canonical_index(order: &[impl AsRef<str>], name: &str) -> usize {
+    order
+        .iter()
+        .position(|key| key.as_ref() == name)
+        .unwrap_or(order.len())
+}
@@ -1,15 +1,21 @@
+mod assists;
 mod lint;
 pub mod options;
+mod package_json;
 mod registry;
+pub mod schema;
 
+pub use crate::assists::correctness::use_sorted_keys::UseSortedKeysOptions;
 pub use crate::registry::visit_registry;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
+    MetadataRegistry, RuleAction, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
 };
 use biome_diagnostics::Error;
 use biome_json_syntax::JsonLanguage;
 
+pub(crate) type JsonRuleAction = RuleAction<JsonLanguage>;
+
 /// Return the static [MetadataRegistry] for the JSON analyzer rules
 pub fn metadata() -> &'static MetadataRegistry {
     lazy_static::lazy_static! {
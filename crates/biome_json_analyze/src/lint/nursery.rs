@@ -2,13 +2,25 @@
 
 use biome_analyze::declare_group;
 
+pub mod no_duplicate_dependencies;
 pub mod no_duplicate_json_keys;
+pub mod no_invalid_dependency_versions;
+pub mod no_invalid_package_engines;
+pub mod no_invalid_package_exports;
+pub mod use_package_types;
+pub mod use_sorted_package_json_keys;
 
 declare_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_duplicate_dependencies :: NoDuplicateDependencies ,
             self :: no_duplicate_json_keys :: NoDuplicateJsonKeys ,
+            self :: no_invalid_dependency_versions :: NoInvalidDependencyVersions ,
+            self :: no_invalid_package_engines :: NoInvalidPackageEngines ,
+            self :: no_invalid_package_exports :: NoInvalidPackageExports ,
+            self :: use_package_types :: UsePackageTypes ,
+            self :: use_sorted_package_json_keys :: UseSortedPackageJsonKeys ,
         ]
      }
 }
@@ -0,0 +1,138 @@
+use crate::package_json::{find_object, is_package_json, manifest_object};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{JsonMemberName, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList};
+use rustc_hash::FxHashMap;
+
+declare_rule! {
+    /// Disallow declaring a package in more than one field of dependencies of a `package.json`.
+    ///
+    /// A package declared in `dependencies` shouldn't be declared in `devDependencies` or in
+    /// `optionalDependencies`: only one of the versions is installed, and the field decides whether
+    /// the package is installed with the production dependencies.
+    /// A package can be declared both in `peerDependencies` and in `devDependencies`, which is how
+    /// a library tests its peer dependencies.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "dependencies": {
+    ///     "react": "^18.2.0"
+    ///   },
+    ///   "devDependencies": {
+    ///     "react": "^18.3.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "devDependencies": {
+    ///     "react": "^18.3.0"
+    ///   },
+    ///   "peerDependencies": {
+    ///     "react": "^18.0.0"
+    ///   }
+    /// }
+    /// ```
+    pub NoDuplicateDependencies {
+        version: "next",
+        name: "noDuplicateDependencies",
+        recommended: false,
+    }
+}
+
+/// The fields that can't declare the same package, in the order they are compared
+const EXCLUSIVE_FIELDS: [&str; 3] = ["dependencies", "devDependencies", "optionalDependencies"];
+
+pub struct DuplicateDependency {
+    /// The first declaration of the package
+    original: JsonMemberName,
+    /// The field of the first declaration
+    original_field: &'static str,
+    /// The declaration that duplicates it
+    duplicate: JsonMemberName,
+    /// The field of the duplicate
+    duplicate_field: &'static str,
+}
+
+impl Rule for NoDuplicateDependencies {
+    type Query = Ast<JsonRoot>;
+    type State = DuplicateDependency;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Vec::new();
+        }
+        let Some(manifest) = manifest_object(ctx.query()) else {
+            return Vec::new();
+        };
+        let mut declarations = FxHashMap::<String, (JsonMemberName, &'static str)>::default();
+        let mut duplicates = Vec::new();
+        for field in EXCLUSIVE_FIELDS {
+            let Some(dependencies) = find_object(&manifest, field) else {
+                continue;
+            };
+            for dependency in dependencies.json_member_list().iter().flatten() {
+                let Ok(name) = dependency.name() else {
+                    continue;
+                };
+                let Ok(text) = name.inner_string_text() else {
+                    continue;
+                };
+                match declarations.get(text.text()) {
+                    // The duplicates inside a field are reported by `noDuplicateJsonKeys`
+                    Some((_, original_field)) if *original_field == field => {}
+                    Some((original, original_field)) => duplicates.push(DuplicateDependency {
+                        original: original.clone(),
+                        original_field,
+                        duplicate: name,
+                        duplicate_field: field,
+                    }),
+                    None => {
+                        declarations.insert(text.text().to_string(), (name, field));
+                    }
+                }
+            }
+        }
+        duplicates
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DuplicateDependency {
+            original,
+            original_field,
+            duplicate,
+            duplicate_field,
+        } = state;
+        let name = duplicate.inner_string_text().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                duplicate.range(),
+                markup! {
+                    "The package "<Emphasis>{name.text()}</Emphasis>" is declared in "<Emphasis>{duplicate_field}</Emphasis>" and in "<Emphasis>{original_field}</Emphasis>"."
+                },
+            )
+            .detail(
+                original.range(),
+                markup! {
+                    "The package is first declared here."
+                },
+            )
+            .note(markup! {
+                "Remove one of the declarations."
+            }),
+        )
+    }
+}
@@ -0,0 +1,96 @@
+use crate::package_json::{
+    find_object, is_package_json, is_valid_version_specifier, manifest_object, DEPENDENCY_FIELDS,
+};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow invalid version specifiers in the dependencies of a `package.json`.
+    ///
+    /// The versions of the fields `dependencies`, `devDependencies`, `peerDependencies` and
+    /// `optionalDependencies` must be semver ranges, dist-tags such as `latest`, protocols such as
+    /// `workspace:` or `npm:`, paths, URLs, or GitHub repositories.
+    /// A package manager fails to install a dependency with an invalid version.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "dependencies": {
+    ///     "react": "^18.2.0.1"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "dependencies": {
+    ///     "react": "^18.2.0",
+    ///     "@acme/ui": "workspace:*",
+    ///     "typescript": "next"
+    ///   }
+    /// }
+    /// ```
+    pub NoInvalidDependencyVersions {
+        version: "next",
+        name: "noInvalidDependencyVersions",
+        recommended: false,
+    }
+}
+
+impl Rule for NoInvalidDependencyVersions {
+    type Query = Ast<JsonRoot>;
+    type State = AnyJsonValue;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Vec::new();
+        }
+        let Some(manifest) = manifest_object(ctx.query()) else {
+            return Vec::new();
+        };
+        DEPENDENCY_FIELDS
+            .iter()
+            .filter_map(|field| find_object(&manifest, field))
+            .flat_map(|dependencies| {
+                dependencies
+                    .json_member_list()
+                    .iter()
+                    .flatten()
+                    .filter_map(|dependency| dependency.value().ok())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|version| {
+                version
+                    .as_json_string_value()
+                    .and_then(|version| version.inner_string_text().ok())
+                    .map_or(true, |version| !is_valid_version_specifier(version.text()))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, version: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                version.range(),
+                markup! {
+                    "This version of the dependency is invalid."
+                },
+            )
+            .note(markup! {
+                "Use a semver range such as "<Emphasis>"^1.2.0"</Emphasis>", a dist-tag such as "<Emphasis>"latest"</Emphasis>", or a protocol such as "<Emphasis>"workspace:*"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -0,0 +1,117 @@
+use crate::package_json::{find_member, is_package_json, is_valid_range, manifest_object};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow invalid `engines` in a `package.json`.
+    ///
+    /// The field `engines` maps the names of the runtimes and of the package managers, such as
+    /// `node` or `pnpm`, to the semver ranges of the versions the package supports.
+    /// Unlike the versions of the dependencies, dist-tags and protocols aren't allowed.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "engines": ">=18"
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "engines": {
+    ///     "node": "latest"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "engines": {
+    ///     "node": "^18.18.0 || >=20.0.0",
+    ///     "pnpm": ">=8"
+    ///   }
+    /// }
+    /// ```
+    pub NoInvalidPackageEngines {
+        version: "next",
+        name: "noInvalidPackageEngines",
+        recommended: false,
+    }
+}
+
+pub enum InvalidEngines {
+    /// The field `engines` isn't an object
+    NotAnObject(AnyJsonValue),
+    /// The version of an engine isn't a semver range
+    InvalidRange(AnyJsonValue),
+}
+
+impl Rule for NoInvalidPackageEngines {
+    type Query = Ast<JsonRoot>;
+    type State = InvalidEngines;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Vec::new();
+        }
+        let Some(engines) = manifest_object(ctx.query())
+            .and_then(|manifest| find_member(&manifest, "engines"))
+            .and_then(|engines| engines.value().ok())
+        else {
+            return Vec::new();
+        };
+        let AnyJsonValue::JsonObjectValue(object) = &engines else {
+            return vec![InvalidEngines::NotAnObject(engines)];
+        };
+        object
+            .json_member_list()
+            .iter()
+            .flatten()
+            .filter_map(|engine| engine.value().ok())
+            .filter(|range| {
+                range
+                    .as_json_string_value()
+                    .and_then(|range| range.inner_string_text().ok())
+                    .map_or(true, |range| !is_valid_range(range.text()))
+            })
+            .map(InvalidEngines::InvalidRange)
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidEngines::NotAnObject(engines) => RuleDiagnostic::new(
+                rule_category!(),
+                engines.range(),
+                markup! {
+                    "The field "<Emphasis>"engines"</Emphasis>" should be an object."
+                },
+            )
+            .note(markup! {
+                "Map the name of each engine to a semver range, such as "<Emphasis>"{ \"node\": \">=18\" }"</Emphasis>"."
+            }),
+            InvalidEngines::InvalidRange(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range.range(),
+                markup! {
+                    "This version of the engine isn't a valid semver range."
+                },
+            )
+            .note(markup! {
+                "Use a semver range, such as "<Emphasis>">=18"</Emphasis>" or "<Emphasis>"^18.18.0 || >=20.0.0"</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,232 @@
+use crate::package_json::{find_member, is_package_json, manifest_object};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonObjectValue, JsonRoot, TextRange};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow invalid `exports` maps in a `package.json`.
+    ///
+    /// Node.js and the bundlers ignore the entry points of an invalid `exports` map, or fail to
+    /// resolve the imports of the package. The rule reports:
+    /// - the objects that mix subpaths, such as `./utils`, and conditions, such as `import`;
+    /// - the subpaths that don't start with `./`, and the subpaths nested inside other subpaths or
+    ///   conditions;
+    /// - the targets that aren't paths relative to the package, starting with `./`, or that leave
+    ///   the package with `..`;
+    /// - the condition `default` when it isn't the last condition of its object,
+    ///   because the conditions are matched in order.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "exports": {
+    ///     ".": "./dist/index.js",
+    ///     "import": "./dist/index.mjs"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "exports": {
+    ///     "default": "./dist/index.js",
+    ///     "import": "./dist/index.mjs"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "exports": "dist/index.js"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "exports": {
+    ///     ".": {
+    ///       "types": "./dist/index.d.ts",
+    ///       "import": "./dist/index.mjs",
+    ///       "default": "./dist/index.js"
+    ///     },
+    ///     "./utils/*": "./dist/utils/*.js",
+    ///     "./internal/*": null
+    ///   }
+    /// }
+    /// ```
+    pub NoInvalidPackageExports {
+        version: "next",
+        name: "noInvalidPackageExports",
+        recommended: false,
+    }
+}
+
+pub enum InvalidExports {
+    /// An object mixes subpaths and conditions
+    MixedKeys(TextRange),
+    /// A subpath doesn't start with `./`
+    InvalidSubpath(TextRange),
+    /// A subpath is nested inside another subpath or a condition
+    NestedSubpath(TextRange),
+    /// A target isn't a relative path inside the package
+    InvalidTarget(TextRange),
+    /// The condition `default` isn't the last condition
+    DefaultNotLast(TextRange),
+}
+
+impl Rule for NoInvalidPackageExports {
+    type Query = Ast<JsonRoot>;
+    type State = InvalidExports;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Vec::new();
+        }
+        let Some(exports) = manifest_object(ctx.query())
+            .and_then(|manifest| find_member(&manifest, "exports"))
+            .and_then(|exports| exports.value().ok())
+        else {
+            return Vec::new();
+        };
+        let mut signals = Vec::new();
+        check_exports(&exports, true, &mut signals);
+        signals
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidExports::MixedKeys(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This object mixes subpaths and conditions."
+                },
+            )
+            .note(markup! {
+                "Declare the conditions inside the object of the subpath "<Emphasis>"."</Emphasis>"."
+            }),
+            InvalidExports::InvalidSubpath(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This subpath doesn't start with "<Emphasis>"./"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The subpaths are relative to the package, such as "<Emphasis>"."</Emphasis>" or "<Emphasis>"./utils"</Emphasis>"."
+            }),
+            InvalidExports::NestedSubpath(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This subpath is nested inside another subpath or a condition."
+                },
+            )
+            .note(markup! {
+                "The subpaths can only be declared at the root of the "<Emphasis>"exports"</Emphasis>" map."
+            }),
+            InvalidExports::InvalidTarget(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This target isn't a path inside the package."
+                },
+            )
+            .note(markup! {
+                "The targets start with "<Emphasis>"./"</Emphasis>" and don't leave the package, such as "<Emphasis>"./dist/index.js"</Emphasis>"."
+            }),
+            InvalidExports::DefaultNotLast(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The condition "<Emphasis>"default"</Emphasis>" isn't the last condition of this object."
+                },
+            )
+            .note(markup! {
+                "The conditions are matched in order, so the conditions that follow "<Emphasis>"default"</Emphasis>" are never used."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Checks the exports `value`. The subpaths can only be declared at the `root` of the map.
+fn check_exports(value: &AnyJsonValue, root: bool, signals: &mut Vec<InvalidExports>) {
+    match value {
+        AnyJsonValue::JsonStringValue(target) => {
+            let Ok(text) = target.inner_string_text() else {
+                return;
+            };
+            if !is_valid_target(text.text()) {
+                signals.push(InvalidExports::InvalidTarget(target.range()));
+            }
+        }
+        AnyJsonValue::JsonArrayValue(fallbacks) => {
+            for fallback in fallbacks.elements().iter().flatten() {
+                check_exports(&fallback, root, signals);
+            }
+        }
+        AnyJsonValue::JsonObjectValue(object) => check_object(object, root, signals),
+        AnyJsonValue::JsonNullValue(_) | AnyJsonValue::JsonBogusValue(_) => {}
+        AnyJsonValue::JsonBooleanValue(_) | AnyJsonValue::JsonNumberValue(_) => {
+            signals.push(InvalidExports::InvalidTarget(value.range()));
+        }
+    }
+}
+
+/// Checks an object of subpaths or of conditions
+fn check_object(object: &JsonObjectValue, root: bool, signals: &mut Vec<InvalidExports>) {
+    let mut has_subpaths = false;
+    let mut has_conditions = false;
+    let mut default = None;
+    for member in object.json_member_list().iter().flatten() {
+        let Ok(name) = member.name() else {
+            continue;
+        };
+        let Ok(text) = name.inner_string_text() else {
+            continue;
+        };
+        if text.text().starts_with('.') {
+            has_subpaths = true;
+            if !root {
+                signals.push(InvalidExports::NestedSubpath(name.range()));
+            } else if text.text() != "." && !text.text().starts_with("./") {
+                signals.push(InvalidExports::InvalidSubpath(name.range()));
+            }
+        } else {
+            has_conditions = true;
+            if let Some(default) = default.take() {
+                signals.push(InvalidExports::DefaultNotLast(default));
+            }
+            if text.text() == "default" {
+                default = Some(name.range());
+            }
+        }
+        if let Ok(value) = member.value() {
+            // The values of the subpaths and of the conditions can only contain conditions
+            check_exports(&value, false, signals);
+        }
+    }
+    if root && has_subpaths && has_conditions {
+        signals.push(InvalidExports::MixedKeys(object.range()));
+    }
+}
+
+/// Whether `target` is a relative path that doesn't leave the package
+fn is_valid_target(target: &str) -> bool {
+    target.starts_with("./")
+        && target
+            .split('/')
+            .skip(1)
+            .all(|segment| !matches!(segment, "." | ".." | "node_modules"))
+}
@@ -0,0 +1,143 @@
+use crate::package_json::{
+    find_member, find_object, is_package_json, manifest_object, DEPENDENCY_FIELDS,
+};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonMemberName, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Require the packages written in TypeScript to declare their types in the `package.json`.
+    ///
+    /// A package that depends on `typescript` is considered written in TypeScript.
+    /// When it publishes entry points with `main`, `module` or `exports`, it should declare the
+    /// declaration files of its entry points with the field `types`, or with the condition `types`
+    /// of its `exports`. Otherwise, the projects that depend on the package can't find its types.
+    ///
+    /// The private packages aren't published, so they are ignored.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "name": "@acme/ui",
+    ///   "main": "./dist/index.js",
+    ///   "devDependencies": {
+    ///     "typescript": "^5.4.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "name": "@acme/ui",
+    ///   "main": "./dist/index.js",
+    ///   "types": "./dist/index.d.ts",
+    ///   "devDependencies": {
+    ///     "typescript": "^5.4.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "name": "@acme/ui",
+    ///   "exports": {
+    ///     "types": "./dist/index.d.ts",
+    ///     "default": "./dist/index.js"
+    ///   },
+    ///   "devDependencies": {
+    ///     "typescript": "^5.4.0"
+    ///   }
+    /// }
+    /// ```
+    pub UsePackageTypes {
+        version: "next",
+        name: "usePackageTypes",
+        recommended: false,
+    }
+}
+
+impl Rule for UsePackageTypes {
+    type Query = Ast<JsonRoot>;
+    /// The declaration of the dependency `typescript`
+    type State = JsonMemberName;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return None;
+        }
+        let manifest = manifest_object(ctx.query())?;
+        let is_private = find_member(&manifest, "private")
+            .and_then(|private| private.value().ok())
+            .and_then(|private| private.as_json_boolean_value()?.value_token().ok())
+            .is_some_and(|private| private.text_trimmed() == "true");
+        let has_entry_points = ["main", "module", "exports"]
+            .iter()
+            .any(|field| find_member(&manifest, field).is_some());
+        if is_private || !has_entry_points {
+            return None;
+        }
+        if ["types", "typings", "typesVersions"]
+            .iter()
+            .any(|field| find_member(&manifest, field).is_some())
+        {
+            return None;
+        }
+        let exports = find_member(&manifest, "exports").and_then(|exports| exports.value().ok());
+        if exports.as_ref().is_some_and(has_types_condition) {
+            return None;
+        }
+        DEPENDENCY_FIELDS.iter().find_map(|field| {
+            find_member(&find_object(&manifest, field)?, "typescript")?
+                .name()
+                .ok()
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, typescript: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                typescript.range(),
+                markup! {
+                    "This package is written in TypeScript, but its manifest doesn't declare its types."
+                },
+            )
+            .note(markup! {
+                "Add the field "<Emphasis>"types"</Emphasis>", or the condition "<Emphasis>"types"</Emphasis>" to the "<Emphasis>"exports"</Emphasis>", with the path of the declaration file of the entry point."
+            }),
+        )
+    }
+}
+
+/// Whether an exports map declares the condition `types`
+fn has_types_condition(exports: &AnyJsonValue) -> bool {
+    match exports {
+        AnyJsonValue::JsonObjectValue(object) => {
+            object.json_member_list().iter().flatten().any(|member| {
+                member
+                    .name()
+                    .and_then(|name| name.inner_string_text())
+                    .is_ok_and(|name| name.text() == "types")
+                    || member
+                        .value()
+                        .is_ok_and(|value| has_types_condition(&value))
+            })
+        }
+        AnyJsonValue::JsonArrayValue(fallbacks) => fallbacks
+            .elements()
+            .iter()
+            .flatten()
+            .any(|fallback| has_types_condition(&fallback)),
+        _ => false,
+    }
+}
@@ -0,0 +1,160 @@
+use crate::package_json::{
+    find_object, is_package_json, manifest_object, DEPENDENCY_FIELDS, KEY_ORDER,
+};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_json_syntax::{JsonMember, JsonObjectValue, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Enforce the canonical order of the fields of a `package.json`.
+    ///
+    /// The fields are sorted in the order used by
+    /// [sort-package-json](https://github.com/keithamus/sort-package-json): the name and the
+    /// description of the package come first, then its entry points, its scripts, its dependencies
+    /// and the configuration of the tools. The fields that aren't known follow them.
+    /// The packages of `dependencies`, `devDependencies`, `peerDependencies` and
+    /// `optionalDependencies` are sorted alphabetically, like package managers write them.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "version": "1.0.0",
+    ///   "name": "@acme/ui",
+    ///   "dependencies": {
+    ///     "react-dom": "^18.2.0",
+    ///     "react": "^18.2.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "name": "@acme/ui",
+    ///   "version": "1.0.0",
+    ///   "dependencies": {
+    ///     "react": "^18.2.0",
+    ///     "react-dom": "^18.2.0"
+    ///   }
+    /// }
+    /// ```
+    pub UseSortedPackageJsonKeys {
+        version: "next",
+        name: "useSortedPackageJsonKeys",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+pub struct UnsortedObject {
+    /// The members of the object, in their current order
+    members: Vec<JsonMember>,
+    /// The members of the object, in their expected order
+    sorted: Vec<JsonMember>,
+}
+
+impl Rule for UseSortedPackageJsonKeys {
+    type Query = Ast<JsonRoot>;
+    type State = UnsortedObject;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Vec::new();
+        }
+        let Some(manifest) = manifest_object(ctx.query()) else {
+            return Vec::new();
+        };
+        let mut signals = Vec::new();
+        signals.extend(sort_members(&manifest, |name| {
+            // The fields that aren't known keep their order after the known fields
+            KEY_ORDER
+                .iter()
+                .position(|key| *key == name)
+                .unwrap_or(KEY_ORDER.len())
+        }));
+        for field in DEPENDENCY_FIELDS {
+            if let Some(dependencies) = find_object(&manifest, field) {
+                signals.extend(sort_members(&dependencies, |name| name.to_string()));
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnsortedObject { members, sorted } = state;
+        // Report the first member that isn't at its place
+        let (member, expected) = members
+            .iter()
+            .zip(sorted)
+            .find(|(member, expected)| member != expected)?;
+        let name = expected.name().ok()?.inner_string_text().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                member.name().ok()?.range(),
+                markup! {
+                    "The keys of this object aren't sorted."
+                },
+            )
+            .note(markup! {
+                "The key "<Emphasis>{name.text()}</Emphasis>" should be declared here."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        let UnsortedObject { members, sorted } = state;
+        let mut mutation = ctx.root().begin();
+        for (member, expected) in members.iter().zip(sorted) {
+            if member != expected {
+                // The members keep their comments
+                mutation.replace_node_discard_trivia(member.clone(), expected.clone());
+            }
+        }
+        Some(JsonRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "Sort the keys." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the members of `object` sorted by `key`, when they aren't sorted yet
+fn sort_members<K: Ord>(
+    object: &JsonObjectValue,
+    key: impl Fn(&str) -> K,
+) -> Option<UnsortedObject> {
+    let members = object
+        .json_member_list()
+        .iter()
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    let mut keys = Vec::with_capacity(members.len());
+    for member in &members {
+        keys.push(key(member.name().ok()?.inner_string_text().ok()?.text()));
+    }
+    if keys.windows(2).all(|pair| pair[0] <= pair[1]) {
+        return None;
+    }
+    let mut sorted: Vec<_> = keys.into_iter().zip(members.iter().cloned()).collect();
+    // The sort is stable, so the keys that compare equal keep their order
+    sorted.sort_by(|(first, _), (second, _)| first.cmp(second));
+    Some(UnsortedObject {
+        members,
+        sorted: sorted.into_iter().map(|(_, member)| member).collect(),
+    })
+}
@@ -2,5 +2,12 @@
 
 use crate::lint;
 
+pub type NoDuplicateDependencies = < lint :: nursery :: no_duplicate_dependencies :: NoDuplicateDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateJsonKeys =
     <lint::nursery::no_duplicate_json_keys::NoDuplicateJsonKeys as biome_analyze::Rule>::Options;
+pub type NoInvalidDependencyVersions = < lint :: nursery :: no_invalid_dependency_versions :: NoInvalidDependencyVersions as biome_analyze :: Rule > :: Options ;
+pub type NoInvalidPackageEngines = < lint :: nursery :: no_invalid_package_engines :: NoInvalidPackageEngines as biome_analyze :: Rule > :: Options ;
+pub type NoInvalidPackageExports = < lint :: nursery :: no_invalid_package_exports :: NoInvalidPackageExports as biome_analyze :: Rule > :: Options ;
+pub type UsePackageTypes =
+    <lint::nursery::use_package_types::UsePackageTypes as biome_analyze::Rule>::Options;
+pub type UseSortedPackageJsonKeys = < lint :: nursery :: use_sorted_package_json_keys :: UseSortedPackageJsonKeys as biome_analyze :: Rule > :: Options ;
@@ -0,0 +1,260 @@
+//! Utilities shared by the rules that lint the `package.json` manifests.
+
+use biome_json_syntax::{AnyJsonValue, JsonMember, JsonObjectValue, JsonRoot};
+use biome_rowan::AstSeparatedList;
+use std::ffi::OsStr;
+use std::path::Path;
+
+/// The fields of a `package.json` that map the names of packages to version specifiers
+pub(crate) const DEPENDENCY_FIELDS: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// The canonical order of the fields of a `package.json`, based on the order used by
+/// [sort-package-json](https://github.com/keithamus/sort-package-json).
+/// The other fields follow them.
+pub(crate) const KEY_ORDER: &[&str] = &[
+    "$schema",
+    "name",
+    "displayName",
+    "version",
+    "private",
+    "description",
+    "categories",
+    "keywords",
+    "homepage",
+    "bugs",
+    "repository",
+    "funding",
+    "license",
+    "author",
+    "maintainers",
+    "contributors",
+    "publisher",
+    "sideEffects",
+    "type",
+    "imports",
+    "exports",
+    "main",
+    "svelte",
+    "umd:main",
+    "jsdelivr",
+    "unpkg",
+    "module",
+    "source",
+    "jsnext:main",
+    "browser",
+    "react-native",
+    "types",
+    "typesVersions",
+    "typings",
+    "style",
+    "example",
+    "examplestyle",
+    "assets",
+    "bin",
+    "man",
+    "directories",
+    "files",
+    "workspaces",
+    "binary",
+    "scripts",
+    "betterScripts",
+    "contributes",
+    "activationEvents",
+    "husky",
+    "simple-git-hooks",
+    "pre-commit",
+    "commitlint",
+    "lint-staged",
+    "config",
+    "nodemonConfig",
+    "browserify",
+    "babel",
+    "browserslist",
+    "xo",
+    "prettier",
+    "eslintConfig",
+    "eslintIgnore",
+    "npmpackagejsonlint",
+    "release",
+    "remarkConfig",
+    "stylelint",
+    "ava",
+    "jest",
+    "mocha",
+    "nyc",
+    "tap",
+    "resolutions",
+    "dependencies",
+    "devDependencies",
+    "dependenciesMeta",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "bundledDependencies",
+    "bundleDependencies",
+    "extensionPack",
+    "extensionDependencies",
+    "flat",
+    "packageManager",
+    "engines",
+    "engineStrict",
+    "volta",
+    "languageName",
+    "os",
+    "cpu",
+    "preferGlobal",
+    "publishConfig",
+    "icon",
+    "badges",
+    "galleryBanner",
+    "preview",
+    "markdown",
+    "pnpm",
+    "overrides",
+];
+
+/// The protocols of the version specifiers that don't use a semver range
+const PROTOCOLS: &[&str] = &[
+    "npm:",
+    "file:",
+    "link:",
+    "portal:",
+    "patch:",
+    "catalog:",
+    "jsr:",
+    "git:",
+    "git+",
+    "github:",
+    "gitlab:",
+    "bitbucket:",
+    "gist:",
+    "http:",
+    "https:",
+];
+
+/// Whether the file at `path` is a `package.json`
+pub(crate) fn is_package_json(path: &Path) -> bool {
+    path.file_name() == Some(OsStr::new("package.json"))
+}
+
+/// Returns the object at the root of the manifest
+pub(crate) fn manifest_object(root: &JsonRoot) -> Option<JsonObjectValue> {
+    match root.value().ok()? {
+        AnyJsonValue::JsonObjectValue(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// Returns the first member of `object` named `name`
+pub(crate) fn find_member(object: &JsonObjectValue, name: &str) -> Option<JsonMember> {
+    object.json_member_list().iter().flatten().find(|member| {
+        member
+            .name()
+            .and_then(|name| name.inner_string_text())
+            .is_ok_and(|text| text.text() == name)
+    })
+}
+
+/// Returns the value of the member of `object` named `name`, when it's an object
+pub(crate) fn find_object(object: &JsonObjectValue, name: &str) -> Option<JsonObjectValue> {
+    match find_member(object, name)?.value().ok()? {
+        AnyJsonValue::JsonObjectValue(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// Whether `range` is a valid semver range, such as `^1.2.0` or `>=18 <20`
+pub(crate) fn is_valid_range(range: &str) -> bool {
+    // npm reads an empty range as `*`
+    range.trim().is_empty() || node_semver::Range::parse(range).is_ok()
+}
+
+/// Whether `specifier` is a valid version specifier of a dependency: a semver range, a dist-tag,
+/// a protocol such as `workspace:` or `npm:`, a path, or a GitHub repository
+pub(crate) fn is_valid_version_specifier(specifier: &str) -> bool {
+    if let Some(range) = specifier.strip_prefix("workspace:") {
+        return matches!(range, "*" | "^" | "~") || is_valid_range(range);
+    }
+    if PROTOCOLS
+        .iter()
+        .any(|protocol| specifier.starts_with(protocol))
+        || ["./", "../", "/", "~/"]
+            .iter()
+            .any(|prefix| specifier.starts_with(prefix))
+    {
+        return true;
+    }
+    if is_valid_range(specifier) {
+        return true;
+    }
+    if let Some((owner, repository)) = specifier.split_once('/') {
+        // A GitHub repository, such as `biomejs/biome#main`
+        return !owner.is_empty()
+            && !owner.starts_with('@')
+            && !repository.is_empty()
+            && !specifier.contains(char::is_whitespace);
+    }
+    is_dist_tag(specifier)
+}
+
+/// Whether `specifier` is a dist-tag, such as `latest` or `next`. A dist-tag can't look like a
+/// version or a range.
+fn is_dist_tag(specifier: &str) -> bool {
+    let mut chars = specifier.chars();
+    let first = chars.next();
+    let looks_like_version = match first {
+        Some('v') => chars.next().is_some_and(|char| char.is_ascii_digit()),
+        Some(char) => char.is_ascii_digit() || matches!(char, '=' | '<' | '>' | '^' | '~'),
+        None => true,
+    };
+    !looks_like_version
+        && specifier.chars().all(|char| {
+            char.is_ascii_alphanumeric() || matches!(char, '-' | '_' | '.' | '!' | '*' | '(' | ')')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_version_specifier;
+
+    #[test]
+    fn validates_the_version_specifiers() {
+        for specifier in [
+            "",
+            "*",
+            "^1.2.3",
+            "~1.2",
+            ">=1.0.0 <2.0.0",
+            "1.2.3 - 2.3.4",
+            "^1 || ^2",
+            "latest",
+            "next",
+            "workspace:*",
+            "workspace:^1.0.0",
+            "npm:react@^18",
+            "file:../utils",
+            "./packages/utils",
+            "github:biomejs/biome",
+            "biomejs/biome#main",
+            "https://example.com/package.tgz",
+        ] {
+            assert!(is_valid_version_specifier(specifier), "{specifier:?}");
+        }
+        for specifier in [
+            "^1.2.3.4",
+            "1.2.3.4",
+            ">=abc",
+            "^^1",
+            "workspace:next",
+            "v1.2.3.4",
+            "not a version",
+        ] {
+            assert!(!is_valid_version_specifier(specifier), "{specifier:?}");
+        }
+    }
+}
@@ -0,0 +1,754 @@
+//! Validation of the JSON documents against a [JSON Schema](https://json-schema.org).
+//!
+//! The validator supports the keywords that describe the structure of a document: `type`,
+//! `enum`, `const`, `properties`, `required`, `additionalProperties`, `patternProperties`,
+//! `items`, `prefixItems`, the bounds of the numbers, the strings and the arrays, `pattern`,
+//! the combinations `allOf`, `anyOf`, `oneOf`, `not`, `if`/`then`/`else`, and the references
+//! to the definitions of the same schema with `$ref`. The other keywords are ignored, so the
+//! validator never reports a document that a complete validator would accept because of them.
+//!
+//! The patterns are compiled when the schema is read. The ECMAScript features that the
+//! [regex] crate doesn't support, such as the lookarounds and the backreferences, make the
+//! schema invalid.
+
+use biome_console::{markup, Markup};
+use biome_diagnostics::{Diagnostic, MessageAndDescription};
+use biome_json_syntax::{AnyJsonValue, JsonObjectValue, JsonRoot, TextRange};
+use biome_rowan::{AstNode, AstSeparatedList};
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use serde_json::{Map, Value};
+
+/// The maximum number of nested `$ref`, which stops the validation of the recursive schemas
+/// that reference themselves without validating a nested value.
+const MAX_REFERENCE_DEPTH: usize = 32;
+
+/// The keywords whose values are data instead of schemas, so their `pattern` keys aren't
+/// regular expressions
+const DATA_KEYWORDS: &[&str] = &["const", "default", "enum", "examples"];
+
+/// A JSON Schema that validates JSON documents
+#[derive(Debug)]
+pub struct JsonSchema {
+    root: Value,
+    /// The compiled `pattern` and `patternProperties` of the schema
+    patterns: FxHashMap<String, Regex>,
+}
+
+impl JsonSchema {
+    /// Reads the JSON Schema of `content`, and compiles its patterns
+    pub fn parse(content: &str) -> Result<Self, JsonSchemaError> {
+        let root = serde_json::from_str(content).map_err(JsonSchemaError::Json)?;
+        let mut patterns = FxHashMap::default();
+        compile_patterns(&root, &mut patterns)?;
+        Ok(Self { root, patterns })
+    }
+
+    /// Validates the document `root`, and returns a diagnostic for each value that the schema
+    /// doesn't allow
+    pub fn validate(&self, root: &JsonRoot) -> Vec<JsonSchemaDiagnostic> {
+        let mut validator = Validator {
+            root: &self.root,
+            patterns: &self.patterns,
+            diagnostics: Vec::new(),
+            reference_depth: 0,
+        };
+        if let Ok(value) = root.value() {
+            validator.validate(&value, &self.root, true);
+        }
+        validator.diagnostics
+    }
+}
+
+/// Adds the patterns of `schema`, and of its nested schemas, to `patterns`
+fn compile_patterns(
+    schema: &Value,
+    patterns: &mut FxHashMap<String, Regex>,
+) -> Result<(), JsonSchemaError> {
+    let mut compile = |pattern: &str| {
+        if !patterns.contains_key(pattern) {
+            let regex = Regex::new(pattern).map_err(|error| JsonSchemaError::Pattern {
+                pattern: pattern.to_string(),
+                error,
+            })?;
+            patterns.insert(pattern.to_string(), regex);
+        }
+        Ok(())
+    };
+    match schema {
+        Value::Object(schema) => {
+            if let Some(Value::String(pattern)) = schema.get("pattern") {
+                compile(pattern)?;
+            }
+            if let Some(Value::Object(properties)) = schema.get("patternProperties") {
+                for pattern in properties.keys() {
+                    compile(pattern)?;
+                }
+            }
+            for (keyword, value) in schema {
+                if !DATA_KEYWORDS.contains(&keyword.as_str()) {
+                    compile_patterns(value, patterns)?;
+                }
+            }
+        }
+        Value::Array(schemas) => {
+            for schema in schemas {
+                compile_patterns(schema, patterns)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// The reason why a JSON Schema can't be read
+#[derive(Debug)]
+pub enum JsonSchemaError {
+    /// The schema isn't valid JSON
+    Json(serde_json::Error),
+    /// A `pattern`, or a key of `patternProperties`, isn't a supported regular expression
+    Pattern {
+        pattern: String,
+        error: regex::Error,
+    },
+}
+
+/// A value of the document that the JSON Schema doesn't allow
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "jsonSchema", severity = Error)]
+pub struct JsonSchemaDiagnostic {
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+
+    #[location(span)]
+    range: Option<TextRange>,
+}
+
+impl JsonSchemaDiagnostic {
+    /// The JSON Schema at `path` couldn't be read
+    pub fn not_found(path: &str) -> Self {
+        Self::new(markup! {
+            "The JSON Schema "<Emphasis>{path}</Emphasis>" couldn't be read."
+        })
+    }
+
+    /// The JSON Schema at `path` can't be read, because of `error`
+    pub fn invalid_schema(path: &str, error: &JsonSchemaError) -> Self {
+        match error {
+            JsonSchemaError::Json(error) => Self::new(markup! {
+                "The JSON Schema "<Emphasis>{path}</Emphasis>" isn't valid JSON: "{error.to_string()}
+            }),
+            JsonSchemaError::Pattern { pattern, error } => {
+                // The last line of the error of the syntax describes it, the others point to it
+                let error = error.to_string();
+                let reason = error.lines().last().unwrap_or_default();
+                let reason = reason.strip_prefix("error: ").unwrap_or(reason);
+                Self::new(markup! {
+                    "The pattern "<Emphasis>{pattern}</Emphasis>" of the JSON Schema "<Emphasis>{path}</Emphasis>" can't be compiled: "{reason}"."
+                })
+            }
+        }
+    }
+
+    fn new(message: Markup) -> Self {
+        Self {
+            message: MessageAndDescription::from(message.to_owned()),
+            range: None,
+        }
+    }
+
+    fn with_range(mut self, range: TextRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+}
+
+struct Validator<'a> {
+    /// The root of the schema, used to resolve the references
+    root: &'a Value,
+    patterns: &'a FxHashMap<String, Regex>,
+    diagnostics: Vec<JsonSchemaDiagnostic>,
+    reference_depth: usize,
+}
+
+impl<'a> Validator<'a> {
+    /// Validates `value` against `schema`. The `$schema` key is always allowed at the root of the
+    /// document.
+    fn validate(&mut self, value: &AnyJsonValue, schema: &'a Value, is_root: bool) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                self.report(value.range(), markup! { "This value isn't allowed." });
+                return;
+            }
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+        if matches!(value, AnyJsonValue::JsonBogusValue(_)) {
+            return;
+        }
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            if let Some(target) = self.resolve(reference) {
+                if self.reference_depth < MAX_REFERENCE_DEPTH {
+                    self.reference_depth += 1;
+                    self.validate(value, target, is_root);
+                    self.reference_depth -= 1;
+                }
+            }
+        }
+
+        if let Some(expected) = schema.get("type") {
+            if !matches_type(value, expected) {
+                let expected = type_names(expected).join(" or ");
+                self.report(
+                    value.range(),
+                    markup! {
+                        "Expected a value of type "<Emphasis>{expected}</Emphasis>", but found "<Emphasis>{kind(value)}</Emphasis>"."
+                    },
+                );
+                // The other keywords would report the same value again
+                return;
+            }
+        }
+
+        if let Some(Value::Array(values)) = schema.get("enum") {
+            if !values.iter().any(|expected| equals(value, expected)) {
+                let values = values
+                    .iter()
+                    .map(Value::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                self.report(
+                    value.range(),
+                    markup! {
+                        "Expected one of the values: "<Emphasis>{values}</Emphasis>"."
+                    },
+                );
+            }
+        }
+        if let Some(expected) = schema.get("const") {
+            if !equals(value, expected) {
+                self.report(
+                    value.range(),
+                    markup! {
+                        "Expected the value "<Emphasis>{expected.to_string()}</Emphasis>"."
+                    },
+                );
+            }
+        }
+
+        match value {
+            AnyJsonValue::JsonObjectValue(object) => self.validate_object(object, schema, is_root),
+            AnyJsonValue::JsonArrayValue(array) => {
+                let elements = array.elements().iter().flatten().collect::<Vec<_>>();
+                self.validate_array(value, &elements, schema);
+            }
+            AnyJsonValue::JsonStringValue(string) => {
+                if let Ok(text) = string.inner_string_text() {
+                    self.validate_string(value, text.text(), schema);
+                }
+            }
+            AnyJsonValue::JsonNumberValue(number) => {
+                if let Some(number) = number
+                    .value_token()
+                    .ok()
+                    .and_then(|token| token.text_trimmed().parse::<f64>().ok())
+                {
+                    self.validate_number(value, number, schema);
+                }
+            }
+            _ => {}
+        }
+
+        self.validate_combinations(value, schema, is_root);
+    }
+
+    fn validate_object(
+        &mut self,
+        object: &JsonObjectValue,
+        schema: &'a Map<String, Value>,
+        is_root: bool,
+    ) {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let pattern_properties = schema
+            .get("patternProperties")
+            .and_then(Value::as_object)
+            .map(|patterns| {
+                patterns
+                    .iter()
+                    .filter_map(|(pattern, schema)| Some((self.patterns.get(pattern)?, schema)))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let additional_properties = schema.get("additionalProperties");
+
+        let mut names = Vec::new();
+        for member in object.json_member_list().iter().flatten() {
+            let (Ok(name), Ok(value)) = (member.name(), member.value()) else {
+                continue;
+            };
+            let Ok(text) = name.inner_string_text() else {
+                continue;
+            };
+            let text = text.text();
+            names.push(text.to_string());
+
+            let mut is_known = false;
+            if let Some(schema) = properties.and_then(|properties| properties.get(text)) {
+                is_known = true;
+                self.validate(&value, schema, false);
+            }
+            for (pattern, schema) in &pattern_properties {
+                if pattern.is_match(text) {
+                    is_known = true;
+                    self.validate(&value, schema, false);
+                }
+            }
+            if is_known || (is_root && text == "$schema") {
+                continue;
+            }
+            match additional_properties {
+                Some(Value::Bool(false)) => self.report(
+                    name.range(),
+                    markup! {
+                        "The property "<Emphasis>{text}</Emphasis>" isn't allowed."
+                    },
+                ),
+                Some(schema) => self.validate(&value, schema, false),
+                None => {}
+            }
+        }
+
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for property in required.iter().filter_map(Value::as_str) {
+                if !names.iter().any(|name| name == property) {
+                    let range = object
+                        .l_curly_token()
+                        .map_or_else(|_| object.range(), |token| token.text_trimmed_range());
+                    self.report(
+                        range,
+                        markup! {
+                            "The property "<Emphasis>{property}</Emphasis>" is missing."
+                        },
+                    );
+                }
+            }
+        }
+        if let Some(min) = schema.get("minProperties").and_then(Value::as_u64) {
+            if (names.len() as u64) < min {
+                self.report(
+                    object.range(),
+                    markup! {
+                        "Expected an object with at least "{min}" properties."
+                    },
+                );
+            }
+        }
+        if let Some(max) = schema.get("maxProperties").and_then(Value::as_u64) {
+            if (names.len() as u64) > max {
+                self.report(
+                    object.range(),
+                    markup! {
+                        "Expected an object with at most "{max}" properties."
+                    },
+                );
+            }
+        }
+    }
+
+    fn validate_array(
+        &mut self,
+        value: &AnyJsonValue,
+        elements: &[AnyJsonValue],
+        schema: &'a Map<String, Value>,
+    ) {
+        // `prefixItems` replaced the array form of `items` in the draft 2020-12
+        let (prefix, items) = match (schema.get("prefixItems"), schema.get("items")) {
+            (Some(Value::Array(prefix)), items) => (prefix.as_slice(), items),
+            (_, Some(Value::Array(prefix))) => (prefix.as_slice(), schema.get("additionalItems")),
+            (_, items) => (&[][..], items),
+        };
+        for (index, element) in elements.iter().enumerate() {
+            if let Some(schema) = prefix.get(index).or(items) {
+                self.validate(element, schema, false);
+            }
+        }
+
+        let len = elements.len() as u64;
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if len < min {
+                self.report(
+                    value.range(),
+                    markup! {
+                        "Expected an array of at least "{min}" items."
+                    },
+                );
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if len > max {
+                self.report(
+                    value.range(),
+                    markup! {
+                        "Expected an array of at most "{max}" items."
+                    },
+                );
+            }
+        }
+    }
+
+    fn validate_string(&mut self, value: &AnyJsonValue, text: &str, schema: &Map<String, Value>) {
+        let len = text.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+            if len < min {
+                self.report(
+                    value.range(),
+                    markup! {
+                        "Expected a string of at least "{min}" characters."
+                    },
+                );
+            }
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+            if len > max {
+                self.report(
+                    value.range(),
+                    markup! {
+                        "Expected a string of at most "{max}" characters."
+                    },
+                );
+            }
+        }
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+            let regex = self.patterns.get(pattern);
+            if regex.is_some_and(|regex| !regex.is_match(text)) {
+                self.report(
+                    value.range(),
+                    markup! {
+                        "Expected a string that matches the pattern "<Emphasis>{pattern}</Emphasis>"."
+                    },
+                );
+            }
+        }
+    }
+
+    fn validate_number(&mut self, value: &AnyJsonValue, number: f64, schema: &Map<String, Value>) {
+        let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+        if let Some(minimum) = bound("minimum").filter(|minimum| number < *minimum) {
+            self.report(
+                value.range(),
+                markup! {
+                    "Expected a number greater than or equal to "{minimum.to_string()}"."
+                },
+            );
+        }
+        if let Some(maximum) = bound("maximum").filter(|maximum| number > *maximum) {
+            self.report(
+                value.range(),
+                markup! {
+                    "Expected a number less than or equal to "{maximum.to_string()}"."
+                },
+            );
+        }
+        if let Some(minimum) = bound("exclusiveMinimum").filter(|minimum| number <= *minimum) {
+            self.report(
+                value.range(),
+                markup! {
+                    "Expected a number greater than "{minimum.to_string()}"."
+                },
+            );
+        }
+        if let Some(maximum) = bound("exclusiveMaximum").filter(|maximum| number >= *maximum) {
+            self.report(
+                value.range(),
+                markup! {
+                    "Expected a number less than "{maximum.to_string()}"."
+                },
+            );
+        }
+    }
+
+    fn validate_combinations(
+        &mut self,
+        value: &AnyJsonValue,
+        schema: &'a Map<String, Value>,
+        is_root: bool,
+    ) {
+        if let Some(Value::Array(schemas)) = schema.get("allOf") {
+            for schema in schemas {
+                self.validate(value, schema, is_root);
+            }
+        }
+        // `oneOf` is validated like `anyOf`: a value that matches several schemas isn't reported
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(schemas)) = schema.get(keyword) {
+                self.validate_alternatives(value, schemas, is_root);
+            }
+        }
+        if let Some(schema) = schema.get("not") {
+            if self.try_validate(value, schema, is_root).is_empty() {
+                self.report(value.range(), markup! { "This value isn't allowed." });
+            }
+        }
+        if let Some(condition) = schema.get("if") {
+            let branch = if self.try_validate(value, condition, is_root).is_empty() {
+                schema.get("then")
+            } else {
+                schema.get("else")
+            };
+            if let Some(branch) = branch {
+                self.validate(value, branch, is_root);
+            }
+        }
+    }
+
+    /// Validates `value` against the alternatives `schemas`, which it must match at least once
+    fn validate_alternatives(&mut self, value: &AnyJsonValue, schemas: &'a [Value], is_root: bool) {
+        let mut candidates = Vec::new();
+        for schema in schemas {
+            let diagnostics = self.try_validate(value, schema, is_root);
+            if diagnostics.is_empty() {
+                return;
+            }
+            if self.accepts_type(value, schema) {
+                candidates.push(diagnostics);
+            }
+        }
+        if candidates.len() == 1 {
+            // Only one alternative accepts the type of the value, so its diagnostics are more
+            // precise than a diagnostic for the whole value
+            self.diagnostics
+                .extend(candidates.pop().unwrap_or_default());
+        } else {
+            self.report(
+                value.range(),
+                markup! { "This value doesn't match any of the allowed schemas." },
+            );
+        }
+    }
+
+    /// Validates `value` against `schema`, and returns the diagnostics instead of reporting them
+    fn try_validate(
+        &mut self,
+        value: &AnyJsonValue,
+        schema: &'a Value,
+        is_root: bool,
+    ) -> Vec<JsonSchemaDiagnostic> {
+        let reported = std::mem::take(&mut self.diagnostics);
+        self.validate(value, schema, is_root);
+        std::mem::replace(&mut self.diagnostics, reported)
+    }
+
+    /// Whether the `type` of `schema`, or of the schema it references, accepts `value`
+    fn accepts_type(&self, value: &AnyJsonValue, schema: &'a Value) -> bool {
+        let mut schema = schema;
+        for _ in 0..MAX_REFERENCE_DEPTH {
+            if let Some(expected) = schema.get("type") {
+                return matches_type(value, expected);
+            }
+            match schema
+                .get("$ref")
+                .and_then(Value::as_str)
+                .and_then(|reference| self.resolve(reference))
+            {
+                Some(target) => schema = target,
+                None => return true,
+            }
+        }
+        true
+    }
+
+    /// Resolves a reference to a definition of the schema, such as `#/definitions/rule`.
+    /// The references to other schemas aren't supported.
+    fn resolve(&self, reference: &str) -> Option<&'a Value> {
+        let pointer = reference.strip_prefix('#')?;
+        self.root.pointer(&pointer.replace("%25", "%"))
+    }
+
+    fn report(&mut self, range: TextRange, message: Markup) {
+        self.diagnostics
+            .push(JsonSchemaDiagnostic::new(message).with_range(range));
+    }
+}
+
+/// Whether `value` has one of the types `expected`
+fn matches_type(value: &AnyJsonValue, expected: &Value) -> bool {
+    let matches = |expected: &str| match (expected, value) {
+        ("object", AnyJsonValue::JsonObjectValue(_))
+        | ("array", AnyJsonValue::JsonArrayValue(_))
+        | ("string", AnyJsonValue::JsonStringValue(_))
+        | ("number", AnyJsonValue::JsonNumberValue(_))
+        | ("boolean", AnyJsonValue::JsonBooleanValue(_))
+        | ("null", AnyJsonValue::JsonNullValue(_))
+        | (_, AnyJsonValue::JsonBogusValue(_)) => true,
+        ("integer", AnyJsonValue::JsonNumberValue(number)) => number
+            .value_token()
+            .ok()
+            .and_then(|token| token.text_trimmed().parse::<f64>().ok())
+            .is_some_and(|number| number.fract() == 0.0),
+        _ => false,
+    };
+    match expected {
+        Value::String(expected) => matches(expected),
+        Value::Array(expected) => expected.iter().filter_map(Value::as_str).any(matches),
+        _ => true,
+    }
+}
+
+/// The names of the types `expected`
+fn type_names(expected: &Value) -> Vec<&str> {
+    match expected {
+        Value::String(expected) => vec![expected.as_str()],
+        Value::Array(expected) => expected.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The name of the type of `value`
+fn kind(value: &AnyJsonValue) -> &'static str {
+    match value {
+        AnyJsonValue::JsonObjectValue(_) => "object",
+        AnyJsonValue::JsonArrayValue(_) => "array",
+        AnyJsonValue::JsonStringValue(_) => "string",
+        AnyJsonValue::JsonNumberValue(_) => "number",
+        AnyJsonValue::JsonBooleanValue(_) => "boolean",
+        AnyJsonValue::JsonNullValue(_) => "null",
+        AnyJsonValue::JsonBogusValue(_) => "unknown",
+    }
+}
+
+/// Whether `value` is equal to the JSON value `expected`
+fn equals(value: &AnyJsonValue, expected: &Value) -> bool {
+    match (value, expected) {
+        (AnyJsonValue::JsonNullValue(_), Value::Null) => true,
+        (AnyJsonValue::JsonBooleanValue(boolean), Value::Bool(expected)) => boolean
+            .value_token()
+            .is_ok_and(|token| token.text_trimmed() == expected.to_string()),
+        (AnyJsonValue::JsonNumberValue(number), Value::Number(expected)) => number
+            .value_token()
+            .ok()
+            .and_then(|token| token.text_trimmed().parse::<f64>().ok())
+            .is_some_and(|number| Some(number) == expected.as_f64()),
+        (AnyJsonValue::JsonStringValue(string), Value::String(expected)) => string
+            .inner_string_text()
+            .is_ok_and(|text| text.text() == expected),
+        (AnyJsonValue::JsonArrayValue(array), Value::Array(expected)) => {
+            let elements = array.elements().iter().flatten().collect::<Vec<_>>();
+            elements.len() == expected.len()
+                && elements
+                    .iter()
+                    .zip(expected)
+                    .all(|(element, expected)| equals(element, expected))
+        }
+        (AnyJsonValue::JsonObjectValue(object), Value::Object(expected)) => {
+            let members = object
+                .json_member_list()
+                .iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            members.len() == expected.len()
+                && members.iter().all(|member| {
+                    let (Ok(name), Ok(value)) = (member.name(), member.value()) else {
+                        return false;
+                    };
+                    name.inner_string_text().is_ok_and(|name| {
+                        expected
+                            .get(name.text())
+                            .is_some_and(|expected| equals(&value, expected))
+                    })
+                })
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsonSchema, JsonSchemaError};
+    use biome_diagnostics::PrintDescription;
+    use biome_json_parser::{parse_json, JsonParserOptions};
+
+    fn validate(schema: &str, document: &str) -> Vec<String> {
+        let schema = JsonSchema::parse(schema).unwrap();
+        let root = parse_json(document, JsonParserOptions::default()).tree();
+        schema
+            .validate(&root)
+            .into_iter()
+            .map(|diagnostic| {
+                let range = diagnostic.range.unwrap();
+                format!("{}: {}", &document[range], PrintDescription(&diagnostic))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn validates_the_documents() {
+        let schema = r##"{
+            "type": "object",
+            "properties": {
+                "$schema": { "type": "string" },
+                "name": { "type": "string", "minLength": 1 },
+                "level": { "enum": ["error", "warn", "off"] },
+                "port": { "type": "integer", "minimum": 1, "maximum": 65535 },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "rule": { "$ref": "#/definitions/rule" }
+            },
+            "required": ["name"],
+            "additionalProperties": false,
+            "definitions": {
+                "rule": {
+                    "anyOf": [
+                        { "type": "string" },
+                        {
+                            "type": "object",
+                            "properties": { "fix": { "type": "boolean" } },
+                            "additionalProperties": false
+                        }
+                    ]
+                }
+            }
+        }"##;
+
+        assert!(validate(
+            schema,
+            r#"{ "$schema": "./schema.json", "name": "app", "level": "warn", "port": 8080, "tags": ["a"], "rule": { "fix": true } }"#
+        )
+        .is_empty());
+        assert_eq!(
+            validate(
+                schema,
+                r#"{ "level": "info", "port": 80.5, "tags": ["a", 1], "rule": { "fixx": true }, "extra": null }"#
+            ),
+            vec![
+                r#""info": Expected one of the values: "error", "warn", "off"."#,
+                "80.5: Expected a value of type integer, but found number.",
+                "1: Expected a value of type string, but found number.",
+                r#""fixx": The property fixx isn't allowed."#,
+                r#""extra": The property extra isn't allowed."#,
+                "{: The property name is missing.",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_the_unsupported_patterns() {
+        let schema = r#"{
+            "properties": {
+                "name": { "type": "string", "pattern": "^(?!internal-)" }
+            },
+            "default": { "pattern": "(?!data)" }
+        }"#;
+
+        assert!(matches!(
+            JsonSchema::parse(schema),
+            Err(JsonSchemaError::Pattern { pattern, .. }) if pattern == "^(?!internal-)"
+        ));
+        assert!(validate(
+            r#"{ "patternProperties": { "^x-": { "type": "string" } }, "default": { "pattern": "(?!data)" } }"#,
+            r#"{ "x-name": "a" }"#
+        )
+        .is_empty());
+    }
+}
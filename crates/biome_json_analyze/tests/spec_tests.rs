@@ -11,7 +11,7 @@ use biome_test_utils::{
 };
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{json}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{json,jsonc}", crate::run_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     register_leak_checker();
@@ -67,18 +67,25 @@ pub(crate) fn analyze_and_snap(
     file_name: &str,
     input_file: &Path,
 ) -> usize {
-    let parsed = parse_json(input_code, JsonParserOptions::default());
+    let parser_options = parser_options(file_name);
+    let parsed = parse_json(input_code, parser_options);
     let root = parsed.tree();
 
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
-    let options = create_analyzer_options(input_file, &mut diagnostics);
+    let mut options = create_analyzer_options(input_file, &mut diagnostics);
+    if file_name.ends_with(".package.json") {
+        // The rules that lint the manifests only apply to the files named `package.json`
+        options.file_path = input_file.with_file_name("package.json");
+    } else if file_name.ends_with(".tsconfig.json") {
+        options.file_path = input_file.with_file_name("tsconfig.json");
+    }
 
     let (_, errors) = biome_json_analyze::analyze(&root, filter, &options, |event| {
         if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
                 if !action.is_suppression() {
-                    check_code_action(input_file, input_code, &action);
+                    check_code_action(input_file, file_name, input_code, &action);
                     diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                 }
             }
@@ -90,7 +97,7 @@ pub(crate) fn analyze_and_snap(
 
         for action in event.actions() {
             if !action.is_suppression() {
-                check_code_action(input_file, input_code, &action);
+                check_code_action(input_file, file_name, input_code, &action);
                 code_fixes.push(code_fix_to_string(input_code, action));
             }
         }
@@ -112,7 +119,23 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
-fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLanguage>) {
+/// The `.jsonc` files allow comments and trailing commas
+fn parser_options(file_name: &str) -> JsonParserOptions {
+    if file_name.ends_with(".jsonc") {
+        JsonParserOptions::default()
+            .with_allow_comments()
+            .with_allow_trailing_commas()
+    } else {
+        JsonParserOptions::default()
+    }
+}
+
+fn check_code_action(
+    path: &Path,
+    file_name: &str,
+    source: &str,
+    action: &AnalyzerAction<JsonLanguage>,
+) {
     let (_, text_edit) = action.mutation.as_text_edits().unwrap_or_default();
 
     let output = text_edit.new_string(source);
@@ -136,6 +159,6 @@ fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLang
     }
 
     // Re-parse the modified code and panic if the resulting tree has syntax errors
-    let re_parse = parse_json(&output, JsonParserOptions::default());
+    let re_parse = parse_json(&output, parser_options(file_name));
     assert_errors_are_absent(re_parse.tree().syntax(), re_parse.diagnostics(), path);
 }
@@ -2,7 +2,7 @@ use crate::comments::{FormatJsonLeadingComment, JsonComments};
 use crate::JsonCommentStyle;
 use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::separated::TrailingSeparator;
-use biome_formatter::{prelude::*, AttributePosition, IndentWidth};
+use biome_formatter::{prelude::*, AttributePosition, IndentWidth, QuoteStyle};
 use biome_formatter::{
     CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
     TransformSourceMap,
@@ -67,6 +67,9 @@ pub struct JsonFormatOptions {
     attribute_position: AttributePosition,
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
     trailing_commas: TrailingCommas,
+    /// The type of quotes used in the strings. The quotes are preserved when it's [None].
+    /// Only JSON5 allows single quotes.
+    quote_style: Option<QuoteStyle>,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
@@ -136,6 +139,11 @@ impl JsonFormatOptions {
         self
     }
 
+    pub fn with_quote_style(mut self, quote_style: Option<QuoteStyle>) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -156,6 +164,14 @@ impl JsonFormatOptions {
         self.trailing_commas = trailing_commas;
     }
 
+    pub fn set_quote_style(&mut self, quote_style: Option<QuoteStyle>) {
+        self.quote_style = quote_style;
+    }
+
+    pub fn quote_style(&self) -> Option<QuoteStyle> {
+        self.quote_style
+    }
+
     pub(crate) fn to_trailing_separator(&self) -> TrailingSeparator {
         match self.trailing_commas {
             TrailingCommas::None => TrailingSeparator::Omit,
@@ -196,6 +212,10 @@ impl fmt::Display for JsonFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.get())?;
-        writeln!(f, "Trailing commas: {}", self.trailing_commas)
+        writeln!(f, "Trailing commas: {}", self.trailing_commas)?;
+        match self.quote_style {
+            Some(quote_style) => writeln!(f, "Quote style: {quote_style}"),
+            None => writeln!(f, "Quote style: Preserved"),
+        }
     }
 }
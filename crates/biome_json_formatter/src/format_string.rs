@@ -16,13 +16,24 @@ impl Format<JsonFormatContext> for CleanedStringLiteralText<'_> {
         let content = self.token.text_trimmed();
         let raw_content = &content[1..content.len() - 1];
 
-        let text = match normalize_string(raw_content, Quote::Double, false) {
-            Cow::Borrowed(_) => Cow::Borrowed(content),
-            Cow::Owned(raw_content) => Cow::Owned(std::format!(
+        // Only the strings of JSON5 can be single quoted
+        let quote = if content.starts_with('\'') {
+            Quote::Single
+        } else {
+            Quote::Double
+        };
+        let preferred_quote = f
+            .options()
+            .quote_style()
+            .map_or(quote, |quote_style| quote_style.into());
+
+        let text = match normalize_string(raw_content, preferred_quote, false) {
+            Cow::Borrowed(_) if preferred_quote == quote => Cow::Borrowed(content),
+            raw_content => Cow::Owned(std::format!(
                 "{}{}{}",
-                Quote::Double.as_char(),
+                preferred_quote.as_char(),
                 raw_content,
-                Quote::Double.as_char()
+                preferred_quote.as_char()
             )),
         };
 
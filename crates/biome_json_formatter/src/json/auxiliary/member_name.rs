@@ -1,12 +1,18 @@
 use crate::format_string::format_string_token;
 use crate::prelude::*;
-use biome_json_syntax::JsonMemberName;
+use biome_json_syntax::{JsonMemberName, JsonSyntaxKind};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsonMemberName;
 
 impl FormatNodeRule<JsonMemberName> for FormatJsonMemberName {
     fn fmt_fields(&self, node: &JsonMemberName, f: &mut JsonFormatter) -> FormatResult<()> {
-        format_string_token(&node.value_token()?).fmt(f)
+        let value_token = node.value_token()?;
+        // The unquoted keys of JSON5 are printed as they are
+        if value_token.kind() == JsonSyntaxKind::IDENT {
+            value_token.format().fmt(f)
+        } else {
+            format_string_token(&value_token).fmt(f)
+        }
     }
 }
@@ -7,6 +7,16 @@ pub(crate) struct FormatJsonNumberValue;
 
 impl FormatNodeRule<JsonNumberValue> for FormatJsonNumberValue {
     fn fmt_fields(&self, node: &JsonNumberValue, f: &mut JsonFormatter) -> FormatResult<()> {
-        format_number_token(&node.value_token()?).fmt(f)
+        let value_token = node.value_token()?;
+        // `Infinity` and `NaN` of JSON5 are printed as they are
+        if value_token
+            .text_trimmed()
+            .trim_start_matches(['+', '-'])
+            .starts_with(['I', 'N'])
+        {
+            value_token.format().fmt(f)
+        } else {
+            format_number_token(&value_token).fmt(f)
+        }
     }
 }
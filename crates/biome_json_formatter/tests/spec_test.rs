@@ -1,5 +1,6 @@
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_json_formatter::context::JsonFormatOptions;
+use biome_json_syntax::JsonFileSource;
 use std::path::Path;
 
 mod language {
@@ -30,8 +31,9 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         return;
     };
 
+    let source_type: JsonFileSource = test_file.input_file().as_path().try_into().unwrap();
     let options = JsonFormatOptions::default();
-    let language = language::JsonTestFormatLanguage::default();
+    let language = language::JsonTestFormatLanguage::new(source_type);
 
     let snapshot = SpecSnapshot::new(test_file, test_directory, language, options);
 
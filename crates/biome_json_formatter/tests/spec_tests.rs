@@ -6,4 +6,8 @@ mod formatter {
     mod json_module {
         tests_macros::gen_tests! {"tests/specs/json/**/*.json", crate::spec_test::run, ""}
     }
+
+    mod json5_module {
+        tests_macros::gen_tests! {"tests/specs/json5/**/*.json5", crate::spec_test::run, ""}
+    }
 }
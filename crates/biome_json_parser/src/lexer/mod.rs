@@ -139,6 +139,8 @@ impl<'src> Lexer<'src> {
                     b'\r' | b'\n' => {
                         break;
                     }
+                    // JSON5 allows the vertical tab and the form feed
+                    _ if self.options.json5 => self.advance(1),
                     _ => {
                         let start = self.text_position();
                         self.advance(1);
@@ -310,6 +312,10 @@ impl<'src> Lexer<'src> {
             IDT => self.lex_identifier(current),
             COM => self.eat_byte(T![,]),
             MIN | DIG | ZER => self.lex_number(current),
+            PLS if self.options.json5 => self.lex_number(current),
+            PRD if self.options.json5 && matches!(self.peek_byte(), Some(b'0'..=b'9')) => {
+                self.lex_number(current)
+            }
             COL => self.eat_byte(T![:]),
             BTO => self.eat_byte(T!['[']),
             BTC => self.eat_byte(T![']']),
@@ -327,6 +333,9 @@ impl<'src> Lexer<'src> {
                     // A BOM can only appear at the start of a file, so if we haven't advanced at all yet,
                     // perform the check. At any other position, the BOM is just considered plain whitespace.
                     UNICODE_BOM
+                } else if self.options.json5 && is_json5_whitespace(chr) {
+                    self.advance(chr.len_utf8());
+                    WHITESPACE
                 } else {
                     self.eat_unexpected_character()
                 }
@@ -358,8 +367,12 @@ impl<'src> Lexer<'src> {
 
         let start = self.text_position();
 
-        if current == b'-' {
+        if matches!(current, b'-' | b'+') {
             self.advance(1);
+
+            if self.options.json5 && self.eat_json5_named_number() {
+                return JSON_NUMBER_LITERAL;
+            }
         }
 
         let mut state = LexNumberState::FirstDigit;
@@ -380,6 +393,26 @@ impl<'src> Lexer<'src> {
                                 reason: InvalidNumberReason::Octal,
                             }
                         }
+                        LexNumberState::FirstDigit
+                            if self.options.json5
+                                && matches!(self.current_byte(), Some(b'x' | b'X')) =>
+                        {
+                            self.advance(1);
+
+                            if !matches!(self.current_byte(), Some(byte) if byte.is_ascii_hexdigit())
+                            {
+                                LexNumberState::Invalid {
+                                    position: self.text_position(),
+                                    reason: InvalidNumberReason::MissingHexDigits,
+                                }
+                            } else {
+                                while matches!(self.current_byte(), Some(byte) if byte.is_ascii_hexdigit())
+                                {
+                                    self.advance(1);
+                                }
+                                LexNumberState::Hexadecimal
+                            }
+                        }
                         LexNumberState::FirstDigit => LexNumberState::IntegerPart,
                         state => state,
                     }
@@ -403,6 +436,17 @@ impl<'src> Lexer<'src> {
                         {
                             LexNumberState::FractionalPart
                         }
+                        // JSON5 allows a leading decimal point, e.g. `.5`
+                        LexNumberState::FirstDigit
+                            if self.options.json5
+                                && matches!(self.current_byte(), Some(b'0'..=b'9')) =>
+                        {
+                            LexNumberState::FractionalPart
+                        }
+                        // JSON5 allows a trailing decimal point, e.g. `5.`
+                        LexNumberState::IntegerPart if self.options.json5 => {
+                            LexNumberState::FractionalPart
+                        }
                         LexNumberState::IntegerPart => LexNumberState::Invalid {
                             position: self.text_position(),
                             reason: InvalidNumberReason::MissingFraction,
@@ -450,12 +494,15 @@ impl<'src> Lexer<'src> {
         match state {
             LexNumberState::IntegerPart
             | LexNumberState::FractionalPart
-            | LexNumberState::Exponent => JSON_NUMBER_LITERAL,
+            | LexNumberState::Exponent
+            | LexNumberState::Hexadecimal => JSON_NUMBER_LITERAL,
             LexNumberState::FirstDigit => {
-                let err = ParseDiagnostic::new(
-                    "Minus must be followed by a digit",
-                    start..self.text_position(),
-                );
+                let message = if current == b'+' {
+                    "Plus must be followed by a digit"
+                } else {
+                    "Minus must be followed by a digit"
+                };
+                let err = ParseDiagnostic::new(message, start..self.text_position());
                 self.diagnostics.push(err);
                 ERROR_TOKEN
             }
@@ -484,6 +531,10 @@ impl<'src> Lexer<'src> {
                         ParseDiagnostic::new( "Missing fraction", position..position + TextSize::from(1))
                             .with_hint("Remove the `.`")
                     }
+                    InvalidNumberReason::MissingHexDigits => {
+                        ParseDiagnostic::new( "Missing hexadecimal digits", start..position)
+                            .with_detail(position..position + TextSize::from(1), "Expected a hexadecimal digit")
+                    }
                 };
 
                 self.diagnostics.push(diagnostic);
@@ -499,7 +550,7 @@ impl<'src> Lexer<'src> {
 
         self.advance(1); // Skip over the quote
         let mut state = match quote {
-            b'\'' => LexStringState::InvalidQuote,
+            b'\'' if !self.options.json5 => LexStringState::InvalidQuote,
             _ => LexStringState::InString,
         };
 
@@ -540,16 +591,45 @@ impl<'src> Lexer<'src> {
                             self.advance(1);
                         }
 
+                        Some(b'x') if self.options.json5 => match (self.lex_hex_escape(), state) {
+                            (Ok(_), _) => {}
+                            (Err(err), LexStringState::InString) => {
+                                self.diagnostics.push(err);
+                                state = LexStringState::InvalidEscapeSequence;
+                            }
+                            (Err(_), _) => {}
+                        },
+
+                        // A line continuation
+                        Some(b'\n' | b'\r') if self.options.json5 => {
+                            self.consume_newline();
+                        }
+
+                        // JSON5 allows to escape any character but the digits, except for `\0`
+                        // when it isn't followed by another digit
+                        Some(chr)
+                            if self.options.json5
+                                && (!chr.is_ascii_digit()
+                                    || (chr == b'0'
+                                        && !matches!(self.peek_byte(), Some(b'0'..=b'9')))) =>
+                        {
+                            self.advance_byte_or_char(chr);
+                        }
+
                         Some(_) => {
                             if matches!(state, LexStringState::InString) {
                                 let c = self.current_char_unchecked();
+                                let hint = if self.options.json5 {
+                                    "Digits can't be escaped, except for `\\0` when it isn't followed by another digit."
+                                } else {
+                                    r#"Valid escape sequences are: `\\`, `\/`, `/"`, `\b\`, `\f`, `\n`, `\r`, `\t` or any unicode escape sequence `\uXXXX` where X is hexedecimal number. "#
+                                };
                                 self.diagnostics.push(
                                     ParseDiagnostic::new(
-
                                         "Invalid escape sequence",
                                         escape_start..self.text_position() + c.text_len(),
                                     )
-                                        .with_hint(r#"Valid escape sequences are: `\\`, `\/`, `/"`, `\b\`, `\f`, `\n`, `\r`, `\t` or any unicode escape sequence `\uXXXX` where X is hexedecimal number. "#),
+                                    .with_hint(hint),
                                 );
                                 state = LexStringState::InvalidEscapeSequence;
                             }
@@ -586,7 +666,12 @@ impl<'src> Lexer<'src> {
                 // * quotation mark: (U+0022),
                 // * reverse solidus (U+005C),
                 // * and the **control characters U+0000 to U+001F** <- This
-                ERR | WHS if matches!(state, LexStringState::InString) && chr <= 0x1f => {
+                // JSON5 allows them
+                ERR | WHS
+                    if matches!(state, LexStringState::InString)
+                        && chr <= 0x1f
+                        && !self.options.json5 =>
+                {
                     self.diagnostics.push(
                         ParseDiagnostic::new(
 
@@ -680,6 +765,67 @@ impl<'src> Lexer<'src> {
         Ok(())
     }
 
+    /// Lexes a `\x00` escape sequence of JSON5. Assumes that the lexer is positioned at the `x` token.
+    ///
+    /// A hexadecimal escape sequence must consist of 2 hex characters.
+    fn lex_hex_escape(&mut self) -> Result<(), ParseDiagnostic> {
+        self.assert_byte(b'x');
+
+        let start = self.text_position();
+        // Subtract 1 to get position of `\`
+        let start = start.checked_sub(TextSize::from(1)).unwrap_or(start);
+
+        self.advance(1); // Advance over `x`
+
+        for _ in 0..2 {
+            match self.current_byte() {
+                Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
+                Some(_) => {
+                    let char = self.current_char_unchecked();
+                    return Err(ParseDiagnostic::new(
+                        "Invalid hexadecimal escape sequence",
+                        start..self.text_position(),
+                    )
+                    .with_detail(self.text_position()..self.text_position().add(char.text_len()), "Non hexadecimal number")
+                    .with_hint("A hexadecimal escape sequence must consist of 2 hexadecimal numbers: `\\xXX`, e.g. `\\x2F' for '/'."));
+                }
+                None => {
+                    return Err(ParseDiagnostic::new(
+                        "Hexadecimal escape sequence with too few hexadecimal numbers.",
+                        start..self.text_position(),
+                    )
+                    .with_detail(
+                        self.text_position()..self.text_position(),
+                        "reached the end of the file",
+                    )
+                    .with_hint("A hexadecimal escape sequence must consist of 2 hexadecimal numbers: `\\xXX`, e.g. `\\x2F' for '/'."));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Eats the `Infinity` or `NaN` that follows the sign of a JSON5 number.
+    ///
+    /// Returns `false` when the sign isn't followed by one of them.
+    fn eat_json5_named_number(&mut self) -> bool {
+        let rest = &self.source.as_bytes()[self.position..];
+
+        for name in [&b"Infinity"[..], &b"NaN"[..]] {
+            if rest.starts_with(name)
+                && !rest
+                    .get(name.len())
+                    .is_some_and(|byte| matches!(lookup_byte(*byte), IDT | DIG | ZER))
+            {
+                self.advance(name.len());
+                return true;
+            }
+        }
+
+        false
+    }
+
     /// Implements basic lexing of identifiers without support for escape sequences.
     /// This is merely for improved error recovery as identifiers are not valid in JSON.
     fn lex_identifier(&mut self, first: u8) -> JsonSyntaxKind {
@@ -823,6 +969,9 @@ enum LexNumberState {
     /// Parsing the exponent digits (after a `e` or `E`)
     Exponent,
 
+    /// After the digits of a JSON5 hexadecimal number (after `0x` or `0X`)
+    Hexadecimal,
+
     /// Parsing the rest of an invalid number
     Invalid {
         reason: InvalidNumberReason,
@@ -845,6 +994,9 @@ enum InvalidNumberReason {
 
     /// Number starting with a 0
     Octal,
+
+    /// Missing digit after `0x` or `0X`
+    MissingHexDigits,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -862,6 +1014,15 @@ enum LexStringState {
     Terminated,
 }
 
+/// Returns `true` if `chr` is a whitespace allowed by JSON5 that isn't an ASCII character
+fn is_json5_whitespace(chr: char) -> bool {
+    matches!(
+        chr,
+        '\u{a0}' | '\u{feff}' | '\u{1680}' | '\u{2000}'
+            ..='\u{200a}' | '\u{2028}' | '\u{2029}' | '\u{202f}' | '\u{205f}' | '\u{3000}'
+    )
+}
+
 enum KeywordMatcher {
     MaybeNull(u32),
     MaybeFalse(u32),
@@ -2,6 +2,7 @@
 #![allow(unused_mut, unused_variables, unused_assignments)]
 
 use super::{Lexer, TextSize};
+use crate::JsonParserOptions;
 use biome_json_syntax::JsonSyntaxKind::{self, EOF};
 use quickcheck_macros::quickcheck;
 use std::sync::mpsc::channel;
@@ -11,8 +12,8 @@ use std::time::Duration;
 // Assert the result of lexing a piece of source code,
 // and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens
 macro_rules! assert_lex {
-    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
-        let mut lexer = Lexer::from_str($src);
+    (@lexer $lexer:expr, $src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = $lexer;
         let mut idx = 0;
         let mut tok_idx = TextSize::default();
 
@@ -56,6 +57,16 @@ macro_rules! assert_lex {
 
         assert_eq!($src, new_str, "Failed to reconstruct input");
     }};
+    (json5, $src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(
+            @lexer Lexer::from_str($src).with_options(JsonParserOptions::default().with_json5()),
+            $src,
+            $($kind:$len,)*
+        )
+    };
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(@lexer Lexer::from_str($src), $src, $($kind:$len,)*)
+    };
 }
 
 // This is for testing if the lexer is truly lossless
@@ -449,3 +460,189 @@ fn keywords() {
         assert_eq!(lexer.next_token().expect("Expected EOF token").kind, EOF);
     }
 }
+
+#[test]
+fn json5_single_quote_string() {
+    assert_lex! {
+        json5,
+        r#"'A string with "double" and \'single\' quotes'"#,
+        JSON_STRING_LITERAL:46,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_escape_sequences() {
+    assert_lex! {
+        json5,
+        r#""\v\0\x2F\a\'""#,
+        JSON_STRING_LITERAL:14,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        r#""\1""#,
+        ERROR_TOKEN:4,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        r#""\01""#,
+        ERROR_TOKEN:5,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        r#""\xZZ""#,
+        ERROR_TOKEN:6,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_multiline_string() {
+    assert_lex! {
+        json5,
+        "\"first line \\\nsecond line\"",
+        JSON_STRING_LITERAL:26,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "'first line \\\r\nsecond line'",
+        JSON_STRING_LITERAL:27,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_hexadecimal() {
+    assert_lex! {
+        json5,
+        "0xDEADbeef",
+        JSON_NUMBER_LITERAL:10,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "-0X1f",
+        JSON_NUMBER_LITERAL:5,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "0x",
+        ERROR_TOKEN:2,
+        EOF:0
+    }
+
+    assert_lex! {
+        "0x1",
+        JSON_NUMBER_LITERAL:1,
+        IDENT:2,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_decimal_points() {
+    assert_lex! {
+        json5,
+        ".5",
+        JSON_NUMBER_LITERAL:2,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "-.5e3",
+        JSON_NUMBER_LITERAL:5,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "5.",
+        JSON_NUMBER_LITERAL:2,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "5..",
+        ERROR_TOKEN:3,
+        EOF:0
+    }
+
+    assert_lex! {
+        ".5",
+        ERROR_TOKEN:1,
+        JSON_NUMBER_LITERAL:1,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_signs() {
+    assert_lex! {
+        json5,
+        "+1",
+        JSON_NUMBER_LITERAL:2,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "+",
+        ERROR_TOKEN:1,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "-Infinity",
+        JSON_NUMBER_LITERAL:9,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "+NaN",
+        JSON_NUMBER_LITERAL:4,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "Infinity",
+        IDENT:8,
+        EOF:0
+    }
+
+    assert_lex! {
+        json5,
+        "-NaNa",
+        ERROR_TOKEN:1,
+        IDENT:4,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_whitespaces() {
+    assert_lex! {
+        json5,
+        "\u{0b}\u{0c}\u{a0}\u{2028}1",
+        WHITESPACE:2,
+        WHITESPACE:2,
+        WHITESPACE:3,
+        JSON_NUMBER_LITERAL:1,
+        EOF:0
+    }
+}
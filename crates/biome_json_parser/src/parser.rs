@@ -16,6 +16,10 @@ pub(crate) struct JsonParser<'source> {
 pub struct JsonParserOptions {
     pub allow_comments: bool,
     pub allow_trailing_commas: bool,
+    /// Parses the document as [JSON5](https://spec.json5.org/): unquoted keys, single quoted
+    /// strings, hexadecimal numbers, `Infinity`, `NaN`, leading and trailing decimal points, and
+    /// strings that span multiple lines.
+    pub json5: bool,
 }
 
 impl JsonParserOptions {
@@ -28,6 +32,14 @@ impl JsonParserOptions {
         self.allow_trailing_commas = true;
         self
     }
+
+    /// Enables the JSON5 syntax, which includes comments and trailing commas
+    pub fn with_json5(mut self) -> Self {
+        self.json5 = true;
+        self.allow_comments = true;
+        self.allow_trailing_commas = true;
+        self
+    }
 }
 
 impl<'source> JsonParser<'source> {
@@ -73,9 +73,21 @@ fn parse_value(p: &mut JsonParser) -> ParsedSyntax {
         T!['{'] => parse_sequence(p, SequenceKind::Object),
         T!['['] => parse_sequence(p, SequenceKind::Array),
 
+        // `Infinity` and `NaN` are numbers in JSON5
+        IDENT if p.options().json5 && matches!(p.cur_text(), "Infinity" | "NaN") => {
+            let m = p.start();
+            p.bump_remap(JSON_NUMBER_LITERAL);
+            Present(m.complete(p, JSON_NUMBER_VALUE))
+        }
+
         IDENT => {
             let m = p.start();
-            p.error(p.err_builder("String values must be double quoted.", p.cur_range()));
+            let message = if p.options().json5 {
+                "String values must be quoted."
+            } else {
+                "String values must be double quoted."
+            };
+            p.error(p.err_builder(message, p.cur_range()));
             p.bump(IDENT);
             Present(m.complete(p, JSON_BOGUS_VALUE))
         }
@@ -282,6 +294,12 @@ fn parse_member_name(p: &mut JsonParser) -> ParsedSyntax {
             p.bump(JSON_STRING_LITERAL);
             Present(m.complete(p, JSON_MEMBER_NAME))
         }
+        // JSON5 allows the keys to be identifiers
+        IDENT if p.options().json5 => {
+            let m = p.start();
+            p.bump(IDENT);
+            Present(m.complete(p, JSON_MEMBER_NAME))
+        }
         IDENT => {
             let m = p.start();
             p.error(p.err_builder("Property key must be double quoted", p.cur_range()));
@@ -35,9 +35,14 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
-    let parse_conifg = JsonParserOptions {
-        allow_comments: test_directory.contains("allow_comments"),
-        allow_trailing_commas: test_directory.contains("allow_trailing_commas"),
+    let parse_conifg = if test_directory.contains("json5") {
+        JsonParserOptions::default().with_json5()
+    } else {
+        JsonParserOptions {
+            allow_comments: test_directory.contains("allow_comments"),
+            allow_trailing_commas: test_directory.contains("allow_trailing_commas"),
+            json5: false,
+        }
     };
     let parsed = parse_json(&content, parse_conifg);
     let formatted_ast = format!("{:#?}", parsed.tree());
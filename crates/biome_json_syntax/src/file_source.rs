@@ -8,6 +8,7 @@ use std::path::Path;
 pub struct JsonFileSource {
     allow_trailing_commas: bool,
     allow_comments: bool,
+    json5: bool,
 }
 
 impl JsonFileSource {
@@ -15,6 +16,16 @@ impl JsonFileSource {
         Self {
             allow_trailing_commas: false,
             allow_comments: false,
+            json5: false,
+        }
+    }
+
+    /// A [JSON5](https://spec.json5.org/) document, which allows comments and trailing commas
+    pub fn json5() -> Self {
+        Self {
+            allow_trailing_commas: true,
+            allow_comments: true,
+            json5: true,
         }
     }
 
@@ -43,6 +54,10 @@ impl JsonFileSource {
     pub fn get_allow_comments(&self) -> bool {
         self.allow_comments
     }
+
+    pub const fn is_json5(&self) -> bool {
+        self.json5
+    }
 }
 
 impl TryFrom<&Path> for JsonFileSource {
@@ -76,6 +91,7 @@ fn compute_source_type_from_path_or_extension(
         match extension {
             "json" => JsonFileSource::json(),
             "jsonc" => JsonFileSource::json().with_comments(true),
+            "json5" => JsonFileSource::json5(),
             _ => {
                 return Err(FileSourceError::UnknownExtension(
                     file_name.into(),
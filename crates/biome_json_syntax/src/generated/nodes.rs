@@ -168,10 +168,10 @@ impl JsonMemberName {
     }
     pub fn as_fields(&self) -> JsonMemberNameFields {
         JsonMemberNameFields {
-            value_token: self.value_token(),
+            value: self.value(),
         }
     }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
 }
@@ -186,7 +186,7 @@ impl Serialize for JsonMemberName {
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct JsonMemberNameFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsonNullValue {
@@ -597,10 +597,7 @@ impl AstNode for JsonMemberName {
 impl std::fmt::Debug for JsonMemberName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("JsonMemberName")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
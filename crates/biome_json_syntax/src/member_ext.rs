@@ -1,8 +1,13 @@
-use crate::{inner_string_text, JsonMemberName};
+use crate::{inner_string_text, JsonMemberName, JsonSyntaxToken};
 use biome_rowan::{SyntaxResult, TokenText};
 
 impl JsonMemberName {
+    /// The token of the name: a string literal, or an identifier in JSON5
+    pub fn value_token(&self) -> SyntaxResult<JsonSyntaxToken> {
+        self.value()
+    }
+
     pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
-        Ok(inner_string_text(&self.value_token()?))
+        Ok(inner_string_text(&self.value()?))
     }
 }
@@ -220,6 +220,8 @@ fn fix_all(
         path: biome_path,
         fix_file_mode: FixFileMode::SafeFixes,
         should_format,
+        suppression_reason: None,
+        suppressed_rules: Vec::new(),
     })?;
 
     if fixed.actions.is_empty() {
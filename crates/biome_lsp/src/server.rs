@@ -136,14 +136,14 @@ impl LSPServer {
                     watchers: vec![
                         FileSystemWatcher {
                             glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.json",
+                                "{}/**/biome.json",
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
                         },
                         FileSystemWatcher {
                             glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.jsonc",
+                                "{}/**/biome.jsonc",
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
@@ -288,6 +288,7 @@ impl LanguageServer for LSPServer {
             self.session.load_workspace_settings(),
             self.session.load_manifest()
         );
+        self.session.load_module_graph().await;
 
         let msg = format!("Server initialized with PID: {}", std::process::id());
         self.session
@@ -310,6 +311,7 @@ impl LanguageServer for LSPServer {
         let _ = params;
         self.session.load_workspace_settings().await;
         self.session.load_extension_settings().await;
+        self.session.load_module_graph().await;
         self.setup_capabilities().await;
         self.session.update_all_diagnostics().await;
     }
@@ -327,12 +329,18 @@ impl LanguageServer for LSPServer {
                     if let Some(base_path) = base_path {
                         let possible_rome_json = file_path.strip_prefix(&base_path);
                         if let Ok(possible_rome_json) = possible_rome_json {
+                            // Nested configuration files apply to their own directory
+                            let file_name = possible_rome_json
+                                .file_name()
+                                .and_then(|file_name| file_name.to_str());
                             if possible_rome_json.display().to_string() == ROME_JSON
-                                || ConfigName::file_names()
-                                    .contains(&&*possible_rome_json.display().to_string())
+                                || file_name.is_some_and(|file_name| {
+                                    ConfigName::file_names().contains(&file_name)
+                                })
                             {
                                 self.session.load_workspace_settings().await;
                                 self.session.load_manifest().await;
+                                self.session.load_module_graph().await;
                                 self.setup_capabilities().await;
                                 self.session.update_all_diagnostics().await;
                                 // for now we are only interested to the configuration file,
@@ -578,6 +586,11 @@ impl ServerFactory {
         workspace_method!(builder, open_file);
         workspace_method!(builder, open_project);
         workspace_method!(builder, update_current_project);
+        workspace_method!(builder, register_project_configuration);
+        workspace_method!(builder, register_module);
+        workspace_method!(builder, is_module_graph_enabled);
+        workspace_method!(builder, is_node_js_workspace_enabled);
+        workspace_method!(builder, get_json_schema_path);
         workspace_method!(builder, get_syntax_tree);
         workspace_method!(builder, get_control_flow_graph);
         workspace_method!(builder, get_formatter_ir);
@@ -8,11 +8,14 @@ use biome_analyze::RuleCategories;
 use biome_console::markup;
 use biome_diagnostics::PrintDescription;
 use biome_fs::{BiomePath, FileSystem};
-use biome_service::configuration::{load_configuration, LoadedConfiguration};
+use biome_service::configuration::{
+    load_configuration, load_nested_configurations, LoadedConfiguration,
+};
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
-    FeaturesBuilder, GetFileContentParams, OpenProjectParams, PullDiagnosticsParams,
-    SupportsFeatureParams, UpdateProjectParams,
+    open_json_schema, open_module_graph, open_node_js_workspace, open_tsconfig, FeaturesBuilder,
+    GetFileContentParams, OpenProjectParams, PullDiagnosticsParams, SupportsFeatureParams,
+    UpdateProjectParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
 use biome_service::{ConfigurationBasePath, Workspace};
@@ -21,7 +24,7 @@ use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
 use rustc_hash::FxHashMap;
 use serde_json::Value;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicU8;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -303,7 +306,11 @@ impl Session {
         } else {
             let mut categories = RuleCategories::SYNTAX;
             if file_features.supports_lint() {
-                categories |= RuleCategories::LINT
+                categories |= RuleCategories::LINT;
+                let result = open_json_schema(&*self.fs, &*self.workspace, &biome_path);
+                if let Err(err) = result {
+                    error!("Couldn't open the JSON Schema, reason:\n {}", err);
+                }
             }
             if file_features.supports_organize_imports() {
                 categories |= RuleCategories::ACTION
@@ -449,12 +456,14 @@ impl Session {
                                 configuration,
                                 vcs_base_path,
                                 gitignore_matches,
+                                nested_directory: None,
                             });
 
                             if let Err(error) = result {
                                 error!("Failed to set workspace settings: {}", error);
                                 ConfigurationStatus::Error
                             } else {
+                                self.load_nested_settings(configuration_path.as_deref());
                                 ConfigurationStatus::Loaded
                             }
                         }
@@ -476,6 +485,58 @@ impl Session {
         self.set_configuration_status(status);
     }
 
+    /// Loads the configuration files nested inside the workspace, and applies each of them to
+    /// the files of its directory
+    fn load_nested_settings(&self, root_directory: Option<&Path>) {
+        let Some(base_path) = self.base_path() else {
+            return;
+        };
+        let nested_configurations = match load_nested_configurations(
+            &self.fs,
+            &*self.workspace,
+            &[base_path],
+            root_directory,
+        ) {
+            Ok(nested_configurations) => nested_configurations,
+            Err(err) => {
+                error!(
+                    "Couldn't load the nested configuration files, reason:\n {}",
+                    err
+                );
+                return;
+            }
+        };
+
+        for loaded_configuration in nested_configurations {
+            if loaded_configuration.has_errors() {
+                error!(
+                    "Couldn't load the nested configuration file {:?}",
+                    loaded_configuration.file_path
+                );
+                continue;
+            }
+            let LoadedConfiguration {
+                configuration,
+                directory_path,
+                ..
+            } = loaded_configuration;
+            let result = configuration
+                .retrieve_gitignore_matches(&self.fs, directory_path.as_deref())
+                .and_then(|(vcs_base_path, gitignore_matches)| {
+                    self.workspace.update_settings(UpdateSettingsParams {
+                        working_directory: self.fs.working_directory(),
+                        configuration,
+                        vcs_base_path,
+                        gitignore_matches,
+                        nested_directory: directory_path,
+                    })
+                });
+            if let Err(error) = result {
+                error!("Failed to set the nested workspace settings: {}", error);
+            }
+        }
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) async fn load_manifest(&self) {
         let base_path = self
@@ -490,19 +551,19 @@ impl Session {
             match result {
                 Ok(result) => {
                     if let Some(result) = result {
-                        let biome_path = BiomePath::new(result.file_path);
-                        let result = self.workspace.open_project(OpenProjectParams {
+                        let biome_path = BiomePath::new(&result.file_path);
+                        let open_result = self.workspace.open_project(OpenProjectParams {
                             path: biome_path.clone(),
                             content: result.content,
                             version: 0,
                         });
-                        if let Err(err) = result {
+                        if let Err(err) = open_result {
                             error!("{}", err);
                         }
-                        let result = self
+                        let update_result = self
                             .workspace
                             .update_current_project(UpdateProjectParams { path: biome_path });
-                        if let Err(err) = result {
+                        if let Err(err) = update_result {
                             error!("{}", err);
                         }
                     }
@@ -511,6 +572,46 @@ impl Session {
                     error!("Couldn't load the package.json file, reason:\n {}", err);
                 }
             }
+
+            let result = self
+                .fs
+                .auto_search(base_path, &["tsconfig.json", "jsconfig.json"], false);
+            match result {
+                Ok(Some(result)) => {
+                    let result =
+                        open_tsconfig(&*self.fs, &*self.workspace, &result.file_path, true);
+                    if let Err(err) = result {
+                        error!("{}", err);
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    error!("Couldn't load the tsconfig.json file, reason:\n {}", err);
+                }
+            }
+        }
+    }
+
+    /// Registers the `package.json` files of the monorepo, and the files of the workspace in the
+    /// module graph, when one of the enabled rules needs them. The settings and the manifests
+    /// must be loaded first.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) async fn load_module_graph(&self) {
+        let Some(base_path) = self.base_path() else {
+            return;
+        };
+        let manifest_directory = self.manifest_path.as_deref().unwrap_or(&base_path);
+        if let Err(err) =
+            open_node_js_workspace(&*self.fs, &*self.workspace, manifest_directory, None)
+        {
+            error!(
+                "Couldn't load the packages of the monorepo, reason:
+ {}",
+                err
+            );
+        }
+        if let Err(err) = open_module_graph(&*self.fs, &*self.workspace, &base_path, &[], false) {
+            error!("Couldn't load the module graph, reason:\n {}", err);
         }
     }
 
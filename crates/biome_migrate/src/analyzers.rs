@@ -1,9 +1,13 @@
 use crate::analyzers::indent_size::IndentSize;
+use crate::analyzers::rule_names::RuleNames;
+use crate::analyzers::rule_options::RuleOptions;
 use crate::analyzers::schema::Schema;
 use biome_analyze::{GroupCategory, RegistryVisitor, RuleCategory, RuleGroup};
 use biome_json_syntax::JsonLanguage;
 
 mod indent_size;
+mod rule_names;
+mod rule_options;
 mod schema;
 
 pub(crate) struct MigrationGroup;
@@ -19,7 +23,10 @@ impl RuleGroup for MigrationGroup {
         // v1.3.0
         registry.record_rule::<IndentSize>();
         // v1.5.0
-        registry.record_rule::<Schema>()
+        registry.record_rule::<Schema>();
+        // v1.7.0
+        registry.record_rule::<RuleNames>();
+        registry.record_rule::<RuleOptions>();
     }
 }
 
@@ -0,0 +1,170 @@
+use crate::lint_rules::{
+    member_name, member_name_with_trivia, parent_member, rebuild_member_list, rule_member_names,
+    LintRules,
+};
+use crate::{declare_migration, MigrationAction};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{ActionCategory, Ast, Rule, RuleAction, RuleDiagnostic};
+use biome_console::markup;
+use biome_diagnostics::{category, Applicability};
+use biome_json_syntax::{AnyJsonValue, JsonMember, JsonMemberList, JsonObjectValue};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_migration! {
+    pub(crate) RuleNames {
+        version: "1.7.0",
+        name: "ruleNames",
+    }
+}
+
+pub(crate) struct RuleNamesState {
+    group: String,
+    name: String,
+    new_group: &'static str,
+    new_name: &'static str,
+}
+
+impl Rule for RuleNames {
+    type Query = Ast<JsonMember>;
+    type State = RuleNamesState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let (group, name) = rule_member_names(node)?;
+        if matches!(name.as_str(), "recommended" | "all") {
+            return None;
+        }
+        let (new_group, new_name) = LintRules::get().find_current_name(&group, &name)?;
+        Some(RuleNamesState {
+            group,
+            name,
+            new_group,
+            new_name,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let RuleNamesState {
+            group,
+            name,
+            new_group,
+            new_name,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                category!("migrate"),
+                node.name().ok()?.range(),
+                markup! {
+                    "The rule "<Emphasis>{group}"/"{name}</Emphasis>" is now "<Emphasis>{new_group}"/"{new_name}</Emphasis>"."
+                }
+                .to_owned(),
+            )
+            .note(markup! {
+                "Rules are renamed when their name doesn't reflect what they check, and leave the "<Emphasis>"nursery"</Emphasis>" group once they are stable."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<MigrationAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let new_group = state.new_group;
+        let new_name = state.new_name;
+
+        let group_list = node.parent::<JsonMemberList>()?;
+        let group_member = parent_member(node)?;
+        let rules_list = group_member.parent::<JsonMemberList>()?;
+
+        let without_node = group_list
+            .iter()
+            .filter_map(Result::ok)
+            .filter(|member| member != node)
+            .collect::<Vec<_>>();
+        let renamed = member_name_with_trivia(node, new_name)?;
+
+        if state.group == new_group {
+            let is_already_configured = without_node
+                .iter()
+                .any(|member| member_name(member).as_deref() == Some(new_name));
+            if is_already_configured {
+                mutation.replace_node(
+                    group_list.clone(),
+                    rebuild_member_list(&group_list, without_node),
+                );
+            } else {
+                mutation.replace_node(node.clone(), renamed);
+            }
+        } else {
+            let old_group_member = with_member_list(
+                &group_member,
+                rebuild_member_list(&group_list, without_node),
+            )?;
+            let mut is_moved = false;
+            let mut rules = Vec::new();
+            for member in rules_list.iter() {
+                let member = member.ok()?;
+                if member == group_member {
+                    rules.push(old_group_member.clone());
+                } else if member_name(&member).as_deref() == Some(new_group) {
+                    is_moved = true;
+                    let list = member_list(&member)?;
+                    let is_already_configured = list.iter().any(|rule| {
+                        rule.ok()
+                            .is_some_and(|rule| member_name(&rule).as_deref() == Some(new_name))
+                    });
+                    if is_already_configured {
+                        rules.push(member);
+                    } else {
+                        let mut members = list.iter().collect::<Result<Vec<_>, _>>().ok()?;
+                        members.push(renamed.clone());
+                        rules.push(with_member_list(
+                            &member,
+                            rebuild_member_list(&list, members),
+                        )?);
+                    }
+                } else {
+                    rules.push(member);
+                }
+            }
+            if !is_moved {
+                let new_group_member = member_name_with_trivia(&group_member, new_group)?;
+                let list = member_list(&new_group_member)?;
+                rules.push(with_member_list(
+                    &new_group_member,
+                    rebuild_member_list(&list, vec![renamed]),
+                )?);
+            }
+            mutation.replace_node(rules_list.clone(), rebuild_member_list(&rules_list, rules));
+        }
+
+        Some(RuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! {
+                "Use the rule "<Emphasis>{new_group}"/"{new_name}</Emphasis>" instead."
+            }
+            .to_owned(),
+            mutation,
+        })
+    }
+}
+
+fn member_list(member: &JsonMember) -> Option<JsonMemberList> {
+    Some(
+        member
+            .value()
+            .ok()?
+            .as_json_object_value()?
+            .json_member_list(),
+    )
+}
+
+fn with_member_list(member: &JsonMember, list: JsonMemberList) -> Option<JsonMember> {
+    let object: JsonObjectValue = member.value().ok()?.as_json_object_value()?.clone();
+    Some(member.clone().with_value(AnyJsonValue::JsonObjectValue(
+        object.with_json_member_list(list),
+    )))
+}
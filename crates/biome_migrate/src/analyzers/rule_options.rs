@@ -0,0 +1,98 @@
+use crate::lint_rules::{
+    member_name, member_name_with_trivia, parent_member, rebuild_member_list, rule_member_names,
+    LintRules,
+};
+use crate::{declare_migration, MigrationAction};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{ActionCategory, Ast, Rule, RuleAction, RuleDiagnostic, RuleOptionMigration};
+use biome_console::markup;
+use biome_diagnostics::{category, Applicability};
+use biome_json_syntax::{JsonMember, JsonMemberList};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_migration! {
+    pub(crate) RuleOptions {
+        version: "1.7.0",
+        name: "ruleOptions",
+    }
+}
+
+impl Rule for RuleOptions {
+    type Query = Ast<JsonMember>;
+    type State = (String, RuleOptionMigration);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = parent_member(node)?;
+        if member_name(&options).as_deref() != Some("options") {
+            return None;
+        }
+        let (group, name) = rule_member_names(&parent_member(&options)?)?;
+        let option_name = member_name(node)?;
+        let migration = LintRules::get()
+            .get_metadata(&group, &name)?
+            .option_migrations
+            .iter()
+            .find(|migration| migration.option_name() == option_name)?;
+        Some((name, *migration))
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let (rule_name, migration) = state;
+        let option_name = migration.option_name();
+        let message = match migration {
+            RuleOptionMigration::Rename { to, .. } => markup! {
+                "The option "<Emphasis>{option_name}</Emphasis>" of the rule "<Emphasis>{rule_name}</Emphasis>" has been renamed "<Emphasis>{to}</Emphasis>"."
+            }
+            .to_owned(),
+            RuleOptionMigration::Remove(_) => markup! {
+                "The option "<Emphasis>{option_name}</Emphasis>" of the rule "<Emphasis>{rule_name}</Emphasis>" has been removed."
+            }
+            .to_owned(),
+        };
+        Some(RuleDiagnostic::new(
+            category!("migrate"),
+            node.name().ok()?.range(),
+            message,
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<MigrationAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let (_, migration) = state;
+
+        let message = match migration {
+            RuleOptionMigration::Rename { to, .. } => {
+                mutation.replace_node(node.clone(), member_name_with_trivia(node, to)?);
+                markup! {
+                    "Use the option "<Emphasis>{to}</Emphasis>" instead."
+                }
+                .to_owned()
+            }
+            RuleOptionMigration::Remove(_) => {
+                let list = node.parent::<JsonMemberList>()?;
+                let members = list
+                    .iter()
+                    .filter_map(Result::ok)
+                    .filter(|member| member != node)
+                    .collect();
+                mutation.replace_node(list.clone(), rebuild_member_list(&list, members));
+                markup! {
+                    "Remove the option."
+                }
+                .to_owned()
+            }
+        };
+
+        Some(RuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message,
+            mutation,
+        })
+    }
+}
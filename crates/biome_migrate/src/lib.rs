@@ -1,4 +1,5 @@
 mod analyzers;
+mod lint_rules;
 mod macros;
 mod registry;
 mod version_services;
@@ -0,0 +1,231 @@
+use biome_analyze::{
+    GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMetadata,
+};
+use biome_css_syntax::CssLanguage;
+use biome_js_syntax::JsLanguage;
+use biome_json_factory::make::{json_member_list, json_member_name};
+use biome_json_syntax::{
+    JsonArrayElementList, JsonArrayValue, JsonLanguage, JsonMember, JsonMemberList,
+    JsonObjectValue, JsonRoot, JsonSyntaxKind, JsonSyntaxToken, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+use std::collections::BTreeMap;
+
+/// The lint rules of all the languages, indexed by group and by name
+#[derive(Debug, Default)]
+pub(crate) struct LintRules {
+    groups: BTreeMap<&'static str, BTreeMap<&'static str, RuleMetadata>>,
+    /// The previous names of the rules, in the form `group/ruleName`, mapped to their current group and name
+    previous_names: BTreeMap<&'static str, (&'static str, &'static str)>,
+}
+
+impl LintRules {
+    pub(crate) fn get() -> &'static Self {
+        lazy_static::lazy_static! {
+            static ref LINT_RULES: LintRules = {
+                let mut rules = LintRules::default();
+                biome_js_analyze::visit_registry(&mut rules);
+                biome_json_analyze::visit_registry(&mut rules);
+                biome_css_analyze::visit_registry(&mut rules);
+                rules
+            };
+        }
+
+        &LINT_RULES
+    }
+
+    pub(crate) fn is_group(&self, group: &str) -> bool {
+        self.groups.contains_key(group)
+    }
+
+    pub(crate) fn get_metadata(&self, group: &str, name: &str) -> Option<&RuleMetadata> {
+        self.groups.get(group)?.get(name)
+    }
+
+    /// Returns the current group and name of a rule that was configured as `group/name` by a
+    /// previous version, or [None] if the rule is up to date or unknown.
+    ///
+    /// Besides the previous names recorded in the metadata of the rules, a rule that doesn't exist
+    /// in `group` anymore is looked up by name in the other groups, which covers the rules
+    /// promoted out of the `nursery` group.
+    pub(crate) fn find_current_name(
+        &self,
+        group: &str,
+        name: &str,
+    ) -> Option<(&'static str, &'static str)> {
+        if self.get_metadata(group, name).is_some() {
+            return None;
+        }
+        if let Some(current) = self.previous_names.get(format!("{group}/{name}").as_str()) {
+            return Some(*current);
+        }
+        let mut groups = self.groups.iter().filter_map(|(group, rules)| {
+            rules.get_key_value(name).map(|(name, _)| (*group, *name))
+        });
+        let current = groups.next()?;
+        // A name that exists in several groups is ambiguous
+        groups.next().is_none().then_some(current)
+    }
+
+    fn record<R: Rule>(&mut self) {
+        let group = <R::Group as RuleGroup>::NAME;
+        let metadata = R::METADATA;
+        for previous_name in metadata.previous_names {
+            self.previous_names
+                .insert(previous_name, (group, metadata.name));
+        }
+        self.groups
+            .entry(group)
+            .or_default()
+            .insert(metadata.name, metadata);
+    }
+}
+
+impl RegistryVisitor<JsLanguage> for LintRules {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.record::<R>();
+    }
+}
+
+impl RegistryVisitor<JsonLanguage> for LintRules {
+    fn record_category<C: GroupCategory<Language = JsonLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsonLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.record::<R>();
+    }
+}
+
+impl RegistryVisitor<CssLanguage> for LintRules {
+    fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = CssLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.record::<R>();
+    }
+}
+
+/// Returns the member that contains the object where `member` is declared
+pub(crate) fn parent_member(member: &JsonMember) -> Option<JsonMember> {
+    member
+        .parent::<JsonMemberList>()?
+        .parent::<JsonObjectValue>()?
+        .parent::<JsonMember>()
+}
+
+pub(crate) fn member_name(member: &JsonMember) -> Option<String> {
+    Some(member.name().ok()?.inner_string_text().ok()?.to_string())
+}
+
+/// Returns whether `member` is the member `rules` of the linter configuration, either at the top
+/// level of the configuration file or inside an override.
+pub(crate) fn is_rules_member(member: &JsonMember) -> bool {
+    if member_name(member).as_deref() != Some("rules") {
+        return false;
+    }
+    let Some(linter) = parent_member(member) else {
+        return false;
+    };
+    if member_name(&linter).as_deref() != Some("linter") {
+        return false;
+    }
+    let Some(linter_object) = linter.parent::<JsonMemberList>() else {
+        return false;
+    };
+    let Some(linter_object) = linter_object.parent::<JsonObjectValue>() else {
+        return false;
+    };
+    if linter_object.parent::<JsonRoot>().is_some() {
+        return true;
+    }
+    // `overrides: [{ "linter": { "rules": {} } }]`
+    linter_object
+        .parent::<JsonArrayElementList>()
+        .and_then(|list| list.parent::<JsonArrayValue>())
+        .and_then(|array| array.parent::<JsonMember>())
+        .is_some_and(|overrides| {
+            member_name(&overrides).as_deref() == Some("overrides")
+                && overrides
+                    .parent::<JsonMemberList>()
+                    .and_then(|list| list.parent::<JsonObjectValue>())
+                    .is_some_and(|object| object.parent::<JsonRoot>().is_some())
+        })
+}
+
+/// Returns the group and the name of the rule configured by `member`, if `member` configures a
+/// rule inside a group of `linter.rules`.
+pub(crate) fn rule_member_names(member: &JsonMember) -> Option<(String, String)> {
+    let group = parent_member(member)?;
+    let rules = parent_member(&group)?;
+    if !is_rules_member(&rules) {
+        return None;
+    }
+    let group_name = member_name(&group)?;
+    if !LintRules::get().is_group(&group_name) {
+        return None;
+    }
+    Some((group_name, member_name(member)?))
+}
+
+/// Creates the name of a member, with the trivia of `previous`
+pub(crate) fn member_name_with_trivia(previous: &JsonMember, name: &str) -> Option<JsonMember> {
+    let previous_token = previous.name().ok()?.value_token().ok()?;
+    let token = JsonSyntaxToken::new_detached(
+        JsonSyntaxKind::JSON_STRING_LITERAL,
+        &format!("\"{name}\""),
+        [],
+        [],
+    )
+    .with_leading_trivia_pieces(previous_token.leading_trivia().pieces())
+    .with_trailing_trivia_pieces(previous_token.trailing_trivia().pieces());
+    Some(previous.clone().with_name(json_member_name(token)))
+}
+
+/// Rebuilds `list` with `members`, reusing the separators of `list` when possible
+pub(crate) fn rebuild_member_list(
+    list: &JsonMemberList,
+    members: Vec<JsonMember>,
+) -> JsonMemberList {
+    let has_trailing_separator = list.trailing_separator().is_some();
+    let mut separators = list
+        .elements()
+        .filter_map(|element| element.trailing_separator().ok().flatten().cloned())
+        .collect::<Vec<_>>();
+    let separator_count = if has_trailing_separator {
+        members.len()
+    } else {
+        members.len().saturating_sub(1)
+    };
+    separators.truncate(separator_count);
+    while separators.len() < separator_count {
+        separators.push(JsonSyntaxToken::new_detached(T![,], ",", [], []));
+    }
+    json_member_list(members, separators)
+}
@@ -1,10 +1,16 @@
 mod package_json;
+mod pnpm_workspace;
+mod tsconfig_json;
+mod workspace;
 
-pub use crate::node_js_project::package_json::PackageJson;
+pub use crate::node_js_project::package_json::{PackageBin, PackageExports, PackageJson};
+pub use crate::node_js_project::pnpm_workspace::PnpmWorkspace;
+pub use crate::node_js_project::tsconfig_json::{CompilerOptions, Jsx, TsConfigJson};
+pub use crate::node_js_project::workspace::NodeJsWorkspace;
 use crate::{Manifest, Project, ProjectAnalyzeDiagnostic, ProjectAnalyzeResult, LICENSE_LIST};
 use biome_json_syntax::JsonRoot;
 use biome_rowan::Language;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 #[derive(Default, Debug, Clone)]
 /// A Node.js project.
@@ -66,3 +72,22 @@ impl Project for NodeJsProject {
         !self.diagnostics.is_empty()
     }
 }
+
+/// Removes the components `.` and `..` of `path`
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(result.components().next_back(), Some(Component::Normal(_))) {
+                    result.pop();
+                } else {
+                    result.push(component);
+                }
+            }
+            _ => result.push(component),
+        }
+    }
+    result
+}
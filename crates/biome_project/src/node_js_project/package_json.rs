@@ -18,6 +18,117 @@ pub struct PackageJson {
     pub peer_dependencies: Dependencies,
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
+    /// The entry point of the package, used when it doesn't declare `exports`
+    pub main: Option<String>,
+    /// The entry points of the package
+    pub exports: Option<PackageExports>,
+    /// The paths of the executables of the package, declared with `bin`
+    pub bin: PackageBin,
+    /// The glob patterns of the directories of the packages of the monorepo, declared with
+    /// `workspaces` as an array or as the field `packages` of an object
+    pub workspaces: Vec<String>,
+}
+
+/// The value of the field `bin` of a `package.json`, which is the path of the executable of the
+/// package or a map of the names of its executables to their paths
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct PackageBin(Vec<String>);
+
+impl PackageBin {
+    /// Returns the paths of the executables
+    pub fn paths(&self) -> &[String] {
+        &self.0
+    }
+}
+
+/// The value of the field `exports` of a `package.json`, or of one of its entries.
+///
+/// See the [Node.js documentation](https://nodejs.org/api/packages.html#package-entry-points)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PackageExports {
+    /// The path of a file of the package, like `"./index.js"`
+    Path(String),
+    /// Subpaths (`{ "./feature": "./feature.js" }`) or conditions
+    /// (`{ "import": "./index.mjs" }`), in the order they were declared
+    Map(Vec<(String, PackageExports)>),
+    /// Alternatives, where the first one that can be resolved is used
+    Array(Vec<PackageExports>),
+    /// `null`, which makes a subpath private
+    Null,
+}
+
+impl PackageExports {
+    /// Returns the path of the file that `subpath` refers to, where `subpath` is `.` for the
+    /// package itself or starts with `./`.
+    ///
+    /// The first condition of a map that is contained in `conditions` is used.
+    pub fn resolve(&self, subpath: &str, conditions: &[&str]) -> Option<String> {
+        match self {
+            Self::Map(entries) if entries.iter().any(|(key, _)| key.starts_with('.')) => {
+                if let Some((_, target)) = entries.iter().find(|(key, _)| key == subpath) {
+                    return target.resolve_target("", conditions);
+                }
+                // The pattern with the longest prefix wins
+                entries
+                    .iter()
+                    .filter_map(|(key, target)| {
+                        let (prefix, suffix) = key.split_once('*')?;
+                        let matched = subpath.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                        Some((prefix.len(), target, matched))
+                    })
+                    .max_by_key(|(prefix_length, ..)| *prefix_length)
+                    .and_then(|(_, target, matched)| target.resolve_target(matched, conditions))
+            }
+            _ if subpath == "." => self.resolve_target("", conditions),
+            _ => None,
+        }
+    }
+
+    /// Returns the path of a target, where `*` is replaced by `pattern_match`
+    fn resolve_target(&self, pattern_match: &str, conditions: &[&str]) -> Option<String> {
+        match self {
+            Self::Path(path) => Some(path.replace('*', pattern_match)),
+            Self::Map(entries) => entries
+                .iter()
+                .find(|(key, _)| key == "default" || conditions.contains(&key.as_str()))
+                .and_then(|(_, target)| target.resolve_target(pattern_match, conditions)),
+            Self::Array(targets) => targets
+                .iter()
+                .find_map(|target| target.resolve_target(pattern_match, conditions)),
+            Self::Null => None,
+        }
+    }
+
+    /// Returns the paths of all the files that can be exported, whatever the conditions.
+    ///
+    /// The subpath patterns, like `"./features/*": "./src/features/*.js"`, are ignored because
+    /// they don't refer to specific files.
+    pub fn targets(&self) -> Vec<&str> {
+        let mut targets = Vec::new();
+        self.collect_targets(&mut targets);
+        targets
+    }
+
+    fn collect_targets<'a>(&'a self, targets: &mut Vec<&'a str>) {
+        match self {
+            Self::Path(path) => {
+                if !path.contains('*') {
+                    targets.push(path);
+                }
+            }
+            Self::Map(entries) => {
+                for (_, target) in entries {
+                    target.collect_targets(targets);
+                }
+            }
+            Self::Array(items) => {
+                for target in items {
+                    target.collect_targets(targets);
+                }
+            }
+            Self::Null => {}
+        }
+    }
 }
 
 impl Manifest for PackageJson {
@@ -88,6 +199,24 @@ impl DeserializationVisitor for PackageJsonVisitor {
                     result.license = Deserializable::deserialize(&value, &key_text, diagnostics)
                         .map(|license| (license, license_range));
                 }
+                "main" => {
+                    result.main = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "exports" => {
+                    result.exports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "bin" => {
+                    if let Some(bin) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                        result.bin = bin;
+                    }
+                }
+                "workspaces" => {
+                    if let Some(workspaces) =
+                        value.deserialize(WorkspacesVisitor, &key_text, diagnostics)
+                    {
+                        result.workspaces = workspaces;
+                    }
+                }
                 "description" => {
                     result.description =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
@@ -139,3 +268,164 @@ impl Deserializable for Version {
         }
     }
 }
+
+impl Deserializable for PackageExports {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageExportsVisitor, name, diagnostics)
+    }
+}
+
+struct PackageExportsVisitor;
+impl DeserializationVisitor for PackageExportsVisitor {
+    type Output = PackageExports;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::STR
+        .union(VisitableType::MAP)
+        .union(VisitableType::ARRAY)
+        .union(VisitableType::NULL);
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Null)
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Path(value.text().to_string()))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Array(
+            items
+                .flatten()
+                .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+                .collect(),
+        ))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut entries = Vec::new();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(value) = Deserializable::deserialize(&value, name, diagnostics) {
+                entries.push((key_text.text().to_string(), value));
+            }
+        }
+        Some(PackageExports::Map(entries))
+    }
+}
+
+impl Deserializable for PackageBin {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageBinVisitor, name, diagnostics)
+    }
+}
+
+struct PackageBinVisitor;
+impl DeserializationVisitor for PackageBinVisitor {
+    type Output = PackageBin;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageBin(vec![value.text().to_string()]))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageBin(
+            members
+                .flatten()
+                .filter_map(|(_, value)| Text::deserialize(&value, name, diagnostics))
+                .map(|path| path.text().to_string())
+                .collect(),
+        ))
+    }
+}
+
+struct WorkspacesVisitor;
+impl DeserializationVisitor for WorkspacesVisitor {
+    type Output = Vec<String>;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::ARRAY.union(VisitableType::MAP);
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(
+            items
+                .flatten()
+                .filter_map(|item| Text::deserialize(&item, name, diagnostics))
+                .map(|pattern| pattern.text().to_string())
+                .collect(),
+        )
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        // The Yarn syntax, `{ "packages": [...], "nohoist": [...] }`
+        let mut result = Vec::new();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if key_text.text() == "packages" {
+                if let Some(packages) = value.deserialize(Self, &key_text, diagnostics) {
+                    result = packages;
+                }
+            }
+        }
+        Some(result)
+    }
+}
@@ -0,0 +1,62 @@
+use serde::Deserialize;
+
+/// The `pnpm-workspace.yaml` at the root of a pnpm monorepo.
+///
+/// Only the list `packages` is read:
+///
+/// ```yaml
+/// packages:
+///   - "packages/*"
+///   - "!**/test/**"
+/// ```
+///
+/// See the [pnpm documentation](https://pnpm.io/pnpm-workspace_yaml)
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct PnpmWorkspace {
+    /// The glob patterns of the directories of the packages
+    pub packages: Vec<String>,
+}
+
+impl PnpmWorkspace {
+    /// The name of the file
+    pub const FILE_NAME: &'static str = "pnpm-workspace.yaml";
+
+    /// Reads the list `packages` of the YAML `source`. The other keys are ignored, and a
+    /// `source` that isn't a valid workspace file has no packages.
+    pub fn parse(source: &str) -> Self {
+        serde_yaml::from_str(source).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PnpmWorkspace;
+
+    #[test]
+    fn reads_the_block_sequence() {
+        let workspace = PnpmWorkspace::parse(
+            r#"# The packages of the monorepo
+packages:
+  - "packages/*"
+  # The applications
+  - 'apps/**' # and their examples
+  - '!**/test/**'
+
+catalog:
+  react: ^18.2.0
+"#,
+        );
+        assert_eq!(workspace.packages, ["packages/*", "apps/**", "!**/test/**"]);
+    }
+
+    #[test]
+    fn reads_the_flow_sequence() {
+        let workspace = PnpmWorkspace::parse(
+            r#"packages: ["packages/*",
+  tools/#internal]
+"#,
+        );
+        assert_eq!(workspace.packages, ["packages/*", "tools/#internal"]);
+    }
+}
@@ -0,0 +1,487 @@
+use super::normalize;
+use crate::{LanguageRoot, Manifest};
+use biome_deserialize::json::deserialize_from_json_ast;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor,
+    Deserialized, Text, VisitableType,
+};
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::JsonLanguage;
+use biome_text_size::TextRange;
+use rustc_hash::FxHashSet;
+use std::path::{Path, PathBuf};
+
+/// The `tsconfig.json` of a TypeScript project.
+///
+/// Only the options that change how the modules are resolved and how JSX is compiled are read.
+/// [TsConfigJson::load] merges the configuration files that the `tsconfig.json` extends.
+#[derive(Debug, Default, Clone)]
+pub struct TsConfigJson {
+    /// The configuration files that this one extends, as written in `extends`
+    pub extends: Vec<String>,
+    pub compiler_options: CompilerOptions,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompilerOptions {
+    /// The directory where the non-relative specifiers are resolved.
+    ///
+    /// It's relative to the directory of the configuration file, until [TsConfigJson::load]
+    /// joins them.
+    pub base_url: Option<PathBuf>,
+    /// The path aliases, like `"@app/*": ["./src/*"]`, in the order they were declared
+    pub paths: Option<Vec<(String, Vec<String>)>>,
+    /// The directory where the targets of `paths` are resolved when there is no `baseUrl`:
+    /// the directory of the configuration file that declares `paths`
+    pub paths_directory: Option<PathBuf>,
+    /// How JSX is compiled
+    pub jsx: Option<Jsx>,
+    /// The module that provides the JSX factory functions of the automatic runtime
+    pub jsx_import_source: Option<String>,
+}
+
+/// The value of the option `jsx` of `compilerOptions`.
+///
+/// See the [TypeScript documentation](https://www.typescriptlang.org/tsconfig#jsx)
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Jsx {
+    /// The JSX is kept as is
+    Preserve,
+    /// The JSX is compiled to calls of `React.createElement`
+    React,
+    /// The JSX is compiled to calls of the functions of `react/jsx-runtime`
+    ReactJsx,
+    /// The JSX is compiled to calls of the functions of `react/jsx-dev-runtime`
+    ReactJsxDev,
+    /// The JSX is kept as is, in files with the extension `.js`
+    ReactNative,
+}
+
+impl Jsx {
+    const ALLOWED_VARIANTS: &'static [&'static str] = &[
+        "preserve",
+        "react",
+        "react-jsx",
+        "react-jsxdev",
+        "react-native",
+    ];
+
+    /// Whether the JSX elements are compiled to calls of the factory of the classic runtime,
+    /// `React.createElement`, which must be in scope
+    pub const fn is_classic_runtime(&self) -> bool {
+        matches!(self, Self::React)
+    }
+}
+
+impl TsConfigJson {
+    /// Reads the configuration file at `path` and the files it extends, where `read` returns
+    /// the content of a file, or [None] when it doesn't exist.
+    ///
+    /// The options of a configuration file override the ones of the files it extends, and the
+    /// paths of `baseUrl` are joined with the directory of the file that declares them.
+    ///
+    /// Returns [None] when the file at `path` can't be read.
+    pub fn load(path: &Path, read: impl Fn(&Path) -> Option<String>) -> Option<Self> {
+        let mut visited = FxHashSet::default();
+        Self::load_with(path, &read, &mut visited)
+    }
+
+    fn load_with(
+        path: &Path,
+        read: &impl Fn(&Path) -> Option<String>,
+        visited: &mut FxHashSet<PathBuf>,
+    ) -> Option<Self> {
+        // A configuration file that extends itself, directly or not, is invalid
+        if !visited.insert(path.to_path_buf()) {
+            return None;
+        }
+        let content = read(path)?;
+        let parsed = parse_json(
+            &content,
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
+        );
+        let mut config = Self::deserialize_manifest(&parsed.tree())
+            .into_deserialized()
+            .unwrap_or_default();
+        let directory = path.parent().unwrap_or(Path::new(""));
+        let options = &mut config.compiler_options;
+        options.base_url = options
+            .base_url
+            .take()
+            .map(|base_url| normalize(&directory.join(base_url)));
+        if options.paths.is_some() {
+            options.paths_directory = Some(normalize(directory));
+        }
+
+        // With several files, the last one has the highest priority
+        for specifier in config.extends.clone().iter().rev() {
+            let base = extends_candidates(directory, specifier)
+                .into_iter()
+                .find_map(|candidate| Self::load_with(&candidate, read, visited));
+            if let Some(base) = base {
+                config.compiler_options.inherit(base.compiler_options);
+            }
+        }
+        Some(config)
+    }
+
+    /// Whether `specifier` matches one of the path aliases declared in `paths`
+    pub fn is_alias(&self, specifier: &str) -> bool {
+        self.matching_alias(specifier).is_some()
+    }
+
+    /// Returns the paths that the non-relative `specifier` refers to, in the order they should
+    /// be tried: the targets of the path alias it matches, then the path relative to `baseUrl`.
+    ///
+    /// The paths don't have an extension when the specifier doesn't have one.
+    pub fn resolve(&self, specifier: &str) -> Vec<PathBuf> {
+        let options = &self.compiler_options;
+        let mut paths = Vec::new();
+        if let Some((targets, matched)) = self.matching_alias(specifier) {
+            if let Some(directory) = options
+                .base_url
+                .as_ref()
+                .or(options.paths_directory.as_ref())
+            {
+                paths.extend(
+                    targets
+                        .iter()
+                        .map(|target| normalize(&directory.join(target.replacen('*', matched, 1)))),
+                );
+            }
+        }
+        if let Some(base_url) = &options.base_url {
+            paths.push(normalize(&base_url.join(specifier)));
+        }
+        paths
+    }
+
+    /// Returns the targets of the path alias that `specifier` matches, with the part of the
+    /// specifier matched by `*`.
+    ///
+    /// An exact alias wins over the patterns, and the pattern with the longest prefix wins over
+    /// the other ones.
+    fn matching_alias<'a>(&'a self, specifier: &'a str) -> Option<(&'a [String], &'a str)> {
+        let paths = self.compiler_options.paths.as_ref()?;
+        if let Some((_, targets)) = paths.iter().find(|(alias, _)| alias == specifier) {
+            return Some((targets, ""));
+        }
+        paths
+            .iter()
+            .filter_map(|(alias, targets)| {
+                let (prefix, suffix) = alias.split_once('*')?;
+                let matched = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                Some((prefix.len(), targets.as_slice(), matched))
+            })
+            .max_by_key(|(prefix_length, ..)| *prefix_length)
+            .map(|(_, targets, matched)| (targets, matched))
+    }
+}
+
+impl CompilerOptions {
+    /// Takes the options that aren't set from the options of the file that is extended
+    fn inherit(&mut self, base: Self) {
+        if self.base_url.is_none() {
+            self.base_url = base.base_url;
+        }
+        if self.paths.is_none() {
+            self.paths = base.paths;
+            self.paths_directory = base.paths_directory;
+        }
+        if self.jsx.is_none() {
+            self.jsx = base.jsx;
+        }
+        if self.jsx_import_source.is_none() {
+            self.jsx_import_source = base.jsx_import_source;
+        }
+    }
+}
+
+/// Returns the paths of the configuration file that `specifier`, a value of `extends` of the
+/// configuration file in `directory`, can refer to
+fn extends_candidates(directory: &Path, specifier: &str) -> Vec<PathBuf> {
+    let with_extensions = |path: PathBuf| {
+        let mut candidates = vec![path.clone()];
+        if path
+            .extension()
+            .map_or(true, |extension| extension != "json")
+        {
+            let mut with_extension = path.clone().into_os_string();
+            with_extension.push(".json");
+            candidates.push(with_extension.into());
+        }
+        candidates.push(path.join("tsconfig.json"));
+        candidates
+    };
+    if specifier.starts_with('.') || Path::new(specifier).is_absolute() {
+        return with_extensions(normalize(&directory.join(specifier)));
+    }
+    // The configuration files of the packages installed in `node_modules`
+    directory
+        .ancestors()
+        .flat_map(|ancestor| with_extensions(ancestor.join("node_modules").join(specifier)))
+        .collect()
+}
+
+impl Manifest for TsConfigJson {
+    type Language = JsonLanguage;
+
+    fn deserialize_manifest(root: &LanguageRoot<Self::Language>) -> Deserialized<Self> {
+        deserialize_from_json_ast::<TsConfigJson>(root, "")
+    }
+}
+
+impl Deserializable for TsConfigJson {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(TsConfigJsonVisitor, name, diagnostics)
+    }
+}
+
+struct TsConfigJsonVisitor;
+impl DeserializationVisitor for TsConfigJsonVisitor {
+    type Output = TsConfigJson;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "extends" => {
+                    if let Some(extends) = value.deserialize(ExtendsVisitor, &key_text, diagnostics)
+                    {
+                        result.extends = extends;
+                    }
+                }
+                "compilerOptions" => {
+                    if let Some(compiler_options) =
+                        value.deserialize(CompilerOptionsVisitor, &key_text, diagnostics)
+                    {
+                        result.compiler_options = compiler_options;
+                    }
+                }
+                _ => {
+                    // The other options don't change how Biome analyzes the files
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+/// `extends` is the specifier of a configuration file, or a list of them
+struct ExtendsVisitor;
+impl DeserializationVisitor for ExtendsVisitor {
+    type Output = Vec<String>;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::ARRAY);
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(vec![value.text().to_string()])
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(
+            items
+                .flatten()
+                .filter_map(|item| Text::deserialize(&item, name, diagnostics))
+                .map(|specifier| specifier.text().to_string())
+                .collect(),
+        )
+    }
+}
+
+struct CompilerOptionsVisitor;
+impl DeserializationVisitor for CompilerOptionsVisitor {
+    type Output = CompilerOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "baseUrl" => {
+                    result.base_url = Text::deserialize(&value, &key_text, diagnostics)
+                        .map(|base_url| PathBuf::from(base_url.text()));
+                }
+                "paths" => {
+                    result.paths = value.deserialize(PathsVisitor, &key_text, diagnostics);
+                }
+                "jsx" => {
+                    result.jsx = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "jsxImportSource" => {
+                    result.jsx_import_source =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                _ => {}
+            }
+        }
+        Some(result)
+    }
+}
+
+struct PathsVisitor;
+impl DeserializationVisitor for PathsVisitor {
+    type Output = Vec<(String, Vec<String>)>;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Vec::new();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(targets) = Deserializable::deserialize(&value, name, diagnostics) {
+                result.push((key_text.text().to_string(), targets));
+            }
+        }
+        Some(result)
+    }
+}
+
+impl Deserializable for Jsx {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        // TypeScript ignores the case of the values
+        match value_text.text().to_ascii_lowercase().as_str() {
+            "preserve" => Some(Self::Preserve),
+            "react" => Some(Self::React),
+            "react-jsx" => Some(Self::ReactJsx),
+            "react-jsxdev" => Some(Self::ReactJsxDev),
+            "react-native" => Some(Self::ReactNative),
+            unknown => {
+                diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                    unknown,
+                    value.range(),
+                    Jsx::ALLOWED_VARIANTS,
+                ));
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Jsx, TsConfigJson};
+    use std::path::{Path, PathBuf};
+
+    fn read(path: &Path) -> Option<String> {
+        let content = match path.to_str()? {
+            "app/tsconfig.json" => {
+                r#"{
+    // The shared options
+    "extends": ["../tsconfig.base.json", "@tsconfig/strictest"],
+    "compilerOptions": {
+        "paths": {
+            "@app/*": ["./src/*", "./generated/*"],
+            "@app/config": ["./config.ts"],
+        },
+    },
+}"#
+            }
+            "tsconfig.base.json" => {
+                r#"{
+    "compilerOptions": {
+        "baseUrl": "./packages",
+        "paths": { "@shared/*": ["shared/*"] },
+        "jsx": "react"
+    }
+}"#
+            }
+            "web/tsconfig.json" => r#"{ "compilerOptions": { "paths": { "~/*": ["./src/*"] } } }"#,
+            "node_modules/@tsconfig/strictest/tsconfig.json" => {
+                r#"{ "compilerOptions": { "jsx": "react-jsx", "jsxImportSource": "preact" } }"#
+            }
+            _ => return None,
+        };
+        Some(content.to_string())
+    }
+
+    #[test]
+    fn merges_the_extended_configurations() {
+        let config = TsConfigJson::load(Path::new("app/tsconfig.json"), read).unwrap();
+        let options = &config.compiler_options;
+        assert_eq!(options.base_url, Some(PathBuf::from("packages")));
+        assert_eq!(options.paths_directory, Some(PathBuf::from("app")));
+        // The last extended file has the highest priority
+        assert_eq!(options.jsx, Some(Jsx::ReactJsx));
+        assert_eq!(options.jsx_import_source.as_deref(), Some("preact"));
+
+        assert!(TsConfigJson::load(Path::new("missing.json"), read).is_none());
+    }
+
+    #[test]
+    fn resolves_the_path_aliases() {
+        let config = TsConfigJson::load(Path::new("app/tsconfig.json"), read).unwrap();
+        assert!(config.is_alias("@app/components/button"));
+        assert!(!config.is_alias("@shared/utils"));
+        assert_eq!(
+            config.resolve("@app/components/button"),
+            [
+                PathBuf::from("packages/src/components/button"),
+                PathBuf::from("packages/generated/components/button"),
+                PathBuf::from("packages/@app/components/button"),
+            ]
+        );
+        assert_eq!(
+            config.resolve("@app/config")[0],
+            PathBuf::from("packages/config.ts")
+        );
+        assert_eq!(config.resolve("react"), [PathBuf::from("packages/react")]);
+
+        // Without `baseUrl`, the targets are relative to the file that declares `paths`
+        let config = TsConfigJson::load(Path::new("web/tsconfig.json"), read).unwrap();
+        assert_eq!(config.resolve("~/utils"), [PathBuf::from("web/src/utils")]);
+        assert!(config.resolve("react").is_empty());
+    }
+}
@@ -0,0 +1,207 @@
+use super::normalize;
+use crate::PackageJson;
+use std::path::{Component, Path, PathBuf};
+
+/// The packages of a Node.js project, which can be a monorepo.
+///
+/// The root of a monorepo declares the directories of its packages with the field `workspaces`
+/// of its `package.json`, or with the list `packages` of its `pnpm-workspace.yaml`.
+/// Each file of the project belongs to the package of its nearest `package.json`.
+#[derive(Debug, Default, Clone)]
+pub struct NodeJsWorkspace {
+    /// The directory of the root of the monorepo, when the project is one
+    root: Option<PathBuf>,
+    /// The glob patterns of the directories of the packages, relative to `root`.
+    /// The patterns that start with `!` exclude the directories they match.
+    patterns: Vec<String>,
+    /// The manifests of the project, by directory
+    packages: Vec<(PathBuf, PackageJson)>,
+}
+
+impl NodeJsWorkspace {
+    /// Sets the root of the monorepo, which is the directory of the `package.json` or the
+    /// `pnpm-workspace.yaml` that declares the glob `patterns` of the directories of its packages
+    pub fn set_root(&mut self, directory: &Path, patterns: Vec<String>) {
+        self.root = Some(normalize(directory));
+        self.patterns = patterns;
+    }
+
+    /// Adds the `package.json` of `directory`. A root of a monorepo is set when it declares
+    /// `workspaces`.
+    pub fn insert_package(&mut self, directory: &Path, manifest: PackageJson) {
+        let directory = normalize(directory);
+        if !manifest.workspaces.is_empty() {
+            self.set_root(&directory, manifest.workspaces.clone());
+        }
+        self.packages.retain(|(path, _)| *path != directory);
+        self.packages.push((directory, manifest));
+    }
+
+    /// Whether the project is a monorepo
+    pub fn is_monorepo(&self) -> bool {
+        self.root.is_some()
+    }
+
+    /// Returns the manifest of the package that contains the file at `path`: the nearest
+    /// `package.json` among its ancestors
+    pub fn manifest(&self, path: &Path) -> Option<&PackageJson> {
+        let path = normalize(path);
+        self.packages
+            .iter()
+            .filter(|(directory, _)| path.starts_with(directory))
+            .max_by_key(|(directory, _)| directory.components().count())
+            .map(|(_, manifest)| manifest)
+    }
+
+    /// Whether `name` is the name of the root or of a package of the monorepo
+    pub fn is_workspace_package(&self, name: &str) -> bool {
+        self.packages.iter().any(|(directory, manifest)| {
+            manifest.name.as_deref() == Some(name)
+                && (self.root.as_ref() == Some(directory) || self.is_member(directory))
+        })
+    }
+
+    /// Whether `directory` is the directory of a package of the monorepo, according to its
+    /// glob patterns
+    pub fn is_member(&self, directory: &Path) -> bool {
+        let Some(root) = &self.root else {
+            return false;
+        };
+        let Ok(relative) = normalize(directory)
+            .strip_prefix(root)
+            .map(Path::to_path_buf)
+        else {
+            return false;
+        };
+        let Some(components) = relative
+            .components()
+            .map(|component| match component {
+                Component::Normal(name) => name.to_str(),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+        let matches = |pattern: &str| {
+            let pattern: Vec<_> = pattern
+                .trim_start_matches("./")
+                .split('/')
+                .filter(|segment| !segment.is_empty() && *segment != ".")
+                .collect();
+            matches_pattern(&pattern, &components)
+        };
+        let mut is_member = false;
+        for pattern in &self.patterns {
+            match pattern.strip_prefix('!') {
+                Some(excluded) if matches(excluded) => return false,
+                Some(_) => {}
+                None => is_member |= matches(pattern),
+            }
+        }
+        is_member
+    }
+}
+
+/// Whether the components of a path match the segments of a glob pattern, where `**` matches
+/// any number of components
+fn matches_pattern(pattern: &[&str], components: &[&str]) -> bool {
+    match (pattern.split_first(), components.split_first()) {
+        (None, None) => true,
+        (Some((&"**", rest)), _) => {
+            matches_pattern(rest, components)
+                || (!components.is_empty() && matches_pattern(pattern, &components[1..]))
+        }
+        (Some((segment, rest)), Some((name, components))) => {
+            matches_segment(segment, name) && matches_pattern(rest, components)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `name` matches `segment`, where `*` matches any sequence of characters
+fn matches_segment(segment: &str, name: &str) -> bool {
+    let mut parts = segment.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<_> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // There is no wildcard
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NodeJsWorkspace;
+    use crate::PackageJson;
+    use std::path::Path;
+
+    fn package(name: &str) -> PackageJson {
+        PackageJson {
+            name: Some(name.to_string()),
+            ..PackageJson::default()
+        }
+    }
+
+    #[test]
+    fn matches_the_directories_of_the_packages() {
+        let mut workspace = NodeJsWorkspace::default();
+        workspace.set_root(
+            Path::new("."),
+            vec![
+                "packages/*".to_string(),
+                "./apps/**".to_string(),
+                "tools/cli-*".to_string(),
+                "!**/fixtures/**".to_string(),
+            ],
+        );
+
+        assert!(workspace.is_member(Path::new("packages/ui")));
+        assert!(workspace.is_member(Path::new("./packages/utils")));
+        assert!(workspace.is_member(Path::new("apps/web/admin")));
+        assert!(workspace.is_member(Path::new("tools/cli-build")));
+        assert!(!workspace.is_member(Path::new("tools/eslint-config")));
+        assert!(!workspace.is_member(Path::new("packages/ui/src")));
+        assert!(!workspace.is_member(Path::new("apps/web/fixtures/app")));
+        assert!(!workspace.is_member(Path::new("docs")));
+    }
+
+    #[test]
+    fn finds_the_nearest_manifest() {
+        let mut workspace = NodeJsWorkspace::default();
+        workspace.insert_package(
+            Path::new(""),
+            PackageJson {
+                workspaces: vec!["packages/*".to_string()],
+                ..package("monorepo")
+            },
+        );
+        workspace.insert_package(Path::new("./packages/ui"), package("@acme/ui"));
+        workspace.insert_package(Path::new("examples/demo"), package("demo"));
+
+        let name = |path: &str| {
+            workspace
+                .manifest(Path::new(path))
+                .and_then(|manifest| manifest.name.as_deref())
+        };
+        assert_eq!(name("packages/ui/src/button.tsx"), Some("@acme/ui"));
+        assert_eq!(name("./packages/utils/index.js"), Some("monorepo"));
+        assert_eq!(name("examples/demo/index.js"), Some("demo"));
+
+        assert!(workspace.is_monorepo());
+        assert!(workspace.is_workspace_package("@acme/ui"));
+        assert!(workspace.is_workspace_package("monorepo"));
+        assert!(!workspace.is_workspace_package("demo"));
+        assert!(!workspace.is_workspace_package("react"));
+    }
+}
@@ -4,7 +4,7 @@ use biome_console::{markup, MarkupBuf};
 use biome_deserialize::DeserializationDiagnostic;
 use biome_diagnostics::adapters::ResolveError;
 use biome_diagnostics::{
-    Advices, Category, Diagnostic, DiagnosticTags, Error, Location, LogCategory,
+    category, Advices, Category, Diagnostic, DiagnosticTags, Error, Location, LogCategory,
     MessageAndDescription, Severity, Visit,
 };
 use biome_rowan::SyntaxError;
@@ -323,6 +323,160 @@ pub struct CantResolve {
     source: Option<Error>,
 }
 
+/// A diagnostic emitted for a configuration file loaded through `extends`. It records the chain of
+/// configuration files that led to the file.
+#[derive(Debug)]
+pub struct ExtendedConfigurationDiagnostic {
+    diagnostic: Error,
+    /// The configuration files, from the one that is loaded first to the one that emitted the diagnostic
+    chain: Vec<String>,
+}
+
+impl ExtendedConfigurationDiagnostic {
+    pub fn new(diagnostic: Error, chain: Vec<String>) -> Self {
+        Self { diagnostic, chain }
+    }
+}
+
+impl Diagnostic for ExtendedConfigurationDiagnostic {
+    fn category(&self) -> Option<&'static Category> {
+        self.diagnostic.category()
+    }
+
+    fn severity(&self) -> Severity {
+        self.diagnostic.severity()
+    }
+
+    fn description(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        self.diagnostic.description(fmt)
+    }
+
+    fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
+        self.diagnostic.message(fmt)
+    }
+
+    fn advices(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        self.diagnostic.advices(visitor)?;
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "The configuration file is loaded through the following chain of "<Emphasis>"extends"</Emphasis>":"
+            },
+        )?;
+        let chain = self
+            .chain
+            .iter()
+            .map(|path| path as &dyn Display)
+            .collect::<Vec<_>>();
+        visitor.record_list(&chain)
+    }
+
+    fn verbose_advices(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        self.diagnostic.verbose_advices(visitor)
+    }
+
+    fn location(&self) -> Location<'_> {
+        self.diagnostic.location()
+    }
+
+    fn tags(&self) -> DiagnosticTags {
+        self.diagnostic.tags()
+    }
+
+    fn source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.source()
+    }
+}
+
+/// A value set by a configuration file loaded through `extends`, and overridden by another
+/// configuration file that doesn't extend it.
+///
+/// The configuration files are recorded with the chain of `extends` that led to them.
+#[derive(Debug)]
+pub struct ConflictingExtendedValue {
+    /// The dotted path of the value, like `formatter.indentStyle`
+    key: String,
+    /// The chain of the configuration file whose value is overridden
+    overridden_chain: Vec<String>,
+    /// The chain of the configuration file whose value is applied
+    applied_chain: Vec<String>,
+}
+
+impl ConflictingExtendedValue {
+    pub fn new(key: String, overridden_chain: Vec<String>, applied_chain: Vec<String>) -> Self {
+        Self {
+            key,
+            overridden_chain,
+            applied_chain,
+        }
+    }
+
+    fn overridden_file(&self) -> &str {
+        self.overridden_chain.last().map_or("", String::as_str)
+    }
+
+    fn applied_file(&self) -> &str {
+        self.applied_chain.last().map_or("", String::as_str)
+    }
+}
+
+impl Diagnostic for ConflictingExtendedValue {
+    fn category(&self) -> Option<&'static Category> {
+        Some(category!("configuration"))
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn description(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            fmt,
+            "The value of {} set by {} is overridden by {}.",
+            self.key,
+            self.overridden_file(),
+            self.applied_file()
+        )
+    }
+
+    fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
+        fmt.write_markup(markup! {
+            "The value of "<Emphasis>{self.key}</Emphasis>" set by "<Emphasis>{self.overridden_file()}</Emphasis>" is overridden by "<Emphasis>{self.applied_file()}</Emphasis>"."
+        })
+    }
+
+    fn advices(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        for (chain, message) in [
+            (
+                &self.overridden_chain,
+                "The overridden value is loaded through the following chain of ",
+            ),
+            (
+                &self.applied_chain,
+                "The applied value is loaded through the following chain of ",
+            ),
+        ] {
+            visitor.record_log(
+                LogCategory::Info,
+                &markup! {
+                    {message}<Emphasis>"extends"</Emphasis>":"
+                },
+            )?;
+            let chain = chain
+                .iter()
+                .map(|path| path as &dyn Display)
+                .collect::<Vec<_>>();
+            visitor.record_list(&chain)?;
+        }
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "Set the value in "<Emphasis>{self.applied_chain.first().map_or("", String::as_str)}</Emphasis>" to choose it explicitly."
+            },
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::configuration::diagnostics::ConfigurationDiagnostic;
@@ -1,9 +1,11 @@
 use crate::configuration::{deserialize_line_width, serialize_line_width, PlainIndentStyle};
+use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
-use biome_formatter::{LineEnding, LineWidth};
+use biome_formatter::{LineEnding, LineWidth, QuoteStyle};
 use biome_json_formatter::context::TrailingCommas;
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Options applied to JSON files
 #[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -18,6 +20,74 @@ pub struct JsonConfiguration {
     /// Formatting options
     #[partial(type, bpaf(external(partial_json_formatter), optional))]
     pub formatter: JsonFormatter,
+
+    /// Associates the JSON files with the JSON Schemas that validate them when they are linted.
+    /// The `$schema` key of a JSON file takes precedence.
+    #[partial(bpaf(hide))]
+    pub schemas: JsonSchemas,
+
+    /// Sorting options
+    #[partial(type, bpaf(external(partial_json_sort_keys), optional))]
+    pub sort_keys: JsonSortKeys,
+}
+
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct JsonSchemas(#[bpaf(hide)] pub Vec<JsonSchemaAssociation>);
+
+impl FromStr for JsonSchemas {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonSchemaAssociation {
+    /// A list of Unix shell style patterns. The JSON files that match these patterns are
+    /// validated against the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub include: Option<StringSet>,
+
+    /// The path of the JSON Schema, relative to the configuration file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub schema: Option<String>,
+}
+
+impl FromStr for JsonSchemaAssociation {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+/// Options that change how the keys of the JSON objects are sorted
+#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct JsonSortKeys {
+    /// Sort the keys of the objects of the JSON files when the imports are organized. The keys of
+    /// the `package.json` and `tsconfig.json` files follow their canonical order. Defaults to false.
+    #[partial(bpaf(long("json-sort-keys-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The keys that come first in the objects of the JSON files, in this order. The other keys
+    /// follow them alphabetically. The keys of the `package.json` and `tsconfig.json` files keep
+    /// their canonical order.
+    #[partial(bpaf(hide))]
+    pub order: StringSet,
 }
 
 /// Options that changes how the JSON parser behaves
@@ -72,6 +142,14 @@ pub struct JsonFormatter {
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
     #[partial(bpaf(long("json-formatter-trailing-commas"), argument("none|all"), optional))]
     pub trailing_commas: Option<TrailingCommas>,
+
+    /// The type of quotes used in the strings of JSON5 files. The quotes are preserved when it isn't set.
+    #[partial(bpaf(
+        long("json-formatter-quote-style"),
+        argument("double|single"),
+        optional
+    ))]
+    pub quote_style: Option<QuoteStyle>,
 }
 
 impl PartialJsonFormatter {
@@ -84,6 +162,7 @@ impl PartialJsonFormatter {
             line_ending: self.line_ending,
             line_width: self.line_width,
             trailing_commas: self.trailing_commas,
+            quote_style: self.quote_style,
         }
     }
 }
@@ -98,6 +177,7 @@ impl Default for JsonFormatter {
             line_ending: Default::default(),
             line_width: Default::default(),
             trailing_commas: Default::default(),
+            quote_style: Default::default(),
         }
     }
 }
@@ -228,7 +228,7 @@ impl Rules {
     #[doc = r" It returns the enabled rules by default."]
     #[doc = r""]
     #[doc = r" The enabled rules are calculated from the difference with the disabled rules."]
-    pub fn as_enabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub fn as_enabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut enabled_rules = IndexSet::new();
         let mut disabled_rules = IndexSet::new();
         if let Some(group) = self.a11y.as_ref() {
@@ -573,7 +573,7 @@ impl A11y {
     pub(crate) fn is_all_unset(&self) -> bool {
         self.all.is_none()
     }
-    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_access_key.as_ref() {
             if rule.is_enabled() {
@@ -727,7 +727,7 @@ impl A11y {
         }
         index_set
     }
-    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_access_key.as_ref() {
             if rule.is_disabled() {
@@ -900,7 +900,7 @@ impl A11y {
         &self,
         parent_is_all: bool,
         parent_is_recommended: bool,
-        enabled_rules: &mut IndexSet<RuleFilter>,
+        enabled_rules: &mut IndexSet<RuleFilter<'static>>,
     ) {
         if self.is_all() || self.is_all_unset() && parent_is_all {
             enabled_rules.extend(Self::all_rules_as_filters());
@@ -1274,7 +1274,7 @@ impl Complexity {
     pub(crate) fn is_all_unset(&self) -> bool {
         self.all.is_none()
     }
-    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_banned_types.as_ref() {
             if rule.is_enabled() {
@@ -1416,7 +1416,7 @@ impl Complexity {
         }
         index_set
     }
-    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_banned_types.as_ref() {
             if rule.is_disabled() {
@@ -1577,7 +1577,7 @@ impl Complexity {
         &self,
         parent_is_all: bool,
         parent_is_recommended: bool,
-        enabled_rules: &mut IndexSet<RuleFilter>,
+        enabled_rules: &mut IndexSet<RuleFilter<'static>>,
     ) {
         if self.is_all() || self.is_all_unset() && parent_is_all {
             enabled_rules.extend(Self::all_rules_as_filters());
@@ -1994,7 +1994,7 @@ impl Correctness {
     pub(crate) fn is_all_unset(&self) -> bool {
         self.all.is_none()
     }
-    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_children_prop.as_ref() {
             if rule.is_enabled() {
@@ -2178,7 +2178,7 @@ impl Correctness {
         }
         index_set
     }
-    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_children_prop.as_ref() {
             if rule.is_disabled() {
@@ -2381,7 +2381,7 @@ impl Correctness {
         &self,
         parent_is_all: bool,
         parent_is_recommended: bool,
-        enabled_rules: &mut IndexSet<RuleFilter>,
+        enabled_rules: &mut IndexSet<RuleFilter<'static>>,
     ) {
         if self.is_all() || self.is_all_unset() && parent_is_all {
             enabled_rules.extend(Self::all_rules_as_filters());
@@ -2568,6 +2568,9 @@ pub struct Nursery {
     #[doc = "Disallow using a callback in asynchronous tests and hooks."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_done_callback: Option<RuleConfiguration<NoDoneCallback>>,
+    #[doc = "Disallow declaring a package in more than one field of dependencies of a package.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_dependencies: Option<RuleConfiguration<NoDuplicateDependencies>>,
     #[doc = "Disallow duplicate conditions in if-else-if chains"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_else_if: Option<RuleConfiguration<NoDuplicateElseIf>>,
@@ -2589,6 +2592,18 @@ pub struct Nursery {
     #[doc = "Disallow focused tests."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_focused_tests: Option<RuleConfiguration<NoFocusedTests>>,
+    #[doc = "Disallow imports that create a cycle of modules."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_import_cycles: Option<RuleConfiguration<NoImportCycles>>,
+    #[doc = "Disallow invalid version specifiers in the dependencies of a package.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_dependency_versions: Option<RuleConfiguration<NoInvalidDependencyVersions>>,
+    #[doc = "Disallow invalid engines in a package.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_package_engines: Option<RuleConfiguration<NoInvalidPackageEngines>>,
+    #[doc = "Disallow invalid exports maps in a package.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_package_exports: Option<RuleConfiguration<NoInvalidPackageExports>>,
     #[doc = "Checks that the assertion function, for example expect, is placed inside an it() function call."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_misplaced_assertion: Option<RuleConfiguration<NoMisplacedAssertion>>,
@@ -2613,6 +2628,21 @@ pub struct Nursery {
     #[doc = "Disallow the use of dependencies that aren't specified in the package.json."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_undeclared_dependencies: Option<RuleConfiguration<NoUndeclaredDependencies>>,
+    #[doc = "Disallow default imports of modules that don't have a default export."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unresolved_default_imports: Option<RuleConfiguration<NoUnresolvedDefaultImports>>,
+    #[doc = "Disallow imports of files of the project that don't exist."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unresolved_imports: Option<RuleConfiguration<NoUnresolvedImports>>,
+    #[doc = "Disallow named imports that aren't exported by the imported module."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unresolved_named_imports: Option<RuleConfiguration<NoUnresolvedNamedImports>>,
+    #[doc = "Disallow exports that aren't imported by any module of the project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_exports: Option<RuleConfiguration<NoUnusedExports>>,
+    #[doc = "Disallow modules that can't be reached from the entry points of the project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_files: Option<RuleConfiguration<NoUnusedFiles>>,
     #[doc = "Disallow ternary operators when simpler alternatives exist."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_useless_ternary: Option<RuleConfiguration<NoUselessTernary>>,
@@ -2625,9 +2655,15 @@ pub struct Nursery {
     #[doc = "Promotes the usage of node:assert/strict over node:assert."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_node_assert_strict: Option<RuleConfiguration<UseNodeAssertStrict>>,
+    #[doc = "Require the packages written in TypeScript to declare their types in the package.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_package_types: Option<RuleConfiguration<UsePackageTypes>>,
     #[doc = "Enforce the sorting of CSS utility classes."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_sorted_classes: Option<RuleConfiguration<UseSortedClasses>>,
+    #[doc = "Enforce the canonical order of the fields of a package.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_package_json_keys: Option<RuleConfiguration<UseSortedPackageJsonKeys>>,
 }
 impl DeserializableValidator for Nursery {
     fn validate(
@@ -2645,11 +2681,12 @@ impl DeserializableValidator for Nursery {
 }
 impl Nursery {
     const GROUP_NAME: &'static str = "nursery";
-    pub(crate) const GROUP_RULES: [&'static str; 24] = [
+    pub(crate) const GROUP_RULES: [&'static str; 36] = [
         "noBarrelFile",
         "noColorInvalidHex",
         "noConsole",
         "noDoneCallback",
+        "noDuplicateDependencies",
         "noDuplicateElseIf",
         "noDuplicateJsonKeys",
         "noDuplicateTestHooks",
@@ -2657,6 +2694,10 @@ impl Nursery {
         "noExcessiveNestedTestSuites",
         "noExportsInTest",
         "noFocusedTests",
+        "noImportCycles",
+        "noInvalidDependencyVersions",
+        "noInvalidPackageEngines",
+        "noInvalidPackageExports",
         "noMisplacedAssertion",
         "noNamespaceImport",
         "noNodejsModules",
@@ -2665,11 +2706,18 @@ impl Nursery {
         "noSkippedTests",
         "noSuspiciousSemicolonInJsx",
         "noUndeclaredDependencies",
+        "noUnresolvedDefaultImports",
+        "noUnresolvedImports",
+        "noUnresolvedNamedImports",
+        "noUnusedExports",
+        "noUnusedFiles",
         "noUselessTernary",
         "useImportRestrictions",
         "useJsxKeyInIterable",
         "useNodeAssertStrict",
+        "usePackageTypes",
         "useSortedClasses",
+        "useSortedPackageJsonKeys",
     ];
     const RECOMMENDED_RULES: [&'static str; 10] = [
         "noDoneCallback",
@@ -2685,17 +2733,17 @@ impl Nursery {
     ];
     const RECOMMENDED_RULES_AS_FILTERS: [RuleFilter<'static>; 10] = [
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]),
     ];
-    const ALL_RULES_AS_FILTERS: [RuleFilter<'static>; 24] = [
+    const ALL_RULES_AS_FILTERS: [RuleFilter<'static>; 36] = [
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
@@ -2720,6 +2768,18 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended(&self) -> bool {
@@ -2734,7 +2794,7 @@ impl Nursery {
     pub(crate) fn is_all_unset(&self) -> bool {
         self.all.is_none()
     }
-    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_barrel_file.as_ref() {
             if rule.is_enabled() {
@@ -2756,109 +2816,169 @@ impl Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
+        if let Some(rule) = self.no_duplicate_dependencies.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_duplicate_test_hooks.as_ref() {
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_evolving_any.as_ref() {
+        if let Some(rule) = self.no_duplicate_test_hooks.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_excessive_nested_test_suites.as_ref() {
+        if let Some(rule) = self.no_evolving_any.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_exports_in_test.as_ref() {
+        if let Some(rule) = self.no_excessive_nested_test_suites.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_focused_tests.as_ref() {
+        if let Some(rule) = self.no_exports_in_test.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
+        if let Some(rule) = self.no_focused_tests.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_namespace_import.as_ref() {
+        if let Some(rule) = self.no_import_cycles.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_nodejs_modules.as_ref() {
+        if let Some(rule) = self.no_invalid_dependency_versions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_re_export_all.as_ref() {
+        if let Some(rule) = self.no_invalid_package_engines.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
+        if let Some(rule) = self.no_invalid_package_exports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_skipped_tests.as_ref() {
+        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_suspicious_semicolon_in_jsx.as_ref() {
+        if let Some(rule) = self.no_namespace_import.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
+        if let Some(rule) = self.no_nodejs_modules.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_useless_ternary.as_ref() {
+        if let Some(rule) = self.no_re_export_all.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.use_jsx_key_in_iterable.as_ref() {
+        if let Some(rule) = self.no_skipped_tests.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.use_node_assert_strict.as_ref() {
+        if let Some(rule) = self.no_suspicious_semicolon_in_jsx.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
+        if let Some(rule) = self.no_unresolved_default_imports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        if let Some(rule) = self.no_unresolved_imports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+            }
+        }
+        if let Some(rule) = self.no_unresolved_named_imports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+            }
+        }
+        if let Some(rule) = self.no_unused_exports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+            }
+        }
+        if let Some(rule) = self.no_unused_files.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+            }
+        }
+        if let Some(rule) = self.no_useless_ternary.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+            }
+        }
+        if let Some(rule) = self.use_jsx_key_in_iterable.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+            }
+        }
+        if let Some(rule) = self.use_node_assert_strict.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+            }
+        }
+        if let Some(rule) = self.use_package_types.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_package_json_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+            }
+        }
         index_set
     }
-    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_barrel_file.as_ref() {
             if rule.is_disabled() {
@@ -2880,106 +3000,166 @@ impl Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
+        if let Some(rule) = self.no_duplicate_dependencies.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_duplicate_test_hooks.as_ref() {
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_evolving_any.as_ref() {
+        if let Some(rule) = self.no_duplicate_test_hooks.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_excessive_nested_test_suites.as_ref() {
+        if let Some(rule) = self.no_evolving_any.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_exports_in_test.as_ref() {
+        if let Some(rule) = self.no_excessive_nested_test_suites.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_focused_tests.as_ref() {
+        if let Some(rule) = self.no_exports_in_test.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
+        if let Some(rule) = self.no_focused_tests.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_namespace_import.as_ref() {
+        if let Some(rule) = self.no_import_cycles.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_nodejs_modules.as_ref() {
+        if let Some(rule) = self.no_invalid_dependency_versions.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_re_export_all.as_ref() {
+        if let Some(rule) = self.no_invalid_package_engines.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
+        if let Some(rule) = self.no_invalid_package_exports.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_skipped_tests.as_ref() {
+        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_suspicious_semicolon_in_jsx.as_ref() {
+        if let Some(rule) = self.no_namespace_import.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
+        if let Some(rule) = self.no_nodejs_modules.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_useless_ternary.as_ref() {
+        if let Some(rule) = self.no_re_export_all.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.use_jsx_key_in_iterable.as_ref() {
+        if let Some(rule) = self.no_skipped_tests.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.use_node_assert_strict.as_ref() {
+        if let Some(rule) = self.no_suspicious_semicolon_in_jsx.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
+        if let Some(rule) = self.no_unresolved_default_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        if let Some(rule) = self.no_unresolved_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+            }
+        }
+        if let Some(rule) = self.no_unresolved_named_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+            }
+        }
+        if let Some(rule) = self.no_unused_exports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+            }
+        }
+        if let Some(rule) = self.no_unused_files.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+            }
+        }
+        if let Some(rule) = self.no_useless_ternary.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+            }
+        }
+        if let Some(rule) = self.use_jsx_key_in_iterable.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+            }
+        }
+        if let Some(rule) = self.use_node_assert_strict.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+            }
+        }
+        if let Some(rule) = self.use_package_types.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_package_json_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -2993,7 +3173,7 @@ impl Nursery {
     pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 10] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
-    pub(crate) fn all_rules_as_filters() -> [RuleFilter<'static>; 24] {
+    pub(crate) fn all_rules_as_filters() -> [RuleFilter<'static>; 36] {
         Self::ALL_RULES_AS_FILTERS
     }
     #[doc = r" Select preset rules"]
@@ -3001,7 +3181,7 @@ impl Nursery {
         &self,
         parent_is_all: bool,
         parent_is_recommended: bool,
-        enabled_rules: &mut IndexSet<RuleFilter>,
+        enabled_rules: &mut IndexSet<RuleFilter<'static>>,
     ) {
         if self.is_all() || self.is_all_unset() && parent_is_all {
             enabled_rules.extend(Self::all_rules_as_filters());
@@ -3032,6 +3212,10 @@ impl Nursery {
                 .no_done_callback
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateDependencies" => self
+                .no_duplicate_dependencies
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDuplicateElseIf" => self
                 .no_duplicate_else_if
                 .as_ref()
@@ -3060,6 +3244,22 @@ impl Nursery {
                 .no_focused_tests
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noImportCycles" => self
+                .no_import_cycles
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidDependencyVersions" => self
+                .no_invalid_dependency_versions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidPackageEngines" => self
+                .no_invalid_package_engines
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidPackageExports" => self
+                .no_invalid_package_exports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noMisplacedAssertion" => self
                 .no_misplaced_assertion
                 .as_ref()
@@ -3092,6 +3292,26 @@ impl Nursery {
                 .no_undeclared_dependencies
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnresolvedDefaultImports" => self
+                .no_unresolved_default_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnresolvedImports" => self
+                .no_unresolved_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnresolvedNamedImports" => self
+                .no_unresolved_named_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedExports" => self
+                .no_unused_exports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedFiles" => self
+                .no_unused_files
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUselessTernary" => self
                 .no_useless_ternary
                 .as_ref()
@@ -3108,10 +3328,18 @@ impl Nursery {
                 .use_node_assert_strict
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "usePackageTypes" => self
+                .use_package_types
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useSortedClasses" => self
                 .use_sorted_classes
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedPackageJsonKeys" => self
+                .use_sorted_package_json_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             _ => None,
         }
     }
@@ -3174,7 +3402,7 @@ impl Performance {
     pub(crate) fn is_all_unset(&self) -> bool {
         self.all.is_none()
     }
-    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_accumulating_spread.as_ref() {
             if rule.is_enabled() {
@@ -3188,7 +3416,7 @@ impl Performance {
         }
         index_set
     }
-    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_accumulating_spread.as_ref() {
             if rule.is_disabled() {
@@ -3221,7 +3449,7 @@ impl Performance {
         &self,
         parent_is_all: bool,
         parent_is_recommended: bool,
-        enabled_rules: &mut IndexSet<RuleFilter>,
+        enabled_rules: &mut IndexSet<RuleFilter<'static>>,
     ) {
         if self.is_all() || self.is_all_unset() && parent_is_all {
             enabled_rules.extend(Self::all_rules_as_filters());
@@ -3320,7 +3548,7 @@ impl Security {
     pub(crate) fn is_all_unset(&self) -> bool {
         self.all.is_none()
     }
-    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_dangerously_set_inner_html.as_ref() {
             if rule.is_enabled() {
@@ -3339,7 +3567,7 @@ impl Security {
         }
         index_set
     }
-    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_dangerously_set_inner_html.as_ref() {
             if rule.is_disabled() {
@@ -3377,7 +3605,7 @@ impl Security {
         &self,
         parent_is_all: bool,
         parent_is_recommended: bool,
-        enabled_rules: &mut IndexSet<RuleFilter>,
+        enabled_rules: &mut IndexSet<RuleFilter<'static>>,
     ) {
         if self.is_all() || self.is_all_unset() && parent_is_all {
             enabled_rules.extend(Self::all_rules_as_filters());
@@ -3711,7 +3939,7 @@ impl Style {
     pub(crate) fn is_all_unset(&self) -> bool {
         self.all.is_none()
     }
-    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_arguments.as_ref() {
             if rule.is_enabled() {
@@ -3920,7 +4148,7 @@ impl Style {
         }
         index_set
     }
-    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_arguments.as_ref() {
             if rule.is_disabled() {
@@ -4148,7 +4376,7 @@ impl Style {
         &self,
         parent_is_all: bool,
         parent_is_recommended: bool,
-        enabled_rules: &mut IndexSet<RuleFilter>,
+        enabled_rules: &mut IndexSet<RuleFilter<'static>>,
     ) {
         if self.is_all() || self.is_all_unset() && parent_is_all {
             enabled_rules.extend(Self::all_rules_as_filters());
@@ -4727,7 +4955,7 @@ impl Suspicious {
     pub(crate) fn is_all_unset(&self) -> bool {
         self.all.is_none()
     }
-    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_approximative_numeric_constant.as_ref() {
             if rule.is_enabled() {
@@ -4981,7 +5209,7 @@ impl Suspicious {
         }
         index_set
     }
-    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
+    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter<'static>> {
         let mut index_set = IndexSet::new();
         if let Some(rule) = self.no_approximative_numeric_constant.as_ref() {
             if rule.is_disabled() {
@@ -5254,7 +5482,7 @@ impl Suspicious {
         &self,
         parent_is_all: bool,
         parent_is_recommended: bool,
-        enabled_rules: &mut IndexSet<RuleFilter>,
+        enabled_rules: &mut IndexSet<RuleFilter<'static>>,
     ) {
         if self.is_all() || self.is_all_unset() && parent_is_all {
             enabled_rules.extend(Self::all_rules_as_filters());
@@ -9,17 +9,19 @@ mod generated;
 pub mod javascript;
 pub mod json;
 pub mod linter;
+mod nested;
 pub mod organize_imports;
 mod overrides;
 pub mod vcs;
 
-use crate::configuration::diagnostics::CantLoadExtendFile;
 pub use crate::configuration::diagnostics::ConfigurationDiagnostic;
+use crate::configuration::diagnostics::{
+    CantLoadExtendFile, ConflictingExtendedValue, ExtendedConfigurationDiagnostic,
+};
 pub(crate) use crate::configuration::generated::push_to_analyzer_rules;
 use crate::configuration::organize_imports::{
     partial_organize_imports, OrganizeImports, PartialOrganizeImports,
 };
-use crate::configuration::overrides::Overrides;
 use crate::configuration::vcs::{
     partial_vcs_configuration, PartialVcsConfiguration, VcsConfiguration,
 };
@@ -49,20 +51,27 @@ pub use javascript::{
     PartialJavascriptConfiguration, PartialJavascriptFormatter,
 };
 pub use json::{
-    partial_json_configuration, JsonConfiguration, JsonFormatter, PartialJsonConfiguration,
-    PartialJsonFormatter,
+    partial_json_configuration, JsonConfiguration, JsonFormatter, JsonSchemaAssociation,
+    JsonSchemas, JsonSortKeys, PartialJsonConfiguration, PartialJsonFormatter, PartialJsonSortKeys,
 };
 pub use linter::{
     partial_linter_configuration, LinterConfiguration, PartialLinterConfiguration,
     RuleConfiguration, Rules,
 };
-pub use overrides::to_override_settings;
+pub use nested::{
+    load_nested_configurations, load_parent_configurations, NestedConfigurationDiscovery,
+};
+pub use overrides::{
+    to_override_settings, OverrideFilesConfiguration, OverrideFormatterConfiguration,
+    OverridePattern, Overrides,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::io::ErrorKind;
 use std::iter::FusedIterator;
 use std::num::NonZeroU64;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 /// The configuration that is contained inside the file `biome.json`
 #[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -274,6 +283,14 @@ impl ConfigurationBasePath {
 pub fn load_configuration(
     fs: &DynRef<'_, dyn FileSystem>,
     config_path: ConfigurationBasePath,
+) -> Result<LoadedConfiguration, WorkspaceError> {
+    load_configuration_from(&**fs, config_path)
+}
+
+/// Same as [load_configuration], for callers that only borrow the [FileSystem]
+pub(crate) fn load_configuration_from(
+    fs: &dyn FileSystem,
+    config_path: ConfigurationBasePath,
 ) -> Result<LoadedConfiguration, WorkspaceError> {
     let config = load_config(fs, config_path)?;
     LoadedConfiguration::try_from_payload(config, fs)
@@ -288,10 +305,7 @@ pub fn load_configuration(
 ///
 /// If a the configuration base path was provided by the user, the function will error. If not, Biome will use
 /// its defaults.
-fn load_config(
-    file_system: &DynRef<'_, dyn FileSystem>,
-    base_path: ConfigurationBasePath,
-) -> LoadConfig {
+fn load_config(file_system: &dyn FileSystem, base_path: ConfigurationBasePath) -> LoadConfig {
     let deprecated_config_name = file_system.deprecated_config_name();
     let working_directory = file_system.working_directory();
     let configuration_directory = match base_path {
@@ -437,6 +451,22 @@ pub struct LoadedConfiguration {
     pub configuration: PartialConfiguration,
     /// All diagnostics that were emitted during parsing and deserialization
     pub diagnostics: Vec<Error>,
+    /// The configuration files that were merged into [LoadedConfiguration::configuration], in
+    /// the order they were merged: the files listed in `extends` come before the file that
+    /// extends them.
+    pub sources: Vec<ConfigurationSource>,
+}
+
+/// A configuration file that was merged into a [LoadedConfiguration]
+#[derive(Debug)]
+pub struct ConfigurationSource {
+    /// The path of the configuration file
+    pub file_path: PathBuf,
+    /// The chain of `extends` that led to the configuration file, from the configuration file
+    /// that is loaded first to this one
+    pub chain: Vec<PathBuf>,
+    /// The configuration declared by the file itself, without the configurations it extends
+    pub configuration: PartialConfiguration,
 }
 
 impl LoadedConfiguration {
@@ -498,7 +528,7 @@ impl FusedIterator for ConfigurationDiagnosticsIter<'_> {}
 impl LoadedConfiguration {
     fn try_from_payload(
         value: Option<ConfigurationPayload>,
-        fs: &DynRef<'_, dyn FileSystem>,
+        fs: &dyn FileSystem,
     ) -> Result<Self, WorkspaceError> {
         let Some(value) = value else {
             return Ok(LoadedConfiguration::default());
@@ -509,54 +539,269 @@ impl LoadedConfiguration {
             configuration_file_path,
             deserialized,
         } = value;
-        let (partial_configuration, mut diagnostics) = deserialized.consume();
+        let (partial_configuration, diagnostics) = deserialized.consume();
+        let mut sources = Vec::new();
+        let mut diagnostics = diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                diagnostic.with_file_path(configuration_file_path.display().to_string())
+            })
+            .collect();
 
         Ok(Self {
             configuration: match partial_configuration {
                 Some(mut partial_configuration) => {
+                    let own_configuration = partial_configuration.clone();
                     partial_configuration.apply_extends(
                         fs,
                         &configuration_file_path,
                         &configuration_directory_path,
                         &mut diagnostics,
+                        &mut sources,
                     )?;
+                    sources.push(ConfigurationSource {
+                        file_path: configuration_file_path.clone(),
+                        chain: vec![configuration_file_path.clone()],
+                        configuration: own_configuration,
+                    });
+                    diagnostics.extend(
+                        conflicting_extended_values(&sources)
+                            .into_iter()
+                            .map(Error::from),
+                    );
                     partial_configuration.migrate_deprecated_fields();
                     partial_configuration
                 }
                 None => PartialConfiguration::default(),
             },
-            diagnostics: diagnostics
-                .into_iter()
-                .map(|diagnostic| {
-                    diagnostic.with_file_path(configuration_file_path.display().to_string())
-                })
-                .collect(),
+            diagnostics,
             directory_path: Some(configuration_directory_path),
             file_path: Some(configuration_file_path),
+            sources,
         })
     }
 }
 
+/// Returns the values that a configuration file sets, and that override the value of a
+/// configuration file that it doesn't extend.
+///
+/// `sources` are in the order they are merged. Only the value that is applied is compared with
+/// the values it overrides, so a value set by the configuration file that is loaded first never
+/// conflicts. The lists aren't compared, because they are merged.
+fn conflicting_extended_values(sources: &[ConfigurationSource]) -> Vec<ConflictingExtendedValue> {
+    let mut setters = BTreeMap::<String, Vec<(&ConfigurationSource, serde_json::Value)>>::new();
+    for source in sources {
+        let Ok(configuration) = serde_json::to_value(&source.configuration) else {
+            continue;
+        };
+        let mut values = Vec::new();
+        flatten_configuration_values(String::new(), configuration, &mut values);
+        for (key, value) in values {
+            setters.entry(key).or_default().push((source, value));
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for (key, setters) in setters {
+        let Some(((applied_source, applied_value), overridden_setters)) = setters.split_last()
+        else {
+            continue;
+        };
+        let overridden_source = overridden_setters.iter().rev().find(|(source, value)| {
+            value != applied_value && !source.chain.starts_with(&applied_source.chain)
+        });
+        if let Some((overridden_source, _)) = overridden_source {
+            let printed_chain = |source: &ConfigurationSource| {
+                source
+                    .chain
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+            };
+            conflicts.push(ConflictingExtendedValue::new(
+                key,
+                printed_chain(overridden_source),
+                printed_chain(applied_source),
+            ));
+        }
+    }
+    conflicts
+}
+
+/// Pushes the values of `value` that aren't objects, `null` or lists to `values`, with the dotted
+/// path of their keys. The `extends` and `overrides` fields are skipped.
+fn flatten_configuration_values(
+    prefix: String,
+    value: serde_json::Value,
+    values: &mut Vec<(String, serde_json::Value)>,
+) {
+    match value {
+        serde_json::Value::Null | serde_json::Value::Array(_) => {}
+        serde_json::Value::Object(object) => {
+            for (key, value) in object {
+                let key = if prefix.is_empty() {
+                    if matches!(key.as_str(), "$schema" | "extends" | "overrides") {
+                        continue;
+                    }
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_configuration_values(key, value, values);
+            }
+        }
+        value => values.push((prefix, value)),
+    }
+}
+
+/// Returns the path of the configuration file that `specifier`, a value of `extends`, refers to.
+///
+/// Relative and absolute paths are resolved from `directory_path`. The other specifiers name a
+/// package, optionally followed by a subpath, like `@acme/biome-config/strict`: they are resolved
+/// from the `node_modules` directories, honoring the `exports` field of the package.
+fn resolve_extended_configuration(
+    fs: &dyn FileSystem,
+    specifier: &str,
+    directory_path: &Path,
+) -> Result<PathBuf, WorkspaceError> {
+    let as_path = Path::new(specifier);
+    if as_path.starts_with(".") || as_path.is_absolute() {
+        return Ok(normalize_path(&directory_path.join(specifier)));
+    }
+    // TODO: Remove in Biome 2.0
+    // A path such as `base.json` or `configs/base.json` used to be resolved from the
+    // configuration directory. A file name without directory can't name a file of a package.
+    let extension = as_path.extension().and_then(|ext| ext.to_str());
+    if matches!(extension, Some("json" | "jsonc")) {
+        let config_path = normalize_path(&directory_path.join(specifier));
+        if as_path.parent() == Some(Path::new("")) || fs.path_exists(&config_path) {
+            return Ok(config_path);
+        }
+    }
+
+    let resolution = fs
+        .resolve_configuration(specifier, Some(directory_path))
+        .map_err(|error| {
+            ConfigurationDiagnostic::cant_resolve(
+                fs.working_directory()
+                    .unwrap_or_default()
+                    .display()
+                    .to_string(),
+                error,
+            )
+        })?;
+    Ok(resolution.into_path_buf())
+}
+
+/// Removes the `.` components of `path`, and the `..` components that follow a directory name,
+/// so that the same configuration file is always represented by the same path
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
 impl PartialConfiguration {
     /// Mutates the configuration so that any fields that have not been configured explicitly are
     /// filled in with their values from configs listed in the `extends` field.
     ///
-    /// The `extends` configs are applied from left to right.
+    /// The `extends` configs are applied from left to right. An extended config can itself
+    /// extend other configs, which are applied first.
+    ///
+    /// If a configuration can't be resolved from the file system, or if a configuration extends
+    /// itself, the operation will fail.
     ///
-    /// If a configuration can't be resolved from the file system, the operation will fail.
+    /// Every extended configuration file is pushed to `sources`, in the order they are merged.
     fn apply_extends(
         &mut self,
-        fs: &DynRef<'_, dyn FileSystem>,
+        fs: &dyn FileSystem,
         file_path: &Path,
         directory_path: &Path,
         diagnostics: &mut Vec<Error>,
+        sources: &mut Vec<ConfigurationSource>,
     ) -> Result<(), WorkspaceError> {
-        let deserialized = self.deserialize_extends(fs, directory_path)?;
-        let (configurations, errors): (Vec<_>, Vec<_>) = deserialized
-            .into_iter()
-            .map(|d| d.consume())
-            .map(|(config, diagnostics)| (config.unwrap_or_default(), diagnostics))
-            .unzip();
+        let mut chain = vec![file_path.to_path_buf()];
+        self.apply_extends_with_chain(fs, &mut chain, directory_path, diagnostics, sources)
+    }
+
+    /// Applies the configurations listed in `extends`. `chain` contains the path of the current
+    /// configuration file, preceded by the paths of the configuration files that extend it.
+    fn apply_extends_with_chain(
+        &mut self,
+        fs: &dyn FileSystem,
+        chain: &mut Vec<PathBuf>,
+        directory_path: &Path,
+        diagnostics: &mut Vec<Error>,
+        sources: &mut Vec<ConfigurationSource>,
+    ) -> Result<(), WorkspaceError> {
+        let extended = self.deserialize_extends(fs, directory_path)?;
+        let mut configurations = Vec::with_capacity(extended.len());
+        for (config_path, content, deserialized) in extended {
+            if chain.contains(&config_path) {
+                let cycle = chain
+                    .iter()
+                    .chain(std::iter::once(&config_path))
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                return Err(WorkspaceError::Configuration(
+                    ConfigurationDiagnostic::invalid_configuration(markup! {
+                        "The configuration file "<Emphasis>{config_path.display().to_string()}</Emphasis>" extends itself: "{cycle}
+                    }),
+                ));
+            }
+
+            chain.push(config_path);
+            let (configuration, errors) = deserialized.consume();
+            let printed_chain = chain
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>();
+            diagnostics.extend(errors.into_iter().map(|diagnostic| {
+                Error::from(ExtendedConfigurationDiagnostic::new(
+                    diagnostic
+                        .with_file_path(printed_chain[printed_chain.len() - 1].clone())
+                        .with_file_source_code(content.clone()),
+                    printed_chain.clone(),
+                ))
+            }));
+
+            let mut configuration = configuration.unwrap_or_default();
+            let own_configuration = configuration.clone();
+            let config_directory = chain[chain.len() - 1]
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            configuration.apply_extends_with_chain(
+                fs,
+                chain,
+                &config_directory,
+                diagnostics,
+                sources,
+            )?;
+            let source_chain = chain.clone();
+            if let Some(file_path) = chain.pop() {
+                sources.push(ConfigurationSource {
+                    file_path,
+                    chain: source_chain,
+                    configuration: own_configuration,
+                });
+            }
+            configurations.push(configuration);
+        }
 
         let extended_configuration = configurations.into_iter().reduce(
             |mut previous_configuration, current_configuration| {
@@ -570,50 +815,24 @@ impl PartialConfiguration {
             self.merge_with(extended_configuration)
         }
 
-        diagnostics.extend(
-            errors
-                .into_iter()
-                .flatten()
-                .map(|diagnostic| diagnostic.with_file_path(file_path.display().to_string()))
-                .collect::<Vec<_>>(),
-        );
-
         Ok(())
     }
 
-    /// It attempts to deserialize all the configuration files that were specified in the `extends` property
+    /// It attempts to deserialize all the configuration files that were specified in the `extends` property.
+    ///
+    /// Returns the path, the content and the deserialized configuration of each file.
     fn deserialize_extends(
         &mut self,
-        fs: &DynRef<'_, dyn FileSystem>,
+        fs: &dyn FileSystem,
         directory_path: &Path,
-    ) -> Result<Vec<Deserialized<PartialConfiguration>>, WorkspaceError> {
+    ) -> Result<Vec<(PathBuf, String, Deserialized<PartialConfiguration>)>, WorkspaceError> {
         let Some(extends) = &self.extends else {
             return Ok(Vec::new());
         };
 
         let mut deserialized_configurations = vec![];
         for path in extends.iter() {
-            let as_path = Path::new(path);
-            let extension = as_path.extension().and_then(|ext| ext.to_str());
-            // TODO: Remove extension in Biome 2.0
-            let config_path = if as_path.starts_with(".")
-                || extension == Some("json")
-                || extension == Some("jsonc")
-            {
-                directory_path.join(path)
-            } else {
-                fs.resolve_configuration(path.as_str(), Some(directory_path))
-                    .map_err(|error| {
-                        ConfigurationDiagnostic::cant_resolve(
-                            fs.working_directory()
-                                .unwrap_or_default()
-                                .display()
-                                .to_string(),
-                            error,
-                        )
-                    })?
-                    .into_path_buf()
-            };
+            let config_path = resolve_extended_configuration(fs, path, directory_path)?;
 
             let mut file = fs
                 .open_with_options(config_path.as_path(), OpenOptions::default().read(true))
@@ -633,12 +852,20 @@ impl PartialConfiguration {
                 )
 
             })?;
+            let parser_options =
+                if config_path.extension().and_then(|ext| ext.to_str()) == Some("jsonc") {
+                    JsonParserOptions::default()
+                        .with_allow_comments()
+                        .with_allow_trailing_commas()
+                } else {
+                    JsonParserOptions::default()
+                };
             let deserialized = deserialize_from_json_str::<PartialConfiguration>(
                 content.as_str(),
-                JsonParserOptions::default(),
+                parser_options,
                 "",
             );
-            deserialized_configurations.push(deserialized)
+            deserialized_configurations.push((config_path, content, deserialized))
         }
         Ok(deserialized_configurations)
     }
@@ -689,6 +916,16 @@ impl PartialConfiguration {
         &self,
         file_system: &DynRef<'_, dyn FileSystem>,
         vcs_base_path: Option<&Path>,
+    ) -> Result<(Option<PathBuf>, Vec<String>), WorkspaceError> {
+        self.gitignore_matches(&**file_system, vcs_base_path)
+    }
+
+    /// Same as [PartialConfiguration::retrieve_gitignore_matches], for callers that only borrow
+    /// the [FileSystem]
+    pub(crate) fn gitignore_matches(
+        &self,
+        file_system: &dyn FileSystem,
+        vcs_base_path: Option<&Path>,
     ) -> Result<(Option<PathBuf>, Vec<String>), WorkspaceError> {
         let Some(vcs) = &self.vcs else {
             return Ok((None, vec![]));
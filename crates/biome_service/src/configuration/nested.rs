@@ -0,0 +1,318 @@
+//! Discovery of the configuration files nested inside the workspace.
+//!
+//! The root configuration file applies to the whole workspace. A `biome.json` file found in a
+//! subdirectory applies to the files of that subdirectory instead, which is how the packages of
+//! a monorepo can have their own configuration.
+use crate::configuration::{load_configuration_from, ConfigurationBasePath, LoadedConfiguration};
+use crate::workspace::{IsPathIgnoredParams, UpdateSettingsParams};
+use crate::{DynRef, Workspace, WorkspaceError};
+use biome_diagnostics::Error;
+use biome_fs::{BiomePath, ConfigName, FileSystem, PathInterner, TraversalContext};
+use rustc_hash::FxHashMap;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+/// Searches the configuration files nested inside `paths`, and the configuration files of the
+/// directories that contain `paths`, and loads them.
+///
+/// The configuration files of the working directory and of `root_directory`, the directory of
+/// the root configuration file, aren't nested configuration files, so they are skipped.
+/// Directories ignored by the root configuration aren't searched.
+///
+/// This function traverses `paths`. When the files are traversed anyway, use
+/// [load_parent_configurations] and [NestedConfigurationDiscovery] instead.
+pub fn load_nested_configurations(
+    fs: &DynRef<'_, dyn FileSystem>,
+    workspace: &dyn Workspace,
+    paths: &[PathBuf],
+    root_directory: Option<&Path>,
+) -> Result<Vec<LoadedConfiguration>, WorkspaceError> {
+    let working_directory = fs.working_directory();
+    let mut directories = parent_configuration_directories(&**fs, paths, root_directory);
+
+    // The configuration files inside the paths
+    let (interner, _) = PathInterner::new();
+    let ctx = NestedConfigurationContext {
+        interner,
+        fs: &**fs,
+        workspace,
+        configuration_files: Mutex::default(),
+    };
+    fs.traversal(Box::new(|scope| {
+        for path in paths {
+            scope.spawn(&ctx, path.clone());
+        }
+    }));
+    for configuration_file in ctx.configuration_files.into_inner().unwrap_or_default() {
+        if let Some(directory) = configuration_file.parent() {
+            if !is_root_directory(directory, working_directory.as_deref(), root_directory) {
+                directories.insert(directory.to_path_buf());
+            }
+        }
+    }
+
+    directories
+        .into_iter()
+        .map(|directory| load_configuration_from(&**fs, ConfigurationBasePath::FromUser(directory)))
+        .collect()
+}
+
+/// Searches the configuration files of the directories that contain `paths`, and loads them.
+///
+/// The configuration files nested inside `paths` aren't searched: they are found by a
+/// [NestedConfigurationDiscovery] while `paths` are traversed.
+pub fn load_parent_configurations(
+    fs: &DynRef<'_, dyn FileSystem>,
+    paths: &[PathBuf],
+    root_directory: Option<&Path>,
+) -> Result<Vec<LoadedConfiguration>, WorkspaceError> {
+    parent_configuration_directories(&**fs, paths, root_directory)
+        .into_iter()
+        .map(|directory| load_configuration_from(&**fs, ConfigurationBasePath::FromUser(directory)))
+        .collect()
+}
+
+/// Finds the nested configuration files during the traversal of the workspace, so that they
+/// don't need a traversal of their own.
+///
+/// Each directory is searched once, the first time the traversal reaches one of its files or
+/// subdirectories. The directories that contain the paths of the traversal are skipped, since
+/// [load_parent_configurations] already searched them.
+pub struct NestedConfigurationDiscovery {
+    working_directory: Option<PathBuf>,
+    /// The directory of the root configuration file
+    root_directory: Option<PathBuf>,
+    /// The directories that were searched, or that are being searched. The cell of a directory
+    /// is set once its search is complete, and tells whether the directory is ignored.
+    searched: RwLock<FxHashMap<PathBuf, Arc<OnceLock<bool>>>>,
+}
+
+impl NestedConfigurationDiscovery {
+    pub fn new(
+        working_directory: Option<PathBuf>,
+        paths: &[PathBuf],
+        root_directory: Option<PathBuf>,
+    ) -> Self {
+        let searched = paths
+            .iter()
+            .flat_map(|path| path.ancestors().skip(1))
+            .map(|directory| (directory.to_path_buf(), Arc::new(OnceLock::from(false))))
+            .collect();
+        Self {
+            working_directory,
+            root_directory,
+            searched: RwLock::new(searched),
+        }
+    }
+
+    /// Searches the directories that contain `path`, and `path` itself if it's a directory,
+    /// and applies the configuration files found in the directories that weren't searched yet.
+    ///
+    /// The settings of each configuration file are applied to the files of its directory
+    /// before the function returns, and before any other call for the same directory can
+    /// return, so that they are available to all the files of the directory. The directories
+    /// ignored by the settings aren't searched. A configuration file that has errors isn't
+    /// applied.
+    ///
+    /// `on_loaded` receives the configuration files that were found, so that their diagnostics
+    /// can be reported. It's called before the search of their directory completes.
+    pub fn discover(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+        path: &Path,
+        mut on_loaded: impl FnMut(Result<LoadedConfiguration, WorkspaceError>),
+    ) {
+        let directory = if fs.path_is_file(path) {
+            path.parent()
+        } else {
+            Some(path)
+        };
+        let Some(directory) = directory else {
+            return;
+        };
+
+        // The directories are searched from the outermost one, so a directory whose search is
+        // complete has all its ancestors searched as well
+        let is_searched = self.searched.read().is_ok_and(|searched| {
+            searched
+                .get(directory)
+                .is_some_and(|cell| cell.get().is_some())
+        });
+        if is_searched {
+            return;
+        }
+
+        let mut directories: Vec<_> = directory
+            .ancestors()
+            .take_while(|directory| {
+                !is_root_directory(
+                    directory,
+                    self.working_directory.as_deref(),
+                    self.root_directory.as_deref(),
+                )
+            })
+            .collect();
+        directories.reverse();
+
+        for directory in directories {
+            let Some(cell) = self.directory_cell(directory) else {
+                return;
+            };
+            // Only the first call searches the directory, the other calls wait for its search
+            // to complete
+            let is_ignored =
+                *cell.get_or_init(|| search_directory(fs, workspace, directory, &mut on_loaded));
+            if is_ignored {
+                break;
+            }
+        }
+    }
+
+    /// Returns the cell of `directory`, and inserts it if the directory wasn't reached yet
+    fn directory_cell(&self, directory: &Path) -> Option<Arc<OnceLock<bool>>> {
+        if let Some(cell) = self.searched.read().ok()?.get(directory) {
+            return Some(cell.clone());
+        }
+        let mut searched = self.searched.write().ok()?;
+        Some(searched.entry(directory.to_path_buf()).or_default().clone())
+    }
+}
+
+/// Applies the configuration file of `directory`, if it has one, and returns whether the
+/// directory is ignored
+fn search_directory(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    directory: &Path,
+    on_loaded: &mut impl FnMut(Result<LoadedConfiguration, WorkspaceError>),
+) -> bool {
+    let is_ignored = workspace
+        .is_path_ignored(IsPathIgnoredParams {
+            biome_path: BiomePath::new(directory),
+            features: Vec::new(),
+        })
+        .unwrap_or(true);
+    if is_ignored {
+        return true;
+    }
+    if has_configuration_file(fs, directory) {
+        let loaded_configuration =
+            load_configuration_from(fs, ConfigurationBasePath::FromUser(directory.to_path_buf()))
+                .and_then(|loaded_configuration| {
+                    if !loaded_configuration.has_errors() {
+                        apply_nested_configuration(fs, workspace, &loaded_configuration)?;
+                    }
+                    Ok(loaded_configuration)
+                });
+        on_loaded(loaded_configuration);
+    }
+    false
+}
+
+/// Applies `loaded_configuration` to the files of its directory
+fn apply_nested_configuration(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    loaded_configuration: &LoadedConfiguration,
+) -> Result<(), WorkspaceError> {
+    let directory_path = loaded_configuration.directory_path.clone();
+    let configuration = loaded_configuration.configuration.clone();
+    let (vcs_base_path, gitignore_matches) =
+        configuration.gitignore_matches(fs, directory_path.as_deref())?;
+    workspace.update_settings(UpdateSettingsParams {
+        working_directory: fs.working_directory(),
+        configuration,
+        vcs_base_path,
+        gitignore_matches,
+        nested_directory: directory_path,
+    })
+}
+
+/// The directories that contain `paths` and a configuration file
+fn parent_configuration_directories(
+    fs: &dyn FileSystem,
+    paths: &[PathBuf],
+    root_directory: Option<&Path>,
+) -> BTreeSet<PathBuf> {
+    let working_directory = fs.working_directory();
+    let mut directories = BTreeSet::new();
+    for path in paths {
+        for ancestor in path.ancestors().skip(1) {
+            if is_root_directory(ancestor, working_directory.as_deref(), root_directory) {
+                break;
+            }
+            if has_configuration_file(fs, ancestor) {
+                directories.insert(ancestor.to_path_buf());
+            }
+        }
+    }
+    directories
+}
+
+/// Whether the configuration file of `directory` applies to the whole workspace
+fn is_root_directory(
+    directory: &Path,
+    working_directory: Option<&Path>,
+    root_directory: Option<&Path>,
+) -> bool {
+    directory.as_os_str().is_empty()
+        || directory == Path::new(".")
+        || working_directory == Some(directory)
+        || root_directory.is_some_and(|root_directory| {
+            working_directory.map_or(directory.to_path_buf(), |working_directory| {
+                working_directory.join(directory)
+            }) == root_directory
+        })
+}
+
+fn has_configuration_file(fs: &dyn FileSystem, directory: &Path) -> bool {
+    ConfigName::file_names()
+        .iter()
+        .any(|file_name| fs.path_is_file(&directory.join(file_name)))
+}
+
+/// Traversal context that collects the configuration files
+struct NestedConfigurationContext<'a> {
+    interner: PathInterner,
+    fs: &'a dyn FileSystem,
+    workspace: &'a dyn Workspace,
+    configuration_files: Mutex<Vec<PathBuf>>,
+}
+
+impl<'a> TraversalContext for NestedConfigurationContext<'a> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {
+        // The errors are reported by the traversal that processes the files
+    }
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        let file_name = path.file_name().and_then(|file_name| file_name.to_str());
+        if file_name.is_some_and(|file_name| ConfigName::file_names().contains(&file_name)) {
+            return true;
+        }
+
+        // Only the directories can contain configuration files
+        !self.fs.path_is_file(path)
+            && !self
+                .workspace
+                .is_path_ignored(IsPathIgnoredParams {
+                    biome_path: path.clone(),
+                    features: Vec::new(),
+                })
+                .unwrap_or(true)
+    }
+
+    fn handle_file(&self, path: &Path) {
+        let file_name = path.file_name().and_then(|file_name| file_name.to_str());
+        if file_name.is_some_and(|file_name| ConfigName::file_names().contains(&file_name)) {
+            if let Ok(mut configuration_files) = self.configuration_files.lock() {
+                configuration_files.push(path.to_path_buf());
+            }
+        }
+    }
+}
@@ -8,7 +8,7 @@ use crate::configuration::{
 };
 use crate::settings::{
     to_matcher, FormatSettings, LanguageListSettings, LanguageSettings, LinterSettings,
-    OrganizeImportsSettings, OverrideFormatSettings, OverrideLinterSettings,
+    OrganizeImportsSettings, OverrideFilesSettings, OverrideFormatSettings, OverrideLinterSettings,
     OverrideOrganizeImportsSettings, OverrideSettingPattern, OverrideSettings, WorkspaceSettings,
 };
 use crate::{Rules, WorkspaceError};
@@ -20,6 +20,7 @@ use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::num::NonZeroU64;
 use std::path::PathBuf;
 use std::str::FromStr;
 
@@ -85,6 +86,11 @@ pub struct OverridePattern {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(override_organize_imports_configuration), optional, hide)]
     pub organize_imports: Option<OverrideOrganizeImportsConfiguration>,
+
+    /// Specific configuration for the filesystem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(override_files_configuration), optional, hide)]
+    pub files: Option<OverrideFilesConfiguration>,
 }
 
 impl FromStr for OverridePattern {
@@ -102,11 +108,13 @@ impl FromStr for OverridePattern {
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct OverrideFormatterConfiguration {
     // if `false`, it disables the feature. `true` by default
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub enabled: Option<bool>,
 
     /// Stores whether formatting should be allowed to proceed if a given file
     /// has syntax errors
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub format_with_errors: Option<bool>,
 
@@ -134,12 +142,14 @@ pub struct OverrideFormatterConfiguration {
     /// What's the max width of a line. Defaults to 80.
     #[serde(
         deserialize_with = "deserialize_line_width",
-        serialize_with = "serialize_line_width"
+        serialize_with = "serialize_line_width",
+        skip_serializing_if = "Option::is_none"
     )]
     #[bpaf(long("line-width"), argument("NUMBER"), optional)]
     pub line_width: Option<LineWidth>,
 
     /// The attribute position style.
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("attribute-position"), argument("multiline|auto"), optional)]
     pub attribute_position: Option<AttributePosition>,
 }
@@ -173,6 +183,24 @@ pub struct OverrideOrganizeImportsConfiguration {
     pub enabled: Option<bool>,
 }
 
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideFilesConfiguration {
+    /// The maximum allowed size for source code files in bytes. Files above
+    /// this limit will be ignored for performance reasons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub max_size: Option<NonZeroU64>,
+
+    /// Tells Biome to not emit diagnostics when handling files that doesn't know
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub ignore_unknown: Option<bool>,
+}
+
 pub fn to_override_settings(
     working_directory: Option<PathBuf>,
     overrides: Overrides,
@@ -190,6 +218,9 @@ pub fn to_override_settings(
         let organize_imports =
             to_organize_imports_settings(organize_imports, &current_settings.organize_imports);
 
+        let files = pattern.files.take().unwrap_or_default();
+        let files = to_override_files_settings(files);
+
         let mut languages = LanguageListSettings::default();
         let javascript = pattern.javascript.take().unwrap_or_default();
         let json = pattern.json.take().unwrap_or_default();
@@ -206,6 +237,7 @@ pub fn to_override_settings(
             formatter,
             linter,
             organize_imports,
+            files,
             languages,
             ..OverrideSettingPattern::default()
         };
@@ -327,6 +359,7 @@ fn to_json_language_settings(
     language_setting.formatter.trailing_commas = formatter
         .trailing_commas
         .or(parent_formatter.trailing_commas);
+    language_setting.formatter.quote_style = formatter.quote_style.or(parent_formatter.quote_style);
 
     let parser = conf.parser.take().unwrap_or_default();
     let parent_parser = &parent_settings.parser;
@@ -338,6 +371,12 @@ fn to_json_language_settings(
         .allow_trailing_commas
         .unwrap_or(parent_parser.allow_trailing_commas);
 
+    let sort_keys = conf.sort_keys.take().unwrap_or_default();
+    language_setting.organize_imports.sort_keys = sort_keys.enabled;
+    language_setting.organize_imports.sort_keys_order = sort_keys
+        .order
+        .map(|order| order.into_index_set().into_iter().collect());
+
     language_setting
 }
 
@@ -390,3 +429,10 @@ fn to_organize_imports_settings(
         enabled: conf.enabled.or(Some(settings.enabled)),
     }
 }
+
+fn to_override_files_settings(conf: OverrideFilesConfiguration) -> OverrideFilesSettings {
+    OverrideFilesSettings {
+        max_size: conf.max_size,
+        ignore_unknown: conf.ignore_unknown,
+    }
+}
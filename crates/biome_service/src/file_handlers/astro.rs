@@ -13,9 +13,11 @@ use biome_fs::BiomePath;
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
 use biome_js_syntax::{JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
+use biome_project::TsConfigJson;
 use biome_rowan::NodeCache;
 use lazy_static::lazy_static;
 use regex::{Matches, Regex, RegexBuilder};
+use std::sync::Arc;
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct AstroFileHandler;
@@ -162,6 +164,11 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     javascript::fix_all(params)
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+fn organize_imports(
+    biome_path: &BiomePath,
+    parse: AnyParse,
+    settings: SettingsHandle,
+    tsconfig: Option<Arc<TsConfigJson>>,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    javascript::organize_imports(biome_path, parse, settings, tsconfig)
 }
@@ -18,8 +18,10 @@ use biome_formatter::{
 };
 use biome_fs::BiomePath;
 use biome_parser::AnyParse;
+use biome_project::TsConfigJson;
 use biome_rowan::NodeCache;
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -258,7 +260,12 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+fn organize_imports(
+    _: &BiomePath,
+    parse: AnyParse,
+    _: SettingsHandle,
+    _: Option<Arc<TsConfigJson>>,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     Ok(OrganizeImportsResult {
         code: parse.syntax::<CssLanguage>().to_string(),
     })
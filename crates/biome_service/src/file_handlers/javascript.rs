@@ -4,17 +4,14 @@ use super::{
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
-use crate::file_handlers::{is_diagnostic_error, FixAllParams};
+use crate::file_handlers::{FixAllParams, ProcessFixAll};
 use crate::settings::OverrideSettings;
 use crate::workspace::{DocumentFileSource, OrganizeImportsResult};
 use crate::{
     settings::{
         FormatSettings, LanguageListSettings, LanguageSettings, ServiceLanguage, SettingsHandle,
     },
-    workspace::{
-        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
-    },
+    workspace::{CodeAction, FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
     WorkspaceError,
 };
 use biome_analyze::options::PreferredQuote;
@@ -22,7 +19,7 @@ use biome_analyze::{
     AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, GroupCategory, Never,
     QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
 };
-use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
+use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{
     AttributePosition, FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
     QuoteStyle,
@@ -30,7 +27,8 @@ use biome_formatter::{
 use biome_fs::BiomePath;
 use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
 use biome_js_analyze::{
-    analyze, analyze_with_inspect_matcher, visit_registry, ControlFlowGraph, RuleError,
+    analyze, analyze_with_inspect_matcher, visit_registry, ControlFlowGraph, JsAnalyzerServices,
+    RuleError,
 };
 use biome_js_formatter::context::trailing_comma::TrailingComma;
 use biome_js_formatter::context::{
@@ -43,10 +41,12 @@ use biome_js_syntax::{
     AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
 };
 use biome_parser::AnyParse;
+use biome_project::TsConfigJson;
 use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache};
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tracing::{debug, debug_span, error, info, trace, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -262,7 +262,7 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         },
         &options,
         JsFileSource::default(),
-        None,
+        JsAnalyzerServices::default(),
         |_| ControlFlow::<Never>::Continue(()),
     );
 
@@ -341,7 +341,12 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 filter,
                 &analyzer_options,
                 file_source,
-                params.manifest,
+                JsAnalyzerServices {
+                    manifest: params.manifest,
+                    workspace: params.workspace,
+                    tsconfig: params.tsconfig,
+                    module_graph: params.module_graph,
+                },
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
@@ -442,6 +447,9 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         settings,
         path,
         manifest,
+        workspace,
+        tsconfig,
+        module_graph,
         language,
     } = params;
     debug_span!("Code actions JavaScript", range =? range, path =? path).in_scope(move || {
@@ -491,7 +499,12 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 filter,
                 &analyzer_options,
                 source_type,
-                manifest,
+                JsAnalyzerServices {
+                    manifest,
+                    workspace,
+                    tsconfig,
+                    module_graph,
+                },
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -525,7 +538,11 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         biome_path,
         mut filter,
         manifest,
+        workspace,
+        tsconfig,
+        module_graph,
         document_file_source,
+        suppression_reason,
     } = params;
 
     let Some(file_source) = document_file_source
@@ -535,104 +552,50 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         return Err(extension_error(biome_path));
     };
     let mut tree: AnyJsRoot = parse.tree();
-    let mut actions = Vec::new();
+    let mut process_fix_all = ProcessFixAll::new(fix_file_mode, rules);
 
     filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
 
-    let mut skipped_suggested_fixes = 0;
-    let mut errors: u16 = 0;
-    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(biome_path.as_path()));
+    let analyzer_options = AnalyzerOptions {
+        suppression_reason,
+        ..compute_analyzer_options(&settings, PathBuf::from(biome_path.as_path()))
+    };
+    let project_services = JsAnalyzerServices {
+        manifest,
+        workspace,
+        tsconfig,
+        module_graph,
+    };
     loop {
         let (action, _) = analyze(
             &tree,
             filter,
             &analyzer_options,
             file_source,
-            manifest.clone(),
-            |signal| {
-                let current_diagnostic = signal.diagnostic();
-
-                if let Some(diagnostic) = current_diagnostic.as_ref() {
-                    if is_diagnostic_error(diagnostic, rules) {
-                        errors += 1;
-                    }
-                }
-
-                for action in signal.actions() {
-                    // suppression actions should not be part of the fixes (safe or suggested)
-                    if action.is_suppression() {
-                        continue;
-                    }
-
-                    match fix_file_mode {
-                        FixFileMode::SafeFixes => {
-                            if action.applicability == Applicability::MaybeIncorrect {
-                                skipped_suggested_fixes += 1;
-                            }
-                            if action.applicability == Applicability::Always {
-                                errors = errors.saturating_sub(1);
-                                return ControlFlow::Break(action);
-                            }
-                        }
-                        FixFileMode::SafeAndUnsafeFixes => {
-                            if matches!(
-                                action.applicability,
-                                Applicability::Always | Applicability::MaybeIncorrect
-                            ) {
-                                errors = errors.saturating_sub(1);
-                                return ControlFlow::Break(action);
-                            }
-                        }
-                    }
-                }
-
-                ControlFlow::Continue(())
-            },
+            project_services.clone(),
+            |signal| process_fix_all.process_signal(signal),
         );
 
-        match action {
-            Some(action) => {
-                if let Some((range, _)) = action.mutation.as_text_edits() {
-                    tree = match AnyJsRoot::cast(action.mutation.commit()) {
-                        Some(tree) => tree,
-                        None => {
-                            return Err(WorkspaceError::RuleError(
-                                RuleError::ReplacedRootWithNonRootError {
-                                    rule_name: action.rule_name.map(|(group, rule)| {
-                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
-                                    }),
-                                },
-                            ));
-                        }
-                    };
-                    actions.push(FixAction {
-                        rule_name: action
-                            .rule_name
-                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
-                        range,
-                    });
-                }
-            }
-            None => {
-                let code = if should_format {
-                    format_node(
-                        settings.format_options::<JsLanguage>(biome_path, &document_file_source),
-                        tree.syntax(),
-                    )?
-                    .print()?
-                    .into_code()
-                } else {
-                    tree.syntax().to_string()
-                };
-                return Ok(FixFileResult {
-                    code,
-                    skipped_suggested_fixes,
-                    actions,
-                    errors: errors.into(),
-                });
-            }
+        let Some(action) = action else {
+            break;
+        };
+        match process_fix_all.process_action(action)? {
+            Some(root) => tree = root,
+            None => break,
         }
     }
+
+    let code = if should_format {
+        format_node(
+            settings.format_options::<JsLanguage>(biome_path, &document_file_source),
+            tree.syntax(),
+        )?
+        .print()?
+        .into_code()
+    } else {
+        tree.syntax().to_string()
+    };
+    Ok(process_fix_all.finish(code))
 }
 
 #[tracing::instrument(level = "trace", skip(parse, settings))]
@@ -752,7 +715,12 @@ fn rename(
     }
 }
 
-pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+pub(crate) fn organize_imports(
+    _: &BiomePath,
+    parse: AnyParse,
+    _: SettingsHandle,
+    tsconfig: Option<Arc<TsConfigJson>>,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
 
     let filter = AnalysisFilter {
@@ -766,7 +734,10 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         filter,
         &AnalyzerOptions::default(),
         JsFileSource::default(),
-        None,
+        JsAnalyzerServices {
+            tsconfig,
+            ..JsAnalyzerServices::default()
+        },
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
@@ -829,10 +800,12 @@ fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> An
             .into_iter()
             .collect(),
         preferred_quote,
+        enabled_rules: Some(settings.as_enabled_rules(file_path.as_path())),
     };
 
     AnalyzerOptions {
         configuration,
         file_path,
+        suppression_reason: None,
     }
 }
@@ -1,35 +1,44 @@
 use super::{CodeActionsParams, DocumentFileSource, ExtensionHandler, Mime, ParseResult};
 use crate::configuration::{to_analyzer_rules, PartialConfiguration};
+use crate::diagnostics::extension_error;
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FixAllParams, FormatterCapabilities, LintParams,
-    LintResults, ParserCapabilities,
+    LintResults, ParserCapabilities, ProcessFixAll,
 };
 use crate::settings::{
     FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
     SettingsHandle,
 };
 use crate::workspace::{
-    FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
+    CodeAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult,
+    PullActionsResult,
 };
-use crate::WorkspaceError;
-use biome_analyze::options::PreferredQuote;
+use crate::{Matcher, WorkspaceError};
+use biome_analyze::options::{PreferredQuote, RuleOptions};
 use biome_analyze::{
     AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, RuleCategories,
+    RuleFilter, RuleKey,
 };
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
-use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_formatter::{
+    FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
+};
 use biome_fs::{BiomePath, ConfigName, ROME_JSON};
-use biome_json_analyze::analyze;
+use biome_js_analyze::RuleError;
+use biome_json_analyze::{analyze, UseSortedKeysOptions};
 use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::{JsonLanguage, JsonRoot, JsonSyntaxNode};
 use biome_parser::AnyParse;
+use biome_project::TsConfigJson;
 use biome_rowan::{AstNode, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -39,6 +48,7 @@ pub struct JsonFormatterSettings {
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub trailing_commas: Option<TrailingCommas>,
+    pub quote_style: Option<QuoteStyle>,
     pub enabled: Option<bool>,
 }
 
@@ -49,10 +59,33 @@ pub struct JsonParserSettings {
     pub allow_trailing_commas: bool,
 }
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JsonOrganizeImportsSettings {
+    /// Whether the keys of the objects are sorted
+    pub sort_keys: Option<bool>,
+    /// The keys that come first in the objects, in this order
+    pub sort_keys_order: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default)]
+pub struct JsonLinterSettings {
+    /// The JSON Schemas associated with the JSON files
+    pub schemas: Vec<JsonSchemaSettings>,
+}
+
+#[derive(Debug)]
+pub struct JsonSchemaSettings {
+    /// The JSON files validated against the schema
+    pub include: Matcher,
+    /// The path of the JSON Schema
+    pub schema: PathBuf,
+}
+
 impl ServiceLanguage for JsonLanguage {
     type FormatterSettings = JsonFormatterSettings;
-    type LinterSettings = ();
-    type OrganizeImportsSettings = ();
+    type LinterSettings = JsonLinterSettings;
+    type OrganizeImportsSettings = JsonOrganizeImportsSettings;
     type FormatOptions = JsonFormatOptions;
     type ParserSettings = JsonParserSettings;
     fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
@@ -64,7 +97,7 @@ impl ServiceLanguage for JsonLanguage {
         overrides: &OverrideSettings,
         language: &JsonFormatterSettings,
         path: &BiomePath,
-        _document_file_source: &DocumentFileSource,
+        document_file_source: &DocumentFileSource,
     ) -> Self::FormatOptions {
         let indent_style = if let Some(indent_style) = language.indent_style {
             indent_style
@@ -88,15 +121,26 @@ impl ServiceLanguage for JsonLanguage {
             global.line_ending.unwrap_or_default()
         };
 
-        overrides.override_json_format_options(
+        let options = overrides.override_json_format_options(
             path,
             JsonFormatOptions::new()
                 .with_line_ending(line_ending)
                 .with_indent_style(indent_style)
                 .with_indent_width(indent_width)
                 .with_line_width(line_width)
-                .with_trailing_commas(language.trailing_commas.unwrap_or_default()),
-        )
+                .with_trailing_commas(language.trailing_commas.unwrap_or_default())
+                .with_quote_style(language.quote_style),
+        );
+
+        // Only the strings of JSON5 can be single quoted
+        if document_file_source
+            .to_json_file_source()
+            .is_some_and(|source| source.is_json5())
+        {
+            options
+        } else {
+            options.with_quote_style(None)
+        }
     }
 }
 
@@ -146,15 +190,20 @@ fn parse(
     let parser = &settings.as_ref().languages.json.parser;
     let overrides = &settings.as_ref().override_settings;
     let optional_json_file_source = file_source.to_json_file_source();
-    let options: JsonParserOptions = overrides.override_json_parser_options(
+    let mut options: JsonParserOptions = overrides.override_json_parser_options(
         biome_path,
         JsonParserOptions {
             allow_comments: parser.allow_comments
                 || optional_json_file_source.map_or(false, |x| x.get_allow_comments()),
             allow_trailing_commas: parser.allow_trailing_commas
                 || optional_json_file_source.map_or(false, |x| x.get_allow_trailing_commas()),
+            json5: false,
         },
     );
+    // The syntax of JSON5 depends on the file, it can't be turned off by the overrides
+    if optional_json_file_source.is_some_and(|source| source.is_json5()) {
+        options = options.with_json5();
+    }
     let parse = biome_json_parser::parse_json_with_cache(text, cache, options);
     let root = parse.syntax();
     let diagnostics = parse.into_diagnostics();
@@ -287,6 +336,19 @@ fn lint(params: LintParams) -> LintResults {
                 );
             }
 
+            match params.json_schema {
+                Some(Ok(schema)) => diagnostics.extend(
+                    schema
+                        .validate(&root)
+                        .into_iter()
+                        .map(biome_diagnostics::serde::Diagnostic::new),
+                ),
+                Some(Err(diagnostic)) => {
+                    diagnostics.push(biome_diagnostics::serde::Diagnostic::new(diagnostic));
+                }
+                None => {}
+            }
+
             let mut diagnostic_count = diagnostics.len() as u32;
             let mut errors = diagnostics
                 .iter()
@@ -369,36 +431,193 @@ fn lint(params: LintParams) -> LintResults {
         })
 }
 
-fn code_actions(_: CodeActionsParams) -> PullActionsResult {
-    PullActionsResult {
-        actions: Vec::new(),
-    }
+fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let CodeActionsParams {
+        parse,
+        range,
+        rules,
+        settings,
+        path,
+        ..
+    } = params;
+    tracing::debug_span!("Code actions JSON", range =? range, path =? path).in_scope(move || {
+        let tree: JsonRoot = parse.tree();
+        let mut actions = Vec::new();
+        let mut rules = rules
+            .map(|rules| rules.as_enabled_rules().into_iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let sort_keys = is_sort_keys_enabled(&settings, path.as_path());
+        if sort_keys {
+            rules.push(RuleFilter::Rule("correctness", "useSortedKeys"));
+        }
+        let mut filter = AnalysisFilter::from_enabled_rules(Some(rules.as_slice()));
+        filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
+        if sort_keys {
+            filter.categories |= RuleCategories::ACTION;
+        }
+        filter.range = Some(range);
+
+        let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(path.as_path()));
+
+        analyze(&tree, filter, &analyzer_options, |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                }
+            }));
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+        PullActionsResult { actions }
+    })
 }
 
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    let tree: JsonRoot = params.parse.tree();
-    Ok(FixFileResult {
-        actions: vec![],
-        errors: 0,
-        skipped_suggested_fixes: 0,
-        code: tree.syntax().to_string(),
-    })
+    let FixAllParams {
+        parse,
+        rules,
+        fix_file_mode,
+        settings,
+        should_format,
+        biome_path,
+        mut filter,
+        document_file_source,
+        ..
+    } = params;
+
+    // The JSON files don't support the suppression comments
+    if matches!(fix_file_mode, FixFileMode::ApplySuppressions) {
+        return Err(extension_error(biome_path));
+    }
+
+    let mut tree: JsonRoot = parse.tree();
+    let mut process_fix_all = ProcessFixAll::new(fix_file_mode, rules);
+
+    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
+
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(biome_path.as_path()));
+    loop {
+        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
+            process_fix_all.process_signal(signal)
+        });
+
+        let Some(action) = action else {
+            break;
+        };
+        match process_fix_all.process_action(action)? {
+            Some(root) => tree = root,
+            None => break,
+        }
+    }
+
+    let code = if should_format {
+        format_node(
+            settings.format_options::<JsonLanguage>(biome_path, &document_file_source),
+            tree.syntax(),
+        )?
+        .print()?
+        .into_code()
+    } else {
+        tree.syntax().to_string()
+    };
+    Ok(process_fix_all.finish(code))
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    Ok(OrganizeImportsResult {
-        code: parse.syntax::<JsonLanguage>().to_string(),
-    })
+/// Sorts the keys of the objects, when it's enabled
+fn organize_imports(
+    biome_path: &BiomePath,
+    parse: AnyParse,
+    settings: SettingsHandle,
+    _: Option<Arc<TsConfigJson>>,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let tree: JsonRoot = parse.tree();
+    if !is_sort_keys_enabled(&settings, biome_path.as_path()) {
+        return Ok(OrganizeImportsResult {
+            code: tree.syntax().to_string(),
+        });
+    }
+
+    let filter = AnalysisFilter {
+        enabled_rules: Some(&[RuleFilter::Rule("correctness", "useSortedKeys")]),
+        categories: RuleCategories::ACTION,
+        ..AnalysisFilter::default()
+    };
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(biome_path.as_path()));
+
+    let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
+        for action in signal.actions() {
+            if action.is_suppression() {
+                continue;
+            }
+
+            return ControlFlow::Break(action);
+        }
+        ControlFlow::Continue(())
+    });
+
+    let Some(action) = action else {
+        return Ok(OrganizeImportsResult {
+            code: tree.syntax().to_string(),
+        });
+    };
+    match JsonRoot::cast(action.mutation.commit()) {
+        Some(tree) => Ok(OrganizeImportsResult {
+            code: tree.syntax().to_string(),
+        }),
+        None => Err(WorkspaceError::RuleError(
+            RuleError::ReplacedRootWithNonRootError {
+                rule_name: action
+                    .rule_name
+                    .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+            },
+        )),
+    }
+}
+
+/// Whether the keys of the objects of the file at `path` are sorted when the imports are organized
+fn is_sort_keys_enabled(settings: &SettingsHandle, path: &Path) -> bool {
+    let settings = settings.as_ref();
+    settings
+        .override_settings
+        .json_sort_keys_enabled(path)
+        .or(settings.languages.json.organize_imports.sort_keys)
+        .unwrap_or_default()
 }
 
 fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
+    let mut rules = to_analyzer_rules(settings.as_ref(), file_path.as_path());
+    let order = settings
+        .as_ref()
+        .override_settings
+        .json_sort_keys_order(file_path.as_path())
+        .or(settings
+            .as_ref()
+            .languages
+            .json
+            .organize_imports
+            .sort_keys_order
+            .as_deref())
+        .unwrap_or_default();
+    rules.push_rule(
+        RuleKey::new("correctness", "useSortedKeys"),
+        RuleOptions::new(UseSortedKeysOptions {
+            order: order.to_vec(),
+        }),
+    );
     let configuration = AnalyzerConfiguration {
-        rules: to_analyzer_rules(settings.as_ref(), file_path.as_path()),
+        rules,
         globals: vec![],
         preferred_quote: PreferredQuote::Double,
+        enabled_rules: Some(settings.as_ref().as_enabled_rules(file_path.as_path())),
     };
     AnalyzerOptions {
         configuration,
         file_path,
+        suppression_reason: None,
     }
 }
@@ -5,28 +5,36 @@ use self::{
 pub use crate::file_handlers::astro::{AstroFileHandler, ASTRO_FENCE};
 pub use crate::file_handlers::svelte::{SvelteFileHandler, SVELTE_FENCE};
 pub use crate::file_handlers::vue::{VueFileHandler, VUE_FENCE};
-use crate::workspace::{FixFileMode, OrganizeImportsResult};
+use crate::workspace::{FixAction, FixFileMode, OrganizeImportsResult};
 use crate::{
     settings::SettingsHandle,
     workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
     Rules, WorkspaceError,
 };
-use biome_analyze::{AnalysisFilter, AnalyzerDiagnostic, RuleCategories};
+use biome_analyze::{
+    AnalysisFilter, AnalyzerAction, AnalyzerDiagnostic, AnalyzerSignal, RuleCategories,
+};
 use biome_console::fmt::Formatter;
 use biome_console::markup;
 use biome_css_formatter::can_format_css_yet;
 use biome_css_syntax::CssFileSource;
-use biome_diagnostics::{Diagnostic, Severity};
+use biome_diagnostics::{Applicability, Diagnostic, Severity};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
+use biome_js_analyze::{ModuleGraph, RuleError};
 use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
+use biome_json_analyze::schema::{JsonSchema, JsonSchemaDiagnostic};
 use biome_json_syntax::JsonFileSource;
 use biome_parser::AnyParse;
-use biome_project::PackageJson;
-use biome_rowan::NodeCache;
+use biome_project::{NodeJsWorkspace, PackageJson, TsConfigJson};
+use biome_rowan::{AstNode, Language, NodeCache};
 pub use javascript::JsFormatterSettings;
+pub use json::{JsonLinterSettings, JsonOrganizeImportsSettings, JsonSchemaSettings};
+use std::borrow::Cow;
 use std::ffi::OsStr;
+use std::ops::ControlFlow;
 use std::path::Path;
+use std::sync::Arc;
 
 mod astro;
 mod css;
@@ -111,6 +119,7 @@ impl DocumentFileSource {
             "d.ts" | "d.mts" | "d.cts" => JsFileSource::d_ts().into(),
             "json" => JsonFileSource::json().into(),
             "jsonc" => JsonFileSource::json().with_comments(true).into(),
+            "json5" => JsonFileSource::json5().into(),
             "astro" => JsFileSource::astro().into(),
             "vue" => JsFileSource::vue().into(),
             "svelte" => JsFileSource::svelte().into(),
@@ -133,6 +142,7 @@ impl DocumentFileSource {
             "typescriptreact" => JsFileSource::tsx().into(),
             "json" => JsonFileSource::json().into(),
             "jsonc" => JsonFileSource::json().with_comments(true).into(),
+            "json5" => JsonFileSource::json5().into(),
             "astro" => JsFileSource::astro().into(),
             "vue" => JsFileSource::vue().into(),
             "svelte" => JsFileSource::svelte().into(),
@@ -341,7 +351,12 @@ pub struct FixAllParams<'a> {
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) workspace: Option<Arc<NodeJsWorkspace>>,
+    pub(crate) tsconfig: Option<Arc<TsConfigJson>>,
+    pub(crate) module_graph: Option<Arc<ModuleGraph>>,
     pub(crate) document_file_source: DocumentFileSource,
+    /// The reason written in the suppression comments
+    pub(crate) suppression_reason: Option<String>,
 }
 
 #[derive(Default)]
@@ -391,6 +406,11 @@ pub(crate) struct LintParams<'a> {
     pub(crate) path: &'a BiomePath,
     pub(crate) categories: RuleCategories,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) workspace: Option<Arc<NodeJsWorkspace>>,
+    pub(crate) tsconfig: Option<Arc<TsConfigJson>>,
+    pub(crate) module_graph: Option<Arc<ModuleGraph>>,
+    /// The JSON Schema that validates a JSON file
+    pub(crate) json_schema: Option<Result<Arc<JsonSchema>, JsonSchemaDiagnostic>>,
 }
 
 pub(crate) struct LintResults {
@@ -406,6 +426,9 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) settings: SettingsHandle<'a>,
     pub(crate) path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) workspace: Option<Arc<NodeJsWorkspace>>,
+    pub(crate) tsconfig: Option<Arc<TsConfigJson>>,
+    pub(crate) module_graph: Option<Arc<ModuleGraph>>,
     pub(crate) language: DocumentFileSource,
 }
 
@@ -413,7 +436,12 @@ type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
-type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
+type OrganizeImports = fn(
+    &BiomePath,
+    AnyParse,
+    SettingsHandle,
+    Option<Arc<TsConfigJson>>,
+) -> Result<OrganizeImportsResult, WorkspaceError>;
 
 #[derive(Default)]
 pub struct AnalyzerCapabilities {
@@ -557,6 +585,140 @@ pub(crate) fn is_diagnostic_error(
     severity >= Severity::Error
 }
 
+/// The state of the loop of the `fix_all` capabilities, which analyzes the file again after
+/// each applied code fix, until no fix is left
+pub(crate) struct ProcessFixAll<'a> {
+    fix_file_mode: FixFileMode,
+    rules: Option<&'a Rules>,
+    errors: u16,
+    skipped_suggested_fixes: u32,
+    actions: Vec<FixAction>,
+    /// The signals that received a suppression comment, identified by their rule and their
+    /// range, which follows the edits of the file
+    suppressed_signals: Vec<(Option<(&'static str, &'static str)>, TextRange)>,
+}
+
+impl<'a> ProcessFixAll<'a> {
+    pub(crate) fn new(fix_file_mode: FixFileMode, rules: Option<&'a Rules>) -> Self {
+        Self {
+            fix_file_mode,
+            rules,
+            errors: 0,
+            skipped_suggested_fixes: 0,
+            actions: Vec::new(),
+            suppressed_signals: Vec::new(),
+        }
+    }
+
+    /// Counts the errors of `signal`, and breaks with the first of its actions that the mode
+    /// applies. The analysis that doesn't break counts all the errors left in the file.
+    pub(crate) fn process_signal<L: Language>(
+        &mut self,
+        signal: &dyn AnalyzerSignal<L>,
+    ) -> ControlFlow<AnalyzerAction<L>> {
+        let mut span = None;
+        if let Some(diagnostic) = signal.diagnostic() {
+            if is_diagnostic_error(&diagnostic, self.rules) {
+                self.errors += 1;
+            }
+            span = diagnostic.location().span;
+        }
+
+        for action in signal.actions() {
+            // suppression actions should not be part of the fixes (safe or suggested),
+            // and they are the only actions applied when adding suppression comments
+            if action.is_suppression()
+                != matches!(self.fix_file_mode, FixFileMode::ApplySuppressions)
+            {
+                continue;
+            }
+
+            match self.fix_file_mode {
+                FixFileMode::SafeFixes => {
+                    if action.applicability == Applicability::MaybeIncorrect {
+                        self.skipped_suggested_fixes += 1;
+                    }
+                    if action.applicability == Applicability::Always {
+                        return ControlFlow::Break(action);
+                    }
+                }
+                FixFileMode::SafeAndUnsafeFixes => {
+                    if matches!(
+                        action.applicability,
+                        Applicability::Always | Applicability::MaybeIncorrect
+                    ) {
+                        return ControlFlow::Break(action);
+                    }
+                }
+                FixFileMode::ApplySuppressions => {
+                    // A signal that comes back wasn't silenced by its comment, e.g. when the
+                    // comment can't be placed on the line above it. Suppressing it again would
+                    // insert comments forever.
+                    if let Some(span) = span {
+                        let signal = (action.rule_name, span);
+                        if self.suppressed_signals.contains(&signal) {
+                            return ControlFlow::Continue(());
+                        }
+                        self.suppressed_signals.push(signal);
+                    }
+                    return ControlFlow::Break(action);
+                }
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Applies `action`, and returns the new root of the file, or [None] when the action leaves
+    /// the file unchanged. The loop must stop then, since the file would be analyzed again with
+    /// the same result.
+    pub(crate) fn process_action<R: AstNode>(
+        &mut self,
+        action: AnalyzerAction<R::Language>,
+    ) -> Result<Option<R>, WorkspaceError> {
+        let Some((range, text_edit)) = action.mutation.as_text_edits() else {
+            return Ok(None);
+        };
+        if text_edit.is_empty() {
+            return Ok(None);
+        }
+        let rule_name = action
+            .rule_name
+            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule)));
+        let previous_end = action.mutation.root().text_range().end();
+        let Some(root) = R::cast(action.mutation.commit()) else {
+            return Err(WorkspaceError::RuleError(
+                RuleError::ReplacedRootWithNonRootError { rule_name },
+            ));
+        };
+        // The suppressed signals that follow the edit move with the code
+        let end = root.syntax().text_range().end();
+        for (_, span) in &mut self.suppressed_signals {
+            if span.start() >= range.start() {
+                *span = if end >= previous_end {
+                    *span + (end - previous_end)
+                } else {
+                    *span - (previous_end - end)
+                };
+            }
+        }
+        self.actions.push(FixAction { rule_name, range });
+        // The file is analyzed again, so only the errors of the last analysis are left
+        self.errors = 0;
+        Ok(Some(root))
+    }
+
+    /// Returns the result of the loop, where `code` is the fixed file
+    pub(crate) fn finish(self, code: String) -> FixFileResult {
+        FixFileResult {
+            code,
+            actions: self.actions,
+            errors: self.errors.into(),
+            skipped_suggested_fixes: self.skipped_suggested_fixes,
+        }
+    }
+}
+
 #[test]
 fn test_order() {
     for items in
@@ -4,13 +4,16 @@ use crate::configuration::linter::to_linter_settings;
 use crate::configuration::organize_imports::{to_organize_imports_settings, OrganizeImports};
 use crate::configuration::{
     push_to_analyzer_rules, to_override_settings, CssConfiguration, FormatterConfiguration,
-    JavascriptConfiguration, JsonConfiguration, LinterConfiguration, PartialConfiguration,
+    JavascriptConfiguration, JsonConfiguration, JsonSchemas, LinterConfiguration,
+    PartialConfiguration,
 };
+use crate::file_handlers::JsonSchemaSettings;
+use crate::matcher::Pattern;
 use crate::workspace::DocumentFileSource;
 use crate::{
     configuration::FilesConfiguration, ConfigurationDiagnostic, Matcher, Rules, WorkspaceError,
 };
-use biome_analyze::AnalyzerRules;
+use biome_analyze::{AnalyzerRules, RuleFilter};
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
@@ -50,6 +53,12 @@ pub struct WorkspaceSettings {
     pub organize_imports: OrganizeImportsSettings,
     /// overrides
     pub override_settings: OverrideSettings,
+    /// The directory of the nested configuration file these settings were loaded from.
+    /// It's [None] for the settings of the workspace.
+    pub directory: Option<PathBuf>,
+    /// The settings of the configuration files nested inside the workspace. Each of them
+    /// applies to the files of its directory.
+    pub nested_settings: Vec<WorkspaceSettings>,
 }
 
 impl WorkspaceSettings {
@@ -132,7 +141,10 @@ impl WorkspaceSettings {
         }
         // json settings
         if let Some(json) = configuration.json {
-            self.languages.json = JsonConfiguration::from(json).into();
+            let json = JsonConfiguration::from(json);
+            let schemas = to_json_schema_settings(working_directory.clone(), &json.schemas)?;
+            self.languages.json = json.into();
+            self.languages.json.linter.schemas = schemas;
         }
         // css settings
         if let Some(css) = configuration.css {
@@ -148,6 +160,74 @@ impl WorkspaceSettings {
         Ok(())
     }
 
+    /// Returns the settings that apply to `path`: the ones of the closest nested configuration
+    /// file that contains it, or the settings of the workspace.
+    pub fn for_path(&self, path: &Path) -> &WorkspaceSettings {
+        self.nested_index(path)
+            .map_or(self, |index| &self.nested_settings[index])
+    }
+
+    /// Returns the index of the closest nested settings whose directory contains `path`
+    fn nested_index(&self, path: &Path) -> Option<usize> {
+        // `./src` and `src` are the same directory
+        let path = path.strip_prefix(".").unwrap_or(path);
+        self.nested_settings
+            .iter()
+            .enumerate()
+            .filter_map(|(index, settings)| {
+                let directory = settings.directory.as_deref()?;
+                let directory = directory.strip_prefix(".").unwrap_or(directory);
+                path.starts_with(directory)
+                    .then(|| (index, directory.components().count()))
+            })
+            .max_by_key(|(_, depth)| *depth)
+            .map(|(index, _)| index)
+    }
+
+    /// Merges the configuration of a configuration file nested inside the workspace. The
+    /// resulting settings apply to the files inside `directory`.
+    ///
+    /// The globs of the configuration are relative to `directory`. The nested settings inherit
+    /// the VCS ignore file of the workspace, unless the nested configuration enables its own.
+    pub fn merge_with_nested_configuration(
+        &mut self,
+        directory: PathBuf,
+        mut configuration: PartialConfiguration,
+        vcs_path: Option<PathBuf>,
+        gitignore_matches: &[String],
+    ) -> Result<(), WorkspaceError> {
+        scope_patterns_to_directory(&mut configuration, &directory);
+
+        let index = match self
+            .nested_settings
+            .iter()
+            .position(|settings| settings.directory.as_ref() == Some(&directory))
+        {
+            Some(index) => index,
+            None => {
+                self.nested_settings.push(WorkspaceSettings {
+                    directory: Some(directory.clone()),
+                    ..WorkspaceSettings::default()
+                });
+                self.nested_settings.len() - 1
+            }
+        };
+
+        let git_ignore = self.files.git_ignore.clone();
+        let nested_settings = &mut self.nested_settings[index];
+        nested_settings.merge_with_configuration(
+            configuration,
+            Some(directory),
+            vcs_path,
+            gitignore_matches,
+        )?;
+        if nested_settings.files.git_ignore.is_none() {
+            nested_settings.files.git_ignore = git_ignore;
+        }
+
+        Ok(())
+    }
+
     /// It retrieves the severity based on the `code` of the rule and the current configuration.
     ///
     /// The code of the has the following pattern: `{group}/{rule_name}`.
@@ -186,6 +266,40 @@ impl WorkspaceSettings {
         }
         result
     }
+
+    /// Returns the rules enabled for the file at `path`, taking `overrides` into account
+    pub fn as_enabled_rules(&self, path: &Path) -> Vec<RuleFilter<'static>> {
+        self.as_rules(path)
+            .map(|rules| rules.as_enabled_rules().into_iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Whether one of `rules` is enabled by the settings of the workspace, by its overrides
+    /// or by its nested settings
+    pub fn has_enabled_rules(&self, rules: &[RuleFilter]) -> bool {
+        let is_enabled = |enabled_rules: IndexSet<RuleFilter>| {
+            enabled_rules.iter().any(|rule| rules.contains(rule))
+        };
+        let enabled_in_workspace = self.linter.enabled
+            && (self
+                .linter
+                .rules
+                .as_ref()
+                .is_some_and(|rules| is_enabled(rules.as_enabled_rules()))
+                || self.override_settings.patterns.iter().any(|pattern| {
+                    pattern.linter.enabled != Some(false)
+                        && pattern
+                            .linter
+                            .rules
+                            .as_ref()
+                            .is_some_and(|rules| is_enabled(rules.as_enabled_rules()))
+                }));
+        enabled_in_workspace
+            || self
+                .nested_settings
+                .iter()
+                .any(|settings| settings.has_enabled_rules(rules))
+    }
 }
 
 /// Formatter settings for the entire workspace
@@ -304,6 +418,16 @@ pub struct OverrideOrganizeImportsSettings {
     pub enabled: Option<bool>,
 }
 
+/// Filesystem settings for the files matched by an override
+#[derive(Debug, Default)]
+pub struct OverrideFilesSettings {
+    /// File size limit in bytes
+    pub max_size: Option<NonZeroU64>,
+
+    /// Files not recognized by Biome should not emit a diagnostic
+    pub ignore_unknown: Option<bool>,
+}
+
 /// Static map of language names to language-specific settings
 #[derive(Debug, Default)]
 pub struct LanguageListSettings {
@@ -344,10 +468,14 @@ impl From<JsonConfiguration> for LanguageSettings<JsonLanguage> {
         language_setting.parser.allow_comments = json.parser.allow_comments;
         language_setting.parser.allow_trailing_commas = json.parser.allow_trailing_commas;
         language_setting.formatter.trailing_commas = json.formatter.trailing_commas;
+        language_setting.formatter.quote_style = json.formatter.quote_style;
         language_setting.formatter.enabled = Some(json.formatter.enabled);
         language_setting.formatter.line_width = json.formatter.line_width;
         language_setting.formatter.indent_width = json.formatter.indent_width.map(Into::into);
         language_setting.formatter.indent_style = json.formatter.indent_style.map(Into::into);
+        language_setting.organize_imports.sort_keys = Some(json.sort_keys.enabled);
+        language_setting.organize_imports.sort_keys_order =
+            Some(json.sort_keys.order.into_index_set().into_iter().collect());
 
         language_setting
     }
@@ -486,19 +614,28 @@ fn to_file_settings(
 #[derive(Debug)]
 pub struct SettingsHandle<'a> {
     inner: RwLockReadGuard<'a, WorkspaceSettings>,
+    /// The index of the nested settings that apply to the file, if any
+    nested_index: Option<usize>,
 }
 
 impl<'a> SettingsHandle<'a> {
-    pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>) -> Self {
+    /// Creates a handle to the settings that apply to `path`
+    pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>, path: &Path) -> Self {
+        let inner = settings.read().unwrap();
+        let nested_index = inner.nested_index(path);
         Self {
-            inner: settings.read().unwrap(),
+            inner,
+            nested_index,
         }
     }
 }
 
 impl<'a> AsRef<WorkspaceSettings> for SettingsHandle<'a> {
     fn as_ref(&self) -> &WorkspaceSettings {
-        &self.inner
+        match self.nested_index {
+            Some(index) => &self.inner.nested_settings[index],
+            None => &self.inner,
+        }
     }
 }
 
@@ -512,10 +649,11 @@ impl<'a> SettingsHandle<'a> {
     where
         L: ServiceLanguage,
     {
+        let settings = self.as_ref();
         L::resolve_format_options(
-            &self.inner.formatter,
-            &self.inner.override_settings,
-            &L::lookup_settings(&self.inner.languages).formatter,
+            &settings.formatter,
+            &settings.override_settings,
+            &L::lookup_settings(&settings.languages).formatter,
             path,
             file_source,
         )
@@ -667,20 +805,47 @@ impl OverrideSettings {
     }
 
     pub fn as_css_parser_options(&self, path: &Path) -> Option<CssParserOptions> {
-        for pattern in &self.patterns {
+        self.patterns.iter().fold(None, |options, pattern| {
             let included = !pattern.include.is_empty() && pattern.include.matches_path(path);
             let excluded = !pattern.exclude.is_empty() && pattern.exclude.matches_path(path);
 
-            if included || !excluded {
+            if included && !excluded {
                 let css_parser = &pattern.languages.css.parser;
 
-                return Some(CssParserOptions {
+                Some(CssParserOptions {
                     allow_wrong_line_comments: css_parser.allow_wrong_line_comments,
-                });
+                })
+            } else {
+                options
             }
-        }
+        })
+    }
 
-        None
+    /// Returns the maximum size of `path` set by the last override that matches it
+    pub fn override_max_size(&self, path: &Path) -> Option<NonZeroU64> {
+        self.patterns.iter().fold(None, |max_size, pattern| {
+            let included = !pattern.include.is_empty() && pattern.include.matches_path(path);
+            let excluded = !pattern.exclude.is_empty() && pattern.exclude.matches_path(path);
+            if included && !excluded {
+                pattern.files.max_size.or(max_size)
+            } else {
+                max_size
+            }
+        })
+    }
+
+    /// Returns whether `path` is ignored when it's unknown, according to the last override that
+    /// matches it and sets `files.ignoreUnknown`
+    pub fn override_ignore_unknown(&self, path: &Path) -> Option<bool> {
+        self.patterns.iter().fold(None, |ignore_unknown, pattern| {
+            let included = !pattern.include.is_empty() && pattern.include.matches_path(path);
+            let excluded = !pattern.exclude.is_empty() && pattern.exclude.matches_path(path);
+            if included && !excluded {
+                pattern.files.ignore_unknown.or(ignore_unknown)
+            } else {
+                ignore_unknown
+            }
+        })
     }
 
     /// Retrieves the options of lint rules that have been overridden
@@ -750,6 +915,45 @@ impl OverrideSettings {
         }
         None
     }
+
+    /// Scans the overrides and checks if there's an override that enables the sorting of the keys
+    /// of the JSON objects for `path`. The last override that matches wins.
+    pub fn json_sort_keys_enabled(&self, path: &Path) -> Option<bool> {
+        self.patterns.iter().fold(None, |enabled, pattern| {
+            let included = !pattern.include.is_empty() && pattern.include.matches_path(path);
+            let excluded = !pattern.exclude.is_empty() && pattern.exclude.matches_path(path);
+            if included && !excluded {
+                pattern
+                    .languages
+                    .json
+                    .organize_imports
+                    .sort_keys
+                    .or(enabled)
+            } else {
+                enabled
+            }
+        })
+    }
+
+    /// Scans the overrides and returns the order of the keys of the JSON objects for `path`. The
+    /// last override that matches wins.
+    pub fn json_sort_keys_order(&self, path: &Path) -> Option<&[String]> {
+        self.patterns.iter().fold(None, |order, pattern| {
+            let included = !pattern.include.is_empty() && pattern.include.matches_path(path);
+            let excluded = !pattern.exclude.is_empty() && pattern.exclude.matches_path(path);
+            if included && !excluded {
+                pattern
+                    .languages
+                    .json
+                    .organize_imports
+                    .sort_keys_order
+                    .as_deref()
+                    .or(order)
+            } else {
+                order
+            }
+        })
+    }
 }
 #[derive(Debug, Default)]
 pub struct OverrideSettingPattern {
@@ -761,6 +965,8 @@ pub struct OverrideSettingPattern {
     pub linter: OverrideLinterSettings,
     /// Linter settings applied to all files in the workspace
     pub organize_imports: OverrideOrganizeImportsSettings,
+    /// Filesystem settings applied to the files matched by the override
+    pub files: OverrideFilesSettings,
     /// Language specific settings
     pub languages: LanguageListSettings,
 
@@ -857,6 +1063,9 @@ impl OverrideSettingPattern {
         if let Some(trailing_commas) = json_formatter.trailing_commas {
             options.set_trailing_commas(trailing_commas);
         }
+        if let Some(quote_style) = json_formatter.quote_style {
+            options.set_quote_style(Some(quote_style));
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_json_format_options.write() {
             let options = options.clone();
@@ -966,6 +1175,75 @@ pub fn to_matcher(
     Ok(matcher)
 }
 
+/// Resolves the paths of the JSON Schemas, which are relative to the configuration file
+fn to_json_schema_settings(
+    working_directory: Option<PathBuf>,
+    schemas: &JsonSchemas,
+) -> Result<Vec<JsonSchemaSettings>, WorkspaceError> {
+    let mut settings = Vec::new();
+    for association in &schemas.0 {
+        let Some(schema) = association.schema.as_deref() else {
+            continue;
+        };
+        let schema = schema.trim_start_matches("./");
+        settings.push(JsonSchemaSettings {
+            include: to_matcher(working_directory.clone(), association.include.as_ref())?,
+            schema: working_directory
+                .as_ref()
+                .map_or_else(|| PathBuf::from(schema), |directory| directory.join(schema)),
+        });
+    }
+    Ok(settings)
+}
+
+/// Prefixes the globs of a nested configuration file with its `directory`, so that they match
+/// the paths of the workspace
+fn scope_patterns_to_directory(configuration: &mut PartialConfiguration, directory: &Path) {
+    let prefix = Pattern::escape(&directory.to_string_lossy());
+    let scope = |patterns: &mut Option<StringSet>| {
+        if let Some(patterns) = patterns.as_mut() {
+            let scoped_patterns = patterns
+                .iter()
+                .map(|pattern| format!("{prefix}/{}", pattern.trim_start_matches("./")))
+                .collect();
+            *patterns = StringSet::new(scoped_patterns);
+        }
+    };
+
+    if let Some(files) = configuration.files.as_mut() {
+        scope(&mut files.include);
+        scope(&mut files.ignore);
+    }
+    if let Some(formatter) = configuration.formatter.as_mut() {
+        scope(&mut formatter.include);
+        scope(&mut formatter.ignore);
+    }
+    if let Some(linter) = configuration.linter.as_mut() {
+        scope(&mut linter.include);
+        scope(&mut linter.ignore);
+    }
+    if let Some(organize_imports) = configuration.organize_imports.as_mut() {
+        scope(&mut organize_imports.include);
+        scope(&mut organize_imports.ignore);
+    }
+    if let Some(overrides) = configuration.overrides.as_mut() {
+        for pattern in overrides.0.iter_mut() {
+            scope(&mut pattern.include);
+            scope(&mut pattern.ignore);
+        }
+    }
+    // The paths of the schemas are joined with the directory when the settings are computed
+    if let Some(schemas) = configuration
+        .json
+        .as_mut()
+        .and_then(|json| json.schemas.as_mut())
+    {
+        for association in schemas.0.iter_mut() {
+            scope(&mut association.include);
+        }
+    }
+}
+
 fn to_git_ignore(path: PathBuf, matches: &[String]) -> Result<Gitignore, WorkspaceError> {
     let mut gitignore_builder = GitignoreBuilder::new(path.clone());
 
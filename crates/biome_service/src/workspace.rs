@@ -57,19 +57,24 @@ use biome_analyze::ActionCategory;
 pub use biome_analyze::RuleCategories;
 use biome_console::{markup, Markup, MarkupBuf};
 use biome_css_formatter::can_format_css_yet;
+use biome_deserialize::json::deserialize_from_json_str;
 use biome_diagnostics::CodeSuggestion;
+use biome_diagnostics::Error;
 use biome_formatter::Printed;
-use biome_fs::BiomePath;
+use biome_fs::{BiomePath, FileSystem, OpenOptions, PathInterner, TraversalContext};
 use biome_js_syntax::{TextRange, TextSize};
+use biome_json_parser::JsonParserOptions;
+use biome_project::{PackageJson, PnpmWorkspace, TsConfigJson};
 use biome_text_edit::TextEdit;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
-use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
+use std::path::{Component, Path, PathBuf};
+use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc, sync::Mutex};
 use tracing::debug;
 
 pub use self::client::{TransportRequest, WorkspaceClient, WorkspaceTransport};
-use crate::configuration::PartialConfiguration;
+use crate::configuration::{normalize_path, PartialConfiguration};
 pub use crate::file_handlers::DocumentFileSource;
 use crate::settings::WorkspaceSettings;
 
@@ -406,6 +411,10 @@ pub struct UpdateSettingsParams {
     // @ematipico TODO: have a better data structure for this
     pub gitignore_matches: Vec<String>,
     pub working_directory: Option<PathBuf>,
+    /// The directory of a configuration file nested inside the workspace. When set, the
+    /// settings apply only to the files inside this directory.
+    #[serde(default)]
+    pub nested_directory: Option<PathBuf>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -440,6 +449,45 @@ pub struct UpdateProjectParams {
     pub path: BiomePath,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RegisterProjectConfigurationParams {
+    /// The path of a `tsconfig.json`, a `jsconfig.json` or a `pnpm-workspace.yaml`
+    pub path: BiomePath,
+    /// Whether the `tsconfig.json` applies to the files that aren't inside the directory of
+    /// another `tsconfig.json`, like the one found from the working directory
+    pub is_default: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RegisterModuleParams {
+    pub path: BiomePath,
+    /// The content of the file. When [None], the file is only registered as existing.
+    pub content: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IsModuleGraphEnabledParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IsNodeJsWorkspaceEnabledParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetJsonSchemaPathParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetJsonSchemaPathResult {
+    /// The path of the JSON Schema, when one validates the file
+    pub path: Option<BiomePath>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetSyntaxTreeParams {
@@ -551,6 +599,8 @@ pub enum FixFileMode {
     SafeFixes,
     /// Applies [safe](biome_diagnostics::Applicability::Always) and [unsafe](biome_diagnostics::Applicability::MaybeIncorrect) fixes
     SafeAndUnsafeFixes,
+    /// Adds a suppression comment for every diagnostic emitted by the lint rules
+    ApplySuppressions,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -559,6 +609,11 @@ pub struct FixFileParams {
     pub path: BiomePath,
     pub fix_file_mode: FixFileMode,
     pub should_format: bool,
+    /// The reason written in the suppression comments added with [FixFileMode::ApplySuppressions]
+    pub suppression_reason: Option<String>,
+    /// The rules whose diagnostics are suppressed with [FixFileMode::ApplySuppressions],
+    /// written as `<GROUP>/<RULE>`. The diagnostics of all the rules are suppressed when it's empty
+    pub suppressed_rules: Vec<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -712,9 +767,50 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Add a new project to the workspace
     fn open_project(&self, params: OpenProjectParams) -> Result<(), WorkspaceError>;
 
-    /// Sets the current project path
+    /// Sets the current project path, which is the path of its `package.json`
     fn update_current_project(&self, params: UpdateProjectParams) -> Result<(), WorkspaceError>;
 
+    /// Registers a configuration file of the project, opened with [Workspace::open_project].
+    ///
+    /// A `tsconfig.json` or a `jsconfig.json` applies to the files of its directory. The
+    /// configuration files that it extends must be opened first. A `pnpm-workspace.yaml` sets
+    /// the root of the monorepo and the directories of its packages.
+    fn register_project_configuration(
+        &self,
+        params: RegisterProjectConfigurationParams,
+    ) -> Result<(), WorkspaceError>;
+
+    /// Adds the imports and the exports of a JavaScript file, or the entry points of a
+    /// `package.json`, to the module graph of the project, without opening the file.
+    ///
+    /// A `package.json` is also the manifest of the files of its directory, and it declares the
+    /// packages of the monorepo when it has the field `workspaces`.
+    fn register_module(&self, params: RegisterModuleParams) -> Result<(), WorkspaceError>;
+
+    /// Checks if one of the enabled rules queries the module graph of the project, in which
+    /// case the files of the project should be registered with [Workspace::register_module]
+    fn is_module_graph_enabled(
+        &self,
+        params: IsModuleGraphEnabledParams,
+    ) -> Result<bool, WorkspaceError>;
+
+    /// Checks if one of the enabled rules queries the manifests of the packages, or the module
+    /// graph, in which case the `package.json` files of the monorepo should be registered with
+    /// [Workspace::register_module]
+    fn is_node_js_workspace_enabled(
+        &self,
+        params: IsNodeJsWorkspaceEnabledParams,
+    ) -> Result<bool, WorkspaceError>;
+
+    /// Returns the path of the JSON Schema that validates the open JSON file at `path` when it's
+    /// linted: the local file referenced by its `$schema` key, or the schema that the
+    /// configuration associates with it. The schema must be opened with
+    /// [Workspace::open_project] before the file is linted.
+    fn get_json_schema_path(
+        &self,
+        params: GetJsonSchemaPathParams,
+    ) -> Result<GetJsonSchemaPathResult, WorkspaceError>;
+
     // Return a textual, debug representation of the syntax tree for a given document
     fn get_syntax_tree(
         &self,
@@ -800,6 +896,321 @@ where
     Ok(Box::new(client::WorkspaceClient::new(transport)?))
 }
 
+/// Opens the `tsconfig.json` at `path` and the configuration files it extends, read from `fs`,
+/// and registers it with [Workspace::register_project_configuration]. When `is_default` is
+/// `true`, it applies to the files that aren't inside the directory of another `tsconfig.json`.
+pub fn open_tsconfig(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    path: &Path,
+    is_default: bool,
+) -> Result<(), WorkspaceError> {
+    let files = RefCell::new(Vec::new());
+    TsConfigJson::load(path, |path| {
+        let mut content = String::new();
+        fs.open_with_options(path, OpenOptions::default().read(true))
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .ok()?;
+        files
+            .borrow_mut()
+            .push((path.to_path_buf(), content.clone()));
+        Some(content)
+    });
+    for (path, content) in files.into_inner() {
+        workspace.open_project(OpenProjectParams {
+            path: BiomePath::new(path),
+            content,
+            version: 0,
+        })?;
+    }
+    workspace.register_project_configuration(RegisterProjectConfigurationParams {
+        path: BiomePath::new(path),
+        is_default,
+    })
+}
+
+/// Opens the JSON Schema that validates the open JSON file at `path`, read from `fs`, if any.
+/// See [Workspace::get_json_schema_path].
+pub fn open_json_schema(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    path: &Path,
+) -> Result<(), WorkspaceError> {
+    let Some(schema_path) = workspace
+        .get_json_schema_path(GetJsonSchemaPathParams {
+            path: BiomePath::new(path),
+        })?
+        .path
+    else {
+        return Ok(());
+    };
+    let mut content = String::new();
+    // The workspace reports the schemas of the configuration that can't be read
+    if fs
+        .open_with_options(&schema_path, OpenOptions::default().read(true))
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .is_ok()
+    {
+        workspace.open_project(OpenProjectParams {
+            path: schema_path,
+            content,
+            version: 0,
+        })?;
+    }
+    Ok(())
+}
+
+/// Finds the root of the monorepo that contains the package of `directory`, which is the
+/// nearest parent directory with a `pnpm-workspace.yaml` or with a `package.json` that declares
+/// `workspaces`, and registers all the `package.json` files of the monorepo with
+/// [Workspace::register_module], so the files are linted with the manifest of their package.
+///
+/// Nothing is registered unless one of the enabled rules needs the manifests, see
+/// [Workspace::is_node_js_workspace_enabled], and the ignored directories aren't walked. The
+/// paths sent to the workspace are relative to `working_directory` when they are inside it,
+/// like the paths of the traversed files.
+pub fn open_node_js_workspace(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    directory: &Path,
+    working_directory: Option<&Path>,
+) -> Result<(), WorkspaceError> {
+    if !workspace.is_node_js_workspace_enabled(IsNodeJsWorkspaceEnabledParams {})? {
+        return Ok(());
+    }
+
+    let read = |path: &Path| {
+        let mut content = String::new();
+        fs.open_with_options(path, OpenOptions::default().read(true))
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .ok()
+            .map(|_| content)
+    };
+    let relative = |path: &Path| {
+        BiomePath::new(
+            working_directory
+                .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+                .unwrap_or(path),
+        )
+    };
+
+    let mut root = None;
+    for ancestor in directory.ancestors() {
+        let pnpm_workspace = ancestor.join(PnpmWorkspace::FILE_NAME);
+        if let Some(content) = read(&pnpm_workspace) {
+            let path = relative(&pnpm_workspace);
+            workspace.open_project(OpenProjectParams {
+                path: path.clone(),
+                content,
+                version: 0,
+            })?;
+            workspace.register_project_configuration(RegisterProjectConfigurationParams {
+                path,
+                is_default: false,
+            })?;
+            root = Some(ancestor);
+            break;
+        }
+        let declares_workspaces = read(&ancestor.join("package.json")).is_some_and(|content| {
+            deserialize_from_json_str::<PackageJson>(&content, JsonParserOptions::default(), "")
+                .into_deserialized()
+                .is_some_and(|manifest| !manifest.workspaces.is_empty())
+        });
+        if declares_workspaces {
+            root = Some(ancestor);
+            break;
+        }
+    }
+    let Some(root) = root else {
+        return Ok(());
+    };
+
+    let (interner, _) = PathInterner::new();
+    let ctx = ManifestsContext {
+        interner,
+        workspace,
+        manifests: Mutex::default(),
+    };
+    let root = if root.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        root
+    };
+    fs.traversal(Box::new(|scope| {
+        scope.spawn(&ctx, root.to_path_buf());
+    }));
+    for path in ctx.manifests.into_inner().unwrap() {
+        let Some(content) = read(&path) else {
+            continue;
+        };
+        workspace.register_module(RegisterModuleParams {
+            path: relative(&path),
+            content: Some(content),
+        })?;
+    }
+    Ok(())
+}
+
+/// Traversal context that collects the `package.json` files
+struct ManifestsContext<'a> {
+    interner: PathInterner,
+    workspace: &'a dyn Workspace,
+    manifests: Mutex<Vec<PathBuf>>,
+}
+
+impl<'a> TraversalContext for ManifestsContext<'a> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {}
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        // The directories and the manifests ignored by the configuration are skipped
+        !self
+            .workspace
+            .is_path_ignored(IsPathIgnoredParams {
+                biome_path: path.clone(),
+                features: FeaturesBuilder::new().with_linter().build(),
+            })
+            .unwrap_or(true)
+    }
+
+    fn handle_file(&self, path: &Path) {
+        if path.file_name() == Some(OsStr::new("package.json")) {
+            self.manifests.lock().unwrap().push(path.to_path_buf());
+        }
+    }
+}
+
+/// Registers the files of the project in the module graph of the workspace, read from `fs`, when
+/// one of the enabled rules queries it. Returns `true` when the files were registered.
+///
+/// The walk covers `project_root`, and the `paths` that aren't inside it. The content of the
+/// JavaScript files and the `package.json` files is sent to the workspace, the `tsconfig.json`
+/// files are opened with [open_tsconfig] for the files of their directory, the other files are
+/// only registered as existing, so the imports of stylesheets or images can be resolved. When
+/// `staged` is `true`, the content is read from the staging area.
+///
+/// The files are registered before the requested files are processed, so the rules can resolve
+/// their imports to the files that aren't requested.
+pub fn open_module_graph(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    project_root: &Path,
+    paths: &[PathBuf],
+    staged: bool,
+) -> Result<bool, WorkspaceError> {
+    if !workspace.is_module_graph_enabled(IsModuleGraphEnabledParams {})? {
+        return Ok(false);
+    }
+
+    let normalized_root = normalize_path(project_root);
+    let is_in_project = |path: &Path| {
+        let path = normalize_path(path);
+        path.starts_with(&normalized_root)
+            && (normalized_root.is_absolute() || !path.starts_with(Component::ParentDir))
+    };
+    let mut roots = vec![project_root.to_path_buf()];
+    roots.extend(paths.iter().filter(|path| !is_in_project(path)).cloned());
+
+    let (interner, _) = PathInterner::new();
+    let ctx = ModuleGraphContext {
+        interner,
+        fs,
+        workspace,
+        staged,
+    };
+    fs.traversal(Box::new(|scope| {
+        for root in roots {
+            scope.spawn(&ctx, root);
+        }
+    }));
+    Ok(true)
+}
+
+/// Traversal context that registers the files in the module graph
+struct ModuleGraphContext<'a> {
+    interner: PathInterner,
+    fs: &'a dyn FileSystem,
+    workspace: &'a dyn Workspace,
+    staged: bool,
+}
+
+impl<'a> TraversalContext for ModuleGraphContext<'a> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {
+        // The errors are reported when the files are processed
+    }
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        if self.fs.path_is_file(path) {
+            return true;
+        }
+        !self
+            .workspace
+            .is_path_ignored(IsPathIgnoredParams {
+                biome_path: path.clone(),
+                features: FeaturesBuilder::new().with_linter().build(),
+            })
+            .unwrap_or(true)
+    }
+
+    fn handle_file(&self, path: &Path) {
+        // The nested `tsconfig.json` files apply to the imports of the files of their directory
+        if path
+            .file_name()
+            .is_some_and(|name| name == "tsconfig.json" || name == "jsconfig.json")
+        {
+            let _ = open_tsconfig(self.fs, self.workspace, path, false);
+            return;
+        }
+        let biome_path = BiomePath::new(path);
+        let content = if self.is_module(&biome_path) {
+            let options = OpenOptions::default().read(true);
+            let file = if self.staged {
+                self.fs.open_staged_with_options(path, options)
+            } else {
+                self.fs.open_with_options(path, options)
+            };
+            let mut content = String::new();
+            if file
+                .and_then(|mut file| file.read_to_string(&mut content))
+                .is_err()
+            {
+                return;
+            }
+            Some(content)
+        } else {
+            None
+        };
+        // The files that can't be registered are reported when they are processed
+        let _ = self.workspace.register_module(RegisterModuleParams {
+            path: biome_path,
+            content,
+        });
+    }
+}
+
+impl<'a> ModuleGraphContext<'a> {
+    /// Whether the content of the file at `path` is registered in the module graph
+    fn is_module(&self, path: &BiomePath) -> bool {
+        if path.file_name() == Some(OsStr::new("package.json")) {
+            return true;
+        }
+
+        self.workspace
+            .file_features(SupportsFeatureParams {
+                path: path.clone(),
+                features: FeaturesBuilder::new().with_linter().build(),
+            })
+            .is_ok_and(|file_features| file_features.supports_lint())
+    }
+}
+
 /// [RAII](https://en.wikipedia.org/wiki/Resource_acquisition_is_initialization)
 /// guard for an open file in a workspace, takes care of closing the file
 /// automatically on drop
@@ -886,11 +1297,15 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         fix_file_mode: FixFileMode,
         should_format: bool,
+        suppression_reason: Option<String>,
+        suppressed_rules: Vec<String>,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
             fix_file_mode,
             should_format,
+            suppression_reason,
+            suppressed_rules,
         })
     }
 
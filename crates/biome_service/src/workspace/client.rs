@@ -1,7 +1,8 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OpenProjectParams,
-    OrganizeImportsParams, OrganizeImportsResult, RageParams, RageResult, ServerInfo,
-    UpdateProjectParams,
+    FileFeaturesResult, GetFileContentParams, GetJsonSchemaPathParams, GetJsonSchemaPathResult,
+    IsModuleGraphEnabledParams, IsNodeJsWorkspaceEnabledParams, IsPathIgnoredParams,
+    OpenProjectParams, OrganizeImportsParams, OrganizeImportsResult, RageParams, RageResult,
+    RegisterModuleParams, RegisterProjectConfigurationParams, ServerInfo, UpdateProjectParams,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -127,6 +128,38 @@ where
         self.request("biome/update_current_project", params)
     }
 
+    fn register_project_configuration(
+        &self,
+        params: RegisterProjectConfigurationParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("biome/register_project_configuration", params)
+    }
+
+    fn register_module(&self, params: RegisterModuleParams) -> Result<(), WorkspaceError> {
+        self.request("biome/register_module", params)
+    }
+
+    fn is_module_graph_enabled(
+        &self,
+        params: IsModuleGraphEnabledParams,
+    ) -> Result<bool, WorkspaceError> {
+        self.request("biome/is_module_graph_enabled", params)
+    }
+
+    fn is_node_js_workspace_enabled(
+        &self,
+        params: IsNodeJsWorkspaceEnabledParams,
+    ) -> Result<bool, WorkspaceError> {
+        self.request("biome/is_node_js_workspace_enabled", params)
+    }
+
+    fn get_json_schema_path(
+        &self,
+        params: GetJsonSchemaPathParams,
+    ) -> Result<GetJsonSchemaPathResult, WorkspaceError> {
+        self.request("biome/get_json_schema_path", params)
+    }
+
     fn get_syntax_tree(
         &self,
         params: GetSyntaxTreeParams,
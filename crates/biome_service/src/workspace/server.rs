@@ -1,40 +1,47 @@
 use super::{
-    ChangeFileParams, CloseFileParams, FeatureName, FixFileResult, FormatFileParams,
+    ChangeFileParams, CloseFileParams, FeatureName, FixFileMode, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, OpenProjectParams, PullActionsParams,
     PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, RenameResult,
     SearchPatternParams, SearchResults, SupportsFeatureParams, UpdateProjectParams,
     UpdateSettingsParams,
 };
+use crate::configuration::normalize_path;
 use crate::file_handlers::{
     Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, ParseResult,
 };
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    FileFeaturesResult, GetFileContentParams, GetJsonSchemaPathParams, GetJsonSchemaPathResult,
+    IsModuleGraphEnabledParams, IsNodeJsWorkspaceEnabledParams, IsPathIgnoredParams,
+    OrganizeImportsParams, OrganizeImportsResult, RageEntry, RageParams, RageResult,
+    RegisterModuleParams, RegisterProjectConfigurationParams, ServerInfo,
 };
 use crate::{
     file_handlers::Features,
     settings::{SettingsHandle, WorkspaceSettings},
     Workspace, WorkspaceError,
 };
-use biome_analyze::AnalysisFilter;
+use biome_analyze::{AnalysisFilter, RuleFilter};
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
-use biome_fs::{BiomePath, ConfigName};
+use biome_fs::{BiomePath, ConfigName, ROME_JSON};
+use biome_js_analyze::{ModuleGraph, ModuleInfo, MANIFEST_RULES, MODULE_GRAPH_RULES};
+use biome_js_syntax::AnyJsRoot;
+use biome_json_analyze::schema::{JsonSchema, JsonSchemaDiagnostic};
 use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
-use biome_json_syntax::JsonFileSource;
+use biome_json_syntax::{AnyJsonValue, JsonFileSource, JsonRoot};
 use biome_parser::AnyParse;
-use biome_project::NodeJsProject;
-use biome_rowan::NodeCache;
+use biome_project::{NodeJsProject, NodeJsWorkspace, PackageJson, PnpmWorkspace, TsConfigJson};
+use biome_rowan::{AstSeparatedList, NodeCache};
 use dashmap::{mapref::entry::Entry, DashMap};
 use indexmap::IndexSet;
 use std::borrow::Borrow;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span};
 
@@ -53,8 +60,14 @@ pub(super) struct WorkspaceServer {
     manifests: DashMap<BiomePath, NodeJsProject>,
     /// The current focused project
     current_project_path: RwLock<Option<BiomePath>>,
+    /// The `package.json` files of the project, and the packages of the monorepo when it's one
+    workspace: RwLock<Arc<NodeJsWorkspace>>,
     /// Stores the document sources used across the workspace
     file_sources: RwLock<IndexSet<DocumentFileSource>>,
+    /// The imports and the exports of the JavaScript files of the project
+    module_graph: Arc<ModuleGraph>,
+    /// The JSON Schemas that validate the JSON files, parsed from the opened schemas
+    json_schemas: DashMap<BiomePath, Arc<JsonSchema>>,
 }
 
 /// The `Workspace` object is long-lived, so we want it to be able to cross
@@ -90,12 +103,16 @@ impl WorkspaceServer {
             file_features: DashMap::default(),
             manifests: DashMap::default(),
             current_project_path: RwLock::default(),
+            workspace: RwLock::default(),
             file_sources: RwLock::default(),
+            module_graph: Arc::default(),
+            json_schemas: DashMap::default(),
         }
     }
 
-    fn settings(&self) -> SettingsHandle {
-        SettingsHandle::new(&self.settings)
+    /// Returns the settings that apply to the file at `path`
+    fn settings(&self, path: &Path) -> SettingsHandle {
+        SettingsHandle::new(&self.settings, path)
     }
 
     /// Get the supported capabilities for a given file path
@@ -170,6 +187,94 @@ impl WorkspaceServer {
         }
     }
 
+    /// Returns the manifest of the package that contains the file at `path`: the nearest
+    /// `package.json` registered with [WorkspaceServer::update_current_project] or
+    /// [WorkspaceServer::register_module], or the one of the current project
+    fn get_manifest(&self, path: &Path) -> Result<Option<PackageJson>, WorkspaceError> {
+        if let Some(manifest) = self.workspace.read().unwrap().manifest(path) {
+            return Ok(Some(manifest.clone()));
+        }
+        Ok(self.get_current_project()?.map(|project| project.manifest))
+    }
+
+    /// Returns the packages of the monorepo, when the project is one
+    fn get_workspace(&self) -> Option<Arc<NodeJsWorkspace>> {
+        let workspace = self.workspace.read().unwrap();
+        workspace.is_monorepo().then(|| workspace.clone())
+    }
+
+    /// Adds the `package.json` of `directory` to the packages of the project
+    fn insert_package(&self, directory: &Path, manifest: PackageJson) {
+        Arc::make_mut(&mut self.workspace.write().unwrap())
+            .insert_package(directory, manifest.clone());
+        // The name of the package can be used to import its modules
+        self.module_graph.insert_package(directory, manifest);
+    }
+
+    /// Returns the nearest `tsconfig.json` of the file at `path`, registered with
+    /// [WorkspaceServer::register_project_configuration]
+    fn get_tsconfig(&self, path: &Path) -> Option<Arc<TsConfigJson>> {
+        self.module_graph.tsconfig(path)
+    }
+
+    /// Resolves the JSON Schema of the JSON file at `path`: the local file referenced by the
+    /// `$schema` key of `root`, or the first schema of the configuration that includes the file.
+    /// Returns whether the schema comes from the configuration.
+    fn resolve_json_schema(&self, path: &BiomePath, root: &JsonRoot) -> Option<(BiomePath, bool)> {
+        // The configuration files are validated when they are deserialized
+        if path.ends_with(ROME_JSON)
+            || path.ends_with(ConfigName::biome_json())
+            || path.ends_with(ConfigName::biome_jsonc())
+        {
+            return None;
+        }
+        if let Some(reference) = json_schema_reference(root) {
+            // The remote schemas can't be fetched
+            if reference.contains("://") {
+                return None;
+            }
+            let directory = path.parent().unwrap_or(Path::new(""));
+            let schema_path = normalize_path(&directory.join(reference));
+            return Some((BiomePath::new(schema_path), false));
+        }
+        let settings = self.settings.read().unwrap();
+        settings
+            .for_path(path)
+            .languages
+            .json
+            .linter
+            .schemas
+            .iter()
+            .find(|schema| schema.include.matches_path(path))
+            .map(|schema| (BiomePath::new(&schema.schema), true))
+    }
+
+    /// Returns the JSON Schema that validates the JSON file at `path`, read from the schema
+    /// opened with [Workspace::open_project]
+    fn get_json_schema(
+        &self,
+        path: &BiomePath,
+        parse: &AnyParse,
+    ) -> Option<Result<Arc<JsonSchema>, JsonSchemaDiagnostic>> {
+        self.get_file_source(path).to_json_file_source()?;
+        let (schema_path, is_configured) = self.resolve_json_schema(path, &parse.tree())?;
+        if let Some(schema) = self.json_schemas.get(&schema_path) {
+            return Some(Ok(schema.clone()));
+        }
+        let display_path = schema_path.display().to_string();
+        let Some(document) = self.documents.get(&schema_path) else {
+            // A `$schema` can reference a schema that isn't installed, such as one of `node_modules`
+            return is_configured.then(|| Err(JsonSchemaDiagnostic::not_found(&display_path)));
+        };
+        let schema = JsonSchema::parse(&document.content)
+            .map(Arc::new)
+            .map_err(|error| JsonSchemaDiagnostic::invalid_schema(&display_path, &error));
+        if let Ok(schema) = &schema {
+            self.json_schemas.insert(schema_path, schema.clone());
+        }
+        Some(schema)
+    }
+
     fn get_source(&self, index: usize) -> Option<DocumentFileSource> {
         let file_sources = self.file_sources.read().unwrap();
         file_sources.get_index(index).copied()
@@ -203,9 +308,13 @@ impl WorkspaceServer {
                     .ok_or_else(self.build_capability_error(biome_path))?;
 
                 let size_limit = {
-                    let settings = self.settings();
+                    let settings = self.settings(biome_path);
                     let settings = settings.as_ref();
-                    let limit = settings.files.max_size.get();
+                    let limit = settings
+                        .override_settings
+                        .override_max_size(biome_path)
+                        .unwrap_or(settings.files.max_size)
+                        .get();
                     usize::try_from(limit).unwrap_or(usize::MAX)
                 };
 
@@ -219,7 +328,7 @@ impl WorkspaceServer {
                     ));
                 }
 
-                let settings = self.settings();
+                let settings = self.settings(biome_path);
                 let Some(file_source) = self.get_source(document.file_source_index) else {
                     return Err(WorkspaceError::not_found());
                 };
@@ -237,6 +346,11 @@ impl WorkspaceServer {
                 if let Some(language) = language {
                     document.file_source_index = self.set_source(language);
                 }
+                // Keep the module graph in sync with the files opened after the project
+                // was registered
+                if self.module_graph.contains(biome_path) {
+                    self.update_module(biome_path, file_source, &any_parse);
+                }
                 Ok(entry.insert(any_parse).clone())
             }
         }
@@ -262,9 +376,18 @@ impl WorkspaceServer {
                 ignored_by_features)
     }
 
+    /// Replaces the imports and the exports of the module at `path` in the module graph
+    fn update_module(&self, path: &BiomePath, file_source: DocumentFileSource, parse: &AnyParse) {
+        if file_source.to_js_file_source().is_some() {
+            let root: AnyJsRoot = parse.tree();
+            self.module_graph
+                .insert_module(path, ModuleInfo::from_root(&root));
+        }
+    }
+
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     fn is_ignored_by_top_level_config(&self, path: &Path) -> bool {
-        let settings = self.settings();
+        let settings = self.settings(path);
         let is_included = settings.as_ref().files.included_files.is_empty()
             || is_dir(path)
             || settings.as_ref().files.included_files.matches_path(path);
@@ -293,7 +416,7 @@ impl WorkspaceServer {
 
     /// Check whether a file is ignored in the feature `ignore`/`include`
     fn is_ignored_by_feature_config(&self, path: &Path, feature: FeatureName) -> bool {
-        let settings = self.settings();
+        let settings = self.settings(path);
         let (feature_included_files, feature_ignored_files) = match feature {
             FeatureName::Format => {
                 let formatter = &settings.as_ref().formatter;
@@ -334,14 +457,19 @@ impl Workspace for WorkspaceServer {
                 let capabilities = self.get_file_capabilities(&params.path);
                 let language = DocumentFileSource::from_path(&params.path);
                 let path = params.path.as_path();
-                let settings = self.settings.read().unwrap();
+                let settings = self.settings(path);
+                let settings = settings.as_ref();
                 let mut file_features = FileFeaturesResult::new();
                 let file_name = path.file_name().and_then(|s| s.to_str());
                 file_features = file_features
                     .with_capabilities(&capabilities)
-                    .with_settings_and_language(&settings, &language, path);
+                    .with_settings_and_language(settings, &language, path);
 
-                if settings.files.ignore_unknown
+                let ignore_unknown = settings
+                    .override_settings
+                    .override_ignore_unknown(path)
+                    .unwrap_or(settings.files.ignore_unknown);
+                if ignore_unknown
                     && language == DocumentFileSource::Unknown
                     && self.get_file_source(&params.path) == DocumentFileSource::Unknown
                 {
@@ -384,12 +512,21 @@ impl Workspace for WorkspaceServer {
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
         let mut settings = self.settings.write().unwrap();
 
-        settings.merge_with_configuration(
-            params.configuration,
-            params.working_directory,
-            params.vcs_base_path,
-            params.gitignore_matches.as_slice(),
-        )?;
+        if let Some(nested_directory) = params.nested_directory {
+            settings.merge_with_nested_configuration(
+                nested_directory,
+                params.configuration,
+                params.vcs_base_path,
+                params.gitignore_matches.as_slice(),
+            )?;
+        } else {
+            settings.merge_with_configuration(
+                params.configuration,
+                params.working_directory,
+                params.vcs_base_path,
+                params.gitignore_matches.as_slice(),
+            )?;
+        }
 
         // settings changed, hence everything that is computed from the settings needs to be purged
         self.file_features.clear();
@@ -419,6 +556,7 @@ impl Workspace for WorkspaceServer {
     fn open_project(&self, params: OpenProjectParams) -> Result<(), WorkspaceError> {
         let index = self.set_source(JsonFileSource::json().into());
         self.syntax.remove(&params.path);
+        self.json_schemas.remove(&params.path);
         self.documents.insert(
             params.path,
             Document {
@@ -432,11 +570,127 @@ impl Workspace for WorkspaceServer {
     }
 
     fn update_current_project(&self, params: UpdateProjectParams) -> Result<(), WorkspaceError> {
-        let mut current_project_path = self.current_project_path.write().unwrap();
-        let _ = current_project_path.insert(params.path);
+        let directory = params.path.parent().map(Path::to_path_buf);
+        {
+            let mut current_project_path = self.current_project_path.write().unwrap();
+            let _ = current_project_path.insert(params.path);
+        }
+        if let (Some(directory), Ok(Some(project))) = (directory, self.get_current_project()) {
+            self.insert_package(&directory, project.manifest);
+        }
         Ok(())
     }
 
+    fn register_project_configuration(
+        &self,
+        params: RegisterProjectConfigurationParams,
+    ) -> Result<(), WorkspaceError> {
+        let directory = params.path.parent().unwrap_or(Path::new(""));
+        if is_tsconfig(&params.path) {
+            let tsconfig = TsConfigJson::load(&params.path, |path| {
+                self.documents
+                    .get(&BiomePath::new(path))
+                    .map(|document| document.content.clone())
+            })
+            .map(Arc::new);
+            if params.is_default {
+                self.module_graph.set_tsconfig(tsconfig);
+            } else if let Some(tsconfig) = tsconfig {
+                self.module_graph.insert_tsconfig(directory, tsconfig);
+            }
+            return Ok(());
+        }
+        if params.path.file_name() == Some(OsStr::new(PnpmWorkspace::FILE_NAME)) {
+            let document = self
+                .documents
+                .get(&params.path)
+                .ok_or_else(WorkspaceError::not_found)?;
+            let pnpm_workspace = PnpmWorkspace::parse(&document.content);
+            Arc::make_mut(&mut self.workspace.write().unwrap())
+                .set_root(directory, pnpm_workspace.packages);
+            return Ok(());
+        }
+        Err(WorkspaceError::not_found())
+    }
+
+    fn register_module(&self, params: RegisterModuleParams) -> Result<(), WorkspaceError> {
+        let Some(content) = params.content else {
+            self.module_graph.insert_file(&params.path);
+            return Ok(());
+        };
+        // The manifests make the names of the packages of the project resolvable
+        if params.path.file_name() == Some(OsStr::new("package.json")) {
+            let parsed = parse_json_with_cache(
+                content.as_str(),
+                &mut NodeCache::default(),
+                JsonParserOptions::default(),
+            );
+            let mut node_js_project = NodeJsProject::default();
+            node_js_project.from_root(&parsed.tree());
+            let directory = params.path.parent().unwrap_or(Path::new(""));
+            self.insert_package(directory, node_js_project.manifest);
+            return Ok(());
+        }
+
+        let file_source = DocumentFileSource::from_path(&params.path);
+        if file_source.to_js_file_source().is_none() {
+            self.module_graph.insert_file(&params.path);
+            return Ok(());
+        }
+        let capabilities = self.get_file_capabilities(&params.path);
+        let parse = capabilities
+            .parser
+            .parse
+            .ok_or_else(self.build_capability_error(&params.path))?;
+        let ParseResult { any_parse, .. } = parse(
+            &params.path,
+            file_source,
+            content.as_str(),
+            self.settings(&params.path),
+            &mut NodeCache::default(),
+        );
+        let root: AnyJsRoot = any_parse.tree();
+        self.module_graph
+            .insert_module(&params.path, ModuleInfo::from_root(&root));
+        Ok(())
+    }
+
+    fn is_module_graph_enabled(
+        &self,
+        _params: IsModuleGraphEnabledParams,
+    ) -> Result<bool, WorkspaceError> {
+        let settings = self.settings.read().unwrap();
+        Ok(settings.has_enabled_rules(MODULE_GRAPH_RULES))
+    }
+
+    fn is_node_js_workspace_enabled(
+        &self,
+        _params: IsNodeJsWorkspaceEnabledParams,
+    ) -> Result<bool, WorkspaceError> {
+        let settings = self.settings.read().unwrap();
+        // The module graph resolves the names of the packages of the monorepo
+        Ok(settings.has_enabled_rules(MANIFEST_RULES)
+            || settings.has_enabled_rules(MODULE_GRAPH_RULES))
+    }
+
+    fn get_json_schema_path(
+        &self,
+        params: GetJsonSchemaPathParams,
+    ) -> Result<GetJsonSchemaPathResult, WorkspaceError> {
+        if self
+            .get_file_source(&params.path)
+            .to_json_file_source()
+            .is_none()
+        {
+            return Ok(GetJsonSchemaPathResult { path: None });
+        }
+        let parse = self.get_parse(params.path.clone())?;
+        let path = self
+            .resolve_json_schema(&params.path, &parse.tree())
+            .map(|(path, _)| path);
+        Ok(GetJsonSchemaPathResult { path })
+    }
+
     fn get_syntax_tree(
         &self,
         params: GetSyntaxTreeParams,
@@ -476,7 +730,7 @@ impl Workspace for WorkspaceServer {
             .debug
             .debug_formatter_ir
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
 
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
@@ -527,18 +781,23 @@ impl Workspace for WorkspaceServer {
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         let parse = self.get_parse(params.path.clone())?;
-        let manifest = self.get_current_project()?.map(|pr| pr.manifest);
+        let manifest = self.get_manifest(&params.path)?;
+        let json_schema = self.get_json_schema(&params.path, &parse);
         let (diagnostics, errors, skipped_diagnostics) =
             if let Some(lint) = self.get_file_capabilities(&params.path).analyzer.lint {
                 info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
                     let results = lint(LintParams {
                         parse,
-                        settings: self.settings(),
+                        settings: self.settings(&params.path),
                         max_diagnostics: params.max_diagnostics as u32,
                         path: &params.path,
                         language: self.get_file_source(&params.path),
                         categories: params.categories,
                         manifest,
+                        workspace: self.get_workspace(),
+                        tsconfig: self.get_tsconfig(&params.path),
+                        module_graph: Some(self.module_graph.clone()),
+                        json_schema,
                     });
 
                     (
@@ -582,17 +841,20 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone())?;
-        let settings = self.settings.read().unwrap();
-        let rules = settings.linter().rules.as_ref();
-        let manifest = self.get_current_project()?.map(|pr| pr.manifest);
+        let settings = self.settings(&params.path);
+        let rules = settings.as_ref().linter().rules.as_ref();
+        let manifest = self.get_manifest(&params.path)?;
         let language = self.get_file_source(&params.path);
         Ok(code_actions(CodeActionsParams {
             parse,
             range: params.range,
             rules,
-            settings: self.settings(),
+            settings: self.settings(&params.path),
             path: &params.path,
             manifest,
+            workspace: self.get_workspace(),
+            tsconfig: self.get_tsconfig(&params.path),
+            module_graph: Some(self.module_graph.clone()),
             language,
         }))
     }
@@ -605,7 +867,7 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
 
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
@@ -621,7 +883,7 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format_range
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
 
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
@@ -644,7 +906,7 @@ impl Workspace for WorkspaceServer {
             .format_on_type
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
         if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
@@ -667,29 +929,47 @@ impl Workspace for WorkspaceServer {
             .analyzer
             .fix_all
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone())?;
         // Compute final rules (taking `overrides` into account)
-        let rules = settings.as_rules(params.path.as_path());
+        let rules = settings.as_ref().as_rules(params.path.as_path());
         let rule_filter_list = rules
             .as_ref()
             .map(|rules| rules.as_enabled_rules())
             .unwrap_or_default()
             .into_iter()
             .collect::<Vec<_>>();
-        let filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
-        let manifest = self.get_current_project()?.map(|pr| pr.manifest);
+        // Only the diagnostics of the selected rules are suppressed, if they are enabled
+        let suppressed_rules = params
+            .suppressed_rules
+            .iter()
+            .filter_map(|name| name.split_once('/'))
+            .map(|(group, rule)| RuleFilter::Rule(group, rule))
+            .filter(|filter| filter.matches_any(&rule_filter_list))
+            .collect::<Vec<_>>();
+        let filter = if matches!(params.fix_file_mode, FixFileMode::ApplySuppressions)
+            && !params.suppressed_rules.is_empty()
+        {
+            AnalysisFilter::from_enabled_rules(Some(suppressed_rules.as_slice()))
+        } else {
+            AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()))
+        };
+        let manifest = self.get_manifest(&params.path)?;
         let language = self.get_file_source(&params.path);
         fix_all(FixAllParams {
             parse,
             rules: rules.as_ref().map(|x| x.borrow()),
             fix_file_mode: params.fix_file_mode,
             filter,
-            settings: self.settings(),
+            settings: self.settings(&params.path),
             should_format: params.should_format,
             biome_path: &params.path,
             manifest,
+            workspace: self.get_workspace(),
+            tsconfig: self.get_tsconfig(&params.path),
+            module_graph: Some(self.module_graph.clone()),
             document_file_source: language,
+            suppression_reason: params.suppression_reason,
         })
     }
 
@@ -737,15 +1017,49 @@ impl Workspace for WorkspaceServer {
             .organize_imports
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let parse = self.get_parse(params.path)?;
-        let result = organize_imports(parse)?;
+        let parse = self.get_parse(params.path.clone())?;
+        let result = organize_imports(
+            &params.path,
+            parse,
+            self.settings(&params.path),
+            self.get_tsconfig(&params.path),
+        )?;
 
         Ok(result)
     }
 }
 
+/// Whether `path` is the configuration file of a TypeScript or a JavaScript project
+fn is_tsconfig(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == "tsconfig.json" || name == "jsconfig.json")
+}
+
 /// Returns `true` if `path` is a directory or
 /// if it is a symlink that resolves to a directory.
 fn is_dir(path: &Path) -> bool {
     path.is_dir() || (path.is_symlink() && fs::read_link(path).is_ok_and(|path| path.is_dir()))
 }
+
+/// Returns the `$schema` key of the JSON document `root`
+fn json_schema_reference(root: &JsonRoot) -> Option<String> {
+    let AnyJsonValue::JsonObjectValue(object) = root.value().ok()? else {
+        return None;
+    };
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .find_map(|member| {
+            let name = member.name().ok()?.inner_string_text().ok()?;
+            if name.text() != "$schema" {
+                return None;
+            }
+            let reference = member
+                .value()
+                .ok()?
+                .as_json_string_value()?
+                .inner_string_text();
+            Some(reference.ok()?.to_string())
+        })
+}
@@ -451,11 +451,16 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 19] {
+pub fn methods() -> [WorkspaceMethod; 24] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("file_features"),
         workspace_method!(update_settings),
         workspace_method!(update_current_project),
+        workspace_method!(register_project_configuration),
+        workspace_method!(register_module),
+        workspace_method!(is_module_graph_enabled),
+        workspace_method!(is_node_js_workspace_enabled),
+        workspace_method!(get_json_schema_path),
         workspace_method!(open_project),
         workspace_method!(open_file),
         workspace_method!(change_file),
@@ -1,4 +1,4 @@
-use biome_diagnostics::{Category, Diagnostic};
+use biome_diagnostics::{category, Category, Diagnostic};
 use biome_rowan::{TextRange, TextSize};
 
 /// Single instance of a suppression comment, with the following syntax:
@@ -14,6 +14,9 @@ use biome_rowan::{TextRange, TextSize};
 /// A suppression must specify a reason: this part has no semantic meaning but
 /// is required to document why a particular feature is being disable for this
 /// line (lint false-positive, specific formatting requirements, ...)
+///
+/// The `// biome-ignore-start`, `// biome-ignore-end` and `// biome-ignore-all`
+/// variants change what the suppression applies to, see [SuppressionScope]
 #[derive(Debug, PartialEq, Eq)]
 pub struct Suppression<'a> {
     /// List of categories for this suppression
@@ -25,6 +28,25 @@ pub struct Suppression<'a> {
     pub reason: &'a str,
     /// If the comment is `// rome-ignore`
     pub is_legacy: bool,
+    /// What the suppression applies to
+    pub scope: SuppressionScope,
+}
+
+/// The part of the source code a [Suppression] applies to
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum SuppressionScope {
+    /// `// biome-ignore`: the suppression applies to the next node
+    #[default]
+    Next,
+    /// `// biome-ignore-start`: the suppression applies to the code until the
+    /// matching `// biome-ignore-end` comment
+    RangeStart,
+    /// `// biome-ignore-end`: ends the suppressions started by the matching
+    /// `// biome-ignore-start` comments. The categories and reason can be
+    /// omitted, in which case it ends all the started suppressions
+    RangeEnd,
+    /// `// biome-ignore-all`: the suppression applies to the whole file
+    File,
 }
 
 pub fn parse_suppression_comment(
@@ -98,12 +120,36 @@ pub fn parse_suppression_comment(
             }
         }
 
-        // Checks for `/rome[-_]ignore/i` without a regex, or skip the line
-        // entirely if it doesn't match
+        // Checks for the `-start`, `-end` and `-all` variants
+        let mut scope = SuppressionScope::Next;
+        if !is_legacy {
+            for (suffix, suffix_scope) in [
+                ("-start", SuppressionScope::RangeStart),
+                ("-end", SuppressionScope::RangeEnd),
+                ("-all", SuppressionScope::File),
+            ] {
+                if let Some(rest) = line.strip_prefix(suffix) {
+                    line = rest;
+                    scope = suffix_scope;
+                    break;
+                }
+            }
+        }
 
         let line = line.trim_start();
+
+        // A bare `// biome-ignore-end` ends all the started suppressions
+        if scope == SuppressionScope::RangeEnd && line.trim_end().is_empty() {
+            return Some(Ok(Suppression {
+                categories: Vec::new(),
+                reason: line,
+                is_legacy,
+                scope,
+            }));
+        }
+
         Some(
-            parse_suppression_line(line, is_legacy).map_err(|err| SuppressionDiagnostic {
+            parse_suppression_line(line, is_legacy, scope).map_err(|err| SuppressionDiagnostic {
                 message: err.message,
                 // Adjust the position of the diagnostic in the whole comment
                 span: err.span + offset_from(base, line),
@@ -172,6 +218,7 @@ impl biome_console::fmt::Display for SuppressionDiagnosticKind {
 fn parse_suppression_line(
     base: &str,
     is_legacy: bool,
+    scope: SuppressionScope,
 ) -> Result<Suppression, SuppressionDiagnostic> {
     let mut line = base;
     let mut categories = Vec::new();
@@ -187,14 +234,23 @@ fn parse_suppression_line(
 
         let (category, rest) = line.split_at(separator);
         let category = category.trim_end();
-        let category: Option<&'static Category> = if !category.is_empty() {
-            let category = category.parse().map_err(|()| SuppressionDiagnostic {
-                message: SuppressionDiagnosticKind::ParseCategory(category.into()),
-                span: TextRange::at(offset_from(base, category), TextSize::of(category)),
-            })?;
-            Some(category)
+        let (category, rule): (Option<&'static Category>, Option<&str>) = if !category.is_empty() {
+            match category.parse() {
+                Ok(category) => (Some(category), None),
+                // The rules that don't exist are kept as the value of the `lint`
+                // category, the analyzer reports them as unknown rules
+                Err(()) if category.starts_with("lint/") => {
+                    (Some(category!("lint")), Some(&category["lint/".len()..]))
+                }
+                Err(()) => {
+                    return Err(SuppressionDiagnostic {
+                        message: SuppressionDiagnosticKind::ParseCategory(category.into()),
+                        span: TextRange::at(offset_from(base, category), TextSize::of(category)),
+                    })
+                }
+            }
         } else {
-            None
+            (None, None)
         };
 
         // Skip over and match the separator
@@ -204,7 +260,7 @@ fn parse_suppression_line(
             // Colon token: stop parsing categories
             ":" => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push((category, rule));
                 }
 
                 line = rest.trim_start();
@@ -234,7 +290,7 @@ fn parse_suppression_line(
             // Whitespace: push a category without value
             _ => {
                 if let Some(category) = category {
-                    categories.push((category, None));
+                    categories.push((category, rule));
                 }
 
                 line = rest.trim_start();
@@ -247,6 +303,7 @@ fn parse_suppression_line(
         categories,
         reason,
         is_legacy,
+        scope,
     })
 }
 
@@ -282,7 +339,7 @@ mod tests {
 
     use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionScope};
 
     #[test]
     fn parse_simple_suppression() {
@@ -291,7 +348,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -300,7 +358,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -314,7 +373,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -329,7 +389,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
     }
@@ -340,7 +401,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -349,7 +411,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -358,7 +421,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
     }
@@ -374,7 +438,8 @@ mod tests {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -387,7 +452,8 @@ mod tests {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -404,7 +470,8 @@ mod tests {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -422,7 +489,8 @@ mod tests {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
     }
@@ -435,7 +503,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Next
             })],
         );
     }
@@ -505,7 +574,7 @@ mod tests_biome_ignore {
 
     use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionScope};
 
     #[test]
     fn parse_simple_suppression() {
@@ -514,7 +583,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -524,7 +594,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -538,7 +609,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -553,7 +625,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
     }
@@ -564,7 +637,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -573,7 +647,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -582,7 +657,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
     }
@@ -598,7 +674,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -611,7 +688,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -628,7 +706,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
 
@@ -646,7 +725,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
             })],
         );
     }
@@ -659,7 +739,71 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Next
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_range_suppressions() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-start lint format: explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint"), None), (category!("format"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                scope: SuppressionScope::RangeStart
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-end format: explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("format"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                scope: SuppressionScope::RangeEnd
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("/* biome-ignore-end */").collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![],
+                reason: "",
+                is_legacy: false,
+                scope: SuppressionScope::RangeEnd
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_unknown_lint_rule() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore lint/style/unknownRule: explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint"), Some("style/unknownRule"))],
+                reason: "explanation",
+                is_legacy: false,
+                scope: SuppressionScope::Next
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_file_suppression() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-all parse(foo): explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("parse"), Some("foo"))],
+                reason: "explanation",
+                is_legacy: false,
+                scope: SuppressionScope::File
             })],
         );
     }
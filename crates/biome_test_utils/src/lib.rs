@@ -5,7 +5,7 @@ use biome_console::markup;
 use biome_diagnostics::termcolor::Buffer;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic};
 use biome_json_parser::{JsonParserOptions, ParseDiagnostic};
-use biome_project::PackageJson;
+use biome_project::{NodeJsWorkspace, PackageJson, TsConfigJson};
 use biome_rowan::{SyntaxKind, SyntaxNode, SyntaxSlot};
 use biome_service::configuration::to_analyzer_rules;
 use biome_service::settings::{ServiceLanguage, WorkspaceSettings};
@@ -34,6 +34,7 @@ pub fn create_analyzer_options(
     let options = AnalyzerOptions {
         configuration: Default::default(),
         file_path: input_file.to_path_buf(),
+        suppression_reason: None,
     };
     // We allow a test file to configure its rule using a special
     // file with the same name as the test but with extension ".options.json"
@@ -42,6 +43,7 @@ pub fn create_analyzer_options(
         rules: AnalyzerRules::default(),
         globals: vec![],
         preferred_quote: PreferredQuote::Double,
+        enabled_rules: None,
     };
     let options_file = input_file.with_extension("options.json");
     if let Ok(json) = std::fs::read_to_string(options_file.clone()) {
@@ -85,6 +87,7 @@ pub fn create_analyzer_options(
                 .merge_with_configuration(configuration, None, None, &[])
                 .unwrap();
             analyzer_configuration.rules = to_analyzer_rules(&settings, input_file);
+            analyzer_configuration.enabled_rules = Some(settings.as_enabled_rules(input_file));
         }
     }
 
@@ -95,8 +98,44 @@ pub fn create_analyzer_options(
 }
 
 pub fn load_manifest(input_file: &Path, diagnostics: &mut Vec<String>) -> Option<PackageJson> {
-    let options_file = input_file.with_extension("package.json");
-    if let Ok(json) = std::fs::read_to_string(options_file.clone()) {
+    load_package_json(&input_file.with_extension("package.json"), diagnostics)
+}
+
+/// Loads the packages of the monorepo of the test `input_file`, when its `package.json` declares
+/// `workspaces`. The packages are the `package.json` files of the subdirectories of the test.
+pub fn load_workspace(input_file: &Path, diagnostics: &mut Vec<String>) -> Option<NodeJsWorkspace> {
+    let manifest = load_manifest(input_file, diagnostics)?;
+    if manifest.workspaces.is_empty() {
+        return None;
+    }
+    let root = input_file.parent()?;
+    let mut workspace = NodeJsWorkspace::default();
+    workspace.insert_package(root, manifest);
+    let mut directories = vec![root.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        for entry in std::fs::read_dir(&directory).ok()?.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                if let Some(manifest) = load_package_json(&path.join("package.json"), diagnostics) {
+                    workspace.insert_package(&path, manifest);
+                }
+                directories.push(path);
+            }
+        }
+    }
+    Some(workspace)
+}
+
+/// Loads the `tsconfig.json` of the test `input_file`, and the configuration files it extends
+pub fn load_tsconfig(input_file: &Path) -> Option<TsConfigJson> {
+    TsConfigJson::load(&input_file.with_extension("tsconfig.json"), |path| {
+        std::fs::read_to_string(path).ok()
+    })
+}
+
+/// Loads the `package.json` at `path`
+pub fn load_package_json(path: &Path, diagnostics: &mut Vec<String>) -> Option<PackageJson> {
+    if let Ok(json) = std::fs::read_to_string(path) {
         let deserialized = biome_deserialize::json::deserialize_from_json_str::<PackageJson>(
             json.as_str(),
             JsonParserOptions::default(),
@@ -109,7 +148,7 @@ pub fn load_manifest(input_file: &Path, diagnostics: &mut Vec<String>) -> Option
                     .into_iter()
                     .map(|diagnostic| {
                         diagnostic_to_string(
-                            options_file.file_stem().unwrap().to_str().unwrap(),
+                            path.file_stem().unwrap().to_str().unwrap(),
                             &json,
                             diagnostic,
                         )
@@ -20,6 +20,10 @@ export type SupportKind =
 export interface UpdateSettingsParams {
 	configuration: PartialConfiguration;
 	gitignore_matches: string[];
+	/**
+	 * The directory of a configuration file nested inside the workspace. When set, the settings apply only to the files inside this directory.
+	 */
+	nested_directory?: string;
 	vcs_base_path?: string;
 	working_directory?: string;
 }
@@ -181,6 +185,14 @@ export interface PartialJsonConfiguration {
 	 * Parsing options
 	 */
 	parser?: PartialJsonParser;
+	/**
+	 * Associates the JSON files with the JSON Schemas that validate them when they are linted. The `$schema` key of a JSON file takes precedence.
+	 */
+	schemas?: JsonSchemas;
+	/**
+	 * Sorting options
+	 */
+	sort_keys?: PartialJsonSortKeys;
 }
 export interface PartialLinterConfiguration {
 	/**
@@ -389,6 +401,10 @@ export interface PartialJsonFormatter {
 	 * What's the max width of a line applied to JSON (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * The type of quotes used in the strings of JSON5 files. The quotes are preserved when it isn't set.
+	 */
+	quoteStyle?: QuoteStyle;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
 	 */
@@ -407,6 +423,20 @@ export interface PartialJsonParser {
 	 */
 	allowTrailingCommas?: boolean;
 }
+export type JsonSchemas = JsonSchemaAssociation[];
+/**
+ * Options that change how the keys of the JSON objects are sorted
+ */
+export interface PartialJsonSortKeys {
+	/**
+	 * Sort the keys of the objects of the JSON files when the imports are organized. The keys of the `package.json` and `tsconfig.json` files follow their canonical order. Defaults to false.
+	 */
+	enabled?: boolean;
+	/**
+	 * The keys that come first in the objects of the JSON files, in this order. The other keys follow them alphabetically. The keys of the `package.json` and `tsconfig.json` files keep their canonical order.
+	 */
+	order?: StringSet;
+}
 export interface Rules {
 	a11y?: A11y;
 	/**
@@ -430,6 +460,10 @@ export interface OverridePattern {
 	 * Specific configuration for the Css language
 	 */
 	css?: PartialCssConfiguration;
+	/**
+	 * Specific configuration for the filesystem
+	 */
+	files?: OverrideFilesConfiguration;
 	/**
 	 * Specific configuration for the Json language
 	 */
@@ -469,6 +503,16 @@ export type Semicolons = "always" | "asNeeded";
  */
 export type TrailingComma = "all" | "es5" | "none";
 export type TrailingCommas = "none" | "all";
+export interface JsonSchemaAssociation {
+	/**
+	 * A list of Unix shell style patterns. The JSON files that match these patterns are validated against the schema.
+	 */
+	include?: StringSet;
+	/**
+	 * The path of the JSON Schema, relative to the configuration file
+	 */
+	schema?: string;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -904,6 +948,10 @@ export interface Nursery {
 	 * Disallow using a callback in asynchronous tests and hooks.
 	 */
 	noDoneCallback?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow declaring a package in more than one field of dependencies of a package.json.
+	 */
+	noDuplicateDependencies?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow duplicate conditions in if-else-if chains
 	 */
@@ -932,6 +980,22 @@ export interface Nursery {
 	 * Disallow focused tests.
 	 */
 	noFocusedTests?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow imports that create a cycle of modules.
+	 */
+	noImportCycles?: RuleConfiguration_for_ImportCyclesOptions;
+	/**
+	 * Disallow invalid version specifiers in the dependencies of a package.json.
+	 */
+	noInvalidDependencyVersions?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow invalid engines in a package.json.
+	 */
+	noInvalidPackageEngines?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow invalid exports maps in a package.json.
+	 */
+	noInvalidPackageExports?: RuleConfiguration_for_Null;
 	/**
 	 * Checks that the assertion function, for example expect, is placed inside an it() function call.
 	 */
@@ -964,6 +1028,26 @@ export interface Nursery {
 	 * Disallow the use of dependencies that aren't specified in the package.json.
 	 */
 	noUndeclaredDependencies?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow default imports of modules that don't have a default export.
+	 */
+	noUnresolvedDefaultImports?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow imports of files of the project that don't exist.
+	 */
+	noUnresolvedImports?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow named imports that aren't exported by the imported module.
+	 */
+	noUnresolvedNamedImports?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow exports that aren't imported by any module of the project.
+	 */
+	noUnusedExports?: RuleConfiguration_for_EntryPointsOptions;
+	/**
+	 * Disallow modules that can't be reached from the entry points of the project.
+	 */
+	noUnusedFiles?: RuleConfiguration_for_EntryPointsOptions;
 	/**
 	 * Disallow ternary operators when simpler alternatives exist.
 	 */
@@ -984,10 +1068,18 @@ export interface Nursery {
 	 * Promotes the usage of node:assert/strict over node:assert.
 	 */
 	useNodeAssertStrict?: RuleConfiguration_for_Null;
+	/**
+	 * Require the packages written in TypeScript to declare their types in the package.json.
+	 */
+	usePackageTypes?: RuleConfiguration_for_Null;
 	/**
 	 * Enforce the sorting of CSS utility classes.
 	 */
 	useSortedClasses?: RuleConfiguration_for_UtilityClassSortingOptions;
+	/**
+	 * Enforce the canonical order of the fields of a package.json.
+	 */
+	useSortedPackageJsonKeys?: RuleConfiguration_for_Null;
 }
 /**
  * A list of rules that belong to this group
@@ -1425,6 +1517,16 @@ export interface Suspicious {
 	 */
 	useValidTypeof?: RuleConfiguration_for_Null;
 }
+export interface OverrideFilesConfiguration {
+	/**
+	 * Tells Biome to not emit diagnostics when handling files that doesn't know
+	 */
+	ignoreUnknown?: boolean;
+	/**
+	 * The maximum allowed size for source code files in bytes. Files above this limit will be ignored for performance reasons.
+	 */
+	maxSize?: number;
+}
 export interface OverrideFormatterConfiguration {
 	/**
 	 * The attribute position style.
@@ -1487,9 +1589,15 @@ export type RuleConfiguration_for_HooksOptions =
 export type RuleConfiguration_for_DeprecatedHooksOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_DeprecatedHooksOptions;
+export type RuleConfiguration_for_ImportCyclesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_ImportCyclesOptions;
 export type RuleConfiguration_for_RestrictedImportsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedImportsOptions;
+export type RuleConfiguration_for_EntryPointsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_EntryPointsOptions;
 export type RuleConfiguration_for_UtilityClassSortingOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UtilityClassSortingOptions;
@@ -1526,10 +1634,18 @@ export interface RuleWithOptions_for_DeprecatedHooksOptions {
 	level: RulePlainConfiguration;
 	options: DeprecatedHooksOptions;
 }
+export interface RuleWithOptions_for_ImportCyclesOptions {
+	level: RulePlainConfiguration;
+	options: ImportCyclesOptions;
+}
 export interface RuleWithOptions_for_RestrictedImportsOptions {
 	level: RulePlainConfiguration;
 	options: RestrictedImportsOptions;
 }
+export interface RuleWithOptions_for_EntryPointsOptions {
+	level: RulePlainConfiguration;
+	options: EntryPointsOptions;
+}
 export interface RuleWithOptions_for_UtilityClassSortingOptions {
 	level: RulePlainConfiguration;
 	options: UtilityClassSortingOptions;
@@ -1576,6 +1692,19 @@ export interface HooksOptions {
  * Options for the `useHookAtTopLevel` rule have been deprecated, since we now use the React hook naming convention to determine whether a function is a hook.
  */
 export interface DeprecatedHooksOptions {}
+/**
+ * Options for the rule `noImportCycles`.
+ */
+export interface ImportCyclesOptions {
+	/**
+	 * Whether the type-only imports are ignored.
+	 */
+	ignoreTypes: boolean;
+	/**
+	 * The maximum number of modules of a reported cycle. All the cycles are reported when it's not set.
+	 */
+	maxDepth?: number;
+}
 /**
  * Options for the rule `noRestrictedImports`.
  */
@@ -1585,6 +1714,15 @@ export interface RestrictedImportsOptions {
 	 */
 	paths: {};
 }
+/**
+ * Options for the rules `noUnusedExports` and `noUnusedFiles`.
+ */
+export interface EntryPointsOptions {
+	/**
+	 * The entry points of the project, relative to the directory of each `package.json`. The fields `exports`, `main` and `bin` of the `package.json` files are used when it's empty.
+	 */
+	entryPoints: string[];
+}
 export interface UtilityClassSortingOptions {
 	/**
 	 * Additional attributes that will be sorted.
@@ -1686,6 +1824,34 @@ export type FilenameCase =
 export interface UpdateProjectParams {
 	path: BiomePath;
 }
+export interface RegisterProjectConfigurationParams {
+	/**
+	 * Whether the `tsconfig.json` applies to the files that aren't inside the directory of another `tsconfig.json`, like the one found from the working directory
+	 */
+	is_default: boolean;
+	/**
+	 * The path of a `tsconfig.json`, a `jsconfig.json` or a `pnpm-workspace.yaml`
+	 */
+	path: BiomePath;
+}
+export interface RegisterModuleParams {
+	/**
+	 * The content of the file. When [None], the file is only registered as existing.
+	 */
+	content?: string;
+	path: BiomePath;
+}
+export interface IsModuleGraphEnabledParams {}
+export interface IsNodeJsWorkspaceEnabledParams {}
+export interface GetJsonSchemaPathParams {
+	path: BiomePath;
+}
+export interface GetJsonSchemaPathResult {
+	/**
+	 * The path of the JSON Schema, when one validates the file
+	 */
+	path?: BiomePath;
+}
 export interface OpenProjectParams {
 	content: string;
 	path: BiomePath;
@@ -1715,6 +1881,7 @@ export interface JsFileSource {
 export interface JsonFileSource {
 	allow_comments: boolean;
 	allow_trailing_commas: boolean;
+	json5: boolean;
 }
 export interface CssFileSource {
 	variant: CssVariant;
@@ -1904,6 +2071,7 @@ export type Category =
 	| "lint/nursery/noColorInvalidHex"
 	| "lint/nursery/noConsole"
 	| "lint/nursery/noDoneCallback"
+	| "lint/nursery/noDuplicateDependencies"
 	| "lint/nursery/noDuplicateElseIf"
 	| "lint/nursery/noDuplicateJsonKeys"
 	| "lint/nursery/noDuplicateTestHooks"
@@ -1911,6 +2079,10 @@ export type Category =
 	| "lint/nursery/noExcessiveNestedTestSuites"
 	| "lint/nursery/noExportsInTest"
 	| "lint/nursery/noFocusedTests"
+	| "lint/nursery/noImportCycles"
+	| "lint/nursery/noInvalidDependencyVersions"
+	| "lint/nursery/noInvalidPackageEngines"
+	| "lint/nursery/noInvalidPackageExports"
 	| "lint/nursery/noMisplacedAssertion"
 	| "lint/nursery/noNamespaceImport"
 	| "lint/nursery/noNodejsModules"
@@ -1920,12 +2092,19 @@ export type Category =
 	| "lint/nursery/noSuspiciousSemicolonInJsx"
 	| "lint/nursery/noTypeOnlyImportAttributes"
 	| "lint/nursery/noUndeclaredDependencies"
+	| "lint/nursery/noUnresolvedDefaultImports"
+	| "lint/nursery/noUnresolvedImports"
+	| "lint/nursery/noUnresolvedNamedImports"
+	| "lint/nursery/noUnusedExports"
+	| "lint/nursery/noUnusedFiles"
 	| "lint/nursery/noUselessTernary"
 	| "lint/nursery/useBiomeSuppressionComment"
 	| "lint/nursery/useImportRestrictions"
 	| "lint/nursery/useJsxKeyInIterable"
 	| "lint/nursery/useNodeAssertStrict"
+	| "lint/nursery/usePackageTypes"
 	| "lint/nursery/useSortedClasses"
+	| "lint/nursery/useSortedPackageJsonKeys"
 	| "lint/performance/noAccumulatingSpread"
 	| "lint/performance/noDelete"
 	| "lint/security/noDangerouslySetInnerHtml"
@@ -2028,10 +2207,12 @@ export type Category =
 	| "ci"
 	| "configuration"
 	| "organizeImports"
+	| "sortKeys"
 	| "migrate"
 	| "deserialize"
 	| "project"
 	| "search"
+	| "jsonSchema"
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
@@ -2052,6 +2233,8 @@ export type Category =
 	| "suppressions/unknownGroup"
 	| "suppressions/unknownRule"
 	| "suppressions/unused"
+	| "suppressions/unmatched"
+	| "suppressions/misplaced"
 	| "suppressions/deprecatedSuppressionComment"
 	| "args/fileNotFound"
 	| "flags/invalid"
@@ -2233,11 +2416,22 @@ export interface FixFileParams {
 	fix_file_mode: FixFileMode;
 	path: BiomePath;
 	should_format: boolean;
+	/**
+	 * The rules whose diagnostics are suppressed with [FixFileMode::ApplySuppressions], written as `<GROUP>/<RULE>`. The diagnostics of all the rules are suppressed when it's empty
+	 */
+	suppressed_rules: string[];
+	/**
+	 * The reason written in the suppression comments added with [FixFileMode::ApplySuppressions]
+	 */
+	suppression_reason?: string;
 }
 /**
  * Which fixes should be applied during the analyzing phase
  */
-export type FixFileMode = "SafeFixes" | "SafeAndUnsafeFixes";
+export type FixFileMode =
+	| "SafeFixes"
+	| "SafeAndUnsafeFixes"
+	| "ApplySuppressions";
 export interface FixFileResult {
 	/**
 	 * List of all the code actions applied to the file
@@ -2286,6 +2480,17 @@ export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<SupportsFeatureResult>;
 	updateSettings(params: UpdateSettingsParams): Promise<void>;
 	updateCurrentProject(params: UpdateProjectParams): Promise<void>;
+	registerProjectConfiguration(
+		params: RegisterProjectConfigurationParams,
+	): Promise<void>;
+	registerModule(params: RegisterModuleParams): Promise<void>;
+	isModuleGraphEnabled(params: IsModuleGraphEnabledParams): Promise<boolean>;
+	isNodeJsWorkspaceEnabled(
+		params: IsNodeJsWorkspaceEnabledParams,
+	): Promise<boolean>;
+	getJsonSchemaPath(
+		params: GetJsonSchemaPathParams,
+	): Promise<GetJsonSchemaPathResult>;
 	openProject(params: OpenProjectParams): Promise<void>;
 	openFile(params: OpenFileParams): Promise<void>;
 	changeFile(params: ChangeFileParams): Promise<void>;
@@ -2319,6 +2524,21 @@ export function createWorkspace(transport: Transport): Workspace {
 		updateCurrentProject(params) {
 			return transport.request("biome/update_current_project", params);
 		},
+		registerProjectConfiguration(params) {
+			return transport.request("biome/register_project_configuration", params);
+		},
+		registerModule(params) {
+			return transport.request("biome/register_module", params);
+		},
+		isModuleGraphEnabled(params) {
+			return transport.request("biome/is_module_graph_enabled", params);
+		},
+		isNodeJsWorkspaceEnabled(params) {
+			return transport.request("biome/is_node_js_workspace_enabled", params);
+		},
+		getJsonSchemaPath(params) {
+			return transport.request("biome/get_json_schema_path", params);
+		},
 		openProject(params) {
 			return transport.request("biome/open_project", params);
 		},
@@ -4,7 +4,7 @@ use biome_css_formatter::context::{CssFormatContext, CssFormatOptions};
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssSyntaxNode;
 use biome_formatter::{FormatResult, Formatted, PrintResult, Printed};
-use biome_js_analyze::analyze;
+use biome_js_analyze::{analyze, JsAnalyzerServices};
 use biome_js_formatter::context::{JsFormatContext, JsFormatOptions};
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{AnyJsRoot, JsFileSource, JsSyntaxNode};
@@ -166,7 +166,7 @@ impl Analyze {
                     filter,
                     &options,
                     JsFileSource::default(),
-                    None,
+                    JsAnalyzerServices::default(),
                     |event| {
                         black_box(event.diagnostic());
                         black_box(event.actions());
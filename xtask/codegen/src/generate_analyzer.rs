@@ -35,8 +35,11 @@ fn generate_json_analyzer() -> Result<()> {
     let mut analyzers = BTreeMap::new();
     generate_category("lint", &mut analyzers, &base_path)?;
 
+    let mut assists = BTreeMap::new();
+    generate_category("assists", &mut assists, &base_path)?;
+
     generate_options(&base_path)?;
-    update_json_registry_builder(analyzers)
+    update_json_registry_builder(analyzers, assists)
 }
 
 fn generate_css_analyzer() -> Result<()> {
@@ -236,10 +239,16 @@ fn update_js_registry_builder(
     Ok(())
 }
 
-fn update_json_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+fn update_json_registry_builder(
+    analyzers: BTreeMap<&'static str, TokenStream>,
+    assists: BTreeMap<&'static str, TokenStream>,
+) -> Result<()> {
     let path = project_root().join("crates/biome_json_analyze/src/registry.rs");
 
-    let categories = analyzers.into_values();
+    let categories = analyzers
+        .into_iter()
+        .chain(assists)
+        .map(|(_, tokens)| tokens);
 
     let tokens = xtask::reformat(quote! {
         use biome_analyze::RegistryVisitor;
@@ -726,7 +726,7 @@ fn assert_lint(
                     filter,
                     &options,
                     source_type,
-                    None,
+                    biome_js_analyze::JsAnalyzerServices::default(),
                     |signal| {
                         if let Some(mut diag) = signal.diagnostic() {
                             let category = diag.category().expect("linter diagnostic has no code");